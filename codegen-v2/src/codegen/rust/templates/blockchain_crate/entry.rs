@@ -10,10 +10,13 @@ use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
-use tw_coin_entry::modules::json_signer::NoJsonSigner;
+use tw_coin_entry::modules::json_signer::ProtoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
+use tw_coin_entry::modules::transaction_hasher::NoTransactionHasher;
+use tw_coin_entry::modules::transaction_summarizer::NoTransactionSummarizer;
+use tw_coin_entry::modules::transaction_verifier::NoTransactionVerifier;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_keypair::tw::PublicKey;
@@ -30,11 +33,14 @@ impl CoinEntry for {BLOCKCHAIN}Entry {
     type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
 
     // Optional modules:
-    type JsonSigner = NoJsonSigner;
+    type JsonSigner = ProtoJsonSigner<Self>;
     type PlanBuilder = NoPlanBuilder;
     type MessageSigner = NoMessageSigner;
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
+    type TransactionVerifier = NoTransactionVerifier;
+    type TransactionHasher = NoTransactionHasher;
+    type TransactionSummarizer = NoTransactionSummarizer;
 
     #[inline]
     fn parse_address(
@@ -90,4 +96,9 @@ impl CoinEntry for {BLOCKCHAIN}Entry {
     ) -> Self::SigningOutput {
         {BLOCKCHAIN}Compiler::compile(coin, input, signatures, public_keys)
     }
+
+    #[inline]
+    fn json_signer(&self) -> Option<Self::JsonSigner> {
+        Some(ProtoJsonSigner::new({BLOCKCHAIN}Entry))
+    }
 }
@@ -6,6 +6,7 @@
 #include "TWBase.h"
 #include "TWCoinType.h"
 #include "TWData.h"
+#include "TWDataVector.h"
 #include "TWString.h"
 
 TW_EXTERN_C_BEGIN
@@ -20,6 +21,15 @@ struct TWAnySigner;
 /// \return The serialized data of a `SigningOutput` proto object. (e.g. TW.Bitcoin.Proto.SigningOutput).
 extern TWData *_Nonnull TWAnySignerSign(TWData *_Nonnull input, enum TWCoinType coin);
 
+/// Signs multiple transactions of the same coin type.
+/// Every input is signed independently, so one invalid input does not fail the rest of the batch.
+///
+/// \param inputs The serialized data of signing inputs (e.g. TW.Ethereum.Proto.SigningInput).
+/// \param coin The given coin type to sign the transactions for.
+/// \return The serialized data of a `TW.BatchSigner.Proto.BatchSigningOutput` proto object
+/// that contains a signing result per input in the same order.
+extern TWData *_Nonnull TWAnySignerSignBatch(const struct TWDataVector *_Nonnull inputs, enum TWCoinType coin);
+
 /// Signs a transaction specified by the JSON representation of signing input, coin type and a private key, returning the JSON representation of the signing output.
 ///
 /// \param json JSON representation of a signing input
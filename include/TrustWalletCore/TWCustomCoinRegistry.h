@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+#include "TWString.h"
+
+TW_EXTERN_C_BEGIN
+
+/// Registry of coins added at runtime, e.g. a new EVM L2 or a Cosmos appchain, without a new release of the library.
+TW_EXPORT_STRUCT
+struct TWCustomCoinRegistry;
+
+/// Registers a custom coin of an account-based blockchain supported in Rust, e.g. `Ethereum` or `Cosmos`.
+/// A custom coin previously registered with the same coin type is replaced.
+///
+/// The coin type can be used with `TWAnySigner` (except JSON signing), `TWAnyAddress` and `TWTransactionCompiler`.
+/// Please note that `TWCoinType` info accessors like `TWCoinTypeDerivationPath` or `TWCoinTypeChainId` return defaults for custom coins,
+/// so the coin item must be stored by the app, and signatures must be compiled via `TWTransactionCompilerCompileWithSignaturesAndPubKeyType`.
+///
+/// \param itemJson coin item in the `registry.json` format, e.g. `{"coinId":90000001,"blockchain":"Ethereum","chainId":"8453",...}`.
+/// \return true if the coin has been registered, false if the item is invalid or conflicts with a built-in coin type.
+TW_EXPORT_STATIC_METHOD
+bool TWCustomCoinRegistryRegister(TWString *_Nonnull itemJson);
+
+/// Unregisters a custom coin previously registered via `TWCustomCoinRegistryRegister`.
+///
+/// \param coinType coin type of the custom coin.
+/// \return true if the coin has been unregistered, false if there is no such custom coin.
+TW_EXPORT_STATIC_METHOD
+bool TWCustomCoinRegistryUnregister(uint32_t coinType);
+
+TW_EXTERN_C_END
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+#include "TWCoinType.h"
+#include "TWData.h"
+#include "TWString.h"
+
+TW_EXTERN_C_BEGIN
+
+TW_EXPORT_STRUCT
+struct TWTransactionHasher;
+
+/// Computes a transaction hash (ID) of a signed transaction.
+///
+/// \param coin coin type.
+/// \param signedTx signed transaction bytes.
+/// \return transaction hash in the chain-specific format, or null if the transaction cannot be hashed.
+TW_EXPORT_STATIC_METHOD
+TWString *_Nullable TWTransactionHasherTxId(enum TWCoinType coinType, TWData *_Nonnull signedTx);
+
+TW_EXTERN_C_END
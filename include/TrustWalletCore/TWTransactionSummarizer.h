@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+#include "TWCoinType.h"
+#include "TWData.h"
+
+TW_EXTERN_C_BEGIN
+
+TW_EXPORT_STRUCT
+struct TWTransactionSummarizer;
+
+/// Describes what a transaction specified by the signing input will do.
+///
+/// \param coin coin type.
+/// \param input the serialized data of a signing input (e.g. TW.Ethereum.Proto.SigningInput).
+/// \param tokens the serialized data of a `TW.TxSummary.Proto.TokenList` proto object
+///        used to format token amounts, may be empty.
+/// \return serialized data of a `TW.TxSummary.Proto.TransactionSummary` proto object.
+TW_EXPORT_STATIC_METHOD
+TWData *_Nonnull TWTransactionSummarizerSummarize(enum TWCoinType coinType, TWData *_Nonnull input, TWData *_Nonnull tokens);
+
+TW_EXTERN_C_END
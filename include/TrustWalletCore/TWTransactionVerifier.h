@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+#include "TWCoinType.h"
+#include "TWData.h"
+
+TW_EXTERN_C_BEGIN
+
+TW_EXPORT_STRUCT
+struct TWTransactionVerifier;
+
+/// Verifies signatures of a signed transaction.
+///
+/// \param coin coin type.
+/// \param input serialized `TxVerifier.Proto.VerifyingInput` protobuf message.
+/// \return serialized `TxVerifier.Proto.VerifyingOutput` protobuf message.
+TW_EXPORT_STATIC_METHOD
+TWData *_Nonnull TWTransactionVerifierVerify(enum TWCoinType coinType, TWData *_Nonnull input);
+
+TW_EXTERN_C_END
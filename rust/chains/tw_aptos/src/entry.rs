@@ -4,17 +4,20 @@
 
 use crate::address::Address;
 use crate::compiler::Compiler;
+use crate::message_signer::AptosMessageSigner;
 use crate::signer::Signer;
+use crate::transaction_decoder::AptosTransactionDecoder;
+use crate::transaction_hasher::AptosTransactionHasher;
+use crate::wallet_connect::connector::AptosWalletConnector;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
-use tw_coin_entry::modules::json_signer::NoJsonSigner;
-use tw_coin_entry::modules::message_signer::NoMessageSigner;
+use tw_coin_entry::modules::json_signer::ProtoJsonSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
-use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
+use tw_coin_entry::modules::transaction_summarizer::NoTransactionSummarizer;
+use tw_coin_entry::modules::transaction_verifier::NoTransactionVerifier;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_keypair::tw::PublicKey;
 use tw_proto::Aptos::Proto;
@@ -30,11 +33,14 @@ impl CoinEntry for AptosEntry {
     type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
 
     // Optional modules:
-    type JsonSigner = NoJsonSigner;
+    type JsonSigner = ProtoJsonSigner<Self>;
     type PlanBuilder = NoPlanBuilder;
-    type MessageSigner = NoMessageSigner;
-    type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type MessageSigner = AptosMessageSigner;
+    type WalletConnector = AptosWalletConnector;
+    type TransactionDecoder = AptosTransactionDecoder;
+    type TransactionVerifier = NoTransactionVerifier;
+    type TransactionHasher = AptosTransactionHasher;
+    type TransactionSummarizer = NoTransactionSummarizer;
 
     #[inline]
     fn parse_address(
@@ -95,11 +101,26 @@ impl CoinEntry for AptosEntry {
 
     #[inline]
     fn json_signer(&self) -> Option<Self::JsonSigner> {
-        None
+        Some(ProtoJsonSigner::new(AptosEntry))
     }
 
     #[inline]
     fn message_signer(&self) -> Option<Self::MessageSigner> {
-        None
+        Some(AptosMessageSigner)
+    }
+
+    #[inline]
+    fn wallet_connector(&self) -> Option<Self::WalletConnector> {
+        Some(AptosWalletConnector)
+    }
+
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(AptosTransactionDecoder)
+    }
+
+    #[inline]
+    fn transaction_hasher(&self) -> Option<Self::TransactionHasher> {
+        Some(AptosTransactionHasher)
     }
 }
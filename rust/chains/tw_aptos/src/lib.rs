@@ -12,7 +12,11 @@ pub mod nft;
 
 pub mod compiler;
 pub mod liquid_staking;
+pub mod message_signer;
 pub mod signer;
 pub mod transaction;
 pub mod transaction_builder;
+pub mod transaction_decoder;
+pub mod transaction_hasher;
 pub mod transaction_payload;
+pub mod wallet_connect;
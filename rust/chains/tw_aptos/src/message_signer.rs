@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use std::borrow::Cow;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::hex::{self, DecodeHex};
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
+use tw_proto::Aptos::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+/// The prefix of every AIP-62 full message.
+const FULL_MESSAGE_PREFIX: &str = "APTOS";
+
+/// AIP-62 `signMessage` fields the full message is built from.
+struct FullMessage<'a> {
+    address: Option<String>,
+    application: &'a str,
+    chain_id: u32,
+    message: &'a str,
+    nonce: &'a str,
+}
+
+impl<'a> FullMessage<'a> {
+    /// Returns `APTOS\naddress: ..\napplication: ..\nchainId: ..\nmessage: ..\nnonce: ..`,
+    /// where `address`, `application` and `chainId` are included only if set.
+    fn build(&self) -> String {
+        let mut full_message = FULL_MESSAGE_PREFIX.to_string();
+        if let Some(ref address) = self.address {
+            full_message.push_str(&format!("\naddress: {address}"));
+        }
+        if !self.application.is_empty() {
+            full_message.push_str(&format!("\napplication: {}", self.application));
+        }
+        if self.chain_id != 0 {
+            full_message.push_str(&format!("\nchainId: {}", self.chain_id));
+        }
+        full_message.push_str(&format!("\nmessage: {}", self.message));
+        full_message.push_str(&format!("\nnonce: {}", self.nonce));
+        full_message
+    }
+}
+
+/// Signs and verifies wallet-standard `signMessage` requests according to AIP-62.
+/// https://github.com/aptos-foundation/AIPs/blob/main/aips/aip-62.md
+pub struct AptosMessageSigner;
+
+impl MessageSigner for AptosMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(input).unwrap_or_default()
+    }
+}
+
+impl AptosMessageSigner {
+    fn message_preimage_hashes_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        if input.include_address {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Cannot include the signer address without a private key");
+        }
+
+        let full_message = Self::signing_full_message(&input, None).build();
+        // There is no hashing for ed25519, so the full message is signed as is.
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::from(full_message.as_bytes().to_vec()),
+            data_hash: Cow::from(full_message.into_bytes()),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+
+        let signer_address = if input.include_address {
+            Some(Address::with_ed25519_pubkey(key_pair.public())?)
+        } else {
+            None
+        };
+        let full_message = Self::signing_full_message(&input, signer_address).build();
+
+        let signature = key_pair.sign(full_message.as_bytes().to_vec())?;
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::from(hex::encode(signature.to_bytes(), false)),
+            full_message: Cow::from(full_message),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(input: Proto::MessageVerifyingInput<'_>) -> SigningResult<bool> {
+        let public_key = ed25519::sha512::PublicKey::try_from(input.public_key.as_ref())?;
+
+        let address = if input.address.is_empty() {
+            None
+        } else {
+            let expected_address = Address::from_str(&input.address)?;
+            let signer_address = Address::with_ed25519_pubkey(&public_key)?;
+            if expected_address.inner() != signer_address.inner() {
+                return Ok(false);
+            }
+            // Use the address as it is, because the dApp may have displayed it in a short form.
+            Some(input.address.to_string())
+        };
+
+        let full_message = FullMessage {
+            address,
+            application: &input.application,
+            chain_id: input.chain_id,
+            message: &input.message,
+            nonce: &input.nonce,
+        }
+        .build();
+
+        let signature_bytes = input
+            .signature
+            .decode_hex()
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Invalid hex signature")?;
+        let signature = ed25519::Signature::try_from(signature_bytes.as_slice())?;
+
+        Ok(public_key.verify(signature, full_message.into_bytes()))
+    }
+
+    fn signing_full_message<'a>(
+        input: &'a Proto::MessageSigningInput<'_>,
+        signer_address: Option<Address>,
+    ) -> FullMessage<'a> {
+        FullMessage {
+            // AIP-40 long form, e.g. `0x07968dab..`.
+            address: signer_address.map(|addr| hex::encode(addr.inner().to_vec(), true)),
+            application: &input.application,
+            chain_id: input.chain_id,
+            message: &input.message,
+            nonce: &input.nonce,
+        }
+    }
+}
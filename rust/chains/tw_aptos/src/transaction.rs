@@ -5,7 +5,7 @@
 use crate::constants::APTOS_SALT;
 use crate::transaction_payload::TransactionPayload;
 use move_core_types::account_address::AccountAddress;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::borrow::Cow;
 use tw_coin_entry::error::prelude::*;
@@ -16,7 +16,7 @@ use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
 use tw_memory::Data;
 use tw_proto::Aptos::Proto;
 
-#[derive(Clone, Serialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum TransactionAuthenticator {
     /// Single Ed25519 signature
     Ed25519 {
@@ -68,7 +68,7 @@ impl TransactionAuthenticator {
 }
 
 /// RawTransaction is the portion of a transaction that a client signs.
-#[derive(Clone, Serialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct RawTransaction {
     /// Sender's address.
     sender: AccountAddress,
@@ -178,6 +178,22 @@ impl RawTransaction {
             "sequence_number": self.sequence_number.to_string()
         })
     }
+
+    pub fn to_proto(&self) -> SigningResult<Proto::RawTransaction<'static>> {
+        let TransactionPayload::EntryFunction(ref entry_function) = self.payload else {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Only EntryFunction payloads are supported");
+        };
+        Ok(Proto::RawTransaction {
+            sender: self.sender.to_hex_literal().into(),
+            sequence_number: self.sequence_number,
+            max_gas_amount: self.max_gas_amount,
+            gas_unit_price: self.gas_unit_price,
+            expiration_timestamp_secs: self.expiration_timestamp_secs,
+            chain_id: self.chain_id as u32,
+            entry_function: Some(entry_function.to_proto()),
+        })
+    }
 }
 
 /// A transaction that has been signed.
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::constants::APTOS_SALT;
+use crate::transaction::{RawTransaction, TransactionAuthenticator};
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::bcs;
+use tw_proto::Aptos::Proto;
+
+pub struct AptosTransactionDecoder;
+
+impl TransactionDecoder for AptosTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, _coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl AptosTransactionDecoder {
+    fn decode_transaction_impl(
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        // The transaction can be prefixed with the `APTOS::RawTransaction` salt hash.
+        let salt = tw_hash::sha3::sha3_256(APTOS_SALT);
+        let tx = tx.strip_prefix(salt.as_slice()).unwrap_or(tx);
+
+        // Try to decode the transaction as a `RawTransaction` first, then as a `SignedTransaction`.
+        let (raw_txn, authenticator) = match bcs::decode::<RawTransaction>(tx) {
+            Ok(raw_txn) => (raw_txn, None),
+            Err(_) => {
+                let (raw_txn, authenticator): (RawTransaction, TransactionAuthenticator) =
+                    bcs::decode(tx)
+                        .tw_err(|_| SigningErrorType::Error_input_parse)
+                        .context(
+                            "Error decoding BCS-encoded RawTransaction or SignedTransaction",
+                        )?;
+                (raw_txn, Some(authenticator))
+            },
+        };
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(raw_txn.to_proto()?),
+            authenticator: authenticator.map(Proto::TransactionAuthenticator::from),
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::constants::APTOS_TRANSACTION_SALT;
+use crate::transaction::{RawTransaction, TransactionAuthenticator};
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_hasher::TransactionHasher;
+use tw_encoding::bcs;
+use tw_encoding::hex::ToHex;
+use tw_hash::sha3::sha3_256;
+
+/// `Transaction::UserTransaction` enum variant index.
+const USER_TRANSACTION_VARIANT: u8 = 0;
+
+/// Computes the transaction hash as `SHA3-256(SHA3-256("APTOS::Transaction") || bcs(Transaction::UserTransaction(signed_tx)))`.
+pub struct AptosTransactionHasher;
+
+impl TransactionHasher for AptosTransactionHasher {
+    fn tx_id(&self, _coin: &dyn CoinContext, signed_tx: &[u8]) -> SigningResult<String> {
+        // Make sure the given bytes are a valid BCS-encoded `SignedTransaction`.
+        let _: (RawTransaction, TransactionAuthenticator) = bcs::decode(signed_tx)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding BCS-encoded SignedTransaction")?;
+
+        let mut preimage = sha3_256(APTOS_TRANSACTION_SALT);
+        preimage.push(USER_TRANSACTION_VARIANT);
+        preimage.extend_from_slice(signed_tx);
+        Ok(sha3_256(&preimage).to_hex_prefixed())
+    }
+}
@@ -9,6 +9,7 @@ use move_core_types::parser::parse_transaction_argument;
 use move_core_types::transaction_argument::TransactionArgument;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use std::borrow::Cow;
 use std::default::Default;
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
@@ -49,7 +50,7 @@ pub struct EntryFunction {
     ty_args: Vec<TypeTag>,
     #[serde(with = "vec_bytes")]
     args: Vec<Vec<u8>>,
-    #[serde(skip_serializing)]
+    #[serde(skip)]
     json_args: Value,
 }
 
@@ -156,6 +157,20 @@ impl EntryFunction {
             "type_arguments": type_arguments
         })
     }
+
+    pub fn to_proto(&self) -> Aptos::Proto::EntryFunction<'static> {
+        Aptos::Proto::EntryFunction {
+            module_address: self.module.address().to_hex_literal().into(),
+            module_name: self.module.name().to_string().into(),
+            function: self.function.to_string().into(),
+            type_arguments: self
+                .ty_args
+                .iter()
+                .map(|item| item.to_string().into())
+                .collect(),
+            arguments: self.args.iter().cloned().map(Cow::from).collect(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
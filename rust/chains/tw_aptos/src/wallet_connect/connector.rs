@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use crate::wallet_connect::request::{SignMessageRequest, SignTransactionRequest};
+use serde::de::DeserializeOwned;
+use serde_json::Value as Json;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::wallet_connector::WalletConnector;
+use tw_coin_entry::signing_output_error;
+use tw_proto::Aptos::Proto;
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+
+pub struct AptosWalletConnector;
+
+impl WalletConnector for AptosWalletConnector {
+    fn parse_request(
+        &self,
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> WCProto::ParseRequestOutput<'static> {
+        Self::parse_request_impl(coin, request)
+            .unwrap_or_else(|e| signing_output_error!(WCProto::ParseRequestOutput, e))
+    }
+}
+
+impl AptosWalletConnector {
+    fn parse_request_impl(
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        match request.method {
+            WCProto::Method::AptosSignTransaction => {
+                Self::parse_sign_transaction_request(coin, request)
+            },
+            WCProto::Method::AptosSignMessage => Self::parse_sign_message_request(coin, request),
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Unknown WalletConnect method"),
+        }
+    }
+
+    pub fn parse_sign_transaction_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let SignTransactionRequest { transaction: tx } = parse_params(&request.payload)?;
+
+        Address::from_str(&tx.sender)
+            .into_tw()
+            .context("Invalid sender address")?;
+        if !matches!(tx.payload, Json::Object(_)) {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Expected the transaction payload as a JSON object");
+        }
+        let chain_id = tx
+            .chain_id
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("No 'chain_id' specified")?;
+
+        // The entry function payload is signed as is.
+        let signing_input = Proto::SigningInput {
+            sender: tx.sender.into(),
+            sequence_number: tx.sequence_number,
+            max_gas_amount: tx.max_gas_amount,
+            gas_unit_price: tx.gas_unit_price,
+            expiration_timestamp_secs: tx.expiration_timestamp_secs,
+            chain_id,
+            any_encoded: tx.payload.to_string().into(),
+            ..Proto::SigningInput::default()
+        };
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::aptos(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+
+    /// Please note that `application` and `chain_id` must be completed by the caller
+    /// if the dApp requested to include them, as their values are not a part of the request.
+    pub fn parse_sign_message_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let msg: SignMessageRequest = parse_params(&request.payload)?;
+
+        let signing_input = Proto::MessageSigningInput {
+            message: msg.message.into(),
+            nonce: msg.nonce.into(),
+            include_address: msg.address,
+            ..Proto::MessageSigningInput::default()
+        };
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::aptos_message(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(payload: &str) -> SigningResult<T> {
+    serde_json::from_str(payload)
+        .tw_err(|_| SigningErrorType::Error_input_parse)
+        .context("Error parsing WalletConnect request params as JSON")
+}
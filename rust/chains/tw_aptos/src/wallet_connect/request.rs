@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::Deserialize;
+use serde_json::Value as Json;
+use tw_misc::serde::as_string;
+
+/// `aptos_signTransaction` request params.
+#[derive(Deserialize)]
+pub struct SignTransactionRequest {
+    pub transaction: TransactionJson,
+}
+
+/// An unsigned transaction in the Aptos REST API JSON format.
+/// All integers are decimal strings.
+#[derive(Deserialize)]
+pub struct TransactionJson {
+    pub sender: String,
+    #[serde(with = "as_string")]
+    pub sequence_number: i64,
+    #[serde(with = "as_string")]
+    pub max_gas_amount: u64,
+    #[serde(with = "as_string")]
+    pub gas_unit_price: u64,
+    #[serde(with = "as_string")]
+    pub expiration_timestamp_secs: u64,
+    /// Not a part of the REST API format, but required to sign the transaction.
+    pub chain_id: Option<u32>,
+    /// `entry_function_payload` object.
+    pub payload: Json,
+}
+
+/// `aptos_signMessage` request params according to AIP-62.
+#[derive(Deserialize)]
+pub struct SignMessageRequest {
+    pub message: String,
+    pub nonce: String,
+    /// Whether the signer address should be included into the full message.
+    #[serde(default)]
+    pub address: bool,
+}
@@ -57,6 +57,30 @@ impl AminoEncoder {
     }
 }
 
+pub struct AminoDecoder;
+
+impl AminoDecoder {
+    /// Reads the content size prefix and returns the content.
+    /// Returns an error if the actual content size doesn't match the prefix.
+    pub fn decode_size_prefixed(data: &[u8]) -> EncodingResult<&[u8]> {
+        let mut reader = quick_protobuf::BytesReader::from_bytes(data);
+        let content = reader
+            .read_bytes(data)
+            .map_err(|_| EncodingError::InvalidInput)?;
+        if !reader.is_eof() {
+            return Err(EncodingError::InvalidInput);
+        }
+        Ok(content)
+    }
+
+    /// Checks whether the Amino content starts with the given `prefix` and returns the rest of the content.
+    pub fn strip_prefix<'a>(content: &'a [u8], prefix: &[u8]) -> EncodingResult<&'a [u8]> {
+        content
+            .strip_prefix(prefix)
+            .ok_or(EncodingError::InvalidInput)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,4 +146,19 @@ mod tests {
             expected: "dc020b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0b0c0d0e0f101112131415161718191a1b1c1d1e0102030405060708",
         });
     }
+
+    #[test]
+    fn test_amino_decode_size_prefixed() {
+        let data = "0c0b0c0d0e0102030405060708".decode_hex().unwrap();
+        let content = AminoDecoder::decode_size_prefixed(&data).unwrap();
+        let content = AminoDecoder::strip_prefix(content, &[0x0b, 0x0c, 0x0d, 0x0e]).unwrap();
+        assert_eq!(content, "0102030405060708".decode_hex().unwrap());
+
+        // The content is shorter than the size prefix.
+        let data = "0d0b0c0d0e0102030405060708".decode_hex().unwrap();
+        AminoDecoder::decode_size_prefixed(&data).unwrap_err();
+        // The content is longer than the size prefix.
+        let data = "0b0b0c0d0e0102030405060708".decode_hex().unwrap();
+        AminoDecoder::decode_size_prefixed(&data).unwrap_err();
+    }
 }
@@ -4,6 +4,7 @@
 
 use crate::address::BinanceAddress;
 use crate::compiler::BinanceCompiler;
+use crate::modules::transaction_decoder::BinanceTransactionDecoder;
 use crate::modules::wallet_connect::connector::BinanceWalletConnector;
 use crate::signer::BinanceSigner;
 use std::str::FromStr;
@@ -12,10 +13,12 @@ use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
-use tw_coin_entry::modules::json_signer::NoJsonSigner;
+use tw_coin_entry::modules::json_signer::ProtoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
+use tw_coin_entry::modules::transaction_hasher::NoTransactionHasher;
+use tw_coin_entry::modules::transaction_summarizer::NoTransactionSummarizer;
+use tw_coin_entry::modules::transaction_verifier::NoTransactionVerifier;
 use tw_keypair::tw::PublicKey;
 use tw_proto::Binance::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
@@ -30,11 +33,14 @@ impl CoinEntry for BinanceEntry {
     type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
 
     // Optional modules:
-    type JsonSigner = NoJsonSigner;
+    type JsonSigner = ProtoJsonSigner<Self>;
     type PlanBuilder = NoPlanBuilder;
     type MessageSigner = NoMessageSigner;
     type WalletConnector = BinanceWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type TransactionDecoder = BinanceTransactionDecoder;
+    type TransactionVerifier = NoTransactionVerifier;
+    type TransactionHasher = NoTransactionHasher;
+    type TransactionSummarizer = NoTransactionSummarizer;
 
     #[inline]
     fn parse_address(
@@ -91,8 +97,18 @@ impl CoinEntry for BinanceEntry {
         BinanceCompiler::compile(coin, input, signatures, public_keys)
     }
 
+    #[inline]
+    fn json_signer(&self) -> Option<Self::JsonSigner> {
+        Some(ProtoJsonSigner::new(BinanceEntry))
+    }
+
     #[inline]
     fn wallet_connector(&self) -> Option<Self::WalletConnector> {
         Some(BinanceWalletConnector)
     }
+
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(BinanceTransactionDecoder)
+    }
 }
@@ -4,5 +4,6 @@
 
 pub mod preimager;
 pub mod serializer;
+pub mod transaction_decoder;
 pub mod tx_builder;
 pub mod wallet_connect;
@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::amino::AminoDecoder;
+use crate::modules::serializer::{PUBLIC_KEY_PREFIX, TRANSACTION_AMINO_PREFIX};
+use crate::modules::tx_builder::TxBuilder;
+use crate::transaction::message::htlt_order::{
+    ClaimHTLTOrder, DepositHTLTOrder, HTLTOrder, RefundHTLTOrder,
+};
+use crate::transaction::message::send_order::SendOrder;
+use crate::transaction::message::side_chain_delegate::{
+    SideDelegateOrderValue, SideRedelegateOrderValue, SideUndelegateOrderValue,
+    StakeMigrationOrderValue,
+};
+use crate::transaction::message::time_lock_order::{
+    TimeLockOrder, TimeRelockOrder, TimeUnlockOrder,
+};
+use crate::transaction::message::token_order::{
+    TokenBurnOrder, TokenFreezeOrder, TokenIssueOrder, TokenMintOrder, TokenUnfreezeOrder,
+};
+use crate::transaction::message::trade_order::{CancelTradeOrder, NewTradeOrder};
+use crate::transaction::message::tranfer_out_order::TransferOutOrder;
+use crate::transaction::message::{BinanceMessageEnum, TWBinanceProto};
+use crate::transaction::UnsignedTransaction;
+use quick_protobuf::MessageRead;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_proto::deserialize;
+use tw_proto::Binance::Proto;
+
+/// Length of a message Amino prefix.
+const MSG_PREFIX_LEN: usize = 4;
+
+pub struct BinanceTransactionDecoder;
+
+impl TransactionDecoder for BinanceTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(coin, tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl BinanceTransactionDecoder {
+    fn decode_transaction_impl(
+        coin: &dyn CoinContext,
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let content = AminoDecoder::decode_size_prefixed(tx)
+            .and_then(|content| AminoDecoder::strip_prefix(content, &TRANSACTION_AMINO_PREFIX))
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Invalid Amino-encoded StdTx")?;
+        let tx: Proto::Transaction = Self::deserialize_proto(content)?;
+
+        let msgs = tx
+            .msgs
+            .iter()
+            .map(|msg| Self::decode_msg(coin, msg))
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        // A signed transaction contains exactly one signature.
+        let signature = match tx.signatures.first() {
+            Some(signature) => Some(Self::deserialize_proto::<Proto::Signature>(signature)?),
+            None => None,
+        };
+
+        let unsigned = UnsignedTransaction {
+            account_number: signature.as_ref().map_or(0, |sign| sign.account_number),
+            // Chain ID is not a part of the encoded transaction.
+            chain_id: String::default(),
+            data: (!tx.data.is_empty()).then(|| tx.data.to_vec()),
+            memo: tx.memo.to_string(),
+            msgs,
+            sequence: signature.as_ref().map_or(0, |sign| sign.sequence),
+            source: tx.source,
+        };
+        let transaction = TxBuilder::unsigned_tx_to_proto(&unsigned)?;
+
+        let (public_key, signature) = match signature {
+            Some(signature) => (
+                Self::decode_public_key(&signature.pub_key)?,
+                signature.signature.to_vec(),
+            ),
+            None => Default::default(),
+        };
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(transaction),
+            public_key: Cow::from(public_key),
+            signature: Cow::from(signature),
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+
+    /// Decodes an Amino-encoded message by its prefix.
+    fn decode_msg(coin: &dyn CoinContext, msg: &[u8]) -> SigningResult<BinanceMessageEnum> {
+        use BinanceMessageEnum as Msg;
+
+        if msg.len() < MSG_PREFIX_LEN {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Transaction message is too short");
+        }
+        let (prefix, content) = msg.split_at(MSG_PREFIX_LEN);
+        let prefix: [u8; MSG_PREFIX_LEN] = prefix
+            .try_into()
+            .expect("Prefix length has been checked already");
+
+        match prefix {
+            HTLTOrder::PREFIX => Self::decode_typed_msg(coin, content).map(Msg::HTLTOrder),
+            DepositHTLTOrder::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::DepositHTLTOrder)
+            },
+            ClaimHTLTOrder::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::ClaimHTLTOrder)
+            },
+            RefundHTLTOrder::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::RefundHTLTOrder)
+            },
+            SendOrder::PREFIX => Self::decode_typed_msg(coin, content).map(Msg::SendOrder),
+            SideDelegateOrderValue::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::SideDelegateOrder)
+            },
+            SideRedelegateOrderValue::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::SideRedelegateOrder)
+            },
+            SideUndelegateOrderValue::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::SideUndelegateOrder)
+            },
+            StakeMigrationOrderValue::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::StakeMigrationOrder)
+            },
+            TimeLockOrder::PREFIX => Self::decode_typed_msg(coin, content).map(Msg::TimeLockOrder),
+            TimeRelockOrder::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::TimeRelockOrder)
+            },
+            TimeUnlockOrder::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::TimeUnlockOrder)
+            },
+            TokenFreezeOrder::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::TokenFreezeOrder)
+            },
+            TokenUnfreezeOrder::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::TokenUnfreezeOrder)
+            },
+            TokenIssueOrder::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::TokenIssueOrder)
+            },
+            TokenMintOrder::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::TokenMintOrder)
+            },
+            TokenBurnOrder::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::TokenBurnOrder)
+            },
+            NewTradeOrder::PREFIX => Self::decode_typed_msg(coin, content).map(Msg::NewTradeOrder),
+            CancelTradeOrder::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::CancelTradeOrder)
+            },
+            TransferOutOrder::PREFIX => {
+                Self::decode_typed_msg(coin, content).map(Msg::TransferOutOrder)
+            },
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Unknown transaction message prefix"),
+        }
+    }
+
+    fn decode_typed_msg<'a, T>(coin: &dyn CoinContext, content: &'a [u8]) -> SigningResult<T>
+    where
+        T: TWBinanceProto,
+        T::Proto<'a>: MessageRead<'a>,
+    {
+        let msg = Self::deserialize_proto(content)?;
+        T::from_tw_proto(coin, &msg)
+    }
+
+    /// Decodes an Amino-encoded public key.
+    fn decode_public_key(pub_key: &[u8]) -> SigningResult<Vec<u8>> {
+        let (public_key_len, public_key) = AminoDecoder::strip_prefix(pub_key, &PUBLIC_KEY_PREFIX)
+            .ok()
+            .and_then(|rest| rest.split_first())
+            .or_tw_err(SigningErrorType::Error_input_parse)
+            .context("Invalid Amino-encoded public key")?;
+        if *public_key_len as usize != public_key.len() {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Invalid Amino-encoded public key length");
+        }
+        Ok(public_key.to_vec())
+    }
+
+    fn deserialize_proto<'a, M: MessageRead<'a>>(data: &'a [u8]) -> SigningResult<M> {
+        deserialize(data)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error deserializing Amino message content")
+    }
+}
@@ -7,15 +7,18 @@ use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
-use tw_coin_entry::modules::json_signer::NoJsonSigner;
-use tw_coin_entry::modules::message_signer::NoMessageSigner;
+use tw_coin_entry::modules::json_signer::ProtoJsonSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
-use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_cosmos_sdk::address::{Address, Bech32Prefix};
 use tw_cosmos_sdk::context::StandardCosmosContext;
 use tw_cosmos_sdk::modules::compiler::tw_compiler::TWTransactionCompiler;
+use tw_cosmos_sdk::modules::message_signer::CosmosMessageSigner;
 use tw_cosmos_sdk::modules::signer::tw_signer::TWSigner;
+use tw_cosmos_sdk::modules::transaction_decoder::CosmosTransactionDecoder;
+use tw_cosmos_sdk::modules::transaction_hasher::CosmosTransactionHasher;
+use tw_cosmos_sdk::modules::transaction_summarizer::CosmosTransactionSummarizer;
+use tw_cosmos_sdk::modules::transaction_verifier::CosmosTransactionVerifier;
+use tw_cosmos_sdk::modules::wallet_connect::connector::CosmosWalletConnector;
 use tw_keypair::tw;
 use tw_proto::Cosmos::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
@@ -30,11 +33,14 @@ impl CoinEntry for CosmosEntry {
     type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
 
     // Optional modules:
-    type JsonSigner = NoJsonSigner;
+    type JsonSigner = ProtoJsonSigner<Self>;
     type PlanBuilder = NoPlanBuilder;
-    type MessageSigner = NoMessageSigner;
-    type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type MessageSigner = CosmosMessageSigner<StandardCosmosContext>;
+    type WalletConnector = CosmosWalletConnector;
+    type TransactionDecoder = CosmosTransactionDecoder;
+    type TransactionVerifier = CosmosTransactionVerifier<StandardCosmosContext>;
+    type TransactionHasher = CosmosTransactionHasher;
+    type TransactionSummarizer = CosmosTransactionSummarizer;
 
     #[inline]
     fn parse_address(
@@ -95,4 +101,39 @@ impl CoinEntry for CosmosEntry {
             public_keys,
         )
     }
+
+    #[inline]
+    fn json_signer(&self) -> Option<Self::JsonSigner> {
+        Some(ProtoJsonSigner::new(CosmosEntry))
+    }
+
+    #[inline]
+    fn message_signer(&self) -> Option<Self::MessageSigner> {
+        Some(CosmosMessageSigner::default())
+    }
+
+    #[inline]
+    fn wallet_connector(&self) -> Option<Self::WalletConnector> {
+        Some(CosmosWalletConnector)
+    }
+
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(CosmosTransactionDecoder)
+    }
+
+    #[inline]
+    fn transaction_verifier(&self) -> Option<Self::TransactionVerifier> {
+        Some(CosmosTransactionVerifier::default())
+    }
+
+    #[inline]
+    fn transaction_hasher(&self) -> Option<Self::TransactionHasher> {
+        Some(CosmosTransactionHasher)
+    }
+
+    #[inline]
+    fn transaction_summarizer(&self) -> Option<Self::TransactionSummarizer> {
+        Some(CosmosTransactionSummarizer)
+    }
 }
@@ -3,22 +3,48 @@
 // Copyright © 2017 Trust Wallet.
 
 use tw_coin_entry::coin_entry_ext::CoinEntryExt;
+use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::test_utils::test_context::TestCoinContext;
-use tw_encoding::hex::DecodeHex;
+use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_ethereum::entry::EthereumEntry;
+use tw_proto::json::json_to_message_data;
+use tw_proto::Ethereum::Proto;
 
 #[test]
 fn test_sign_json() {
     let coin = TestCoinContext::default();
 
-    let input_json = r#"{"chainId":"AQ==","gasPrice":"1pOkAA==","gasLimit":"Ugg=","toAddress":"0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1","transaction":{"transfer":{"amount":"A0i8paFgAA=="}}})"#;
+    let input_json = r#"{"chainId":"AQ==","gasPrice":"1pOkAA==","gasLimit":"Ugg=","toAddress":"0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1","transaction":{"transfer":{"amount":"A0i8paFgAA=="}}}"#;
     let private_key = "17209af590a86462395d5881e60d11c7fa7d482cfb02b5a01b93c2eeef243543"
         .decode_hex()
         .unwrap();
 
-    EthereumEntry
+    let output_json = EthereumEntry
         .sign_json(&coin, input_json, private_key)
-        .expect_err("'EthEntry::sign_json' is not supported yet");
+        .expect("!sign_json");
+    let output_json: serde_json::Value = serde_json::from_str(&output_json).unwrap();
 
-    // Expected result - "f86a8084d693a400825208947d8bf18c7ce84b3e175b339c4ca93aed1dd166f1870348bca5a160008025a0fe5802b49e04c6b1705088310e133605ed8b549811a18968ad409ea02ad79f21a05bf845646fb1e1b9365f63a7fd5eb5e984094e3ed35c3bed7361aebbcbf41f10"
+    let output_data = json_to_message_data::<Proto::SigningOutput>(&output_json).unwrap();
+    let output: Proto::SigningOutput = tw_proto::deserialize(&output_data).unwrap();
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "f86a8084d693a400825208947d8bf18c7ce84b3e175b339c4ca93aed1dd166f1870348bca5a160008025a0fe5802b49e04c6b1705088310e133605ed8b549811a18968ad409ea02ad79f21a05bf845646fb1e1b9365f63a7fd5eb5e984094e3ed35c3bed7361aebbcbf41f10";
+    assert_eq!(output.encoded.to_hex(), expected);
+}
+
+#[test]
+fn test_sign_json_invalid_field() {
+    let coin = TestCoinContext::default();
+
+    let input_json = r#"{"chainId":"AQ==","unknownField":"value"}"#;
+    let private_key = "17209af590a86462395d5881e60d11c7fa7d482cfb02b5a01b93c2eeef243543"
+        .decode_hex()
+        .unwrap();
+
+    let err = EthereumEntry
+        .sign_json(&coin, input_json, private_key)
+        .expect_err("'unknownField' must not be accepted");
+    assert_eq!(*err.error_type(), SigningErrorType::Error_input_parse);
 }
@@ -4,18 +4,21 @@
 
 use crate::address::GreenfieldAddress;
 use crate::compiler::GreenfieldCompiler;
+use crate::modules::transaction_decoder::GreenfieldTransactionDecoder;
 use crate::signer::GreenfieldSigner;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
-use tw_coin_entry::modules::json_signer::NoJsonSigner;
+use tw_coin_entry::modules::json_signer::ProtoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
+use tw_coin_entry::modules::transaction_summarizer::NoTransactionSummarizer;
+use tw_coin_entry::modules::transaction_verifier::NoTransactionVerifier;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
+use tw_cosmos_sdk::modules::transaction_hasher::CosmosTransactionHasher;
 use tw_keypair::tw::PublicKey;
 use tw_proto::Greenfield::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
@@ -30,11 +33,14 @@ impl CoinEntry for GreenfieldEntry {
     type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
 
     // Optional modules:
-    type JsonSigner = NoJsonSigner;
+    type JsonSigner = ProtoJsonSigner<Self>;
     type PlanBuilder = NoPlanBuilder;
     type MessageSigner = NoMessageSigner;
     type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type TransactionDecoder = GreenfieldTransactionDecoder;
+    type TransactionVerifier = NoTransactionVerifier;
+    type TransactionHasher = CosmosTransactionHasher;
+    type TransactionSummarizer = NoTransactionSummarizer;
 
     #[inline]
     fn parse_address(
@@ -93,4 +99,19 @@ impl CoinEntry for GreenfieldEntry {
     ) -> Self::SigningOutput {
         GreenfieldCompiler::compile(coin, input, signatures, public_keys)
     }
+
+    #[inline]
+    fn json_signer(&self) -> Option<Self::JsonSigner> {
+        Some(ProtoJsonSigner::new(GreenfieldEntry))
+    }
+
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(GreenfieldTransactionDecoder)
+    }
+
+    #[inline]
+    fn transaction_hasher(&self) -> Option<Self::TransactionHasher> {
+        Some(CosmosTransactionHasher)
+    }
 }
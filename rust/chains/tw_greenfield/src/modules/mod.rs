@@ -3,4 +3,5 @@
 // Copyright © 2017 Trust Wallet.
 
 pub mod eip712_signer;
+pub mod transaction_decoder;
 pub mod tx_builder;
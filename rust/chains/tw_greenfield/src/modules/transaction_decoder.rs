@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_cosmos_sdk::modules::transaction_decoder::{decode_any, is_type, CosmosTransactionDecoder};
+use tw_cosmos_sdk::proto::cosmos::base::v1beta1 as base_proto;
+use tw_cosmos_sdk::proto::cosmos::tx::v1beta1 as tx_proto;
+use tw_cosmos_sdk::proto::{cosmos, greenfield};
+use tw_proto::Greenfield::Proto;
+use tw_proto::{deserialize, google};
+
+use Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+
+/// `GreenfieldTransactionDecoder` decodes a Protobuf-serialized [`tx_proto::TxRaw`] transaction
+/// and represents it as [`Proto::SigningInput`].
+///
+/// Messages other than `MsgSend` and `MsgTransferOut` cannot be represented by [`Proto::Message`],
+/// so such transactions are not supported.
+pub struct GreenfieldTransactionDecoder;
+
+impl TransactionDecoder for GreenfieldTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, _coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl GreenfieldTransactionDecoder {
+    fn decode_transaction_impl(
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let tx_raw: tx_proto::TxRaw = deserialize(tx)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding Greenfield TxRaw")?;
+        let tx_body: tx_proto::TxBody = deserialize(&tx_raw.body_bytes)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding Greenfield TxBody")?;
+        let auth_info: tx_proto::AuthInfo = deserialize(&tx_raw.auth_info_bytes)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding Greenfield AuthInfo")?;
+
+        let messages = tx_body
+            .messages
+            .iter()
+            .map(Self::message_from_any)
+            .collect::<SigningResult<_>>()?;
+
+        let signers: Vec<_> = auth_info
+            .signer_infos
+            .iter()
+            .map(Self::signer_to_proto)
+            .collect::<SigningResult<_>>()?;
+
+        let (sequence, public_key) = match signers.first() {
+            Some(signer) => (signer.sequence, signer.public_key.clone()),
+            None => (0, Cow::default()),
+        };
+
+        let transaction = Proto::SigningInput {
+            encoding_mode: Proto::EncodingMode::Protobuf,
+            signing_mode: Proto::SigningMode::Eip712,
+            fee: auth_info.fee.as_ref().map(Self::fee_to_proto),
+            memo: Cow::from(tx_body.memo),
+            sequence,
+            messages,
+            public_key,
+            ..Proto::SigningInput::default()
+        };
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(transaction),
+            signers,
+            signatures: tx_raw.signatures.into_iter().map(Cow::from).collect(),
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+
+    fn signer_to_proto(signer: &tx_proto::SignerInfo) -> SigningResult<Proto::SignerInfo<'static>> {
+        Ok(Proto::SignerInfo {
+            public_key: Cow::from(CosmosTransactionDecoder::public_key_from_signer(signer)?),
+            sequence: signer.sequence,
+        })
+    }
+
+    fn fee_to_proto(fee: &tx_proto::Fee) -> Proto::Fee<'static> {
+        Proto::Fee {
+            amounts: fee.amount.iter().map(Self::coin_to_proto).collect(),
+            gas: fee.gas_limit,
+        }
+    }
+
+    fn coin_to_proto(coin: &base_proto::Coin) -> Proto::Amount<'static> {
+        Proto::Amount {
+            denom: Cow::from(coin.denom.clone()),
+            amount: Cow::from(coin.amount.clone()),
+        }
+    }
+
+    fn message_from_any(any: &google::protobuf::Any) -> SigningResult<Proto::Message<'static>> {
+        use cosmos::bank::v1beta1::MsgSend;
+        use greenfield::bridge::MsgTransferOut;
+
+        let message_oneof = if is_type::<MsgSend>(any) {
+            Self::send_msg_to_proto(decode_any(any)?)
+        } else if is_type::<MsgTransferOut>(any) {
+            Self::transfer_out_msg_to_proto(decode_any(any)?)
+        } else {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .with_context(|| format!("Unsupported Greenfield message: {}", any.type_url));
+        };
+        Ok(Proto::Message { message_oneof })
+    }
+
+    fn send_msg_to_proto(msg: cosmos::bank::v1beta1::MsgSend) -> MessageEnum<'static> {
+        MessageEnum::send_coins_message(Proto::mod_Message::Send {
+            from_address: Cow::from(msg.from_address),
+            to_address: Cow::from(msg.to_address),
+            amounts: msg.amount.iter().map(Self::coin_to_proto).collect(),
+            ..Proto::mod_Message::Send::default()
+        })
+    }
+
+    fn transfer_out_msg_to_proto(msg: greenfield::bridge::MsgTransferOut) -> MessageEnum<'static> {
+        MessageEnum::bridge_transfer_out(Proto::mod_Message::BridgeTransferOut {
+            from_address: Cow::from(msg.from),
+            to_address: Cow::from(msg.to),
+            amount: msg.amount.as_ref().map(Self::coin_to_proto),
+            ..Proto::mod_Message::BridgeTransferOut::default()
+        })
+    }
+}
@@ -5,12 +5,14 @@
 use std::str::FromStr;
 
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
+use tw_coin_entry::modules::transaction_hasher::NoTransactionHasher;
+use tw_coin_entry::modules::transaction_verifier::NoTransactionVerifier;
 use tw_coin_entry::{
     coin_context::CoinContext,
     coin_entry::CoinEntry,
     error::prelude::*,
     modules::{
-        json_signer::NoJsonSigner, message_signer::NoMessageSigner, plan_builder::NoPlanBuilder,
+        json_signer::ProtoJsonSigner, message_signer::NoMessageSigner, plan_builder::NoPlanBuilder,
         wallet_connector::NoWalletConnector,
     },
     prefix::NoPrefix,
@@ -23,6 +25,7 @@ use tw_proto::{
 };
 
 use crate::{address::AccountIdentifier, context::StandardInternetComputerContext, signer::Signer};
+use tw_coin_entry::modules::transaction_summarizer::NoTransactionSummarizer;
 
 pub struct InternetComputerEntry;
 
@@ -34,11 +37,14 @@ impl CoinEntry for InternetComputerEntry {
     type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
 
     // Optional modules:
-    type JsonSigner = NoJsonSigner;
+    type JsonSigner = ProtoJsonSigner<Self>;
     type PlanBuilder = NoPlanBuilder;
     type MessageSigner = NoMessageSigner;
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
+    type TransactionVerifier = NoTransactionVerifier;
+    type TransactionHasher = NoTransactionHasher;
+    type TransactionSummarizer = NoTransactionSummarizer;
 
     #[inline]
     fn parse_address(
@@ -105,4 +111,9 @@ impl CoinEntry for InternetComputerEntry {
             SigningError::new(CommonError::Error_not_supported)
         )
     }
+
+    #[inline]
+    fn json_signer(&self) -> Option<Self::JsonSigner> {
+        Some(ProtoJsonSigner::new(InternetComputerEntry))
+    }
 }
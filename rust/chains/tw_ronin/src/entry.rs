@@ -9,15 +9,18 @@ use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
-use tw_coin_entry::modules::json_signer::NoJsonSigner;
+use tw_coin_entry::modules::json_signer::ProtoJsonSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_evm::evm_entry::EvmEntry;
 use tw_evm::modules::compiler::Compiler;
 use tw_evm::modules::message_signer::EthMessageSigner;
 use tw_evm::modules::signer::Signer;
+use tw_evm::modules::transaction_decoder::EthTransactionDecoder;
+use tw_evm::modules::transaction_hasher::EthTransactionHasher;
+use tw_evm::modules::transaction_summarizer::EthTransactionSummarizer;
+use tw_evm::modules::transaction_verifier::EthTransactionVerifier;
 use tw_keypair::tw::PublicKey;
 use tw_proto::Ethereum::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
@@ -32,11 +35,14 @@ impl CoinEntry for RoninEntry {
     type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
 
     // Optional modules:
-    type JsonSigner = NoJsonSigner;
+    type JsonSigner = ProtoJsonSigner<Self>;
     type PlanBuilder = NoPlanBuilder;
     type MessageSigner = EthMessageSigner;
     type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type TransactionDecoder = EthTransactionDecoder;
+    type TransactionVerifier = EthTransactionVerifier;
+    type TransactionHasher = EthTransactionHasher;
+    type TransactionSummarizer = EthTransactionSummarizer;
 
     #[inline]
     fn parse_address(
@@ -71,34 +77,59 @@ impl CoinEntry for RoninEntry {
     }
 
     #[inline]
-    fn sign(&self, _coin: &dyn CoinContext, input: Self::SigningInput<'_>) -> Self::SigningOutput {
-        Signer::<RoninContext>::sign_proto(input)
+    fn sign(&self, coin: &dyn CoinContext, input: Self::SigningInput<'_>) -> Self::SigningOutput {
+        Signer::<RoninContext>::sign_proto_with_coin(coin, input)
     }
 
     #[inline]
     fn preimage_hashes(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         input: Self::SigningInput<'_>,
     ) -> Self::PreSigningOutput {
-        Compiler::<RoninContext>::preimage_hashes(input)
+        Compiler::<RoninContext>::preimage_hashes_with_coin(coin, input)
     }
 
     #[inline]
     fn compile(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         input: Self::SigningInput<'_>,
         signatures: Vec<SignatureBytes>,
         public_keys: Vec<PublicKeyBytes>,
     ) -> Self::SigningOutput {
-        Compiler::<RoninContext>::compile(input, signatures, public_keys)
+        Compiler::<RoninContext>::compile_with_coin(coin, input, signatures, public_keys)
+    }
+
+    #[inline]
+    fn json_signer(&self) -> Option<Self::JsonSigner> {
+        Some(ProtoJsonSigner::new(RoninEntry))
     }
 
     #[inline]
     fn message_signer(&self) -> Option<Self::MessageSigner> {
         Some(EthMessageSigner)
     }
+
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(EthTransactionDecoder)
+    }
+
+    #[inline]
+    fn transaction_verifier(&self) -> Option<Self::TransactionVerifier> {
+        Some(EthTransactionVerifier)
+    }
+
+    #[inline]
+    fn transaction_hasher(&self) -> Option<Self::TransactionHasher> {
+        Some(EthTransactionHasher)
+    }
+
+    #[inline]
+    fn transaction_summarizer(&self) -> Option<Self::TransactionSummarizer> {
+        Some(EthTransactionSummarizer)
+    }
 }
 
 impl EvmEntry for RoninEntry {
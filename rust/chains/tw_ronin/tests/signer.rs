@@ -8,6 +8,7 @@ use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::test_utils::test_context::TestCoinContext;
 use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_number::U256;
+use tw_proto::json::json_to_message_data;
 use tw_proto::Ethereum::Proto;
 use tw_proto::{deserialize, serialize};
 use tw_ronin::entry::RoninEntry;
@@ -60,9 +61,17 @@ fn test_sign_json() {
         .decode_hex()
         .unwrap();
 
-    RoninEntry
+    let output_json = RoninEntry
         .sign_json(&coin, input_json, private_key)
-        .expect_err("'EthEntry::sign_json' is not supported yet");
+        .expect("!sign_json");
+    let output_json: serde_json::Value = serde_json::from_str(&output_json).unwrap();
 
-    // Expected result - "f86880843b9aca0082520894c36edf48e21cf395b206352a1819de658fd7f988830437df80820feca0442aa06b0d0465bfecf84b28e2ce614a32a1ccc12735dc03a5799517d6659d7aa004e1bf2efa30743f1b6d49dbec2671e9fb5ead1e7da15e352ca1df6fb86a8ba7"
+    let output_data = json_to_message_data::<Proto::SigningOutput>(&output_json).unwrap();
+    let output: Proto::SigningOutput = deserialize(&output_data).unwrap();
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "f86880843b9aca0082520894c36edf48e21cf395b206352a1819de658fd7f988830437df80820feca0442aa06b0d0465bfecf84b28e2ce614a32a1ccc12735dc03a5799517d6659d7aa004e1bf2efa30743f1b6d49dbec2671e9fb5ead1e7da15e352ca1df6fb86a8ba7";
+    assert_eq!(output.encoded.to_hex(), expected);
 }
@@ -4,7 +4,11 @@
 
 use crate::address::SolanaAddress;
 use crate::compiler::SolanaCompiler;
+use crate::modules::message_signer::SolanaMessageSigner;
 use crate::modules::transaction_decoder::SolanaTransactionDecoder;
+use crate::modules::transaction_hasher::SolanaTransactionHasher;
+use crate::modules::transaction_summarizer::SolanaTransactionSummarizer;
+use crate::modules::transaction_verifier::SolanaTransactionVerifier;
 use crate::modules::wallet_connect::connector::SolanaWalletConnector;
 use crate::signer::SolanaSigner;
 use std::str::FromStr;
@@ -12,8 +16,7 @@ use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
-use tw_coin_entry::modules::json_signer::NoJsonSigner;
-use tw_coin_entry::modules::message_signer::NoMessageSigner;
+use tw_coin_entry::modules::json_signer::ProtoJsonSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_keypair::tw::PublicKey;
@@ -29,11 +32,14 @@ impl CoinEntry for SolanaEntry {
     type PreSigningOutput = Proto::PreSigningOutput<'static>;
 
     // Optional modules:
-    type JsonSigner = NoJsonSigner;
+    type JsonSigner = ProtoJsonSigner<Self>;
     type PlanBuilder = NoPlanBuilder;
-    type MessageSigner = NoMessageSigner;
+    type MessageSigner = SolanaMessageSigner;
     type WalletConnector = SolanaWalletConnector;
     type TransactionDecoder = SolanaTransactionDecoder;
+    type TransactionVerifier = SolanaTransactionVerifier;
+    type TransactionHasher = SolanaTransactionHasher;
+    type TransactionSummarizer = SolanaTransactionSummarizer;
 
     #[inline]
     fn parse_address(
@@ -90,6 +96,16 @@ impl CoinEntry for SolanaEntry {
         SolanaCompiler::compile(coin, input, signatures, public_keys)
     }
 
+    #[inline]
+    fn json_signer(&self) -> Option<Self::JsonSigner> {
+        Some(ProtoJsonSigner::new(SolanaEntry))
+    }
+
+    #[inline]
+    fn message_signer(&self) -> Option<Self::MessageSigner> {
+        Some(SolanaMessageSigner)
+    }
+
     #[inline]
     fn wallet_connector(&self) -> Option<Self::WalletConnector> {
         Some(SolanaWalletConnector)
@@ -99,4 +115,19 @@ impl CoinEntry for SolanaEntry {
     fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
         Some(SolanaTransactionDecoder)
     }
+
+    #[inline]
+    fn transaction_verifier(&self) -> Option<Self::TransactionVerifier> {
+        Some(SolanaTransactionVerifier)
+    }
+
+    #[inline]
+    fn transaction_hasher(&self) -> Option<Self::TransactionHasher> {
+        Some(SolanaTransactionHasher)
+    }
+
+    #[inline]
+    fn transaction_summarizer(&self) -> Option<Self::TransactionSummarizer> {
+        Some(SolanaTransactionSummarizer)
+    }
 }
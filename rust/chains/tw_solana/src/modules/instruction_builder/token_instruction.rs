@@ -63,6 +63,18 @@ impl TokenInstruction {
         };
         buf
     }
+
+    /// Unpacks a byte buffer packed by [`TokenInstruction::pack`].
+    /// Returns `None` if the instruction is not supported or invalid.
+    pub fn unpack(data: &[u8]) -> Option<TokenInstruction> {
+        match data {
+            [12, amount @ .., decimals] => Some(Self::TransferChecked {
+                amount: u64::from_le_bytes(amount.try_into().ok()?),
+                decimals: *decimals,
+            }),
+            _ => None,
+        }
+    }
 }
 
 pub struct TokenInstructionBuilder;
@@ -543,7 +543,7 @@ impl<'a> MessageBuilder<'a> {
         }
     }
 
-    fn signer_address(&self) -> SigningResult<SolanaAddress> {
+    pub fn signer_address(&self) -> SigningResult<SolanaAddress> {
         if self.input.private_key.is_empty() {
             SolanaAddress::from_str(&self.input.sender)
                 .map_err(SigningError::from)
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::offchain_message::OffchainMessage;
+use crate::SOLANA_ALPHABET;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base58;
+use tw_keypair::ed25519;
+use tw_keypair::traits::{SigningKeyTrait, VerifyingKeyTrait};
+use tw_memory::Data;
+use tw_proto::Solana::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+pub struct SolanaMessageSigner;
+
+impl MessageSigner for SolanaMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(input).unwrap_or_default()
+    }
+}
+
+impl SolanaMessageSigner {
+    fn message_preimage_hashes_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let preimage = Self::preimage(&input.message, input.message_type)?;
+        // There is no hashing for Solana, so the preimage is signed as is.
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::Owned(preimage.clone()),
+            data_hash: Cow::Owned(preimage),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let private_key = ed25519::sha512::PrivateKey::try_from(input.private_key.as_ref())?;
+        let preimage = Self::preimage(&input.message, input.message_type)?;
+
+        let signature = private_key.sign(preimage)?;
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::Owned(base58::encode(
+                signature.to_bytes().as_slice(),
+                SOLANA_ALPHABET,
+            )),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(input: Proto::MessageVerifyingInput<'_>) -> SigningResult<bool> {
+        let public_key = ed25519::sha512::PublicKey::try_from(input.public_key.as_ref())?;
+        let signature_bytes = base58::decode(&input.signature, SOLANA_ALPHABET)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Invalid Base58 signature")?;
+        let signature = ed25519::Signature::try_from(signature_bytes.as_slice())?;
+
+        let preimage = Self::preimage(&input.message, input.message_type)?;
+        Ok(public_key.verify(signature, preimage))
+    }
+
+    fn preimage(message: &[u8], message_type: Proto::MessageType) -> SigningResult<Data> {
+        match message_type {
+            Proto::MessageType::MessageType_off_chain => {
+                OffchainMessage::new(message).map(|msg| msg.serialize())
+            },
+            Proto::MessageType::MessageType_raw => Ok(message.to_vec()),
+        }
+    }
+}
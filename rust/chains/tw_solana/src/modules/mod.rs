@@ -10,8 +10,13 @@ pub mod compiled_instructions;
 pub mod compiled_keys;
 pub mod instruction_builder;
 pub mod message_builder;
+pub mod message_signer;
+pub mod offchain_message;
 pub mod proto_builder;
 pub mod transaction_decoder;
+pub mod transaction_hasher;
+pub mod transaction_summarizer;
+pub mod transaction_verifier;
 pub mod tx_signer;
 pub mod utils;
 pub mod wallet_connect;
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::error::prelude::*;
+use tw_memory::Data;
+
+/// cbindgen:ignore
+pub const SIGNING_DOMAIN: &[u8] = b"\xffsolana offchain";
+/// Length of the signing domain and version.
+const BASE_HEADER_LEN: usize = SIGNING_DOMAIN.len() + 1;
+/// Length of the message format and message length.
+const V0_HEADER_LEN: usize = 3;
+/// Maximum length of a message that can be signed by a Ledger device.
+/// Equals to `PACKET_DATA_SIZE - BASE_HEADER_LEN - V0_HEADER_LEN`.
+const MAX_LEN_LEDGER: usize = 1232 - BASE_HEADER_LEN - V0_HEADER_LEN;
+/// Maximum length of an off-chain message.
+const MAX_LEN: usize = u16::MAX as usize - BASE_HEADER_LEN - V0_HEADER_LEN;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum MessageFormat {
+    RestrictedAscii = 0,
+    LimitedUtf8 = 1,
+    ExtendedUtf8 = 2,
+}
+
+/// Off-chain message of version 0.
+/// https://github.com/solana-labs/solana/blob/master/docs/src/proposals/off-chain-message-signing.md
+pub struct OffchainMessage {
+    format: MessageFormat,
+    message: Data,
+}
+
+impl OffchainMessage {
+    /// cbindgen:ignore
+    pub const VERSION: u8 = 0;
+
+    /// Creates an off-chain message, detecting the message format by its content.
+    pub fn new(message: &[u8]) -> SigningResult<OffchainMessage> {
+        if message.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Off-chain message cannot be empty");
+        }
+
+        let format = if message.len() <= MAX_LEN_LEDGER {
+            if Self::is_printable_ascii(message) {
+                MessageFormat::RestrictedAscii
+            } else if std::str::from_utf8(message).is_ok() {
+                MessageFormat::LimitedUtf8
+            } else {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Off-chain message must be a valid UTF-8 string");
+            }
+        } else if message.len() <= MAX_LEN {
+            if std::str::from_utf8(message).is_ok() {
+                MessageFormat::ExtendedUtf8
+            } else {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Off-chain message must be a valid UTF-8 string");
+            }
+        } else {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .with_context(|| format!("Off-chain message cannot be longer than {MAX_LEN}"));
+        };
+
+        Ok(OffchainMessage {
+            format,
+            message: message.to_vec(),
+        })
+    }
+
+    pub fn format(&self) -> MessageFormat {
+        self.format
+    }
+
+    /// Serializes the message as `signing_domain || version || format || length (u16 LE) || message`.
+    pub fn serialize(&self) -> Data {
+        let message_len = self.message.len() as u16;
+
+        let mut data = Vec::with_capacity(BASE_HEADER_LEN + V0_HEADER_LEN + self.message.len());
+        data.extend_from_slice(SIGNING_DOMAIN);
+        data.push(Self::VERSION);
+        data.push(self.format as u8);
+        data.extend_from_slice(&message_len.to_le_bytes());
+        data.extend_from_slice(&self.message);
+        data
+    }
+
+    fn is_printable_ascii(message: &[u8]) -> bool {
+        message.iter().all(|ch| (0x20..=0x7e).contains(ch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_offchain_message_ascii() {
+        let message = OffchainMessage::new(b"Hello, Solana!").unwrap();
+        assert_eq!(message.format(), MessageFormat::RestrictedAscii);
+        assert_eq!(
+            message.serialize().to_hex(),
+            "ff736f6c616e61206f6666636861696e00000e0048656c6c6f2c20536f6c616e6121"
+        );
+    }
+
+    #[test]
+    fn test_offchain_message_utf8() {
+        let message = OffchainMessage::new("Привет".as_bytes()).unwrap();
+        assert_eq!(message.format(), MessageFormat::LimitedUtf8);
+        assert_eq!(
+            message.serialize().to_hex(),
+            "ff736f6c616e61206f6666636861696e00010c00d09fd180d0b8d0b2d0b5d182"
+        );
+
+        let message = OffchainMessage::new(&[b'a'; MAX_LEN_LEDGER + 1]).unwrap();
+        assert_eq!(message.format(), MessageFormat::ExtendedUtf8);
+    }
+
+    #[test]
+    fn test_offchain_message_invalid() {
+        OffchainMessage::new(&[]).unwrap_err();
+        OffchainMessage::new(&[0xff, 0xfe]).unwrap_err();
+        OffchainMessage::new(&[b'a'; MAX_LEN + 1]).unwrap_err();
+    }
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::versioned::VersionedTransaction;
+use crate::SOLANA_ALPHABET;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_hasher::TransactionHasher;
+use tw_encoding::base58;
+
+/// Solana transactions are identified by the first (fee payer) signature.
+pub struct SolanaTransactionHasher;
+
+impl TransactionHasher for SolanaTransactionHasher {
+    fn tx_id(&self, _coin: &dyn CoinContext, signed_tx: &[u8]) -> SigningResult<String> {
+        let tx: VersionedTransaction = bincode::deserialize(signed_tx)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding transaction as 'bincode'")?;
+
+        let fee_payer_signature = tx
+            .signatures
+            .first()
+            .or_tw_err(SigningErrorType::Error_signatures_count)
+            .context("Transaction does not contain signatures")?;
+        Ok(base58::encode(
+            fee_payer_signature.0.as_slice(),
+            SOLANA_ALPHABET,
+        ))
+    }
+}
@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::defined_addresses::*;
+use crate::modules::instruction_builder::compute_budget_instruction::ComputeBudgetInstruction;
+use crate::modules::instruction_builder::stake_instruction::StakeInstruction;
+use crate::modules::instruction_builder::system_instruction::SystemInstruction;
+use crate::modules::instruction_builder::token_instruction::TokenInstruction;
+use crate::modules::message_builder::MessageBuilder;
+use crate::transaction::versioned::VersionedMessage;
+use crate::transaction::CompiledInstruction;
+use borsh::BorshDeserialize;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_summarizer::{
+    action, amount, fee, native_amount, native_asset, warning, KnownTokens, TransactionSummarizer,
+};
+use tw_coin_entry::signing_output_error;
+use tw_number::U256;
+use tw_proto::Solana::Proto;
+use tw_proto::TxSummary::Proto as SummaryProto;
+
+use SummaryProto::{ActionType, WarningType};
+
+/// Fee in lamports charged for every transaction signature.
+const LAMPORTS_PER_SIGNATURE: u64 = 5000;
+/// Maximum number of compute units a transaction can consume.
+/// Used to estimate the max priority fee if the compute unit limit is not set explicitly.
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+/// Describes Solana transactions by the instructions of the message built from `SigningInput`.
+///
+/// System, SPL token (`TransferChecked`), associated token account and stake program instructions are recognized.
+pub struct SolanaTransactionSummarizer;
+
+impl TransactionSummarizer for SolanaTransactionSummarizer {
+    type SigningInput<'a> = Proto::SigningInput<'a>;
+
+    fn summarize_transaction(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::SigningInput<'_>,
+        tokens: &KnownTokens,
+    ) -> SummaryProto::TransactionSummary<'static> {
+        Self::summarize_transaction_impl(coin, input, tokens)
+            .unwrap_or_else(|e| signing_output_error!(SummaryProto::TransactionSummary, e))
+    }
+}
+
+/// Compute budget requested by the message instructions.
+#[derive(Default)]
+struct ComputeBudget {
+    unit_limit: Option<u32>,
+    unit_price: Option<u64>,
+}
+
+impl SolanaTransactionSummarizer {
+    fn summarize_transaction_impl(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        tokens: &KnownTokens,
+    ) -> SigningResult<SummaryProto::TransactionSummary<'static>> {
+        let is_raw_message = input.raw_message.is_some();
+        let builder = MessageBuilder::new(input);
+
+        // Fee payer can be different from the signer only if the message is built from a transaction type.
+        let external_fee_payer = if is_raw_message {
+            None
+        } else {
+            let fee_payer = builder.fee_payer()?;
+            (fee_payer != builder.signer_address()?).then_some(fee_payer)
+        };
+
+        let message = builder.build()?;
+
+        let mut summary = SummaryProto::TransactionSummary::default();
+        let mut compute_budget = ComputeBudget::default();
+        for instruction in message.instructions() {
+            Self::summarize_instruction(
+                coin,
+                tokens,
+                &mut summary,
+                &mut compute_budget,
+                &message,
+                instruction,
+            );
+        }
+
+        let max_fee = Self::max_fee(&message, &compute_budget)?;
+        summary.fee = Some(fee(native_asset(coin), native_amount(coin, max_fee)));
+
+        if let Some(fee_payer) = external_fee_payer {
+            summary.warnings.push(warning(
+                WarningType::ExternalFeePayer,
+                format!("The fee is paid by {fee_payer}"),
+            ));
+        }
+        Ok(summary)
+    }
+
+    fn summarize_instruction(
+        coin: &dyn CoinContext,
+        tokens: &KnownTokens,
+        summary: &mut SummaryProto::TransactionSummary<'static>,
+        compute_budget: &mut ComputeBudget,
+        message: &VersionedMessage,
+        instruction: &CompiledInstruction,
+    ) {
+        // Returns an account by the index within the instruction accounts.
+        // Accounts loaded from address lookup tables cannot be resolved, so an empty string is returned.
+        let account = |idx: usize| -> String {
+            instruction
+                .accounts
+                .get(idx)
+                .and_then(|key_idx| message.account_keys().get(*key_idx as usize))
+                .map(SolanaAddress::to_string)
+                .unwrap_or_default()
+        };
+        let Some(program_id) = message
+            .account_keys()
+            .get(instruction.program_id_index as usize)
+        else {
+            return;
+        };
+        let data = instruction.data.as_slice();
+
+        let native = |value: u64| Some(native_amount(coin, U256::from(value)));
+
+        if *program_id == *SYSTEM_PROGRAM_ID_ADDRESS {
+            match bincode::deserialize::<SystemInstruction>(data) {
+                Ok(SystemInstruction::Transfer { lamports }) => summary.actions.push(action(
+                    ActionType::Transfer,
+                    native_asset(coin),
+                    native(lamports),
+                    account(0),
+                    account(1),
+                )),
+                Ok(
+                    SystemInstruction::CreateAccount { lamports, .. }
+                    | SystemInstruction::CreateAccountWithSeed { lamports, .. },
+                ) => summary.actions.push(action(
+                    ActionType::CreateAccount,
+                    native_asset(coin),
+                    native(lamports),
+                    account(0),
+                    account(1),
+                )),
+                Ok(SystemInstruction::WithdrawNonceAccount(lamports)) => {
+                    summary.actions.push(action(
+                        ActionType::Withdraw,
+                        native_asset(coin),
+                        native(lamports),
+                        account(0),
+                        account(1),
+                    ))
+                },
+                // Technical instructions that do not move funds.
+                Ok(
+                    SystemInstruction::AdvanceNonceAccount
+                    | SystemInstruction::InitializeNonceAccount(_),
+                ) => (),
+                _ => Self::push_unknown_call(coin, summary, program_id),
+            }
+        } else if *program_id == *TOKEN_PROGRAM_ID_ADDRESS {
+            match TokenInstruction::unpack(data) {
+                Some(TokenInstruction::TransferChecked {
+                    amount: token_amount,
+                    decimals,
+                }) => summary.actions.push(action(
+                    ActionType::TokenTransfer,
+                    tokens.asset(account(1)),
+                    Some(amount(U256::from(token_amount), Some(decimals))),
+                    account(0),
+                    account(2),
+                )),
+                None => Self::push_unknown_call(coin, summary, program_id),
+            }
+        } else if *program_id == *ASSOCIATED_TOKEN_PROGRAM_ID_ADDRESS {
+            // Accounts: funding account, associated token account, wallet address, token mint, ...
+            summary.actions.push(action(
+                ActionType::CreateAccount,
+                tokens.asset(account(3)),
+                None,
+                account(0),
+                account(1),
+            ));
+        } else if *program_id == *STAKE_PROGRAM_ID_ADDRESS {
+            match bincode::deserialize::<StakeInstruction>(data) {
+                // Accounts: stake account, vote account, ...
+                Ok(StakeInstruction::DelegateStake) => summary.actions.push(action(
+                    ActionType::Stake,
+                    native_asset(coin),
+                    None,
+                    account(0),
+                    account(1),
+                )),
+                // Accounts: stake account, ...
+                Ok(StakeInstruction::Deactivate) => summary.actions.push(action(
+                    ActionType::Unstake,
+                    native_asset(coin),
+                    None,
+                    "",
+                    account(0),
+                )),
+                // Accounts: stake account, recipient, ...
+                Ok(StakeInstruction::Withdraw(lamports)) => summary.actions.push(action(
+                    ActionType::Withdraw,
+                    native_asset(coin),
+                    native(lamports),
+                    account(0),
+                    account(1),
+                )),
+                Ok(StakeInstruction::Initialize(..)) => (),
+                _ => Self::push_unknown_call(coin, summary, program_id),
+            }
+        } else if *program_id == *COMPUTE_BUDGET_ADDRESS {
+            match ComputeBudgetInstruction::try_from_slice(data) {
+                Ok(ComputeBudgetInstruction::SetComputeUnitLimit(limit)) => {
+                    compute_budget.unit_limit = Some(limit)
+                },
+                Ok(ComputeBudgetInstruction::SetComputeUnitPrice(price)) => {
+                    compute_budget.unit_price = Some(price)
+                },
+                _ => (),
+            }
+        } else if *program_id != *MEMO_PROGRAM_ID_ADDRESS {
+            Self::push_unknown_call(coin, summary, program_id);
+        }
+    }
+
+    fn push_unknown_call(
+        coin: &dyn CoinContext,
+        summary: &mut SummaryProto::TransactionSummary<'static>,
+        program_id: &SolanaAddress,
+    ) {
+        summary.warnings.push(warning(
+            WarningType::UnknownContractCall,
+            format!("Unknown instruction of the {program_id} program"),
+        ));
+        summary.actions.push(action(
+            ActionType::ContractCall,
+            native_asset(coin),
+            None,
+            "",
+            program_id,
+        ));
+    }
+
+    /// Returns the base fee for the message signatures plus the max priority fee in lamports.
+    fn max_fee(message: &VersionedMessage, compute_budget: &ComputeBudget) -> SigningResult<U256> {
+        let signatures_fee =
+            message.num_required_signatures() as u128 * LAMPORTS_PER_SIGNATURE as u128;
+
+        let priority_fee = match compute_budget.unit_price {
+            Some(unit_price) => {
+                let unit_limit = compute_budget.unit_limit.unwrap_or(MAX_COMPUTE_UNIT_LIMIT);
+                let micro_lamports = unit_price as u128 * unit_limit as u128;
+                (micro_lamports + MICRO_LAMPORTS_PER_LAMPORT - 1) / MICRO_LAMPORTS_PER_LAMPORT
+            },
+            None => 0,
+        };
+
+        let max_fee = signatures_fee + priority_fee;
+        U256::from_big_endian_slice(&max_fee.to_be_bytes()).into_tw()
+    }
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SolanaAddress;
+use crate::modules::tx_signer::TxSigner;
+use crate::transaction::versioned::VersionedTransaction;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_verifier::{verifying_output, TransactionVerifier};
+use tw_coin_entry::signing_output_error;
+use tw_hash::H256;
+use tw_keypair::ed25519;
+use tw_keypair::traits::VerifyingKeyTrait;
+use tw_proto::TxVerifier::Proto as VerifierProto;
+
+/// Verifies signatures of all required signers of a `bincode` encoded transaction.
+pub struct SolanaTransactionVerifier;
+
+impl TransactionVerifier for SolanaTransactionVerifier {
+    fn verify_transaction(
+        &self,
+        _coin: &dyn CoinContext,
+        input: VerifierProto::VerifyingInput<'_>,
+    ) -> VerifierProto::VerifyingOutput<'static> {
+        Self::verify_transaction_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(VerifierProto::VerifyingOutput, e))
+    }
+}
+
+impl SolanaTransactionVerifier {
+    fn verify_transaction_impl(
+        input: VerifierProto::VerifyingInput<'_>,
+    ) -> SigningResult<VerifierProto::VerifyingOutput<'static>> {
+        let expected_signers = input
+            .public_keys
+            .iter()
+            .map(|key| H256::try_from(key.as_ref()).map(SolanaAddress::with_public_key_bytes))
+            .collect::<Result<Vec<_>, _>>()
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .context("Invalid expected public key")?;
+
+        let tx: VersionedTransaction = bincode::deserialize(&input.transaction)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding transaction as 'bincode'")?;
+
+        let actual_signatures = tx.signatures.len();
+        let expected_signatures = tx.message.num_required_signatures();
+        if actual_signatures != expected_signatures {
+            return SigningError::err(SigningErrorType::Error_signatures_count).with_context(
+                || {
+                    format!(
+                        "Expected '{expected_signatures}' signatures, found '{actual_signatures}'"
+                    )
+                },
+            );
+        }
+
+        let preimage = TxSigner::preimage_versioned(&tx.message)?;
+
+        let signatures = tx
+            .message
+            .signers()
+            .zip(tx.signatures.iter())
+            .map(|(signer, signature)| {
+                let is_expected = expected_signers.is_empty() || expected_signers.contains(signer);
+                let valid = is_expected && Self::verify_signature(signer, &signature.0, &preimage);
+
+                VerifierProto::SignatureVerification {
+                    public_key: Cow::Owned(signer.bytes().into_vec()),
+                    signature: Cow::Owned(signature.0.into_vec()),
+                    valid,
+                }
+            })
+            .collect();
+
+        Ok(verifying_output(signatures))
+    }
+
+    fn verify_signature(signer: &SolanaAddress, signature: &[u8], preimage: &[u8]) -> bool {
+        let Ok(public_key) = ed25519::sha512::PublicKey::try_from(signer.bytes().as_slice()) else {
+            return false;
+        };
+        let Ok(signature) = ed25519::Signature::try_from(signature) else {
+            return false;
+        };
+        public_key.verify(signature, preimage.to_vec())
+    }
+}
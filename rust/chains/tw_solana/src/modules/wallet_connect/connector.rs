@@ -2,13 +2,20 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::address::SolanaAddress;
 use crate::modules::proto_builder::ProtoBuilder;
-use crate::modules::wallet_connect::request::SignTransactionRequest;
+use crate::modules::wallet_connect::request::{
+    SignAllTransactionsRequest, SignMessageRequest, SignTransactionRequest,
+};
 use crate::transaction::versioned::VersionedTransaction;
+use bincode::Options;
+use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::wallet_connector::WalletConnector;
 use tw_coin_entry::signing_output_error;
+use tw_encoding::base58::{self, Alphabet};
+use tw_encoding::base64;
 use tw_proto::Solana::Proto;
 use tw_proto::WalletConnect::Proto::{
     self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
@@ -36,6 +43,10 @@ impl SolanaWalletConnector {
             WCProto::Method::SolanaSignTransaction => {
                 Self::parse_sign_transaction_request(coin, request)
             },
+            WCProto::Method::SolanaSignAllTransactions => {
+                Self::parse_sign_all_transactions_request(coin, request)
+            },
+            WCProto::Method::SolanaSignMessage => Self::parse_sign_message_request(coin, request),
             _ => SigningError::err(SigningErrorType::Error_not_supported)
                 .context("Unknown WalletConnect method"),
         }
@@ -63,4 +74,92 @@ impl SolanaWalletConnector {
             ..WCProto::ParseRequestOutput::default()
         })
     }
+
+    pub fn parse_sign_all_transactions_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let sign_req: SignAllTransactionsRequest = serde_json::from_str(&request.payload)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error parsing WalletConnect signing request as JSON")?;
+
+        let inputs = sign_req
+            .transactions
+            .iter()
+            .enumerate()
+            .map(|(i, encoded)| {
+                let (transaction, tx_encoding) = decode_transaction(encoded)
+                    .with_context(|| format!("Invalid transaction at index {i}"))?;
+                // Sign the transaction in the same encoding as it was requested.
+                Ok(Proto::SigningInput {
+                    raw_message: Some(ProtoBuilder::build_from_tx(&transaction)),
+                    tx_encoding,
+                    ..Proto::SigningInput::default()
+                })
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::solana_all(WCProto::SolanaSigningInputs {
+                inputs,
+            }),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+
+    pub fn parse_sign_message_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let sign_req: SignMessageRequest = serde_json::from_str(&request.payload)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error parsing WalletConnect signing request as JSON")?;
+
+        SolanaAddress::from_str(&sign_req.pubkey)
+            .into_tw()
+            .context("Invalid signer pubkey")?;
+
+        // dApps expect the message bytes to be signed as is.
+        let signing_input = Proto::MessageSigningInput {
+            message: sign_req.message.into(),
+            message_type: Proto::MessageType::MessageType_raw,
+            ..Proto::MessageSigningInput::default()
+        };
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::solana_message(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+}
+
+/// Decodes a Base64 or Base58 encoded transaction.
+/// Returns the transaction and the encoding it was decoded from.
+///
+/// A Base58 string can also be a valid Base64 one, so the decoded data must be
+/// exactly one `bincode` transaction without trailing bytes.
+fn decode_transaction(encoded: &str) -> SigningResult<(VersionedTransaction, Proto::Encoding)> {
+    let deserialize = |data: &[u8]| {
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .reject_trailing_bytes()
+            .deserialize::<VersionedTransaction>(data)
+            .ok()
+    };
+
+    let is_url = false;
+    if let Some(tx) = base64::decode(encoded, is_url)
+        .ok()
+        .and_then(|data| deserialize(&data))
+    {
+        return Ok((tx, Proto::Encoding::Base64));
+    }
+    if let Some(tx) = base58::decode(encoded, Alphabet::Bitcoin)
+        .ok()
+        .and_then(|data| deserialize(&data))
+    {
+        return Ok((tx, Proto::Encoding::Base58));
+    }
+
+    SigningError::err(SigningErrorType::Error_input_parse)
+        .context("Expected a Base64 or Base58 encoded 'bincode' transaction")
 }
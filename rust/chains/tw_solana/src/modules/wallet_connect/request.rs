@@ -3,7 +3,9 @@
 // Copyright © 2017 Trust Wallet.
 
 use serde::Deserialize;
+use tw_encoding::base58::as_base58_bitcoin;
 use tw_encoding::base64::Base64Encoded;
+use tw_memory::Data;
 
 /// `solana_signTransaction` request payload without legacy fields.
 /// https://docs.walletconnect.com/advanced/rpc-reference/solana-rpc#solana_signtransaction
@@ -12,3 +14,23 @@ use tw_encoding::base64::Base64Encoded;
 pub struct SignTransactionRequest {
     pub transaction: Base64Encoded,
 }
+
+/// `solana_signAllTransactions` request payload.
+/// Transactions are either Base64 or Base58 encoded.
+/// https://docs.walletconnect.com/advanced/rpc-reference/solana-rpc#solana_signalltransactions
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignAllTransactionsRequest {
+    pub transactions: Vec<String>,
+}
+
+/// `solana_signMessage` request payload.
+/// https://docs.walletconnect.com/advanced/rpc-reference/solana-rpc#solana_signmessage
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignMessageRequest {
+    /// Base58-encoded message.
+    #[serde(with = "as_base58_bitcoin")]
+    pub message: Data,
+    pub pubkey: String,
+}
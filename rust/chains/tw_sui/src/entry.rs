@@ -4,17 +4,20 @@
 
 use crate::address::SuiAddress;
 use crate::compiler::SuiCompiler;
+use crate::modules::message_signer::SuiMessageSigner;
+use crate::modules::transaction_decoder::SuiTransactionDecoder;
+use crate::modules::transaction_hasher::SuiTransactionHasher;
+use crate::modules::wallet_connect::connector::SuiWalletConnector;
 use crate::signer::SuiSigner;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
-use tw_coin_entry::modules::json_signer::NoJsonSigner;
-use tw_coin_entry::modules::message_signer::NoMessageSigner;
+use tw_coin_entry::modules::json_signer::ProtoJsonSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
-use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
+use tw_coin_entry::modules::transaction_summarizer::NoTransactionSummarizer;
+use tw_coin_entry::modules::transaction_verifier::NoTransactionVerifier;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_keypair::tw::PublicKey;
 use tw_proto::Sui::Proto;
@@ -30,11 +33,14 @@ impl CoinEntry for SuiEntry {
     type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
 
     // Optional modules:
-    type JsonSigner = NoJsonSigner;
+    type JsonSigner = ProtoJsonSigner<Self>;
     type PlanBuilder = NoPlanBuilder;
-    type MessageSigner = NoMessageSigner;
-    type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type MessageSigner = SuiMessageSigner;
+    type WalletConnector = SuiWalletConnector;
+    type TransactionDecoder = SuiTransactionDecoder;
+    type TransactionVerifier = NoTransactionVerifier;
+    type TransactionHasher = SuiTransactionHasher;
+    type TransactionSummarizer = NoTransactionSummarizer;
 
     #[inline]
     fn parse_address(
@@ -93,4 +99,29 @@ impl CoinEntry for SuiEntry {
     ) -> Self::SigningOutput {
         SuiCompiler::compile(coin, input, signatures, public_keys)
     }
+
+    #[inline]
+    fn json_signer(&self) -> Option<Self::JsonSigner> {
+        Some(ProtoJsonSigner::new(SuiEntry))
+    }
+
+    #[inline]
+    fn message_signer(&self) -> Option<Self::MessageSigner> {
+        Some(SuiMessageSigner)
+    }
+
+    #[inline]
+    fn wallet_connector(&self) -> Option<Self::WalletConnector> {
+        Some(SuiWalletConnector)
+    }
+
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(SuiTransactionDecoder)
+    }
+
+    #[inline]
+    fn transaction_hasher(&self) -> Option<Self::TransactionHasher> {
+        Some(SuiTransactionHasher)
+    }
 }
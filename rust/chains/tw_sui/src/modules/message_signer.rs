@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SuiAddress;
+use crate::modules::tx_signer::{IntentScope, TransactionPreimage, TxSigner};
+use crate::signature::SuiSignatureInfo;
+use std::borrow::Cow;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::bcs;
+use tw_keypair::ed25519;
+use tw_keypair::traits::VerifyingKeyTrait;
+use tw_memory::Data;
+use tw_proto::Sui::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+/// Signs and verifies personal messages (`signPersonalMessage`).
+/// The message is BCS-encoded as `vector<u8>` and signed with the `PersonalMessage` intent.
+pub struct SuiMessageSigner;
+
+impl MessageSigner for SuiMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(input).unwrap_or_default()
+    }
+}
+
+impl SuiMessageSigner {
+    fn message_preimage_hashes_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let preimage = Self::preimage(&input.message)?;
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::from(preimage.tx_data_to_sign),
+            data_hash: Cow::from(preimage.tx_hash_to_sign.into_vec()),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let signer_key = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+        let message_data = Self::encode_message(&input.message)?;
+
+        let (_, signature_info) =
+            TxSigner::sign_with_intent(IntentScope::PersonalMessage, message_data, &signer_key)?;
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::from(signature_info.to_base64()),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(input: Proto::MessageVerifyingInput<'_>) -> SigningResult<bool> {
+        let expected_address = SuiAddress::from_str(&input.address)?;
+        let signature_info = SuiSignatureInfo::from_base64(&input.signature)?;
+
+        let public_key = signature_info.ed25519_public_key()?;
+        if SuiAddress::with_ed25519_pubkey(&public_key)? != expected_address {
+            return Ok(false);
+        }
+
+        let preimage = Self::preimage(&input.message)?;
+        let signature = signature_info.ed25519_signature()?;
+        Ok(public_key.verify(signature, preimage.tx_hash_to_sign.into_vec()))
+    }
+
+    fn preimage(message: &[u8]) -> SigningResult<TransactionPreimage> {
+        let message_data = Self::encode_message(message)?;
+        TxSigner::preimage_with_intent(IntentScope::PersonalMessage, message_data)
+    }
+
+    /// Encodes the message as a `bcs` `vector<u8>`.
+    fn encode_message(message: &[u8]) -> SigningResult<Data> {
+        bcs::encode(message)
+            .tw_err(|_| SigningErrorType::Error_internal)
+            .context("Error serializing PersonalMessage")
+    }
+}
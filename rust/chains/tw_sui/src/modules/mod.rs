@@ -2,5 +2,9 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod message_signer;
+pub mod transaction_decoder;
+pub mod transaction_hasher;
 pub mod tx_builder;
 pub mod tx_signer;
+pub mod wallet_connect;
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::tx_signer::{AppId, IntentScope, IntentVersion};
+use crate::transaction::command::{Argument, Command};
+use crate::transaction::sui_types::{
+    CallArg, GasData, ObjectArg, ObjectRef, TransactionExpiration,
+};
+use crate::transaction::transaction_data::{TransactionData, TransactionKind};
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::bcs;
+use tw_proto::Sui::Proto;
+
+/// The intent prefix of a transaction data to sign.
+const TRANSACTION_DATA_INTENT: [u8; 3] = [
+    IntentScope::TransactionData as u8,
+    IntentVersion::V0 as u8,
+    AppId::Sui as u8,
+];
+
+pub struct SuiTransactionDecoder;
+
+impl TransactionDecoder for SuiTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, _coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl SuiTransactionDecoder {
+    fn decode_transaction_impl(
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let tx_data = Self::decode_tx_data(tx)?;
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(Self::tx_data_to_proto(tx_data)),
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+
+    /// Decodes a BCS-encoded `TransactionData`, optionally prefixed with the transaction intent.
+    pub(crate) fn decode_tx_data(tx: &[u8]) -> SigningResult<TransactionData> {
+        let decoded = bcs::decode(tx);
+        if decoded.is_err() {
+            // Try to decode the transaction data as an `IntentMessage<TransactionData>`.
+            if let Some(tx_data) = tx.strip_prefix(TRANSACTION_DATA_INTENT.as_slice()) {
+                if let Ok(decoded) = bcs::decode(tx_data) {
+                    return Ok(decoded);
+                }
+            }
+        }
+        decoded
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding BCS-encoded TransactionData")
+    }
+
+    fn tx_data_to_proto(tx_data: TransactionData) -> Proto::TransactionData<'static> {
+        use Proto::mod_TransactionData::OneOfexpiration as ProtoExpiration;
+
+        let TransactionData::V1(tx_data) = tx_data;
+        let TransactionKind::ProgrammableTransaction(pt) = tx_data.kind;
+
+        let expiration = match tx_data.expiration {
+            TransactionExpiration::None => ProtoExpiration::None,
+            TransactionExpiration::Epoch(epoch) => ProtoExpiration::expiration_epoch(epoch),
+        };
+
+        Proto::TransactionData {
+            sender: Cow::from(tx_data.sender.to_string()),
+            gas_data: Some(Self::gas_data_to_proto(tx_data.gas_data)),
+            inputs: pt.inputs.into_iter().map(Self::call_arg_to_proto).collect(),
+            commands: pt
+                .commands
+                .into_iter()
+                .map(Self::command_to_proto)
+                .collect(),
+            expiration,
+        }
+    }
+
+    fn gas_data_to_proto(gas_data: GasData) -> Proto::GasData<'static> {
+        Proto::GasData {
+            payment: gas_data
+                .payment
+                .iter()
+                .map(Self::object_ref_to_proto)
+                .collect(),
+            owner: Cow::from(gas_data.owner.to_string()),
+            price: gas_data.price,
+            budget: gas_data.budget,
+        }
+    }
+
+    fn object_ref_to_proto(object_ref: &ObjectRef) -> Proto::ObjectRef<'static> {
+        let (object_id, version, digest) = object_ref;
+        Proto::ObjectRef {
+            object_id: Cow::from(object_id.to_string()),
+            version: version.0,
+            object_digest: Cow::from(digest.to_string()),
+        }
+    }
+
+    fn call_arg_to_proto(call_arg: CallArg) -> Proto::CallArg<'static> {
+        use Proto::mod_CallArg::OneOfcall_arg as ProtoCallArg;
+
+        let call_arg = match call_arg {
+            CallArg::Pure(data) => ProtoCallArg::pure(Cow::from(data)),
+            CallArg::Object(ObjectArg::ImmOrOwnedObject(object_ref)) => {
+                ProtoCallArg::imm_or_owned_object(Self::object_ref_to_proto(&object_ref))
+            },
+            CallArg::Object(ObjectArg::SharedObject {
+                id,
+                initial_shared_version,
+                mutable,
+            }) => ProtoCallArg::shared_object(Proto::SharedObject {
+                object_id: Cow::from(id.to_string()),
+                initial_shared_version: initial_shared_version.0,
+                mutable,
+            }),
+        };
+        Proto::CallArg { call_arg }
+    }
+
+    fn command_to_proto(command: Command) -> Proto::Command<'static> {
+        use Proto::mod_Command::{self as command_proto, OneOfcommand as ProtoCommand};
+
+        let command = match command {
+            Command::MoveCall(call) => ProtoCommand::move_call(command_proto::MoveCall {
+                package: Cow::from(call.package.to_string()),
+                module: Cow::from(call.module.to_string()),
+                function: Cow::from(call.function.to_string()),
+                type_arguments: call
+                    .type_arguments
+                    .iter()
+                    .map(|type_tag| Cow::from(type_tag.to_string()))
+                    .collect(),
+                arguments: Self::arguments_to_proto(&call.arguments),
+            }),
+            Command::TransferObjects(objects, address) => {
+                ProtoCommand::transfer_objects(command_proto::TransferObjects {
+                    objects: Self::arguments_to_proto(&objects),
+                    address: Some(Self::argument_to_proto(&address)),
+                })
+            },
+            Command::SplitCoins(coin, amounts) => {
+                ProtoCommand::split_coins(command_proto::SplitCoins {
+                    coin: Some(Self::argument_to_proto(&coin)),
+                    amounts: Self::arguments_to_proto(&amounts),
+                })
+            },
+            Command::MergeCoins(destination, sources) => {
+                ProtoCommand::merge_coins(command_proto::MergeCoins {
+                    destination: Some(Self::argument_to_proto(&destination)),
+                    sources: Self::arguments_to_proto(&sources),
+                })
+            },
+            Command::Publish(modules, dependencies) => {
+                ProtoCommand::publish(command_proto::Publish {
+                    modules: modules.into_iter().map(Cow::from).collect(),
+                    dependencies: dependencies
+                        .iter()
+                        .map(|id| Cow::from(id.to_string()))
+                        .collect(),
+                })
+            },
+            Command::MakeMoveVec(type_tag, elements) => {
+                ProtoCommand::make_move_vec(command_proto::MakeMoveVec {
+                    type_tag: type_tag
+                        .map(|type_tag| Cow::from(type_tag.to_string()))
+                        .unwrap_or_default(),
+                    elements: Self::arguments_to_proto(&elements),
+                })
+            },
+        };
+        Proto::Command { command }
+    }
+
+    fn arguments_to_proto(arguments: &[Argument]) -> Vec<Proto::Argument> {
+        arguments.iter().map(Self::argument_to_proto).collect()
+    }
+
+    fn argument_to_proto(argument: &Argument) -> Proto::Argument {
+        use Proto::mod_Argument::{NestedResult, OneOfargument as ProtoArgument};
+
+        let argument = match *argument {
+            Argument::GasCoin => ProtoArgument::gas_coin(true),
+            Argument::Input(input) => ProtoArgument::input(u32::from(input)),
+            Argument::Result(result) => ProtoArgument::result(u32::from(result)),
+            Argument::NestedResult(result, index) => ProtoArgument::nested_result(NestedResult {
+                result: u32::from(result),
+                index: u32::from(index),
+            }),
+        };
+        Proto::Argument { argument }
+    }
+}
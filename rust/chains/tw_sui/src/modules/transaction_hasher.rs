@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::transaction_decoder::SuiTransactionDecoder;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_hasher::TransactionHasher;
+use tw_encoding::base58::{self, Alphabet};
+use tw_encoding::bcs;
+use tw_hash::blake2::blake2_b;
+use tw_hash::H256;
+
+/// The salt of a transaction digest.
+const TRANSACTION_DIGEST_SALT: &[u8] = b"TransactionData::";
+
+/// Computes the transaction digest as `Blake2b-256("TransactionData::" || bcs(TransactionData))`.
+///
+/// Signatures do not affect the digest, so the BCS-encoded `TransactionData` is expected,
+/// optionally prefixed with the transaction intent, i.e. `SigningOutput.unsigned_tx` decoded from Base64.
+/// Transaction bytes followed by signatures or a serialized `SenderSignedData` are rejected,
+/// as Sui transactions are broadcasted with the signatures passed separately anyway.
+pub struct SuiTransactionHasher;
+
+impl TransactionHasher for SuiTransactionHasher {
+    fn tx_id(&self, _coin: &dyn CoinContext, signed_tx: &[u8]) -> SigningResult<String> {
+        let tx_data = SuiTransactionDecoder::decode_tx_data(signed_tx)?;
+        let tx_data_bytes = bcs::encode(&tx_data)
+            .tw_err(|_| SigningErrorType::Error_internal)
+            .context("Error serializing TransactionData")?;
+
+        let mut preimage = TRANSACTION_DIGEST_SALT.to_vec();
+        preimage.extend_from_slice(&tx_data_bytes);
+
+        let digest = blake2_b(&preimage, H256::LEN).tw_err(|_| SigningErrorType::Error_internal)?;
+        Ok(base58::encode(&digest, Alphabet::Bitcoin))
+    }
+}
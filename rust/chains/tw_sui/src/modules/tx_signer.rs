@@ -21,6 +21,8 @@ use tw_memory::Data;
 pub enum IntentScope {
     /// Used for a user signature on a transaction data.
     TransactionData = 0,
+    /// Used for a user signature on a personal message.
+    PersonalMessage = 3,
 }
 
 /// The version here is to distinguish between signing different versions of the struct
@@ -102,7 +104,16 @@ impl TxSigner {
         unsigned_tx_data: Data,
         signer_key: &ed25519::sha512::KeyPair,
     ) -> SigningResult<(TransactionPreimage, SuiSignatureInfo)> {
-        let preimage = Self::preimage_direct(unsigned_tx_data)?;
+        Self::sign_with_intent(IntentScope::TransactionData, unsigned_tx_data, signer_key)
+    }
+
+    /// Signs the `bcs` encoded `data` prefixed with an intent of the given `scope`.
+    pub fn sign_with_intent(
+        scope: IntentScope,
+        data: Data,
+        signer_key: &ed25519::sha512::KeyPair,
+    ) -> SigningResult<(TransactionPreimage, SuiSignatureInfo)> {
+        let preimage = Self::preimage_with_intent(scope, data)?;
         let signature = signer_key.sign(preimage.tx_hash_to_sign.into_vec())?;
         let signature_info = SuiSignatureInfo::ed25519(&signature, signer_key.public());
         Ok((preimage, signature_info))
@@ -116,8 +127,16 @@ impl TxSigner {
     }
 
     pub fn preimage_direct(unsigned_tx_data: Data) -> SigningResult<TransactionPreimage> {
+        Self::preimage_with_intent(IntentScope::TransactionData, unsigned_tx_data)
+    }
+
+    /// Extends the `bcs` encoded `data` with an intent of the given `scope`, and hashes the result.
+    pub fn preimage_with_intent(
+        scope: IntentScope,
+        unsigned_tx_data: Data,
+    ) -> SigningResult<TransactionPreimage> {
         let intent = Intent {
-            scope: IntentScope::TransactionData,
+            scope,
             version: IntentVersion::V0,
             app_id: AppId::Sui,
         };
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SuiAddress;
+use crate::modules::wallet_connect::request::{SignPersonalMessageRequest, SignTransactionRequest};
+use serde::de::DeserializeOwned;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::wallet_connector::WalletConnector;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base64;
+use tw_proto::Sui::Proto::{self, mod_SigningInput::OneOftransaction_payload as TransactionType};
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+
+pub struct SuiWalletConnector;
+
+impl WalletConnector for SuiWalletConnector {
+    fn parse_request(
+        &self,
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> WCProto::ParseRequestOutput<'static> {
+        Self::parse_request_impl(coin, request)
+            .unwrap_or_else(|e| signing_output_error!(WCProto::ParseRequestOutput, e))
+    }
+}
+
+impl SuiWalletConnector {
+    fn parse_request_impl(
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        match request.method {
+            WCProto::Method::SuiSignTransaction => {
+                Self::parse_sign_transaction_request(coin, request)
+            },
+            WCProto::Method::SuiSignPersonalMessage => {
+                Self::parse_sign_personal_message_request(coin, request)
+            },
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Unknown WalletConnect method"),
+        }
+    }
+
+    pub fn parse_sign_transaction_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let tx: SignTransactionRequest = parse_params(&request.payload)?;
+        let signer = parse_address(&tx.address)?;
+
+        // Make sure the transaction is a valid Base64 string.
+        // The transaction data itself is validated on signing.
+        let is_url = false;
+        base64::decode(&tx.transaction, is_url)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Expected a Base64-encoded transaction")?;
+
+        let direct = Proto::SignDirect {
+            unsigned_tx_msg: tx.transaction.into(),
+        };
+        let signing_input = Proto::SigningInput {
+            signer: signer.to_string().into(),
+            transaction_payload: TransactionType::sign_direct_message(direct),
+            ..Proto::SigningInput::default()
+        };
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::sui(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+
+    pub fn parse_sign_personal_message_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let msg: SignPersonalMessageRequest = parse_params(&request.payload)?;
+        parse_address(&msg.address)?;
+
+        let signing_input = Proto::MessageSigningInput {
+            message: msg.message.into_bytes().into(),
+            ..Proto::MessageSigningInput::default()
+        };
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::sui_message(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(payload: &str) -> SigningResult<T> {
+    serde_json::from_str(payload)
+        .tw_err(|_| SigningErrorType::Error_input_parse)
+        .context("Error parsing WalletConnect request params as JSON")
+}
+
+fn parse_address(address: &str) -> SigningResult<SuiAddress> {
+    SuiAddress::from_str(address)
+        .into_tw()
+        .context("Invalid signer address")
+}
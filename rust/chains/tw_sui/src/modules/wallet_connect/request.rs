@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::Deserialize;
+
+/// `sui_signTransaction` request params.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/sui-rpc#sui_signtransaction
+#[derive(Deserialize)]
+pub struct SignTransactionRequest {
+    /// BCS-serialized `TransactionData`, Base64-encoded.
+    pub transaction: String,
+    pub address: String,
+}
+
+/// `sui_signPersonalMessage` request params.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/sui-rpc#sui_signpersonalmessage
+#[derive(Deserialize)]
+pub struct SignPersonalMessageRequest {
+    pub message: String,
+    pub address: String,
+}
@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use tw_coin_entry::error::prelude::*;
 use tw_encoding::base64;
 use tw_hash::{H256, H512};
 use tw_keypair::ed25519;
@@ -31,6 +32,45 @@ impl SuiSignatureInfo {
         }
     }
 
+    /// Parses a serialized `flag || signature || public_key` signature.
+    pub fn from_bytes(bytes: &[u8]) -> SigningResult<SuiSignatureInfo> {
+        let (scheme, rest) = bytes
+            .split_first()
+            .or_tw_err(SigningErrorType::Error_input_parse)
+            .context("Empty Sui signature")?;
+        if *scheme != SignatureScheme::ED25519 as u8 {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context(format!("Unsupported Sui signature scheme: {scheme}"));
+        }
+        if rest.len() != H512::LEN + H256::LEN {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Invalid Sui ed25519 signature length");
+        }
+
+        let (signature, public_key) = rest.split_at(H512::LEN);
+        Ok(SuiSignatureInfo {
+            scheme: SignatureScheme::ED25519,
+            signature: H512::try_from(signature).expect("Expected a valid signature length"),
+            public_key: H256::try_from(public_key).expect("Expected a valid public key length"),
+        })
+    }
+
+    pub fn from_base64(s: &str) -> SigningResult<SuiSignatureInfo> {
+        let is_url = false;
+        let bytes = base64::decode(s, is_url)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Invalid Base64 Sui signature")?;
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn ed25519_signature(&self) -> SigningResult<ed25519::Signature> {
+        ed25519::Signature::try_from(self.signature.as_slice()).into_tw()
+    }
+
+    pub fn ed25519_public_key(&self) -> SigningResult<ed25519::sha512::PublicKey> {
+        ed25519::sha512::PublicKey::try_from(self.public_key.as_slice()).into_tw()
+    }
+
     pub fn to_vec(&self) -> Data {
         let mut scheme: Data = Vec::with_capacity(H512::LEN + H256::LEN + 1);
         scheme.push(self.scheme as u8);
@@ -2,9 +2,11 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::encode::reader::Reader;
 use crate::encode::stream::Stream;
-use crate::encode::Encodable;
+use crate::encode::{Decodable, Encodable};
 use std::ops::RangeInclusive;
+use tw_coin_entry::error::prelude::*;
 
 const ONE_BYTE_RANGE: RangeInclusive<u64> = 0..=0xFC;
 const TWO_BYTES_RANGE: RangeInclusive<u64> = 0xFD..=0xFFFF;
@@ -24,6 +26,14 @@ impl From<usize> for CompactInteger {
     }
 }
 
+impl CompactInteger {
+    pub fn as_usize(&self) -> SigningResult<usize> {
+        usize::try_from(self.0)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Compact integer overflow")
+    }
+}
+
 impl Encodable for CompactInteger {
     fn encode(&self, stream: &mut Stream) {
         let v = self.0;
@@ -55,6 +65,27 @@ impl Encodable for CompactInteger {
     }
 }
 
+impl Decodable for CompactInteger {
+    fn decode(reader: &mut Reader) -> SigningResult<Self> {
+        let (v, range) = match reader.read::<u8>()? {
+            TWO_BYTES_FLAG => (u64::from(reader.read::<u16>()?), TWO_BYTES_RANGE),
+            FOUR_BYTES_FLAG => (u64::from(reader.read::<u32>()?), FOUR_BYTES_RANGE),
+            EIGHT_BYTES_FLAG => (
+                reader.read::<u64>()?,
+                *FOUR_BYTES_RANGE.end() + 1..=u64::MAX,
+            ),
+            v => (u64::from(v), ONE_BYTE_RANGE),
+        };
+
+        // The integer must be encoded with the least possible number of bytes.
+        if !range.contains(&v) {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Non-canonical compact integer");
+        }
+        Ok(CompactInteger(v))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,4 +110,29 @@ mod tests {
 
         assert_eq!(stream.out(), expected);
     }
+
+    #[test]
+    fn test_compact_integer_decode() {
+        use crate::encode::decode;
+
+        assert_eq!(
+            decode::<CompactInteger>(&[0xfc]).unwrap(),
+            CompactInteger(0xfc)
+        );
+        assert_eq!(
+            decode::<CompactInteger>(&[0xfd, 0xfd, 0x00]).unwrap(),
+            CompactInteger(0xfd)
+        );
+        assert_eq!(
+            decode::<CompactInteger>(&[0xff, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00])
+                .unwrap(),
+            CompactInteger(0x1_0000_0000)
+        );
+
+        // Non-canonical encoding.
+        assert!(decode::<CompactInteger>(&[0xfd, 0xfc, 0x00]).is_err());
+        assert!(decode::<CompactInteger>(&[0xfe, 0xff, 0xff, 0x00, 0x00]).is_err());
+        // Unexpected end of the stream.
+        assert!(decode::<CompactInteger>(&[0xfd, 0x01]).is_err());
+    }
 }
@@ -3,9 +3,11 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::encode::compact_integer::CompactInteger;
+use crate::encode::reader::Reader;
 use crate::encode::stream::Stream;
-use crate::encode::Encodable;
+use crate::encode::{Decodable, Encodable};
 use byteorder::{LittleEndian, WriteBytesExt};
+use tw_coin_entry::error::prelude::*;
 use tw_hash::Hash;
 use tw_memory::Data;
 
@@ -21,6 +23,13 @@ impl Encodable for Data {
     }
 }
 
+impl Decodable for Data {
+    fn decode(reader: &mut Reader) -> SigningResult<Self> {
+        let len = reader.read::<CompactInteger>()?.as_usize()?;
+        reader.read_slice(len).map(<[u8]>::to_vec)
+    }
+}
+
 impl<const N: usize> Encodable for Hash<N> {
     #[inline]
     fn encode(&self, stream: &mut Stream) {
@@ -33,6 +42,13 @@ impl<const N: usize> Encodable for Hash<N> {
     }
 }
 
+impl<const N: usize> Decodable for Hash<N> {
+    #[inline]
+    fn decode(reader: &mut Reader) -> SigningResult<Self> {
+        reader.read_array().map(Hash::from)
+    }
+}
+
 impl Encodable for u8 {
     #[inline]
     fn encode(&self, s: &mut Stream) {
@@ -45,6 +61,13 @@ impl Encodable for u8 {
     }
 }
 
+impl Decodable for u8 {
+    #[inline]
+    fn decode(reader: &mut Reader) -> SigningResult<Self> {
+        reader.read_array().map(u8::from_le_bytes)
+    }
+}
+
 macro_rules! impl_encodable_for_int {
     ($int:ty, $size:literal, $write_fn:tt) => {
         impl Encodable for $int {
@@ -58,6 +81,13 @@ macro_rules! impl_encodable_for_int {
                 $size
             }
         }
+
+        impl Decodable for $int {
+            #[inline]
+            fn decode(reader: &mut Reader) -> SigningResult<Self> {
+                reader.read_array().map(<$int>::from_le_bytes)
+            }
+        }
     };
 }
 
@@ -70,7 +100,7 @@ impl_encodable_for_int!(u64, 8, write_u64);
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::encode::encode;
+    use crate::encode::{decode, encode};
     use tw_encoding::hex::{DecodeHex, ToHex};
 
     #[test]
@@ -94,6 +124,28 @@ mod tests {
         assert_eq!(expected, encode(&bytes));
     }
 
+    #[test]
+    fn test_bytes_deserialize() {
+        let encoded = "020145".decode_hex().unwrap();
+        let bytes: Data = decode(&encoded).unwrap();
+        assert_eq!(bytes.to_hex(), "0145");
+
+        // The length prefix exceeds the actual data length.
+        assert!(decode::<Data>(&"030145".decode_hex().unwrap()).is_err());
+        // Trailing bytes.
+        assert!(decode::<Data>(&"01014500".decode_hex().unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_int_deserialize() {
+        let encoded = "0102000300000004000000".decode_hex().unwrap();
+        let mut reader = Reader::new(&encoded);
+        assert_eq!(reader.read::<u8>().unwrap(), 1);
+        assert_eq!(reader.read::<u16>().unwrap(), 2);
+        assert_eq!(reader.read::<u32>().unwrap(), 3);
+        assert!(reader.read::<u64>().is_err());
+    }
+
     #[test]
     fn test_steam_append_slice() {
         let mut slice = [0u8; 4];
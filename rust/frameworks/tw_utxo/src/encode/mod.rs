@@ -2,11 +2,14 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::encode::reader::Reader;
 use crate::encode::stream::Stream;
+use tw_coin_entry::error::prelude::*;
 use tw_memory::Data;
 
 pub mod compact_integer;
 pub mod impls;
+pub mod reader;
 pub mod stream;
 
 pub fn encode<T>(t: &T) -> Data
@@ -18,6 +21,21 @@ where
     stream.out()
 }
 
+/// Deserializes the struct from the given `data`.
+/// Returns an error if there are bytes left after the struct is read.
+pub fn decode<T>(data: &[u8]) -> SigningResult<T>
+where
+    T: Decodable,
+{
+    let mut reader = Reader::new(data);
+    let t = reader.read()?;
+    if !reader.is_finished() {
+        return SigningError::err(SigningErrorType::Error_input_parse)
+            .context("Unexpected trailing bytes");
+    }
+    Ok(t)
+}
+
 pub trait Encodable {
     /// Serialize the struct and appends it to the end of stream.
     fn encode(&self, stream: &mut Stream);
@@ -25,3 +43,8 @@ pub trait Encodable {
     /// Hint about the size of serialized struct.
     fn encoded_size(&self) -> usize;
 }
+
+pub trait Decodable: Sized {
+    /// Deserialize the struct from the beginning of the stream.
+    fn decode(reader: &mut Reader) -> SigningResult<Self>;
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::encode::compact_integer::CompactInteger;
+use crate::encode::Decodable;
+use tw_coin_entry::error::prelude::*;
+
+/// Reader used for deserialization of Bitcoin structures.
+pub struct Reader<'a> {
+    buffer: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// New reader over the given `buffer`.
+    pub fn new(buffer: &'a [u8]) -> Self {
+        Reader { buffer }
+    }
+
+    /// Deserializes the struct from the beginning of the stream.
+    pub fn read<T>(&mut self) -> SigningResult<T>
+    where
+        T: Decodable,
+    {
+        T::decode(self)
+    }
+
+    /// Reads exactly `len` raw bytes.
+    pub fn read_slice(&mut self, len: usize) -> SigningResult<&'a [u8]> {
+        if self.buffer.len() < len {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Unexpected end of the stream");
+        }
+        let (bytes, rest) = self.buffer.split_at(len);
+        self.buffer = rest;
+        Ok(bytes)
+    }
+
+    /// Reads exactly `N` raw bytes.
+    pub fn read_array<const N: usize>(&mut self) -> SigningResult<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read_slice(N)?);
+        Ok(array)
+    }
+
+    /// Reads a list of deserializable structs prefixed with its length.
+    pub fn read_list<T: Decodable>(&mut self) -> SigningResult<Vec<T>> {
+        let len = self.read::<CompactInteger>()?.as_usize()?;
+        // Do not preallocate the list as the length can be arbitrary large.
+        let mut list = Vec::new();
+        for _ in 0..len {
+            list.push(self.read()?);
+        }
+        Ok(list)
+    }
+
+    /// Whether the whole stream has been read.
+    pub fn is_finished(&self) -> bool {
+        self.buffer.is_empty()
+    }
+}
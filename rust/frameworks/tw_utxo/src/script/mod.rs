@@ -3,9 +3,11 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::encode::compact_integer::CompactInteger;
+use crate::encode::reader::Reader;
 use crate::encode::stream::Stream;
-use crate::encode::Encodable;
+use crate::encode::{Decodable, Encodable};
 use standard_script::opcodes::*;
+use tw_coin_entry::error::prelude::*;
 use tw_memory::Data;
 
 pub mod standard_script;
@@ -28,6 +30,12 @@ impl Encodable for Script {
     }
 }
 
+impl Decodable for Script {
+    fn decode(reader: &mut Reader) -> SigningResult<Self> {
+        reader.read::<Data>().map(Script::from)
+    }
+}
+
 impl Script {
     pub fn new() -> Self {
         Self::default()
@@ -125,6 +133,14 @@ impl Encodable for Witness {
     }
 }
 
+impl Decodable for Witness {
+    fn decode(reader: &mut Reader) -> SigningResult<Self> {
+        Ok(Witness {
+            items: reader.read_list()?,
+        })
+    }
+}
+
 impl Witness {
     pub fn new() -> Self {
         Self::default()
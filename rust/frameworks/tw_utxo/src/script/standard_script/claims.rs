@@ -1,9 +1,9 @@
-use tw_hash::H264;
+use tw_hash::{H160, H264};
 
 use crate::script::Witness;
 use crate::signature::{BitcoinEcdsaSignature, BitcoinSchnorrSignature};
 
-use super::Script;
+use super::{conditions, Script};
 
 /// Creates a script to claim a P2PK spending condition (_scriptSig_).
 ///
@@ -45,24 +45,36 @@ pub fn new_p2pkh(sig: &BitcoinEcdsaSignature, pubkey: &H264) -> Script {
     s
 }
 
-// TODO next iteration.
-// /// Creates witness script items to claim a P2WSH spending condition
-// /// (_witness_).
-// ///
-// /// ```txt
-// /// <witness_item_1>
-// /// <witness_item_2>
-// /// ...
-// /// <redeem_script>
-// /// ```
-// pub fn new_p2wsh(items: Vec<Script>, redeem_script: Script) -> Witness {
-//     let mut w = Witness::new();
-//     for item in items {
-//         w.push_item(item.clone());
-//     }
-//     w.push_item(redeem_script);
-//     w
-// }
+/// Creates a script to claim a P2SH-P2WPKH (nested segwit) spending condition (_scriptSig_).
+/// The signature and the public key are claimed by a P2WPKH witness.
+///
+/// ```txt
+/// <push><OP_0 <push><pubkey_hash>>
+/// ```
+pub fn new_p2sh_p2wpkh(pubkey_hash: &H160) -> Script {
+    let redeem_script = conditions::new_p2wpkh(pubkey_hash);
+    let mut s = Script::with_capacity(23);
+    s.push_slice(redeem_script.as_slice());
+    s
+}
+
+/// Creates witness script items to claim a P2WSH spending condition
+/// (_witness_).
+///
+/// ```txt
+/// <witness_item_1>
+/// <witness_item_2>
+/// ...
+/// <redeem_script>
+/// ```
+pub fn new_p2wsh(items: Vec<Script>, redeem_script: Script) -> Witness {
+    let mut w = Witness::new();
+    for item in items {
+        w.push_item(item);
+    }
+    w.push_item(redeem_script);
+    w
+}
 
 /// Creates witness script items to claim a P2WPKH spending condition
 /// (_witness_).
@@ -188,3 +188,21 @@ pub fn match_p2tr(s: &Script) -> Option<H256> {
         None
     }
 }
+
+/// Returns a script hash if matched.
+pub fn match_p2sh(s: &Script) -> Option<H160> {
+    if is_p2sh(s) {
+        Some(H160::try_from(&s.as_slice()[2..22]).expect("is_p2sh checks the length"))
+    } else {
+        None
+    }
+}
+
+/// Returns a script hash if matched.
+pub fn match_p2wsh(s: &Script) -> Option<H256> {
+    if is_p2wsh(s) {
+        Some(H256::try_from(&s.as_slice()[2..]).expect("is_p2wsh checks the length"))
+    } else {
+        None
+    }
+}
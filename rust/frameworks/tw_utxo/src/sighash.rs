@@ -28,6 +28,8 @@ pub struct SighashType {
     raw_sighash: u32,
     /// Sighash base type.
     base: SighashBase,
+    /// Whether `All` sighash is committed to as 0x01 rather than as 0x00 (`Default`) in Taproot.
+    explicit_taproot_all: bool,
 }
 
 impl SighashType {
@@ -45,6 +47,21 @@ impl SighashType {
         Ok(SighashType {
             raw_sighash: u,
             base,
+            explicit_taproot_all: false,
+        })
+    }
+
+    /// Creates Sighash from a byte appended to a 65-byte schnorr signature.
+    /// Unlike [`SighashType::from_u32`], `All` (0x01) is then committed to explicitly
+    /// instead of as `Default` (0x00), as BIP-341 requires.
+    pub fn from_taproot_u8(u: u8) -> SigningResult<Self> {
+        if u == DEFAULT_TAPROOT_SIGHASH_TYPE {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Default sighash type must not be appended to a schnorr signature");
+        }
+        Ok(SighashType {
+            explicit_taproot_all: true,
+            ..SighashType::from_u32(u as u32)?
         })
     }
 
@@ -79,6 +96,9 @@ impl SighashType {
     /// The 0x00 variant is only supported in Taproot transactions,
     /// not in Legacy or Segwit transactions.
     pub fn is_default_taproot_sighash(&self) -> bool {
+        if self.explicit_taproot_all {
+            return false;
+        }
         self.raw_sighash == DEFAULT_TAPROOT_SIGHASH_TYPE as u32
             || self.raw_sighash == SighashBase::All as u32
     }
@@ -103,6 +123,7 @@ impl Default for SighashType {
         SighashType {
             raw_sighash: SighashBase::All as u32,
             base: SighashBase::All,
+            explicit_taproot_all: false,
         }
     }
 }
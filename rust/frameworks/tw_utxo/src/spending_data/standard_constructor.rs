@@ -8,7 +8,8 @@ use crate::signature::{BitcoinEcdsaSignature, BitcoinSchnorrSignature};
 use crate::spending_data::{
     EcdsaSpendingDataConstructor, SchnorrSpendingDataConstructor, SpendingData,
 };
-use tw_hash::H264;
+use tw_hash::ripemd::bitcoin_hash_160;
+use tw_hash::{H160, H264};
 use tw_memory::Data;
 
 #[derive(Clone, Debug)]
@@ -51,6 +52,42 @@ impl EcdsaSpendingDataConstructor for P2WPKH {
     }
 }
 
+/// Claims a P2WSH output with a witness script that requires a single signature,
+/// e.g. `<pubkey> OP_CHECKSIG`.
+#[derive(Clone, Debug)]
+pub struct P2WSH {
+    pub witness_script: Script,
+}
+
+impl EcdsaSpendingDataConstructor for P2WSH {
+    fn get_spending_data(&self, sig: &BitcoinEcdsaSignature) -> SpendingData {
+        SpendingData {
+            script_sig: Script::default(),
+            witness: claims::new_p2wsh(
+                vec![Script::from(sig.serialize())],
+                self.witness_script.clone(),
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct P2SHP2WPKH {
+    pub pubkey: H264,
+}
+
+impl EcdsaSpendingDataConstructor for P2SHP2WPKH {
+    fn get_spending_data(&self, sig: &BitcoinEcdsaSignature) -> SpendingData {
+        let h = bitcoin_hash_160(self.pubkey.as_slice());
+        let pubkey_hash: H160 = h.as_slice().try_into().expect("hash length is 20 bytes");
+
+        SpendingData {
+            script_sig: claims::new_p2sh_p2wpkh(&pubkey_hash),
+            witness: claims::new_p2wpkh(sig, self.pubkey),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct P2TRKeyPath;
 
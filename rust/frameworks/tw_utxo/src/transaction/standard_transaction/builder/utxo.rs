@@ -178,29 +178,36 @@ impl UtxoBuilder {
         ))
     }
 
-    // TODO next iteration.
-    // pub fn p2wsh(mut self, redeem_script: Script) -> SigningResult<(TransactionInput, UtxoToSign)> {
-    //     let h = sha256(redeem_script.as_slice());
-    //     let redeem_hash: H256 = h.as_slice().try_into().expect("hash length is 32 bytes");
-    //
-    //     self.finalize_out_point()?;
-    //     let amount = self.finalize_amount()?;
-    //     let sighash_ty = self.finalize_sighash_type()?;
-    //
-    //     Ok((
-    //         self.input,
-    //         UtxoToSign {
-    //             script_pubkey: conditions::new_p2wsh(&redeem_hash),
-    //             // P2WSH output can be spent by a legacy address only.
-    //             signing_method: SigningMethod::Segwit,
-    //             spending_data_constructor: SpendingDataConstructor::ecdsa(todo!()),
-    //             amount,
-    //             leaf_hash_code_separator: None,
-    //             tx_hasher: DEFAULT_TX_HASHER,
-    //             sighash_ty,
-    //         },
-    //     ))
-    // }
+    /// Spends a P2WSH output with the `witness_script` that requires a single signature of the `pubkey`,
+    /// e.g. `<pubkey> OP_CHECKSIG`.
+    pub fn p2wsh(
+        mut self,
+        witness_script: Script,
+        pubkey: &ecdsa::secp256k1::PublicKey,
+    ) -> SigningResult<(TransactionInput, UtxoToSign)> {
+        self.finalize_out_point()?;
+        let amount = self.finalize_amount()?;
+        let sighash_ty = self.finalize_sighash_type()?;
+
+        Ok((
+            self.input,
+            UtxoToSign {
+                // P2WSH UTXO is signed with the witness script as the scriptCode.
+                script_pubkey: witness_script.clone(),
+                // When the sighash is signed, build a P2WSH witness.
+                spending_data_constructor: SpendingDataConstructor::ecdsa(
+                    standard_constructor::P2WSH { witness_script },
+                ),
+                spender_public_key: pubkey.compressed().to_vec(),
+                // P2WSH output can be spent by a Witness (eg "bc1") address only.
+                signing_method: SigningMethod::Segwit,
+                amount,
+                leaf_hash_code_separator: None,
+                tx_hasher: DEFAULT_TX_HASHER,
+                sighash_ty,
+            },
+        ))
+    }
 
     pub fn p2wpkh(
         mut self,
@@ -237,6 +244,38 @@ impl UtxoBuilder {
         ))
     }
 
+    pub fn p2sh_p2wpkh(
+        mut self,
+        pubkey: &ecdsa::secp256k1::PublicKey,
+    ) -> SigningResult<(TransactionInput, UtxoToSign)> {
+        let h = bitcoin_hash_160(pubkey.compressed().as_slice());
+        let pubkey_hash: H160 = h.as_slice().try_into().expect("hash length is 20 bytes");
+
+        self.finalize_out_point()?;
+        let amount = self.finalize_amount()?;
+        let sighash_ty = self.finalize_sighash_type()?;
+
+        Ok((
+            self.input,
+            UtxoToSign {
+                // P2SH-P2WPKH UTXO is signed exactly as P2WPKH, i.e with a corresponding P2PKH scriptCode.
+                script_pubkey: conditions::new_p2pkh(&pubkey_hash),
+                // When the sighash is signed, build a scriptSig with the P2WPKH redeem script and a P2WPKH witness.
+                spending_data_constructor: SpendingDataConstructor::ecdsa(
+                    standard_constructor::P2SHP2WPKH {
+                        pubkey: pubkey.compressed(),
+                    },
+                ),
+                spender_public_key: pubkey.compressed().to_vec(),
+                signing_method: SigningMethod::Segwit,
+                amount,
+                leaf_hash_code_separator: None,
+                tx_hasher: DEFAULT_TX_HASHER,
+                sighash_ty,
+            },
+        ))
+    }
+
     pub fn p2tr_key_path(
         self,
         pubkey: &schnorr::PublicKey,
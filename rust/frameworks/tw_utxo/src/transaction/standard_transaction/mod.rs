@@ -3,8 +3,9 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::encode::compact_integer::CompactInteger;
+use crate::encode::reader::Reader;
 use crate::encode::stream::Stream;
-use crate::encode::Encodable;
+use crate::encode::{Decodable, Encodable};
 use crate::script::{Script, Witness};
 use crate::signing_mode::SigningMethod;
 use crate::transaction::transaction_interface::{
@@ -217,6 +218,44 @@ impl Encodable for Transaction {
     }
 }
 
+impl Decodable for Transaction {
+    fn decode(reader: &mut Reader) -> SigningResult<Self> {
+        let version = reader.read()?;
+
+        // An empty list of inputs is either a witness marker or an invalid transaction.
+        let mut inputs: Vec<TransactionInput> = reader.read_list()?;
+        let has_witness = inputs.is_empty();
+        if has_witness {
+            let flag: u8 = reader.read()?;
+            if flag != WITNESS_FLAG {
+                return SigningError::err(SigningErrorType::Error_input_parse)
+                    .context(format!("Unknown transaction flag: {flag}"));
+            }
+            inputs = reader.read_list()?;
+        }
+
+        let outputs = reader.read_list()?;
+
+        if has_witness {
+            for input in inputs.iter_mut() {
+                input.witness = reader.read()?;
+            }
+            // It's not allowed to use the extended format if there are no witnesses.
+            if !inputs.iter().any(TransactionInput::has_witness) {
+                return SigningError::err(SigningErrorType::Error_input_parse)
+                    .context("Superfluous witness record");
+            }
+        }
+
+        Ok(Transaction {
+            version,
+            inputs,
+            outputs,
+            locktime: reader.read()?,
+        })
+    }
+}
+
 impl TransactionPreimage for Transaction {
     fn preimage_tx(&self, args: &UtxoPreimageArgs) -> SigningResult<H256> {
         match args.signing_method {
@@ -317,6 +356,18 @@ impl Encodable for TransactionInput {
     }
 }
 
+impl Decodable for TransactionInput {
+    fn decode(reader: &mut Reader) -> SigningResult<Self> {
+        Ok(TransactionInput {
+            previous_output: reader.read()?,
+            script_sig: reader.read()?,
+            sequence: reader.read()?,
+            // Witness is decoded separately after all transaction outputs.
+            witness: Witness::default(),
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TransactionOutput {
     /// Transaction amount.
@@ -358,3 +409,12 @@ impl Encodable for TransactionOutput {
         self.value.encoded_size() + self.script_pubkey.encoded_size()
     }
 }
+
+impl Decodable for TransactionOutput {
+    fn decode(reader: &mut Reader) -> SigningResult<Self> {
+        Ok(TransactionOutput {
+            value: reader.read()?,
+            script_pubkey: reader.read()?,
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use tw_encoding::hex;
+
+use tw_utxo::encode::decode;
+use tw_utxo::script::standard_script::conditions;
+use tw_utxo::transaction::standard_transaction::Transaction;
+use tw_utxo::transaction::transaction_interface::{TransactionInterface, TxInputInterface};
+
+#[test]
+fn decode_tx_legacy() {
+    let encoded = "02000000017be4e642bb278018ab12277de9427773ad1c5f5b1d164a157e0d99aa48dc1c1e000000006a473044022078eda020d4b86fcb3af78ef919912e6d79b81164dbbb0b0b96da6ac58a2de4b102201a5fd8d48734d5a02371c4b5ee551a69dca3842edbf577d863cf8ae9fdbbd4590121036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b87389536ffffffff01c0aff629010000001976a9145eaaa4f458f9158f86afcba08dd7448d27045e3d88ac00000000";
+    let tx: Transaction = decode(&hex::decode(encoded).unwrap()).unwrap();
+
+    assert_eq!(tx.version, 2);
+    assert_eq!(tx.locktime, 0);
+    assert!(!tx.has_witness());
+
+    assert_eq!(tx.inputs.len(), 1);
+    assert_eq!(
+        hex::encode(tx.inputs[0].previous_output.hash, false),
+        "7be4e642bb278018ab12277de9427773ad1c5f5b1d164a157e0d99aa48dc1c1e"
+    );
+    assert_eq!(tx.inputs[0].previous_output.index, 0);
+    assert_eq!(tx.inputs[0].sequence, u32::MAX);
+    assert!(tx.inputs[0].has_script_sig());
+
+    assert_eq!(tx.outputs.len(), 1);
+    assert_eq!(tx.outputs[0].value, 50 * 100_000_000 - 1_000_000);
+    assert!(conditions::is_p2pkh(&tx.outputs[0].script_pubkey));
+
+    assert_eq!(hex::encode(tx.encode_out(), false), encoded);
+}
+
+#[test]
+fn decode_tx_segwit() {
+    let encoded = "020000000001016e1f16dcfafbb3a83697f6c23c624cd71085a7f8a25ce0bd9743a41d0a458e850000000000ffffffff01806de7290100000016001460cda7b50f14c152d7401c28ae773c698db9237302483045022100a9b517de5a5e036d7133df499b5b751db6f9a01576a6c5dc38229ec08b6c45cd02200e42c9f8c707c9bf0ceab4f739ec8d683dc1f1f29e195a8da9bc183584d624a60121025a0af1510f0f24d40dd00d7c0e51605ca504bbc177c3e19b065f373a1efdd22f00000000";
+    let tx: Transaction = decode(&hex::decode(encoded).unwrap()).unwrap();
+
+    assert!(tx.has_witness());
+    assert_eq!(tx.inputs.len(), 1);
+    assert!(!tx.inputs[0].has_script_sig());
+    assert_eq!(tx.inputs[0].witness.as_items().len(), 2);
+
+    assert_eq!(tx.outputs.len(), 1);
+    assert!(conditions::is_p2wpkh(&tx.outputs[0].script_pubkey));
+
+    assert_eq!(hex::encode(tx.encode_out(), false), encoded);
+}
+
+#[test]
+fn decode_tx_invalid() {
+    // Trailing bytes after the lock time.
+    let encoded = "020000000100000000000000000000000000000000000000000000000000000000000000000000000000ffffffff000000000000";
+    assert!(decode::<Transaction>(&hex::decode(encoded).unwrap()).is_err());
+
+    // Extended format without any witness.
+    let encoded = "0200000000010100000000000000000000000000000000000000000000000000000000000000000000000000ffffffff000000000000";
+    assert!(decode::<Transaction>(&hex::decode(encoded).unwrap()).is_err());
+
+    // Unexpected end of the stream.
+    let encoded = "020000000101";
+    assert!(decode::<Transaction>(&hex::decode(encoded).unwrap()).is_err());
+}
@@ -2,11 +2,14 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use std::cmp::Reverse;
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::prefix::AddressPrefix;
-use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::custom_registry::custom_coin_items;
 use tw_coin_registry::dispatcher::coin_dispatcher;
+use tw_coin_registry::registry::supported_coin_items;
+use tw_encoding::base58::{self, Alphabet};
 use tw_keypair::tw::PublicKey;
 use tw_memory::Data;
 use tw_misc::try_or_false;
@@ -14,25 +17,66 @@ use tw_misc::try_or_false;
 /// Represents an address in Rust for almost any blockchain.
 #[derive(Debug, PartialEq)]
 pub struct AnyAddress {
-    coin: CoinType,
+    coin: u32,
     address: String,
 }
 
 impl AnyAddress {
     /// Determines if the string is a valid Any address.
     #[inline]
-    pub fn is_valid(coin: CoinType, address: &str, prefix: Option<AddressPrefix>) -> bool {
+    pub fn is_valid(coin: impl Into<u32>, address: &str, prefix: Option<AddressPrefix>) -> bool {
         let (ctx, entry) = try_or_false!(coin_dispatcher(coin));
         entry.validate_address(&ctx, address, prefix).is_ok()
     }
 
+    /// Returns coin types, including custom coins, that accept the given address string.
+    ///
+    /// Candidates are ranked by specificity: coins whose Bech32 HRP matches come first,
+    /// then coins whose Base58 version byte matches, then coins with formats shared among many chains
+    /// (e.g. EVM hex, Solana, Sui, Aptos addresses or Internet Computer principals).
+    /// Candidates of the same rank are ordered by coin type.
+    pub fn detect(address: &str) -> Vec<u32> {
+        let address = address.trim();
+        if address.is_empty() {
+            return Vec::default();
+        }
+        let base58_version = base58::decode(address, Alphabet::Bitcoin)
+            .ok()
+            .and_then(|data| data.first().copied());
+
+        let built_in = supported_coin_items().map(|item| {
+            let prefixes = [item.p2pkh_prefix, item.p2sh_prefix];
+            let specificity =
+                AddressSpecificity::of(address, base58_version, item.hrp.as_deref(), &prefixes);
+            (item.coin_id as u32, specificity)
+        });
+        let custom = custom_coin_items().into_iter().map(|item| {
+            let specificity =
+                AddressSpecificity::of(address, base58_version, item.hrp.as_deref(), &[]);
+            (item.coin_id, specificity)
+        });
+
+        let mut candidates: Vec<_> = built_in
+            .chain(custom)
+            .filter(|(coin_id, _specificity)| AnyAddress::is_valid(*coin_id, address, None))
+            .collect();
+        // Registry items are not ordered, so sort the candidates by coin type within the same rank.
+        candidates.sort_by_key(|(coin_id, specificity)| (Reverse(*specificity), *coin_id));
+
+        candidates
+            .into_iter()
+            .map(|(coin_id, _specificity)| coin_id)
+            .collect()
+    }
+
     /// Creates an address from a string representation and a coin type.
     #[inline]
     pub fn with_string(
-        coin: CoinType,
+        coin: impl Into<u32>,
         address: &str,
         prefix: Option<AddressPrefix>,
     ) -> AddressResult<AnyAddress> {
+        let coin = coin.into();
         let (ctx, entry) = coin_dispatcher(coin).map_err(|_| AddressError::UnknownCoinType)?;
         entry.validate_address(&ctx, address, prefix)?;
         let address = entry.normalize_address(&ctx, address)?;
@@ -42,9 +86,10 @@ impl AnyAddress {
     /// Creates an address from a string representation and a coin type.
     /// Please note that his function does not validate if the address belongs to the given chain.
     pub(crate) fn with_string_unchecked(
-        coin: CoinType,
+        coin: impl Into<u32>,
         address: &str,
     ) -> AddressResult<AnyAddress> {
+        let coin = coin.into();
         let (ctx, entry) = coin_dispatcher(coin).map_err(|_| AddressError::UnknownCoinType)?;
         let address = entry.normalize_address(&ctx, address)?;
         Ok(AnyAddress { coin, address })
@@ -53,11 +98,12 @@ impl AnyAddress {
     /// Creates an address from a public key, derivation and prefix option.
     #[inline]
     pub fn with_public_key(
-        coin: CoinType,
+        coin: impl Into<u32>,
         public_key: PublicKey,
         derivation: Derivation,
         prefix: Option<AddressPrefix>,
     ) -> AddressResult<AnyAddress> {
+        let coin = coin.into();
         let (ctx, entry) = coin_dispatcher(coin).map_err(|_| AddressError::UnknownCoinType)?;
         let address = entry.derive_address(&ctx, public_key, derivation, prefix)?;
         Ok(AnyAddress { coin, address })
@@ -76,3 +122,35 @@ impl AnyAddress {
         &self.address
     }
 }
+
+/// How specific an address format is to a coin.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum AddressSpecificity {
+    /// The format is shared among many chains, e.g. EVM hex or Solana Base58 addresses.
+    Generic,
+    /// The address is prefixed with a Base58 version byte.
+    Base58Prefix,
+    /// The address is prefixed with the coin Bech32 HRP.
+    Bech32Hrp,
+}
+
+impl AddressSpecificity {
+    /// `base58_version` is the first byte of the Base58 decoded address if it can be decoded.
+    fn of(
+        address: &str,
+        base58_version: Option<u8>,
+        hrp: Option<&str>,
+        base58_prefixes: &[Option<u8>],
+    ) -> AddressSpecificity {
+        if let Some(hrp) = hrp {
+            let bech32_prefix = format!("{hrp}1");
+            if address.to_lowercase().starts_with(&bech32_prefix) {
+                return AddressSpecificity::Bech32Hrp;
+            }
+        }
+        if base58_version.is_some() && base58_prefixes.contains(&base58_version) {
+            return AddressSpecificity::Base58Prefix;
+        }
+        AddressSpecificity::Generic
+    }
+}
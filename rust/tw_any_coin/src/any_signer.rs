@@ -3,7 +3,6 @@
 // Copyright © 2017 Trust Wallet.
 
 use tw_coin_entry::error::prelude::*;
-use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::dispatcher::coin_dispatcher;
 use tw_memory::Data;
 
@@ -13,16 +12,62 @@ pub struct AnySigner;
 impl AnySigner {
     /// Signs a transaction specified by the signing input and coin type.
     #[inline]
-    pub fn sign(input: &[u8], coin: CoinType) -> SigningResult<Data> {
+    pub fn sign(input: &[u8], coin: impl Into<u32>) -> SigningResult<Data> {
         let (ctx, entry) = coin_dispatcher(coin)?;
         entry.sign(&ctx, input).map_err(SigningError::from)
     }
 
+    /// Signs multiple transactions of the same coin type.
+    /// The coin is dispatched once, and the same context and entry are used to sign every input.
+    /// Returns a signing result per input in the same order as `inputs`.
+    ///
+    /// Please note every input is still parsed and signed independently, including its private key.
+    /// Parsed keys are not cached across the batch since [`CoinEntryExt::sign`] takes a serialized input,
+    /// and the key is parsed into a blockchain-specific type deep inside the signer.
+    /// Besides, a cache indexed by the key bytes would keep private keys in memory after they are used.
+    ///
+    /// [`CoinEntryExt::sign`]: tw_coin_entry::coin_entry_ext::CoinEntryExt::sign
+    pub fn sign_batch<'a, I>(
+        inputs: I,
+        coin: impl Into<u32>,
+    ) -> SigningResult<Vec<SigningResult<Data>>>
+    where
+        I: IntoIterator<Item = &'a [u8]>,
+    {
+        let (ctx, entry) = coin_dispatcher(coin)?;
+        let results = inputs
+            .into_iter()
+            .map(|input| entry.sign(&ctx, input).map_err(SigningError::from))
+            .collect();
+        Ok(results)
+    }
+
     /// Planning, for UTXO chains, in preparation for signing
     /// It is optional, only UTXO chains need it, default impl. leaves empty result.
     #[inline]
-    pub fn plan(input: &[u8], coin: CoinType) -> SigningResult<Data> {
+    pub fn plan(input: &[u8], coin: impl Into<u32>) -> SigningResult<Data> {
         let (ctx, entry) = coin_dispatcher(coin)?;
         entry.plan(&ctx, input)
     }
+
+    /// Signs a transaction specified by the proto3 JSON representation of the signing input,
+    /// the private key and coin type.
+    /// Returns the proto3 JSON representation of the signing output.
+    #[inline]
+    pub fn sign_json(
+        input_json: &str,
+        private_key: Data,
+        coin: impl Into<u32>,
+    ) -> SigningResult<String> {
+        let (ctx, entry) = coin_dispatcher(coin)?;
+        entry.sign_json(&ctx, input_json, private_key)
+    }
+
+    /// Checks whether the given coin supports signing JSON input.
+    #[inline]
+    pub fn supports_json_signing(coin: impl Into<u32>) -> bool {
+        coin_dispatcher(coin)
+            .map(|(_, entry)| entry.supports_json_signing())
+            .unwrap_or_default()
+    }
 }
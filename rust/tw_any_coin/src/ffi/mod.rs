@@ -4,7 +4,11 @@
 
 pub mod tw_any_address;
 pub mod tw_any_signer;
+pub mod tw_coin_registry;
 pub mod tw_message_signer;
 pub mod tw_transaction_compiler;
 pub mod tw_transaction_decoder;
+pub mod tw_transaction_hasher;
+pub mod tw_transaction_summarizer;
+pub mod tw_transaction_verifier;
 pub mod tw_wallet_connect_request;
@@ -7,12 +7,12 @@
 use crate::any_address::AnyAddress;
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::prefix::{AddressPrefix, BitcoinBase58Prefix};
-use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::tw_derivation::TWDerivation;
 use tw_keypair::ffi::pubkey::TWPublicKey;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::tw_string::TWString;
 use tw_memory::ffi::RawPtrTrait;
+use tw_memory::Data;
 use tw_misc::{try_or_else, try_or_false};
 
 /// Represents an address in Rust for almost any blockchain.
@@ -29,7 +29,6 @@ impl RawPtrTrait for TWAnyAddress {}
 pub unsafe extern "C" fn tw_any_address_is_valid(string: *const TWString, coin: u32) -> bool {
     let string = try_or_false!(TWString::from_ptr_as_ref(string));
     let string = try_or_false!(string.as_str());
-    let coin = try_or_false!(CoinType::try_from(coin));
 
     AnyAddress::is_valid(coin, string, None)
 }
@@ -52,8 +51,6 @@ pub unsafe extern "C" fn tw_any_address_is_valid_bech32(
     let hrp = try_or_false!(TWString::from_ptr_as_ref(hrp));
     let hrp = try_or_false!(hrp.as_str());
 
-    let coin = try_or_false!(CoinType::try_from(coin));
-
     let prefix = AddressPrefix::Hrp(hrp.to_string());
     AnyAddress::is_valid(coin, string, Some(prefix))
 }
@@ -75,12 +72,27 @@ pub unsafe extern "C" fn tw_any_address_is_valid_base58(
     let string = try_or_false!(TWString::from_ptr_as_ref(string));
     let string = try_or_false!(string.as_str());
 
-    let coin = try_or_false!(CoinType::try_from(coin));
-
     let prefix = AddressPrefix::BitcoinBase58(BitcoinBase58Prefix { p2pkh, p2sh });
     AnyAddress::is_valid(coin, string, Some(prefix))
 }
 
+/// Detects coins, including custom coins, that accept the given address string.
+///
+/// \param string address to detect coins of.
+/// \return coin types ranked by specificity (the most specific first) encoded as little-endian `u32` values,
+/// or an empty data if no coin accepts the address.
+#[no_mangle]
+pub unsafe extern "C" fn tw_any_address_detect(string: *const TWString) -> *mut TWData {
+    let string = try_or_else!(TWString::from_ptr_as_ref(string), std::ptr::null_mut);
+    let string = try_or_else!(string.as_str(), std::ptr::null_mut);
+
+    let coins: Data = AnyAddress::detect(string)
+        .into_iter()
+        .flat_map(u32::to_le_bytes)
+        .collect();
+    TWData::from(coins).into_ptr()
+}
+
 /// Creates an address from a string representation and a coin type. Must be deleted with `TWAnyAddressDelete` after use.
 ///
 /// \param string address to create.
@@ -93,7 +105,6 @@ pub unsafe extern "C" fn tw_any_address_create_with_string(
 ) -> *mut TWAnyAddress {
     let string = try_or_else!(TWString::from_ptr_as_ref(string), std::ptr::null_mut);
     let string = try_or_else!(string.as_str(), std::ptr::null_mut);
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
 
     AnyAddress::with_string(coin, string, None)
         .map(|any_address| TWAnyAddress(any_address).into_ptr())
@@ -115,7 +126,6 @@ pub unsafe extern "C" fn tw_any_address_create_with_public_key_derivation(
     let public_key = try_or_else!(TWPublicKey::from_ptr_as_ref(public_key), std::ptr::null_mut);
     let derivation = try_or_else!(TWDerivation::from_repr(derivation), std::ptr::null_mut);
     let derivation = Derivation::from(derivation);
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
 
     AnyAddress::with_public_key(coin, public_key.as_ref().clone(), derivation, None)
         .map(|any_address| TWAnyAddress(any_address).into_ptr())
@@ -135,7 +145,6 @@ pub unsafe extern "C" fn tw_any_address_create_bech32_with_public_key(
     hrp: *const TWString,
 ) -> *mut TWAnyAddress {
     let public_key = try_or_else!(TWPublicKey::from_ptr_as_ref(public_key), std::ptr::null_mut);
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
 
     let hrp = try_or_else!(TWString::from_ptr_as_ref(hrp), std::ptr::null_mut);
     let hrp = try_or_else!(hrp.as_str(), std::ptr::null_mut);
@@ -166,7 +175,6 @@ pub unsafe extern "C" fn tw_any_address_create_base58_with_public_key(
     p2sh: u8,
 ) -> *mut TWAnyAddress {
     let public_key = try_or_else!(TWPublicKey::from_ptr_as_ref(public_key), std::ptr::null_mut);
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
 
     let prefix = AddressPrefix::BitcoinBase58(BitcoinBase58Prefix { p2pkh, p2sh });
     AnyAddress::with_public_key(
@@ -231,7 +239,6 @@ pub unsafe extern "C" fn tw_any_address_create_with_string_unchecked(
 ) -> *mut TWAnyAddress {
     let string = try_or_else!(TWString::from_ptr_as_ref(string), std::ptr::null_mut);
     let string = try_or_else!(string.as_str(), std::ptr::null_mut);
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
 
     AnyAddress::with_string_unchecked(coin, string)
         .map(|any_address| TWAnyAddress(any_address).into_ptr())
@@ -5,10 +5,16 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::any_signer::AnySigner;
-use tw_coin_registry::coin_type::CoinType;
+use std::borrow::Cow;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
 use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::tw_data_vector::TWDataVector;
+use tw_memory::ffi::tw_string::TWString;
 use tw_memory::ffi::RawPtrTrait;
+use tw_memory::Data;
 use tw_misc::try_or_else;
+use tw_proto::BatchSigner::Proto as BatchProto;
 
 /// Signs a transaction specified by the signing input and coin type.
 ///
@@ -18,13 +24,56 @@ use tw_misc::try_or_else;
 #[no_mangle]
 pub unsafe extern "C" fn tw_any_signer_sign(input: *const TWData, coin: u32) -> *mut TWData {
     let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
 
     AnySigner::sign(input.as_slice(), coin)
         .map(|output| TWData::from(output).into_ptr())
         .unwrap_or_else(|_| std::ptr::null_mut())
 }
 
+/// Signs multiple transactions of the same coin type.
+///
+/// \param inputs The serialized data of signing inputs (e.g. TW.Ethereum.Proto.SigningInput).
+/// \param coin The given coin type to sign the transactions for.
+/// \return The serialized data of a `TW.BatchSigner.Proto.BatchSigningOutput` proto object
+/// that contains a signing result per input in the same order,
+/// or the batch error if the coin is not supported.
+#[no_mangle]
+pub unsafe extern "C" fn tw_any_signer_sign_batch(
+    inputs: *const TWDataVector,
+    coin: u32,
+) -> *mut TWData {
+    let inputs = TWDataVector::from_ptr_as_ref(inputs)
+        .map(TWDataVector::to_data_vec)
+        .or_tw_err(SigningErrorType::Error_invalid_params)
+        .context("No inputs specified");
+
+    let output = match inputs
+        .and_then(|inputs| AnySigner::sign_batch(inputs.iter().map(Data::as_slice), coin))
+    {
+        Ok(results) => {
+            let results = results
+                .into_iter()
+                .map(|result| match result {
+                    Ok(output) => BatchProto::SigningResult {
+                        output: Cow::Owned(output),
+                        ..BatchProto::SigningResult::default()
+                    },
+                    Err(e) => signing_output_error!(BatchProto::SigningResult, e),
+                })
+                .collect();
+            BatchProto::BatchSigningOutput {
+                results,
+                ..BatchProto::BatchSigningOutput::default()
+            }
+        },
+        Err(e) => signing_output_error!(BatchProto::BatchSigningOutput, e),
+    };
+
+    tw_proto::serialize(&output)
+        .map(|output| TWData::from(output).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
 /// Plans a transaction (for UTXO chains only).
 ///
 /// \param input The serialized data of a signing input
@@ -33,9 +82,38 @@ pub unsafe extern "C" fn tw_any_signer_sign(input: *const TWData, coin: u32) ->
 #[no_mangle]
 pub unsafe extern "C" fn tw_any_signer_plan(input: *const TWData, coin: u32) -> *mut TWData {
     let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
 
     AnySigner::plan(input.as_slice(), coin)
         .map(|output| TWData::from(output).into_ptr())
         .unwrap_or_else(|_| std::ptr::null_mut())
 }
+
+/// Signs a transaction specified by the JSON representation of the signing input, private key and coin type.
+///
+/// \param input The proto3 JSON representation of a signing input (e.g. TW.Ethereum.Proto.SigningInput).
+/// \param key The private key used to sign the transaction.
+/// \param coin The given coin type to sign the transaction for.
+/// \return The proto3 JSON representation of a `SigningOutput` proto object (e.g. TW.Ethereum.Proto.SigningOutput).
+#[no_mangle]
+pub unsafe extern "C" fn tw_any_signer_sign_json(
+    input: *const TWString,
+    key: *const TWData,
+    coin: u32,
+) -> *mut TWString {
+    let input = try_or_else!(TWString::from_ptr_as_ref(input), std::ptr::null_mut);
+    let input = try_or_else!(input.as_str(), std::ptr::null_mut);
+    let key = try_or_else!(TWData::from_ptr_as_ref(key), std::ptr::null_mut);
+
+    AnySigner::sign_json(input, key.to_vec(), coin)
+        .map(|output| TWString::from(output).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Checks whether the given coin supports signing JSON input.
+///
+/// \param coin The given coin type.
+/// \return true if the coin supports signing JSON input, false otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn tw_any_signer_supports_json(coin: u32) -> bool {
+    AnySigner::supports_json_signing(coin)
+}
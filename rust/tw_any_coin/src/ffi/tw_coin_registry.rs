@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use tw_coin_registry::custom_registry::{
+    get_custom_coin_item, register_custom_coin_json, unregister_custom_coin,
+};
+use tw_memory::ffi::tw_string::TWString;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_false;
+
+/// Registers a custom coin at runtime, e.g. a new EVM L2 or a Cosmos appchain.
+/// A custom coin previously registered with the same coin type is replaced.
+///
+/// \param item_json coin item in the `registry.json` format.
+/// \return true if the coin has been registered, false if the item is invalid or conflicts with a built-in coin type.
+#[no_mangle]
+pub unsafe extern "C" fn tw_coin_registry_register_custom_coin(item_json: *const TWString) -> bool {
+    let item_json = try_or_false!(TWString::from_ptr_as_ref(item_json));
+    let item_json = try_or_false!(item_json.as_str());
+
+    register_custom_coin_json(item_json).is_ok()
+}
+
+/// Unregisters a custom coin previously registered via `tw_coin_registry_register_custom_coin`.
+///
+/// \param coin coin type of the custom coin.
+/// \return true if the coin has been unregistered, false if there is no such custom coin.
+#[no_mangle]
+pub unsafe extern "C" fn tw_coin_registry_unregister_custom_coin(coin: u32) -> bool {
+    unregister_custom_coin(coin)
+}
+
+/// Checks whether a custom coin is registered with the given coin type.
+///
+/// \param coin coin type.
+/// \return true if there is a custom coin with the given coin type, false otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn tw_coin_registry_is_custom_coin(coin: u32) -> bool {
+    get_custom_coin_item(coin).is_ok()
+}
@@ -5,7 +5,6 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::message_signer::MessageSigner;
-use tw_coin_registry::coin_type::CoinType;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::RawPtrTrait;
 use tw_misc::{try_or_else, try_or_false};
@@ -18,7 +17,6 @@ use tw_misc::{try_or_else, try_or_false};
 #[no_mangle]
 pub unsafe extern "C" fn tw_message_signer_sign(input: *const TWData, coin: u32) -> *mut TWData {
     let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
 
     MessageSigner::sign_message(input.as_slice(), coin)
         .map(|output| TWData::from(output).into_ptr())
@@ -33,7 +31,6 @@ pub unsafe extern "C" fn tw_message_signer_sign(input: *const TWData, coin: u32)
 #[no_mangle]
 pub unsafe extern "C" fn tw_message_signer_verify(input: *const TWData, coin: u32) -> bool {
     let input = try_or_false!(TWData::from_ptr_as_ref(input));
-    let coin = try_or_false!(CoinType::try_from(coin));
     MessageSigner::verify_message(input.as_slice(), coin).unwrap_or_default()
 }
 
@@ -48,7 +45,6 @@ pub unsafe extern "C" fn tw_message_signer_pre_image_hashes(
     coin: u32,
 ) -> *mut TWData {
     let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
 
     MessageSigner::message_preimage_hashes(input.as_slice(), coin)
         .map(|output| TWData::from(output).into_ptr())
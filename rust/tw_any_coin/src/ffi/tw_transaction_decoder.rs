@@ -5,7 +5,6 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::transaction_decoder::TransactionDecoder;
-use tw_coin_registry::coin_type::CoinType;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::RawPtrTrait;
 use tw_misc::try_or_else;
@@ -21,7 +20,6 @@ pub unsafe extern "C" fn tw_transaction_decoder_decode(
     tx: *const TWData,
 ) -> *mut TWData {
     let tx = try_or_else!(TWData::from_ptr_as_ref(tx), std::ptr::null_mut);
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
 
     TransactionDecoder::decode_transaction(coin, tx.as_slice())
         .map(|output| TWData::from(output).into_ptr())
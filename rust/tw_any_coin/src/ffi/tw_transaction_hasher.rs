@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::transaction_hasher::TransactionHasher;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::tw_string::TWString;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+
+/// Computes a transaction hash (ID) of a signed transaction.
+///
+/// \param coin coin type.
+/// \param signed_tx signed transaction bytes.
+/// \return transaction hash in the chain-specific format, or null if the transaction cannot be hashed.
+#[no_mangle]
+pub unsafe extern "C" fn tw_transaction_hasher_tx_id(
+    coin: u32,
+    signed_tx: *const TWData,
+) -> *mut TWString {
+    let signed_tx = try_or_else!(TWData::from_ptr_as_ref(signed_tx), std::ptr::null_mut);
+
+    TransactionHasher::tx_id(coin, signed_tx.as_slice())
+        .map(|tx_id| TWString::from(tx_id).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
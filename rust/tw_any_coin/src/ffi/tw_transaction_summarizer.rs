@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::transaction_summarizer::TransactionSummarizer;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+
+/// Describes what a transaction specified by the signing input will do.
+///
+/// \param coin coin type.
+/// \param input the serialized data of a signing input (e.g. `TW.Ethereum.Proto.SigningInput`).
+/// \param tokens the serialized data of a `TW.TxSummary.Proto.TokenList` used to format token amounts, may be empty.
+/// \return serialized `TW.TxSummary.Proto.TransactionSummary`.
+#[no_mangle]
+pub unsafe extern "C" fn tw_transaction_summarizer_summarize(
+    coin: u32,
+    input: *const TWData,
+    tokens: *const TWData,
+) -> *mut TWData {
+    let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let tokens = try_or_else!(TWData::from_ptr_as_ref(tokens), std::ptr::null_mut);
+
+    TransactionSummarizer::summarize_transaction(coin, input.as_slice(), tokens.as_slice())
+        .map(|output| TWData::from(output).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::transaction_verifier::TransactionVerifier;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+
+/// Verifies signatures of a signed transaction.
+///
+/// \param coin coin type.
+/// \param input serialized `TxVerifier.Proto.VerifyingInput` protobuf message.
+/// \return serialized `TxVerifier.Proto.VerifyingOutput` protobuf message.
+#[no_mangle]
+pub unsafe extern "C" fn tw_transaction_verifier_verify(
+    coin: u32,
+    input: *const TWData,
+) -> *mut TWData {
+    let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+
+    TransactionVerifier::verify_transaction(coin, input.as_slice())
+        .map(|output| TWData::from(output).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
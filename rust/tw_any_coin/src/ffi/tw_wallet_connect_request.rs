@@ -5,7 +5,6 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::wallet_connect_request::WalletConnectRequest;
-use tw_coin_registry::coin_type::CoinType;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::RawPtrTrait;
 use tw_misc::try_or_else;
@@ -20,7 +19,6 @@ pub unsafe extern "C" fn tw_wallet_connect_request_parse(
     coin: u32,
     input: *const TWData,
 ) -> *mut TWData {
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
     let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
 
     WalletConnectRequest::parse(coin, input.as_slice())
@@ -8,6 +8,9 @@ pub mod ffi;
 pub mod message_signer;
 pub mod transaction_compiler;
 pub mod transaction_decoder;
+pub mod transaction_hasher;
+pub mod transaction_summarizer;
+pub mod transaction_verifier;
 pub mod wallet_connect_request;
 
 #[cfg(feature = "test-utils")]
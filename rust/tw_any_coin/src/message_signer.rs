@@ -3,7 +3,6 @@
 // Copyright © 2017 Trust Wallet.
 
 use tw_coin_entry::error::prelude::*;
-use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::dispatcher::coin_dispatcher;
 use tw_memory::Data;
 
@@ -13,21 +12,21 @@ pub struct MessageSigner;
 impl MessageSigner {
     /// Signs a message.
     #[inline]
-    pub fn sign_message(input: &[u8], coin: CoinType) -> SigningResult<Data> {
+    pub fn sign_message(input: &[u8], coin: impl Into<u32>) -> SigningResult<Data> {
         let (ctx, entry) = coin_dispatcher(coin)?;
         entry.sign_message(&ctx, input)
     }
 
     /// Computes preimage hashes of a message.
     #[inline]
-    pub fn message_preimage_hashes(input: &[u8], coin: CoinType) -> SigningResult<Data> {
+    pub fn message_preimage_hashes(input: &[u8], coin: impl Into<u32>) -> SigningResult<Data> {
         let (ctx, entry) = coin_dispatcher(coin)?;
         entry.message_preimage_hashes(&ctx, input)
     }
 
     /// Verifies a signature for a message.
     #[inline]
-    pub fn verify_message(input: &[u8], coin: CoinType) -> SigningResult<bool> {
+    pub fn verify_message(input: &[u8], coin: impl Into<u32>) -> SigningResult<bool> {
         let (ctx, entry) = coin_dispatcher(coin)?;
         entry.verify_message(&ctx, input)
     }
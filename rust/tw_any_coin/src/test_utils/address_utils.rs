@@ -5,8 +5,8 @@
 use crate::ffi::tw_any_address::{
     tw_any_address_create_base58_with_public_key, tw_any_address_create_bech32_with_public_key,
     tw_any_address_create_with_string, tw_any_address_data, tw_any_address_delete,
-    tw_any_address_description, tw_any_address_is_valid, tw_any_address_is_valid_base58,
-    tw_any_address_is_valid_bech32, TWAnyAddress,
+    tw_any_address_description, tw_any_address_detect, tw_any_address_is_valid,
+    tw_any_address_is_valid_base58, tw_any_address_is_valid_bech32, TWAnyAddress,
 };
 use tw_coin_registry::coin_type::CoinType;
 use tw_encoding::hex::{DecodeHex, ToHex};
@@ -143,3 +143,16 @@ pub fn test_address_base58_is_valid(input: AddressBase58IsValid<'_>) {
     };
     assert!(is_valid, "!tw_any_address_is_valid_base58");
 }
+
+/// Returns coin types that accept the given `address` in the order returned by `tw_any_address_detect`.
+pub fn detect_address_coins(address: &str) -> Vec<u32> {
+    let address = TWStringHelper::create(address);
+    let coins = TWDataHelper::wrap(unsafe { tw_any_address_detect(address.ptr()) })
+        .to_vec()
+        .expect("!tw_any_address_detect returned nullptr");
+
+    coins
+        .chunks_exact(4)
+        .map(|coin| u32::from_le_bytes(coin.try_into().unwrap()))
+        .collect()
+}
@@ -6,4 +6,7 @@ pub mod address_utils;
 pub mod plan_utils;
 pub mod sign_utils;
 pub mod transaction_decode_utils;
+pub mod transaction_hash_utils;
+pub mod transaction_summary_utils;
+pub mod transaction_verify_utils;
 pub mod wallet_connect_utils;
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ffi::tw_transaction_hasher::tw_transaction_hasher_tx_id;
+use tw_coin_registry::coin_type::CoinType;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
+
+/// Returns a transaction hash (ID) of the given signed transaction, or `None` if it cannot be computed.
+pub fn tx_id(coin_type: CoinType, signed_tx: &[u8]) -> Option<String> {
+    let signed_tx = TWDataHelper::create(signed_tx.to_vec());
+    TWStringHelper::wrap(unsafe { tw_transaction_hasher_tx_id(coin_type as u32, signed_tx.ptr()) })
+        .to_string()
+}
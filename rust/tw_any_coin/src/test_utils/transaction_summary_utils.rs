@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ffi::tw_transaction_summarizer::tw_transaction_summarizer_summarize;
+use tw_coin_registry::coin_type::CoinType;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::Data;
+use tw_proto::TxSummary::Proto;
+use tw_proto::{deserialize, serialize, MessageWrite};
+
+#[derive(Default)]
+pub struct TransactionSummarizerHelper {
+    output_data: Data,
+}
+
+impl TransactionSummarizerHelper {
+    pub fn summarize<Input: MessageWrite>(
+        &mut self,
+        coin_type: CoinType,
+        input: &Input,
+    ) -> Proto::TransactionSummary<'_> {
+        self.summarize_with_tokens(coin_type, input, &Proto::TokenList::default())
+    }
+
+    pub fn summarize_with_tokens<Input: MessageWrite>(
+        &mut self,
+        coin_type: CoinType,
+        input: &Input,
+        tokens: &Proto::TokenList,
+    ) -> Proto::TransactionSummary<'_> {
+        let input_data = TWDataHelper::create(serialize(input).unwrap());
+        let tokens_data = TWDataHelper::create(serialize(tokens).unwrap());
+
+        self.output_data = TWDataHelper::wrap(unsafe {
+            tw_transaction_summarizer_summarize(
+                coin_type as u32,
+                input_data.ptr(),
+                tokens_data.ptr(),
+            )
+        })
+        .to_vec()
+        .expect("!tw_transaction_summarizer_summarize returned nullptr");
+
+        deserialize(&self.output_data).unwrap()
+    }
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ffi::tw_transaction_verifier::tw_transaction_verifier_verify;
+use tw_coin_registry::coin_type::CoinType;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::Data;
+use tw_proto::TxVerifier::Proto;
+use tw_proto::{deserialize, serialize};
+
+#[derive(Default)]
+pub struct TransactionVerifierHelper {
+    output_data: Data,
+}
+
+impl TransactionVerifierHelper {
+    pub fn verify(
+        &mut self,
+        coin_type: CoinType,
+        input: Proto::VerifyingInput,
+    ) -> Proto::VerifyingOutput<'_> {
+        let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+        self.output_data = TWDataHelper::wrap(unsafe {
+            tw_transaction_verifier_verify(coin_type as u32, input_data.ptr())
+        })
+        .to_vec()
+        .expect("!tw_transaction_verifier_verify returned nullptr");
+
+        deserialize(&self.output_data).unwrap()
+    }
+}
@@ -4,7 +4,6 @@
 
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::error::prelude::*;
-use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::dispatcher::coin_dispatcher;
 use tw_memory::Data;
 
@@ -14,7 +13,7 @@ pub struct TransactionCompiler;
 impl TransactionCompiler {
     /// Obtains pre-signing hashes of a transaction.
     #[inline]
-    pub fn preimage_hashes(coin: CoinType, input: &[u8]) -> SigningResult<Data> {
+    pub fn preimage_hashes(coin: impl Into<u32>, input: &[u8]) -> SigningResult<Data> {
         let (ctx, entry) = coin_dispatcher(coin)?;
         entry
             .preimage_hashes(&ctx, input)
@@ -24,7 +23,7 @@ impl TransactionCompiler {
     /// Compiles a complete transaction with one or more external signatures.
     #[inline]
     pub fn compile(
-        coin: CoinType,
+        coin: impl Into<u32>,
         input: &[u8],
         signatures: Vec<SignatureBytes>,
         public_keys: Vec<PublicKeyBytes>,
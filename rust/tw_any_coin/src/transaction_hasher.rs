@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::dispatcher::coin_dispatcher;
+
+pub struct TransactionHasher;
+
+impl TransactionHasher {
+    /// Computes a transaction hash (ID) of a signed transaction.
+    #[inline]
+    pub fn tx_id(coin: impl Into<u32>, signed_tx: &[u8]) -> SigningResult<String> {
+        let (ctx, entry) = coin_dispatcher(coin)?;
+        entry.tx_id(&ctx, signed_tx)
+    }
+}
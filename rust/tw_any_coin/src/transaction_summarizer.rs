@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_summarizer::KnownTokens;
+use tw_coin_registry::dispatcher::coin_dispatcher;
+use tw_memory::Data;
+use tw_proto::deserialize;
+use tw_proto::TxSummary::Proto;
+
+pub struct TransactionSummarizer;
+
+impl TransactionSummarizer {
+    /// Returns a human-readable summary of a transaction specified by the signing input.
+    /// `tokens` is a serialized `TW.TxSummary.Proto.TokenList`, may be empty.
+    #[inline]
+    pub fn summarize_transaction(
+        coin: impl Into<u32>,
+        input: &[u8],
+        tokens: &[u8],
+    ) -> SigningResult<Data> {
+        let (ctx, entry) = coin_dispatcher(coin)?;
+        let tokens: Proto::TokenList = deserialize(tokens)?;
+        let tokens = KnownTokens::from_proto(&tokens)?;
+        entry.summarize_transaction(&ctx, input, &tokens)
+    }
+}
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::dispatcher::coin_dispatcher;
+use tw_memory::Data;
+
+pub struct TransactionVerifier;
+
+impl TransactionVerifier {
+    /// Verifies signatures of a signed transaction.
+    #[inline]
+    pub fn verify_transaction(coin: impl Into<u32>, input: &[u8]) -> SigningResult<Data> {
+        let (ctx, entry) = coin_dispatcher(coin)?;
+        entry.verify_transaction(&ctx, input)
+    }
+}
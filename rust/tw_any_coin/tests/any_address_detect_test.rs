@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::address_utils::detect_address_coins;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::custom_registry::{register_custom_coin_json, unregister_custom_coin};
+
+const CUSTOM_COSMOS_COIN: u32 = 90_000_004;
+
+#[test]
+fn test_detect_bech32_address() {
+    let coins = detect_address_coins("cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx");
+    assert_eq!(coins.first(), Some(&(CoinType::Cosmos as u32)));
+
+    let coins = detect_address_coins("bc1qunq74p3h8425hr6wllevlvqqr6sezfxj262rff");
+    assert_eq!(coins.first(), Some(&(CoinType::Bitcoin as u32)));
+}
+
+#[test]
+fn test_detect_base58_address() {
+    let coins = detect_address_coins("1MrZNGN7mfWZiZNQttrzHjfw72jnJC2JNx");
+    assert_eq!(coins.first(), Some(&(CoinType::Bitcoin as u32)));
+    assert!(!coins.contains(&(CoinType::Ethereum as u32)));
+}
+
+#[test]
+fn test_detect_evm_address() {
+    let coins = detect_address_coins("0x3535353535353535353535353535353535353535");
+    assert_eq!(coins.first(), Some(&(CoinType::Ethereum as u32)));
+    assert!(coins.contains(&(CoinType::SmartChain as u32)));
+    assert!(coins.contains(&(CoinType::Polygon as u32)));
+    assert!(!coins.contains(&(CoinType::Bitcoin as u32)));
+}
+
+#[test]
+fn test_detect_32_bytes_hex_address() {
+    let coins =
+        detect_address_coins("0x259ff8074ab425cbb489f236e18e08f03f1a7856bdf7c7a2877bd64f738b5015");
+    assert!(coins.contains(&(CoinType::Sui as u32)));
+    assert!(coins.contains(&(CoinType::Aptos as u32)));
+    assert!(!coins.contains(&(CoinType::Ethereum as u32)));
+}
+
+#[test]
+fn test_detect_solana_address() {
+    let coins = detect_address_coins(" EN2sCsJ1WDV8UFqsiTXHcUPUxQ4juE71eCknHYYMifkd\n");
+    assert_eq!(coins.first(), Some(&(CoinType::Solana as u32)));
+}
+
+#[test]
+fn test_detect_custom_coin_address() {
+    let item_json = format!(
+        r#"{{
+            "id": "custom-detect",
+            "name": "Custom Detect",
+            "coinId": {CUSTOM_COSMOS_COIN},
+            "symbol": "DTCT",
+            "decimals": 6,
+            "blockchain": "Cosmos",
+            "derivation": [{{ "path": "m/44'/118'/0'/0/0" }}],
+            "publicKeyType": "secp256k1",
+            "addressHasher": "sha256ripemd",
+            "hrp": "detect"
+        }}"#
+    );
+    register_custom_coin_json(&item_json).unwrap();
+
+    let coins = detect_address_coins("detect1mky69cn8ektwy0845vec9upsdphktxt00c86l5");
+    assert_eq!(coins, vec![CUSTOM_COSMOS_COIN]);
+
+    assert!(unregister_custom_coin(CUSTOM_COSMOS_COIN));
+    assert!(detect_address_coins("detect1mky69cn8ektwy0845vec9upsdphktxt00c86l5").is_empty());
+}
+
+#[test]
+fn test_detect_invalid_address() {
+    assert!(detect_address_coins("").is_empty());
+    assert!(detect_address_coins("not an address").is_empty());
+}
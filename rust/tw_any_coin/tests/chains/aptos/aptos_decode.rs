@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::aptos::test_cases::transfer_b4d62afd::{
+    DATA_TO_SIGN, ENCODED, RAW_TXN, SIGNATURE,
+};
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Aptos::Proto;
+use tw_proto::Common::Proto::SigningError;
+
+const SENDER: &str = "0x7968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30";
+
+fn assert_transfer_b4d62afd(tx: Proto::RawTransaction) {
+    assert_eq!(tx.sender, SENDER);
+    assert_eq!(tx.sequence_number, 99);
+    assert_eq!(tx.max_gas_amount, 3296766);
+    assert_eq!(tx.gas_unit_price, 100);
+    assert_eq!(tx.expiration_timestamp_secs, 3664390082);
+    assert_eq!(tx.chain_id, 33);
+
+    let entry_function = tx.entry_function.unwrap();
+    assert_eq!(entry_function.module_address, "0x1");
+    assert_eq!(entry_function.module_name, "aptos_account");
+    assert_eq!(entry_function.function, "transfer");
+    assert!(entry_function.type_arguments.is_empty());
+    assert_eq!(entry_function.arguments.len(), 2);
+    assert_eq!(
+        entry_function.arguments[0].to_hex(),
+        "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30"
+    );
+    // 1000 as a little-endian `u64`.
+    assert_eq!(entry_function.arguments[1].to_hex(), "e803000000000000");
+}
+
+#[test]
+fn test_aptos_decode_raw_transaction() {
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Aptos, RAW_TXN.decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+    assert!(output.authenticator.is_none());
+    assert_transfer_b4d62afd(output.transaction.unwrap());
+}
+
+#[test]
+fn test_aptos_decode_salted_raw_transaction() {
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Aptos, DATA_TO_SIGN.decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+    assert!(output.authenticator.is_none());
+    assert_transfer_b4d62afd(output.transaction.unwrap());
+}
+
+#[test]
+fn test_aptos_decode_signed_transaction() {
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Aptos, ENCODED.decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+    assert_transfer_b4d62afd(output.transaction.unwrap());
+
+    let authenticator = output.authenticator.unwrap();
+    assert_eq!(authenticator.signature.to_hex(), SIGNATURE);
+    assert_eq!(
+        authenticator.public_key.to_hex(),
+        "ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c"
+    );
+}
+
+#[test]
+fn test_aptos_decode_invalid() {
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Aptos, "0102030405".decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(!output.error_message.is_empty());
+}
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::aptos::test_cases::transfer_b4d62afd::PRIVATE_KEY;
+use crate::chains::aptos::APTOS_COIN_TYPE;
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Aptos::Proto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+
+const PUBLIC_KEY: &str = "ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c";
+const ADDRESS: &str = "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30";
+const MESSAGE: &str = "Hello, Aptos!";
+const NONCE: &str = "1234567890";
+const APPLICATION: &str = "https://example.com";
+const FULL_SIGNATURE: &str = "ed0813f6268320b5c0e3cb39dd0b77672562d7fdee369c3faa33f64210f5a20fadfc208594dd02ebc48f774ef4fd1816d58e365a49cff6a7e5187f285e020504";
+
+fn sign_message(input: Proto::MessageSigningInput) -> Proto::MessageSigningOutput<'static> {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output =
+        TWDataHelper::wrap(unsafe { tw_message_signer_sign(input_data.ptr(), APTOS_COIN_TYPE) })
+            .to_vec()
+            .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    Proto::MessageSigningOutput {
+        error: output.error,
+        error_message: output.error_message.to_string().into(),
+        signature: output.signature.to_string().into(),
+        full_message: output.full_message.to_string().into(),
+    }
+}
+
+fn verify_message(input: Proto::MessageVerifyingInput) -> bool {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    unsafe { tw_message_signer_verify(input_data.ptr(), APTOS_COIN_TYPE) }
+}
+
+fn full_verifying_input(signature: &str) -> Proto::MessageVerifyingInput {
+    Proto::MessageVerifyingInput {
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: signature.into(),
+        message: MESSAGE.into(),
+        nonce: NONCE.into(),
+        address: ADDRESS.into(),
+        application: APPLICATION.into(),
+        chain_id: 1,
+    }
+}
+
+#[test]
+fn test_aptos_message_sign_full() {
+    let output = sign_message(Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: MESSAGE.into(),
+        nonce: NONCE.into(),
+        include_address: true,
+        application: APPLICATION.into(),
+        chain_id: 1,
+    });
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(
+        output.full_message,
+        "APTOS\naddress: 0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30\napplication: https://example.com\nchainId: 1\nmessage: Hello, Aptos!\nnonce: 1234567890"
+    );
+    assert_eq!(output.signature, FULL_SIGNATURE);
+}
+
+#[test]
+fn test_aptos_message_sign_minimal() {
+    let output = sign_message(Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: MESSAGE.into(),
+        nonce: NONCE.into(),
+        ..Proto::MessageSigningInput::default()
+    });
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.full_message,
+        "APTOS\nmessage: Hello, Aptos!\nnonce: 1234567890"
+    );
+    assert_eq!(output.signature, "0f3e5174a07feaaaaa37536f2a62844317a3a932d038346ac2071b936987031fe4dba252a75f0b68aad1fa56206c06c7507f9ed0292ec64372f88c4866fb770b");
+
+    assert!(verify_message(Proto::MessageVerifyingInput {
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: output.signature.clone(),
+        message: MESSAGE.into(),
+        nonce: NONCE.into(),
+        ..Proto::MessageVerifyingInput::default()
+    }));
+}
+
+#[test]
+fn test_aptos_message_pre_image_hashes() {
+    let input = Proto::MessageSigningInput {
+        message: MESSAGE.into(),
+        nonce: NONCE.into(),
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(input_data.ptr(), APTOS_COIN_TYPE)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.data_hash.as_ref(),
+        b"APTOS\nmessage: Hello, Aptos!\nnonce: 1234567890"
+    );
+}
+
+#[test]
+fn test_aptos_message_verify() {
+    assert!(verify_message(full_verifying_input(FULL_SIGNATURE)));
+    // The signature can be `0x` prefixed.
+    assert!(verify_message(full_verifying_input(&format!(
+        "0x{FULL_SIGNATURE}"
+    ))));
+}
+
+#[test]
+fn test_aptos_message_verify_invalid() {
+    // Another nonce.
+    assert!(!verify_message(Proto::MessageVerifyingInput {
+        nonce: "1234567891".into(),
+        ..full_verifying_input(FULL_SIGNATURE)
+    }));
+    // The address does not belong to the public key.
+    assert!(!verify_message(Proto::MessageVerifyingInput {
+        address: "0x1".into(),
+        ..full_verifying_input(FULL_SIGNATURE)
+    }));
+    // Chain ID is omitted.
+    assert!(!verify_message(Proto::MessageVerifyingInput {
+        chain_id: 0,
+        ..full_verifying_input(FULL_SIGNATURE)
+    }));
+}
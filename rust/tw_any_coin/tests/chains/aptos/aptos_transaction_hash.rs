@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_hash_utils::tx_id;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+
+/// Successfully broadcasted https://explorer.aptoslabs.com/txn/0xb4d62afd3862116e060dd6ad9848ccb50c2bc177799819f1d29c059ae2042467?network=devnet
+const SIGNED_TRANSFER_TX: &str = "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada00000000210020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c405707246db31e2335edc4316a7a656a11691d1d1647f6e864d1ab12f43428aaaf806cf02120d0b608cdd89c5c904af7b137432aacdd60cc53f9fad7bd33578e01";
+/// The raw transaction of [`SIGNED_TRANSFER_TX`] without an authenticator.
+const RAW_TRANSFER_TX: &str = "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021";
+
+#[test]
+fn test_aptos_transaction_hash() {
+    let signed_tx = SIGNED_TRANSFER_TX.decode_hex().unwrap();
+    assert_eq!(
+        tx_id(CoinType::Aptos, &signed_tx).unwrap(),
+        "0xb4d62afd3862116e060dd6ad9848ccb50c2bc177799819f1d29c059ae2042467"
+    );
+}
+
+#[test]
+fn test_aptos_transaction_hash_unsigned() {
+    let raw_tx = RAW_TRANSFER_TX.decode_hex().unwrap();
+    assert_eq!(tx_id(CoinType::Aptos, &raw_tx), None);
+}
@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde_json::json;
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_any_coin::test_utils::wallet_connect_utils::WalletConnectRequestHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Aptos::Proto;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+
+fn wc_input(method: WCProto::Method, payload: String) -> WCProto::ParseRequestInput<'static> {
+    WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method,
+        payload: payload.into(),
+    }
+}
+
+// Successfully broadcasted: https://explorer.aptoslabs.com/txn/0x7efd69e7f9462774b932ce500ab51c0d0dcc004cf272e09f8ffd5804c2a84e33?network=mainnet
+#[test]
+fn test_aptos_wallet_connect_sign_transaction() {
+    let payload = json!({
+        "transaction": {
+            "sender": "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+            "sequence_number": "42",
+            "max_gas_amount": "100011",
+            "gas_unit_price": "100",
+            "expiration_timestamp_secs": "3664390082",
+            "chain_id": 1,
+            "payload": {
+                "function": "0x16fe2df00ea7dde4a63409201f7f4e536bde7bb7335526a35d05111e68aa322c::AnimeSwapPoolV1::swap_exact_coins_for_coins_3_pair_entry",
+                "type_arguments": [
+                    "0x1::aptos_coin::AptosCoin",
+                    "0x881ac202b1f1e6ad4efcff7a1d0579411533f2502417a19211cfc49751ddb5f4::coin::MOJO",
+                    "0xf22bede237a07e121b56d91a491eb7bcdfd1f5907926a9e58338f964a01b17fa::asset::USDT",
+                    "0xf22bede237a07e121b56d91a491eb7bcdfd1f5907926a9e58338f964a01b17fa::asset::USDC"
+                ],
+                "arguments": ["1000000", "49329"],
+                "type": "entry_function_payload"
+            }
+        }
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Aptos,
+        &wc_input(WCProto::Method::AptosSignTransaction, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::OK);
+
+    let mut signing_input = match output.signing_input_oneof {
+        SigningInputEnum::aptos(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_input.sequence_number, 42);
+    assert_eq!(signing_input.chain_id, 1);
+
+    // Set missing private key.
+    signing_input.private_key = "5d996aa76b3212142792d9130796cd2e11e3c445a93118c08414df4f66bc60ec"
+        .decode_hex()
+        .unwrap()
+        .into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let signing_output = signer.sign(CoinType::Aptos, signing_input);
+
+    assert_eq!(signing_output.error, SigningError::OK);
+    assert_eq!(
+        signing_output.authenticator.unwrap().signature.to_hex(),
+        "42cd67406e85afd1e948e7ad7f5f484fb4c60d82b267c6b6b28a92301e228b983206d2b87cd5487cf9acfb0effbd183ab90123570eb2e047cb152d337152210b"
+    );
+}
+
+#[test]
+fn test_aptos_wallet_connect_sign_transaction_invalid() {
+    let payload = json!({
+        "transaction": {
+            "sender": "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+            "sequence_number": "42",
+            "max_gas_amount": "100011",
+            "gas_unit_price": "100",
+            "expiration_timestamp_secs": "3664390082",
+            "chain_id": 1,
+            "payload": "0x1::aptos_account::transfer"
+        }
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Aptos,
+        &wc_input(WCProto::Method::AptosSignTransaction, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::Error_input_parse);
+
+    // Missing chain ID.
+    let payload = json!({
+        "transaction": {
+            "sender": "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30",
+            "sequence_number": "42",
+            "max_gas_amount": "100011",
+            "gas_unit_price": "100",
+            "expiration_timestamp_secs": "3664390082",
+            "payload": {
+                "function": "0x1::aptos_account::transfer",
+                "type_arguments": [],
+                "arguments": ["0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30", "1000"],
+                "type": "entry_function_payload"
+            }
+        }
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Aptos,
+        &wc_input(WCProto::Method::AptosSignTransaction, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_aptos_wallet_connect_sign_message() {
+    let payload = json!({
+        "message": "Hello, world!",
+        "nonce": "1",
+        "address": true,
+        "application": true,
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Aptos,
+        &wc_input(WCProto::Method::AptosSignMessage, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::OK);
+
+    match output.signing_input_oneof {
+        SigningInputEnum::aptos_message(input) => {
+            assert_eq!(input.message, "Hello, world!");
+            assert_eq!(input.nonce, "1");
+            assert!(input.include_address);
+            // Must be completed by the app.
+            assert!(input.application.is_empty());
+        },
+        _ => unreachable!(),
+    }
+}
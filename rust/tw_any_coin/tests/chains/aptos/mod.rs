@@ -4,7 +4,11 @@
 
 mod aptos_address;
 mod aptos_compile;
+mod aptos_decode;
+mod aptos_message_sign;
 mod aptos_sign;
+mod aptos_transaction_hash;
+mod aptos_wallet_connect;
 mod test_cases;
 
 const APTOS_COIN_TYPE: u32 = 637;
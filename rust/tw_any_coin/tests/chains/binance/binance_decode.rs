@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::binance::make_token;
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Binance::Proto;
+use tw_proto::Binance::Proto::mod_SigningInput::OneOforder_oneof as OrderEnum;
+use tw_proto::Common::Proto::SigningError;
+
+#[test]
+fn test_binance_decode_trade_order() {
+    // Signed in `test_binance_sign_trade_order`.
+    let encoded = "dc01f0625dee0a64ce6dc0430a14ba36f0fad74d8f41045463e4774f328f4af779e5122b424133364630464144373444384634313034353436334534373734463332384634414637373945352d33361a0b4e4e422d3333385f424e422002280130b09282413880c2d72f4001126e0a26eb5ae98721029729a52e4e3c2b4a4e52aa74033eedaf8ba1df5ab6d1f518fd69e67bbd309b0e12409123cb6906bb20aeb753f4a121d4d88ff0e9750ba75b0c4e10d76caee1e7d2481290fa3b9887a6225d6997f5f939ef834ea61d596a314237c48e560da9e17b5a180c20232001";
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Binance, encoded.decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+
+    let new_order = Proto::TradeOrder {
+        sender: "ba36f0fad74d8f41045463e4774f328f4af779e5"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        id: "BA36F0FAD74D8F41045463E4774F328F4AF779E5-36".into(),
+        symbol: "NNB-338_BNB".into(),
+        ordertype: 2,
+        side: 1,
+        price: 136350000,
+        quantity: 100000000,
+        timeinforce: 1,
+    };
+    let expected = Proto::SigningInput {
+        account_number: 12,
+        sequence: 35,
+        source: 1,
+        order_oneof: OrderEnum::trade_order(new_order),
+        ..Proto::SigningInput::default()
+    };
+    assert_eq!(output.transaction, Some(expected));
+    assert_eq!(
+        output.public_key.to_hex(),
+        "029729a52e4e3c2b4a4e52aa74033eedaf8ba1df5ab6d1f518fd69e67bbd309b0e"
+    );
+    assert_eq!(output.signature.to_hex(), "9123cb6906bb20aeb753f4a121d4d88ff0e9750ba75b0c4e10d76caee1e7d2481290fa3b9887a6225d6997f5f939ef834ea61d596a314237c48e560da9e17b5a");
+}
+
+#[test]
+fn test_binance_decode_send_order() {
+    // Signed in `test_binance_sign_send_order`.
+    let encoded = concat!(
+        "cc01f0625dee0a4e2a2c87fa0a230a1440c2979694bbc961023d1d27be6fc4d21a9febe6120b0a03424e42",
+        "10c098a8dd0312230a1488b37d5e05f3699e2a1406468e5d87cb9dcceb95120b0a03424e4210c098a8dd",
+        "03126e0a26eb5ae98721026a35920088d98c3888ca68c53dfc93f4564602606cbb87f0fe5ee533db38e5",
+        "021240c65a13440f18a155bd971ee40b9e0dd58586f5bf344e12ec4c76c439aebca8c7789bab7bfbfb4c",
+        "e89aadc4a02df225b6b6efc861c13bbeb5f7a3eea2d7ffc80f181320171a04746573742001",
+    );
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Binance, encoded.decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+
+    let amount = 1_001_000_000;
+    let send_order = Proto::SendOrder {
+        inputs: vec![Proto::mod_SendOrder::Input {
+            address: "40c2979694bbc961023d1d27be6fc4d21a9febe6"
+                .decode_hex()
+                .unwrap()
+                .into(),
+            coins: vec![make_token("BNB", amount)],
+        }],
+        outputs: vec![Proto::mod_SendOrder::Output {
+            address: "88b37d5e05f3699e2a1406468e5d87cb9dcceb95"
+                .decode_hex()
+                .unwrap()
+                .into(),
+            coins: vec![make_token("BNB", amount)],
+        }],
+    };
+    let expected = Proto::SigningInput {
+        account_number: 19,
+        sequence: 23,
+        source: 1,
+        memo: "test".into(),
+        order_oneof: OrderEnum::send_order(send_order),
+        ..Proto::SigningInput::default()
+    };
+    assert_eq!(output.transaction, Some(expected));
+    assert_eq!(
+        output.public_key.to_hex(),
+        "026a35920088d98c3888ca68c53dfc93f4564602606cbb87f0fe5ee533db38e502"
+    );
+    assert_eq!(output.signature.to_hex(), "c65a13440f18a155bd971ee40b9e0dd58586f5bf344e12ec4c76c439aebca8c7789bab7bfbfb4ce89aadc4a02df225b6b6efc861c13bbeb5f7a3eea2d7ffc80f");
+}
+
+#[test]
+fn test_binance_decode_side_chain_delegate_order() {
+    // Signed in `test_binance_sign_side_chain_delegate_order`.
+    let encoded = concat!(
+        "ba01f0625dee0a44e3a07fd20a1408c7c918f6b72c3c0c21b7d08eb6fc66509998e112147cc24a1de524",
+        "5f14a95e457f903bcc8461ac869c1a0a0a03424e42108084af5f220663686170656c126e0a26eb5ae987",
+        "2103a9a55c040c8eb8120f3d1b32193250841c08af44ea561aac993dbe0f6b6a8fc7124039302c9975fb",
+        "2a09ac2b6b6fb1d3b9fb5b4c03630d3d7a7da42b1c6736d6127142a3fcdca0b70a3d065da8d4f4df8b5d",
+        "9d8f46aeb3627a7d7aa901fe186af34c180f2001",
+    );
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Binance, encoded.decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+
+    let side_delegate = Proto::SideChainDelegate {
+        delegator_addr: "08c7c918f6b72c3c0c21b7d08eb6fc66509998e1"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        validator_addr: "7cc24a1de5245f14a95e457f903bcc8461ac869c"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        delegation: Some(make_token("BNB", 200000000)),
+        chain_id: "chapel".into(),
+    };
+    let expected = Proto::SigningInput {
+        account_number: 15,
+        sequence: 1,
+        order_oneof: OrderEnum::side_delegate_order(side_delegate),
+        ..Proto::SigningInput::default()
+    };
+    assert_eq!(output.transaction, Some(expected));
+}
+
+#[test]
+fn test_binance_decode_invalid() {
+    // The content size prefix doesn't match the actual content size.
+    let encoded = "ff01f0625dee0a04010203041a0474657374";
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Binance, encoded.decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(!output.error_message.is_empty());
+}
@@ -6,6 +6,7 @@ use tw_proto::Binance::Proto;
 
 mod binance_address;
 mod binance_compile;
+mod binance_decode;
 mod binance_sign;
 mod binance_wallet_connect;
 
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto::SigningError;
+
+#[test]
+fn test_bitcoin_decode_p2pkh_transaction() {
+    let encoded_tx = "020000000111b9f62923af73e297abb69f749e7a1aa2735fbdfd32ac5f6aa89e5c96841c18000000006b483045022100a42afe29055e70d912c7bc3b113a6a58216ad0eafc7d73ae447df9044e347d900220530c5b4721c0c832d06be4921369792da9187df6fcf17bcf6c77a27d5ed85a200121028d7dce6d72fb8f7af9566616c6436349c67ad379f2404dd66fe7085fe0fba28fffffffff01e8030000000000001976a9140d0e1cec6c2babe8badde5e9b3dea667da90036d88ac00000000"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Bitcoin, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(
+        output.txid.to_hex(),
+        "ff0901ef4796e9a0a82c4f8a6edebe9112cc54c4eefac77f6d4c4dd267b0da86"
+    );
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.version, 2);
+    assert_eq!(tx.lock_time, 0);
+
+    assert_eq!(tx.inputs.len(), 1);
+    let out_point = tx.inputs[0].out_point.as_ref().unwrap();
+    assert_eq!(
+        out_point.hash.to_hex(),
+        "11b9f62923af73e297abb69f749e7a1aa2735fbdfd32ac5f6aa89e5c96841c18"
+    );
+    assert_eq!(out_point.vout, 0);
+    assert_eq!(tx.inputs[0].sequence, u32::MAX);
+    assert!(tx.inputs[0].witness_items.is_empty());
+
+    assert_eq!(tx.outputs.len(), 1);
+    assert_eq!(tx.outputs[0].value, 1_000);
+    assert_eq!(
+        output.output_addresses,
+        vec!["12C2h5hXPxyrdvnYUBFaBGFnNNYjpWXhPX"]
+    );
+}
+
+#[test]
+fn test_bitcoin_decode_p2pk_output_transaction() {
+    // Unsigned transaction with a P2PK output.
+    let encoded_tx = "020000000111b9f62923af73e297abb69f749e7a1aa2735fbdfd32ac5f6aa89e5c96841c180000000000ffffffff01e8030000000000002321028d7dce6d72fb8f7af9566616c6436349c67ad379f2404dd66fe7085fe0fba28fac00000000"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Bitcoin, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.outputs.len(), 1);
+    assert_eq!(tx.outputs[0].value, 1_000);
+    assert_eq!(
+        tx.outputs[0].script_pubkey.to_hex(),
+        "21028d7dce6d72fb8f7af9566616c6436349c67ad379f2404dd66fe7085fe0fba28fac"
+    );
+    // P2PK output does not have an address.
+    assert_eq!(output.output_addresses, vec![""]);
+}
+
+#[test]
+fn test_bitcoin_decode_p2wpkh_transaction() {
+    let encoded_tx = "010000000001027d4c6ade48ade606a8f9894222fb30f87b427c5861b0b7a293825ffc8bc1621f0100000000ffffffff8d0e94ed369601d13f32bd653071844dfe7139508d2e71d9c105d1faa58230b30100000000ffffffff01836d0000000000001600145360df8231ac5965147c9d90ca930a2aafb0523202483045022100f95f9ac5d39f4b47dcd8c86daaaeac86374258d9960f922333ba0d5fdaa15b7e0220761794672dc9fbd71398d608f72f5d21a0f6c1306c6b700ad0d82f747c221062012103a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c02483045022100eb6ba0dcc64af61b2186b7efdab1ff03784d585ee03437f9a53875e93429db080220015a268d308436d3564b83ceaed90bc7272ca164016298ea855d1936568002a7012103a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c00000000"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Bitcoin, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(
+        output.txid.to_hex(),
+        "5d6bf53576a54be4d92cd8abf58d28ecc9ea7956eaf970d24d6bfcb9fcfe9855"
+    );
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.version, 1);
+    assert_eq!(tx.inputs.len(), 2);
+    for input in tx.inputs.iter() {
+        assert!(input.script_sig.is_empty());
+        assert_eq!(input.witness_items.len(), 2);
+    }
+
+    assert_eq!(tx.outputs.len(), 1);
+    assert_eq!(tx.outputs[0].value, 28_035);
+    assert_eq!(
+        output.output_addresses,
+        vec!["bc1q2dsdlq3343vk29runkgv4yc292hmq53jedfjmp"]
+    );
+}
+
+#[test]
+fn test_bitcoin_decode_p2tr_output_transaction() {
+    let encoded_tx = "020000000111b9f62923af73e297abb69f749e7a1aa2735fbdfd32ac5f6aa89e5c96841c18000000006b483045022100c85b0030e0f18fdb6bf4b6085945732ed6354aa45beb4da3cc8fb76378ab424a02204aef0ccfd9270549c302ca8fca2aed9e6d459b07e7a1af8477f2137e5ca070f30121028d7dce6d72fb8f7af9566616c6436349c67ad379f2404dd66fe7085fe0fba28fffffffff01e8030000000000002251200c23049f1f39b84aaa0544c9dc644577dd2304898abe337fb8f25d574f89d64b00000000"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Bitcoin, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(
+        output.txid.to_hex(),
+        "b37fdb67c208aeb9a42b329eeafe52d9ff870341c8bdc2e09b2a8de64ea13d2a"
+    );
+    assert_eq!(
+        output.output_addresses,
+        vec!["bc1pps3sf8cl8xuy42s9gnyacez9wlwjxpyf32lrxlac7fw4wnuf6e9s0v066l"]
+    );
+}
+
+#[test]
+fn test_bitcoin_decode_transaction_error() {
+    // The transaction is truncated.
+    let encoded_tx = "020000000111b9f62923af73e297abb69f749e7a1aa2735fbdfd32ac5f6aa89e5c96841c18"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Bitcoin, encoded_tx);
+
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(output.transaction.is_none());
+}
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+
+// Test vectors are taken from https://github.com/bitcoinjs/bitcoinjs-message
+const PRIVATE_KEY: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+const MESSAGE: &str = "This is an example of a signed message.";
+
+/// Returns a Base64-encoded signature and the signer address.
+fn sign_message(address_type: Proto::MessageAddressType) -> (String, String) {
+    let input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: MESSAGE.into(),
+        address_type,
+        format: Proto::MessageFormat::BIP137,
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(input_data.ptr(), CoinType::Bitcoin as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    (output.signature.to_string(), output.address.to_string())
+}
+
+fn verify_message(address: &str, message: &str, signature: &str) -> bool {
+    let input = Proto::MessageVerifyingInput {
+        address: address.into(),
+        message: message.into(),
+        signature: signature.into(),
+        format: Proto::MessageFormat::BIP137,
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    unsafe { tw_message_signer_verify(input_data.ptr(), CoinType::Bitcoin as u32) }
+}
+
+#[test]
+fn test_bitcoin_message_sign_p2pkh() {
+    let (signature, address) = sign_message(Proto::MessageAddressType::P2PKHCompressed);
+    assert_eq!(address, "1F3sAm6ZtwLAUnj7d38pGFxtP3RVEvtsbV");
+    assert_eq!(
+        signature,
+        "H9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk="
+    );
+}
+
+#[test]
+fn test_bitcoin_message_sign_p2pkh_uncompressed() {
+    let (signature, address) = sign_message(Proto::MessageAddressType::P2PKHUncompressed);
+    assert_eq!(address, "1HZwkjkeaoZfTSaJxDw6aKkxp45agDiEzN");
+    assert_eq!(
+        signature,
+        "G9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk="
+    );
+}
+
+#[test]
+fn test_bitcoin_message_sign_p2sh_p2wpkh() {
+    let (signature, address) = sign_message(Proto::MessageAddressType::P2SHP2WPKH);
+    assert_eq!(address, "3DnW8JGpPViEZdpqat8qky1zc26EKbXnmM");
+    assert_eq!(
+        signature,
+        "I9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk="
+    );
+}
+
+#[test]
+fn test_bitcoin_message_sign_p2wpkh() {
+    let (signature, address) = sign_message(Proto::MessageAddressType::P2WPKH);
+    assert_eq!(address, "bc1qngw83fg8dz0k749cg7k3emc7v98wy0c74dlrkd");
+    assert_eq!(
+        signature,
+        "J9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk="
+    );
+}
+
+#[test]
+fn test_bitcoin_message_verify() {
+    assert!(verify_message(
+        "1F3sAm6ZtwLAUnj7d38pGFxtP3RVEvtsbV",
+        MESSAGE,
+        "H9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk="
+    ));
+    assert!(verify_message(
+        "1HZwkjkeaoZfTSaJxDw6aKkxp45agDiEzN",
+        MESSAGE,
+        "G9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk="
+    ));
+    assert!(verify_message(
+        "3DnW8JGpPViEZdpqat8qky1zc26EKbXnmM",
+        MESSAGE,
+        "I9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk="
+    ));
+    assert!(verify_message(
+        "bc1qngw83fg8dz0k749cg7k3emc7v98wy0c74dlrkd",
+        MESSAGE,
+        "J9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk="
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_verify_electrum_segwit() {
+    // Electrum signs messages for segwit addresses with the P2PKH compressed header.
+    let signature =
+        "H9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk=";
+    assert!(verify_message(
+        "bc1qngw83fg8dz0k749cg7k3emc7v98wy0c74dlrkd",
+        MESSAGE,
+        signature
+    ));
+    assert!(verify_message(
+        "3DnW8JGpPViEZdpqat8qky1zc26EKbXnmM",
+        MESSAGE,
+        signature
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_verify_invalid() {
+    let signature =
+        "J9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk=";
+    // Another message.
+    assert!(!verify_message(
+        "bc1qngw83fg8dz0k749cg7k3emc7v98wy0c74dlrkd",
+        "This is an example of a signed message!",
+        signature
+    ));
+    // The header byte commits to a P2WPKH address.
+    assert!(!verify_message(
+        "1F3sAm6ZtwLAUnj7d38pGFxtP3RVEvtsbV",
+        MESSAGE,
+        signature
+    ));
+    // Another address.
+    assert!(!verify_message(
+        "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq",
+        MESSAGE,
+        signature
+    ));
+    // Invalid header byte.
+    assert!(!verify_message(
+        "bc1qngw83fg8dz0k749cg7k3emc7v98wy0c74dlrkd",
+        MESSAGE,
+        "A9L5yLFjti0QTHhPyFrZCT1V/MMnBtXKmoiKDZ78NDBjERki6ZTQZdSMCtkgoNmp17By9ItJr8o7ChX0XxY91nk="
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_pre_image_hashes() {
+    let input = Proto::MessageSigningInput {
+        message: MESSAGE.into(),
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(input_data.ptr(), CoinType::Bitcoin as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.data.to_hex(),
+        "18426974636f696e205369676e6564204d6573736167653a0a275468697320697320616e206578616d706c65206f662061207369676e6564206d6573736167652e"
+    );
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "d0e5595ac689a1df9f0b13443e0efd876eeb762d50a05f7179b1506bfccfeec5"
+    );
+}
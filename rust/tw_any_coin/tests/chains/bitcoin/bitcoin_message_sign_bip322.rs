@@ -0,0 +1,377 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{tw_message_signer_sign, tw_message_signer_verify};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::{deserialize, serialize};
+
+// Test vectors are taken from https://github.com/bitcoin/bips/blob/master/bip-0322.mediawiki
+// Private key of the `L3VFeEujGtevx9w18HD1fhRbCH67Az2dpCymeRE1SoPK6XQtaN2k` WIF.
+const PRIVATE_KEY: &str = "bb051cd0dda0246f33c5a9e133ebd8e7bc02a92af6c41adc131ccd7826c5b004";
+const P2WPKH_ADDRESS: &str = "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l";
+const P2TR_ADDRESS: &str = "bc1ppv609nr0vr25u07u95waq5lucwfm6tde4nydujnu8npg4q75mr5sxq8lt3";
+const P2PKH_ADDRESS: &str = "14vV3aCHBeStb5bkenkNHbe2YAFinYdXgc";
+const P2SH_P2WPKH_ADDRESS: &str = "37qyp7jQAzqb2rCBpMvVtLDuuzKAUCVnJb";
+/// P2WSH address of the `<pubkey> OP_CHECKSIG` witness script.
+const P2WSH_ADDRESS: &str = "bc1qumw0r30366ya78zvcv6tyr0ffgtqg9vm9jt6mud7slmehggpd0jqucu77z";
+/// P2WSH address of the `OP_2 <pubkey> <pubkey_2> OP_2 OP_CHECKMULTISIG` witness script,
+/// where `pubkey_2` is `0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1`.
+const P2WSH_MULTISIG_ADDRESS: &str =
+    "bc1q7hsrsv7dm6wc5dhzrp759sjlmde0xuhr6hd89gdupm36e9w6vklq2atsah";
+const HELLO_WORLD: &str = "Hello World";
+
+fn sign_message_output(
+    address: &str,
+    message: &str,
+    format: Proto::MessageFormat,
+) -> Proto::MessageSigningOutput<'static> {
+    let input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: message.into(),
+        format,
+        address: address.into(),
+        dangerous_use_fixed_schnorr_rng: true,
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(input_data.ptr(), CoinType::Bitcoin as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    Proto::MessageSigningOutput {
+        error: output.error,
+        error_message: output.error_message.to_string().into(),
+        signature: output.signature.to_string().into(),
+        address: output.address.to_string().into(),
+    }
+}
+
+/// Returns a Base64-encoded BIP-322 proof.
+fn sign_message(address: &str, message: &str, format: Proto::MessageFormat) -> String {
+    let output = sign_message_output(address, message, format);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.address, address);
+    output.signature.to_string()
+}
+
+fn verify_message(
+    address: &str,
+    message: &str,
+    signature: &str,
+    format: Proto::MessageFormat,
+) -> bool {
+    let input = Proto::MessageVerifyingInput {
+        address: address.into(),
+        message: message.into(),
+        signature: signature.into(),
+        format,
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    unsafe { tw_message_signer_verify(input_data.ptr(), CoinType::Bitcoin as u32) }
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_simple_p2wpkh() {
+    let signature = sign_message(P2WPKH_ADDRESS, "", Proto::MessageFormat::BIP322Simple);
+    assert_eq!(
+        signature,
+        "AkgwRQIhAPkJ1Q4oYS0htvyuSFHLxRQpFAY56b70UvE7Dxazen0ZAiAtZfFz1S6T6I23MWI2lK/pcNTWncuyL8UL+oMdydVgzAEhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1Yhy"
+    );
+    assert!(verify_message(
+        P2WPKH_ADDRESS,
+        "",
+        &signature,
+        Proto::MessageFormat::BIP322Simple
+    ));
+
+    let signature = sign_message(
+        P2WPKH_ADDRESS,
+        HELLO_WORLD,
+        Proto::MessageFormat::BIP322Simple,
+    );
+    assert_eq!(
+        signature,
+        "AkgwRQIhAOzyynlqt93lOKJr+wmmxIens//zPzl9tqIOua93wO6MAiBi5n5EyAcPScOjf1lAqIUIQtr3zKNeavYabHyR8eGhowEhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1Yhy"
+    );
+    assert!(verify_message(
+        P2WPKH_ADDRESS,
+        HELLO_WORLD,
+        &signature,
+        Proto::MessageFormat::BIP322Simple
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_full_p2wpkh() {
+    let signature = sign_message(
+        P2WPKH_ADDRESS,
+        HELLO_WORLD,
+        Proto::MessageFormat::BIP322Full,
+    );
+    assert_eq!(
+        signature,
+        "AAAAAAABASs1A9aiYU3q8XFsIzJcU+BRS0r8mBAcdxdSrUBnGZ23AAAAAAAAAAAAAQAAAAAAAAAAAWoCSDBFAiEA7PLKeWq33eU4omv7CabEh6ez//M/OX22og65r3fA7owCIGLmfkTIBw9Jw6N/WUCohQhC2vfMo15q9hpsfJHx4aGjASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHIAAAAA"
+    );
+    assert!(verify_message(
+        P2WPKH_ADDRESS,
+        HELLO_WORLD,
+        &signature,
+        Proto::MessageFormat::BIP322Full
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_p2tr() {
+    let signature = sign_message(
+        P2TR_ADDRESS,
+        HELLO_WORLD,
+        Proto::MessageFormat::BIP322Simple,
+    );
+    assert_eq!(
+        signature,
+        "AUDjpClYFHngjnqQ3F0/3dyrLsOHFNEm4rKaaAc9GsfhC5+DngPJmXTeAmz+yfsVRa61PD2k9/CEQnLDvNUn9Qug"
+    );
+    assert!(verify_message(
+        P2TR_ADDRESS,
+        HELLO_WORLD,
+        &signature,
+        Proto::MessageFormat::BIP322Simple
+    ));
+
+    let signature = sign_message(P2TR_ADDRESS, HELLO_WORLD, Proto::MessageFormat::BIP322Full);
+    assert_eq!(
+        signature,
+        "AAAAAAABAQZ52yMWanylo3mYung2wzGYu6l1UmV7Eo2xCNKfbmYhAAAAAAAAAAAAAQAAAAAAAAAAAWoBQOOkKVgUeeCOepDcXT/d3Ksuw4cU0SbisppoBz0ax+ELn4OeA8mZdN4CbP7J+xVFrrU8PaT38IRCcsO81Sf1C6AAAAAA"
+    );
+    assert!(verify_message(
+        P2TR_ADDRESS,
+        HELLO_WORLD,
+        &signature,
+        Proto::MessageFormat::BIP322Full
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_p2sh_p2wpkh() {
+    let signature = sign_message(
+        P2SH_P2WPKH_ADDRESS,
+        HELLO_WORLD,
+        Proto::MessageFormat::BIP322Simple,
+    );
+    assert_eq!(
+        signature,
+        "AkcwRAIgRfq2Gfv9guFcXAf2vQEJSHX8FP5OuiHs1DSK1I0wk/0CIGPzqm6QNPTDJuki148OQ2DbJtXyrr71s4xYPwogQUupASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI="
+    );
+    assert!(verify_message(
+        P2SH_P2WPKH_ADDRESS,
+        HELLO_WORLD,
+        &signature,
+        Proto::MessageFormat::BIP322Simple
+    ));
+
+    let signature = sign_message(
+        P2SH_P2WPKH_ADDRESS,
+        HELLO_WORLD,
+        Proto::MessageFormat::BIP322Full,
+    );
+    assert_eq!(
+        signature,
+        "AAAAAAABAcbRsnNWkk3JpUbXQM8ONPeKRfpyUCEoGI9hMjBvVKIjAAAAABcWABQrBdVk5qejPAh/FuD3MNFEASN5nQAAAAABAAAAAAAAAAABagJHMEQCIEX6thn7/YLhXFwH9r0BCUh1/BT+Troh7NQ0itSNMJP9AiBj86pukDT0wybpItePDkNg2ybV8q6+9bOMWD8KIEFLqQEhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1YhyAAAAAA=="
+    );
+    assert!(verify_message(
+        P2SH_P2WPKH_ADDRESS,
+        HELLO_WORLD,
+        &signature,
+        Proto::MessageFormat::BIP322Full
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_full_p2pkh() {
+    let signature = sign_message(P2PKH_ADDRESS, HELLO_WORLD, Proto::MessageFormat::BIP322Full);
+    assert_eq!(
+        signature,
+        "AAAAAAHZIvdvR4fompS+lLTvaKJgjitVabp8CizknOvglZs2XgAAAABqRzBEAiB3hjKYQcm/KGTsalB3I4kixH3+uDyHQzt1PN5cBGJsvQIgJnRxSVWIbijmMST7VnxGpI8OOCU/tky8Pg7UH5HgSt4BIQLH8SADGWRClD2FiOAa7oQEI8xU/BUhUmo7hcKwy9WIcgAAAAABAAAAAAAAAAABagAAAAA="
+    );
+    assert!(verify_message(
+        P2PKH_ADDRESS,
+        HELLO_WORLD,
+        &signature,
+        Proto::MessageFormat::BIP322Full
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_simple_p2pkh_not_supported() {
+    let output = sign_message_output(
+        P2PKH_ADDRESS,
+        HELLO_WORLD,
+        Proto::MessageFormat::BIP322Simple,
+    );
+    assert_eq!(output.error, SigningErrorType::Error_not_supported);
+    assert!(output.signature.is_empty());
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_address_mismatch() {
+    // The address belongs to another private key.
+    let output = sign_message_output(
+        "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+        HELLO_WORLD,
+        Proto::MessageFormat::BIP322Simple,
+    );
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+    assert!(output.signature.is_empty());
+}
+
+#[test]
+fn test_bitcoin_message_verify_bip322_simple_p2wpkh() {
+    assert!(verify_message(
+        P2WPKH_ADDRESS,
+        "",
+        "AkcwRAIgM2gBAQqvZX15ZiysmKmQpDrG83avLIT492QBzLnQIxYCIBaTpOaD20qRlEylyxFSeEA2ba9YOixpX8z46TSDtS40ASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=",
+        Proto::MessageFormat::BIP322Simple
+    ));
+    assert!(verify_message(
+        P2WPKH_ADDRESS,
+        HELLO_WORLD,
+        "AkcwRAIgZRfIY3p7/DoVTty6YZbWS71bc5Vct9p9Fia83eRmw2QCICK/ENGfwLtptFluMGs2KsqoNSk89pO7F29zJLUx9a/sASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=",
+        Proto::MessageFormat::BIP322Simple
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_verify_bip322_p2tr_sighash_all() {
+    // The signature is followed by an explicit `SIGHASH_ALL` byte.
+    assert!(verify_message(
+        P2TR_ADDRESS,
+        HELLO_WORLD,
+        "AUHd69PrJQEv+oKTfZ8l+WROBHuy9HKrbFCJu7U1iK2iiEy1vMU5EfMtjc+VSHM7aU0SDbak5IUZRVno2P5mjSafAQ==",
+        Proto::MessageFormat::BIP322Simple
+    ));
+    // The same signature without the `SIGHASH_ALL` byte commits to another sighash.
+    assert!(!verify_message(
+        P2TR_ADDRESS,
+        HELLO_WORLD,
+        "AUDd69PrJQEv+oKTfZ8l+WROBHuy9HKrbFCJu7U1iK2iiEy1vMU5EfMtjc+VSHM7aU0SDbak5IUZRVno2P5mjSaf",
+        Proto::MessageFormat::BIP322Simple
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_verify_bip322_invalid() {
+    let hello_world_signature = "AkcwRAIgZRfIY3p7/DoVTty6YZbWS71bc5Vct9p9Fia83eRmw2QCICK/ENGfwLtptFluMGs2KsqoNSk89pO7F29zJLUx9a/sASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=";
+
+    // Another message.
+    assert!(!verify_message(
+        P2WPKH_ADDRESS,
+        "Hello World!",
+        hello_world_signature,
+        Proto::MessageFormat::BIP322Simple
+    ));
+    // Another address.
+    assert!(!verify_message(
+        "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+        HELLO_WORLD,
+        hello_world_signature,
+        Proto::MessageFormat::BIP322Simple
+    ));
+    // Witness is passed as a full proof.
+    assert!(!verify_message(
+        P2WPKH_ADDRESS,
+        HELLO_WORLD,
+        hello_world_signature,
+        Proto::MessageFormat::BIP322Full
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_p2wsh() {
+    let signature = sign_message(
+        P2WSH_ADDRESS,
+        HELLO_WORLD,
+        Proto::MessageFormat::BIP322Simple,
+    );
+    assert_eq!(
+        signature,
+        "AkcwRAIgWuUrBwFuOBAs3qd3FnpHbVDrU7exet454Alnz+T4WCECIAVUtpcauPRwQZx3ZHDgb1Zb8uzn9VODXqXZDawOfVsSASMhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1YhyrA=="
+    );
+    assert!(verify_message(
+        P2WSH_ADDRESS,
+        HELLO_WORLD,
+        &signature,
+        Proto::MessageFormat::BIP322Simple
+    ));
+
+    let signature = sign_message(P2WSH_ADDRESS, HELLO_WORLD, Proto::MessageFormat::BIP322Full);
+    assert_eq!(
+        signature,
+        "AAAAAAABAb8mAku4GJhDeznJQuTLFXVlSjQ1jg57Vc93O8N2agmNAAAAAAAAAAAAAQAAAAAAAAAAAWoCRzBEAiBa5SsHAW44ECzep3cWekdtUOtTt7F63jngCWfP5PhYIQIgBVS2lxq49HBBnHdkcOBvVlvy7Of1U4NepdkNrA59WxIBIyECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHKsAAAAAA=="
+    );
+    assert!(verify_message(
+        P2WSH_ADDRESS,
+        HELLO_WORLD,
+        &signature,
+        Proto::MessageFormat::BIP322Full
+    ));
+    assert!(!verify_message(
+        P2WSH_ADDRESS,
+        "",
+        &signature,
+        Proto::MessageFormat::BIP322Full
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_p2wsh_multisig_not_supported() {
+    let output = sign_message_output(
+        P2WSH_MULTISIG_ADDRESS,
+        HELLO_WORLD,
+        Proto::MessageFormat::BIP322Simple,
+    );
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+}
+
+#[test]
+fn test_bitcoin_message_verify_bip322_p2wsh_multisig() {
+    // [<empty>, <sig>, <sig_2>, <witness_script>]
+    let signature = "BABHMEQCIHIbbPQQmwFzYo6RGq5/3SJAyEDi+03t2QZzxN2yXjraAiAfc+coqtQaO4UVf1DTcjgbm2K66VPu3558NiKYGC7jvQFIMEUCIQDToOmPEHH4Sq4x0S7rTAzVKE/05kuHBHTWZsCagf0CpQIgHpi3vgP67ugXHuPxHlu4yxnzB0mpqxfOX93+mggra7cBR1IhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1YhyIQOZxvUa1vmMnFg/jpK7d1irLKmgQRDAoRJuxD5UU9GWwVKu";
+    assert!(verify_message(
+        P2WSH_MULTISIG_ADDRESS,
+        HELLO_WORLD,
+        signature,
+        Proto::MessageFormat::BIP322Simple
+    ));
+    assert!(!verify_message(
+        P2WSH_MULTISIG_ADDRESS,
+        "",
+        signature,
+        Proto::MessageFormat::BIP322Simple
+    ));
+    assert!(!verify_message(
+        P2WSH_ADDRESS,
+        HELLO_WORLD,
+        signature,
+        Proto::MessageFormat::BIP322Simple
+    ));
+
+    // The signatures must be ordered the same way as the public keys.
+    let reversed_signatures = "BABIMEUCIQDToOmPEHH4Sq4x0S7rTAzVKE/05kuHBHTWZsCagf0CpQIgHpi3vgP67ugXHuPxHlu4yxnzB0mpqxfOX93+mggra7cBRzBEAiByG2z0EJsBc2KOkRquf90iQMhA4vtN7dkGc8Tdsl462gIgH3PnKKrUGjuFFX9Q03I4G5tiuulT7t+efDYimBgu470BR1IhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1YhyIQOZxvUa1vmMnFg/jpK7d1irLKmgQRDAoRJuxD5UU9GWwVKu";
+    assert!(!verify_message(
+        P2WSH_MULTISIG_ADDRESS,
+        HELLO_WORLD,
+        reversed_signatures,
+        Proto::MessageFormat::BIP322Simple
+    ));
+}
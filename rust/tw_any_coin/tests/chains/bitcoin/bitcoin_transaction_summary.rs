@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::{plan, DUST};
+use tw_any_coin::test_utils::transaction_summary_utils::TransactionSummarizerHelper;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::TxSummary::Proto as SummaryProto;
+
+/// P2PKH address of the public key used by [`plan::make_planning_input`].
+const ADDRESS: &str = "19dYwcMgeK3KKrc2GzzbRLWK5B6eGytay5";
+
+#[test]
+fn test_bitcoin_transaction_summary_with_change() {
+    let input = plan::make_planning_input(plan::PlanArgs {
+        inputs: vec![1_000, 3_000, 4_000],
+        outputs: vec![1_000, 1_000],
+        change: true,
+        max: false,
+        dust_threshold: DUST,
+        order: Proto::InputSelector::SelectAscending,
+        fee_per_vb: 2,
+    });
+
+    let mut summarizer = TransactionSummarizerHelper::default();
+    let summary = summarizer.summarize(CoinType::Bitcoin, &input);
+    assert_eq!(summary.error, SigningErrorType::OK);
+    assert!(summary.warnings.is_empty());
+
+    assert_eq!(summary.actions.len(), 3);
+    for action in summary.actions.iter() {
+        assert_eq!(action.recipient, ADDRESS);
+    }
+    assert_eq!(
+        summary.actions[0].type_pb,
+        SummaryProto::ActionType::Transfer
+    );
+    assert_eq!(
+        summary.actions[1].type_pb,
+        SummaryProto::ActionType::Transfer
+    );
+    assert_eq!(
+        summary.actions[2].type_pb,
+        SummaryProto::ActionType::ChangeTransfer
+    );
+    assert_eq!(
+        summary.actions[0].description,
+        format!("Send 0.00001 BTC to {ADDRESS}")
+    );
+    assert_eq!(
+        summary.actions[1].description,
+        format!("Send 0.00001 BTC to {ADDRESS}")
+    );
+    assert_eq!(
+        summary.actions[2].description,
+        format!("Return change of 0.0000118 BTC to {ADDRESS}")
+    );
+
+    // vsize * fee_rate
+    let fee = summary.fee.as_ref().unwrap();
+    assert_eq!(fee.amount.as_ref().unwrap().value, "820");
+}
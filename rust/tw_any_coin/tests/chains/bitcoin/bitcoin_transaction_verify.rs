@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_verify_utils::TransactionVerifierHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TxVerifier::Proto;
+
+/// https://mempool.space/tx/5d6bf53576a54be4d92cd8abf58d28ecc9ea7956eaf970d24d6bfcb9fcfe9855
+const P2WPKH_TX: &str = "010000000001027d4c6ade48ade606a8f9894222fb30f87b427c5861b0b7a293825ffc8bc1621f0100000000ffffffff8d0e94ed369601d13f32bd653071844dfe7139508d2e71d9c105d1faa58230b30100000000ffffffff01836d0000000000001600145360df8231ac5965147c9d90ca930a2aafb0523202483045022100f95f9ac5d39f4b47dcd8c86daaaeac86374258d9960f922333ba0d5fdaa15b7e0220761794672dc9fbd71398d608f72f5d21a0f6c1306c6b700ad0d82f747c221062012103a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c02483045022100eb6ba0dcc64af61b2186b7efdab1ff03784d585ee03437f9a53875e93429db080220015a268d308436d3564b83ceaed90bc7272ca164016298ea855d1936568002a7012103a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c00000000";
+const P2WPKH_SCRIPT: &str = "001460d7ee599766db323fb1916c7f9e5d818aaf8c1b";
+const P2WPKH_PUBLIC_KEY: &str =
+    "03a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c";
+
+fn prev_output(script_pubkey: &str, value: i64) -> Proto::PreviousOutput<'static> {
+    Proto::PreviousOutput {
+        script_pubkey: script_pubkey.decode_hex().unwrap().into(),
+        value,
+    }
+}
+
+#[test]
+fn test_bitcoin_verify_p2wpkh_transaction() {
+    let input = Proto::VerifyingInput {
+        transaction: P2WPKH_TX.decode_hex().unwrap().into(),
+        public_keys: vec![P2WPKH_PUBLIC_KEY.decode_hex().unwrap().into()],
+        previous_outputs: vec![
+            prev_output(P2WPKH_SCRIPT, 4_863),
+            prev_output(P2WPKH_SCRIPT, 30_269),
+        ],
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Bitcoin, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+    assert!(output.valid);
+
+    assert_eq!(output.signatures.len(), 2);
+    assert!(output.signatures.iter().all(|sig| sig.valid));
+    assert_eq!(output.signatures[0].public_key.to_hex(), P2WPKH_PUBLIC_KEY);
+    // The signature is returned without the sighash type.
+    assert_eq!(
+        output.signatures[0].signature.to_hex(),
+        "3045022100f95f9ac5d39f4b47dcd8c86daaaeac86374258d9960f922333ba0d5fdaa15b7e0220761794672dc9fbd71398d608f72f5d21a0f6c1306c6b700ad0d82f747c221062"
+    );
+}
+
+#[test]
+fn test_bitcoin_verify_p2wpkh_transaction_wrong_amount() {
+    let input = Proto::VerifyingInput {
+        transaction: P2WPKH_TX.decode_hex().unwrap().into(),
+        previous_outputs: vec![
+            prev_output(P2WPKH_SCRIPT, 4_864),
+            prev_output(P2WPKH_SCRIPT, 30_269),
+        ],
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Bitcoin, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(!output.valid);
+    assert_eq!(output.signatures.len(), 2);
+    // Segwit sighashes commit to the spent amount.
+    assert!(!output.signatures[0].valid);
+    assert!(output.signatures[1].valid);
+}
+
+#[test]
+fn test_bitcoin_verify_transaction_unsupported_input() {
+    // Pretend the second input spends a P2WSH output that cannot be verified.
+    let p2wsh_script = "0020701a8d401c84fb13e6baf169d59684e17abd9fa216c8cc5b9fc63d622ff8c58d";
+    let input = Proto::VerifyingInput {
+        transaction: P2WPKH_TX.decode_hex().unwrap().into(),
+        previous_outputs: vec![
+            prev_output(P2WPKH_SCRIPT, 4_863),
+            prev_output(p2wsh_script, 30_269),
+        ],
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Bitcoin, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(!output.valid);
+    assert_eq!(output.signatures.len(), 2);
+    assert!(output.signatures[0].valid);
+    assert!(!output.signatures[1].valid);
+    assert!(output.signatures[1].public_key.is_empty());
+    assert!(output.signatures[1].signature.is_empty());
+}
+
+#[test]
+fn test_bitcoin_verify_p2pkh_transaction() {
+    let tx = "02000000017be4e642bb278018ab12277de9427773ad1c5f5b1d164a157e0d99aa48dc1c1e000000006a473044022078eda020d4b86fcb3af78ef919912e6d79b81164dbbb0b0b96da6ac58a2de4b102201a5fd8d48734d5a02371c4b5ee551a69dca3842edbf577d863cf8ae9fdbbd4590121036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b87389536ffffffff01c0aff629010000001976a9145eaaa4f458f9158f86afcba08dd7448d27045e3d88ac00000000";
+
+    let input = Proto::VerifyingInput {
+        transaction: tx.decode_hex().unwrap().into(),
+        previous_outputs: vec![prev_output(
+            "76a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac",
+            5_000_000_000,
+        )],
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Bitcoin, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.valid);
+    assert_eq!(output.signatures.len(), 1);
+    assert_eq!(
+        output.signatures[0].public_key.to_hex(),
+        "036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b87389536"
+    );
+}
+
+#[test]
+fn test_bitcoin_verify_transaction_missing_previous_outputs() {
+    let input = Proto::VerifyingInput {
+        transaction: P2WPKH_TX.decode_hex().unwrap().into(),
+        previous_outputs: vec![prev_output(P2WPKH_SCRIPT, 4_863)],
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Bitcoin, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+    assert!(!output.valid);
+}
@@ -4,5 +4,11 @@
 
 mod bitcoin_address;
 mod bitcoin_compile;
+mod bitcoin_decode;
+mod bitcoin_message_sign;
+mod bitcoin_message_sign_bip322;
 mod bitcoin_plan;
 mod bitcoin_sign;
+mod bitcoin_transaction_hash;
+mod bitcoin_transaction_summary;
+mod bitcoin_transaction_verify;
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Cosmos::Proto;
+use tw_proto::Cosmos::Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+
+#[test]
+fn test_cosmos_decode_send_transaction() {
+    // Signed in `test_any_signer_sign_cosmos`.
+    let encoded_tx = base64::decode("CpIBCo8BChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEm8KLWNvc21vczFta3k2OWNuOGVrdHd5MDg0NXZlYzl1cHNkcGhrdHh0MDNna3dseBItY29zbW9zMThzMGhkbnNsbGdjY2x3ZXU5YXltdzRuZ2t0cjJrMHJreWdkemRwGg8KBXVhdG9tEgY0MDAwMDASZQpOCkYKHy9jb3Ntb3MuY3J5cHRvLnNlY3AyNTZrMS5QdWJLZXkSIwohAuzvXOQ3owLGf5VGjeSzHzbpEfRn1+alK0HB4T4dVjZJEgQKAggBEhMKDQoFdWF0b20SBDEwMDAQwJoMGkCvvVE6d29P30cO9/lnXyGunWMPxNY12NuqDcCnFkNM0H4CUQdl1Gc9+ogIJbro5nyzZzlv9rl2/GsZox/JXoCX", false).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Cosmos, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(output.signatures.len(), 1);
+    assert_eq!(
+        output.signatures[0].to_hex(),
+        "afbd513a776f4fdf470ef7f9675f21ae9d630fc4d635d8dbaa0dc0a716434cd07e02510765d4673dfa880825bae8e67cb367396ff6b976fc6b19a31fc95e8097"
+    );
+
+    assert_eq!(output.signers.len(), 1);
+    assert_eq!(
+        output.signers[0].public_key.to_hex(),
+        "02ecef5ce437a302c67f95468de4b31f36e911f467d7e6a52b41c1e13e1d563649"
+    );
+    assert_eq!(output.signers[0].sequence, 0);
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.signing_mode, Proto::SigningMode::Protobuf);
+    assert_eq!(tx.sequence, 0);
+    assert_eq!(
+        tx.public_key.to_hex(),
+        "02ecef5ce437a302c67f95468de4b31f36e911f467d7e6a52b41c1e13e1d563649"
+    );
+    assert!(tx.memo.is_empty());
+
+    let fee = tx.fee.unwrap();
+    assert_eq!(fee.gas, 200000);
+    assert_eq!(fee.amounts.len(), 1);
+    assert_eq!(fee.amounts[0].denom, "uatom");
+    assert_eq!(fee.amounts[0].amount, "1000");
+
+    assert_eq!(tx.messages.len(), 1);
+    let MessageEnum::send_coins_message(ref send) = tx.messages[0].message_oneof else {
+        panic!("Expected a send message, found: {:?}", tx.messages[0]);
+    };
+    assert_eq!(
+        send.from_address,
+        "cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx"
+    );
+    assert_eq!(
+        send.to_address,
+        "cosmos18s0hdnsllgcclweu9aymw4ngktr2k0rkygdzdp"
+    );
+    assert_eq!(send.amounts.len(), 1);
+    assert_eq!(send.amounts[0].denom, "uatom");
+    assert_eq!(send.amounts[0].amount, "400000");
+}
+
+#[test]
+fn test_cosmos_decode_multiple_signers() {
+    // The transaction above with a second signer info and a dummy signature appended.
+    let encoded_tx = base64::decode("CpIBCo8BChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEm8KLWNvc21vczFta3k2OWNuOGVrdHd5MDg0NXZlYzl1cHNkcGhrdHh0MDNna3dseBItY29zbW9zMThzMGhkbnNsbGdjY2x3ZXU5YXltdzRuZ2t0cjJrMHJreWdkemRwGg8KBXVhdG9tEgY0MDAwMDAStwEKTgpGCh8vY29zbW9zLmNyeXB0by5zZWNwMjU2azEuUHViS2V5EiMKIQLs71zkN6MCxn+VRo3ksx826RH0Z9fmpStBweE+HVY2SRIECgIIAQpQCkYKHy9jb3Ntb3MuY3J5cHRvLnNlY3AyNTZrMS5QdWJLZXkSIwohAnnvNAZNoQ2wRjxwSAYWugIHA+w6RQJt73vr0ggvXW/IEgQKAggBGAcSEwoNCgV1YXRvbRIEMTAwMBDAmgwaQK+9UTp3b0/fRw73+WdfIa6dYw/E1jXY26oNwKcWQ0zQfgJRB2XUZz36iAgluujmfLNnOW/2uXb8axmjH8legJcaQAABAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZGhscHR4fICEiIyQlJicoKSorLC0uLzAxMjM0NTY3ODk6Ozw9Pj8=", false).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Cosmos, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(output.signatures.len(), 2);
+    assert_eq!(output.signers.len(), 2);
+    assert_eq!(
+        output.signers[0].public_key.to_hex(),
+        "02ecef5ce437a302c67f95468de4b31f36e911f467d7e6a52b41c1e13e1d563649"
+    );
+    assert_eq!(output.signers[0].sequence, 0);
+    assert_eq!(
+        output.signers[1].public_key.to_hex(),
+        "0279ef34064da10db0463c70480616ba020703ec3a45026def7bebd2082f5d6fc8"
+    );
+    assert_eq!(output.signers[1].sequence, 7);
+
+    // The signing input describes the first signer only.
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.sequence, 0);
+    assert_eq!(
+        tx.public_key.to_hex(),
+        "02ecef5ce437a302c67f95468de4b31f36e911f467d7e6a52b41c1e13e1d563649"
+    );
+}
+
+#[test]
+fn test_cosmos_decode_transaction_with_unknown_message() {
+    // Contains `/cosmos.staking.v1beta1.MsgDelegate` and `/cosmos.gov.v1.MsgVote` messages.
+    let encoded_tx = "0a88020a9e010a232f636f736d6f732e7374616b696e672e763162657461312e4d736744656c656761746512770a2d636f736d6f73316d6b793639636e38656b74777930383435766563397570736470686b7478743033676b776c781234636f736d6f7376616c6f70657231676a74766c79396c656c367a736b767774766c673576687770753963397761773773787a77781a100a057561746f6d1207313030303030300a4d0a162f636f736d6f732e676f762e76312e4d7367566f74651233082a122d636f736d6f73316d6b793639636e38656b74777930383435766563397570736470686b7478743033676b776c781801121144656c656761746520616e6420766f746518c0c3930712670a500a460a1f2f636f736d6f732e63727970746f2e736563703235366b312e5075624b657912230a2102ecef5ce437a302c67f95468de4b31f36e911f467d7e6a52b41c1e13e1d56364912040a020801180712130a0d0a057561746f6d1204323530301090a10f1a40000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Cosmos, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signatures.len(), 1);
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.sequence, 7);
+    assert_eq!(tx.memo, "Delegate and vote");
+    assert_eq!(tx.timeout_height, 15000000);
+
+    let fee = tx.fee.unwrap();
+    assert_eq!(fee.gas, 250000);
+    assert_eq!(fee.amounts[0].amount, "2500");
+
+    assert_eq!(tx.messages.len(), 2);
+    let MessageEnum::stake_message(ref delegate) = tx.messages[0].message_oneof else {
+        panic!("Expected a delegate message, found: {:?}", tx.messages[0]);
+    };
+    assert_eq!(
+        delegate.delegator_address,
+        "cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx"
+    );
+    assert_eq!(
+        delegate.validator_address,
+        "cosmosvaloper1gjtvly9lel6zskvwtvlg5vhwpu9c9waw7sxzwx"
+    );
+    let amount = delegate.amount.as_ref().unwrap();
+    assert_eq!(amount.denom, "uatom");
+    assert_eq!(amount.amount, "1000000");
+
+    let MessageEnum::raw_protobuf_message(ref raw) = tx.messages[1].message_oneof else {
+        panic!(
+            "Expected a raw Protobuf message, found: {:?}",
+            tx.messages[1]
+        );
+    };
+    assert_eq!(raw.type_url, "/cosmos.gov.v1.MsgVote");
+    assert_eq!(
+        raw.value.to_hex(),
+        "082a122d636f736d6f73316d6b793639636e38656b74777930383435766563397570736470686b7478743033676b776c781801"
+    );
+}
+
+#[test]
+fn test_cosmos_decode_invalid_transaction() {
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Cosmos, "0a0512".decode_hex().unwrap());
+
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(output.transaction.is_none());
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::{deserialize, serialize, Cosmos, TxCompiler};
+
+const PRIVATE_KEY: &str = "8bbec3772ddb4df68f3186440380c301af116d1422001c1877d6f5e4dba8c8af";
+const PUBLIC_KEY: &str = "02ecef5ce437a302c67f95468de4b31f36e911f467d7e6a52b41c1e13e1d563649";
+const SIGNER: &str = "cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx";
+const SIGNATURE: &str = "65ac23a46678d6f144bd5cc4bb571d658262514ecc1f37da544800d332f3be90297901acb9dae9ae607503caad68f32a53e323ce3ee0ff6d28de0d1200c9b15c";
+
+fn verify_message(message: &[u8], signature: &str) -> bool {
+    let input = Cosmos::Proto::MessageVerifyingInput {
+        signer: SIGNER.into(),
+        message: message.into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: signature.decode_hex().unwrap().into(),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    unsafe { tw_message_signer_verify(input_data.ptr(), CoinType::Cosmos as u32) }
+}
+
+#[test]
+fn test_cosmos_message_sign_adr036() {
+    let input = Cosmos::Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        signer: SIGNER.into(),
+        message: b"Hello, Cosmos!".as_slice().into(),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(input_data.ptr(), CoinType::Cosmos as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Cosmos::Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature.to_hex(), SIGNATURE);
+    assert_eq!(
+        output.signature_json,
+        r#"{"pub_key":{"type":"tendermint/PubKeySecp256k1","value":"AuzvXOQ3owLGf5VGjeSzHzbpEfRn1+alK0HB4T4dVjZJ"},"signature":"ZawjpGZ41vFEvVzEu1cdZYJiUU7MHzfaVEgA0zLzvpApeQGsudrprmB1A8qtaPMqU+Mjzj7g/20o3g0SAMmxXA=="}"#
+    );
+}
+
+#[test]
+fn test_cosmos_message_verify_adr036() {
+    assert!(verify_message(b"Hello, Cosmos!", SIGNATURE));
+}
+
+#[test]
+fn test_cosmos_message_verify_adr036_invalid() {
+    assert!(!verify_message(b"Hello, Cosmos?", SIGNATURE));
+    // Invalid signature length.
+    assert!(!verify_message(b"Hello, Cosmos!", "65ac23a46678d6f1"));
+}
+
+#[test]
+fn test_cosmos_message_pre_image_hashes_adr036() {
+    let input = Cosmos::Proto::MessageSigningInput {
+        signer: SIGNER.into(),
+        message: b"Hello, Cosmos!".as_slice().into(),
+        ..Cosmos::Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(input_data.ptr(), CoinType::Cosmos as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(
+        String::from_utf8(output.data.to_vec()).unwrap(),
+        r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"SGVsbG8sIENvc21vcyE=","signer":"cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx"}}],"sequence":"0"}"#
+    );
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "2d49016d002866d131c344f1a25953268a2530c94efb5ae14561aef73f4c70b2"
+    );
+}
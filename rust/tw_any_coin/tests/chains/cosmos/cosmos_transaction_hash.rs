@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_hash_utils::tx_id;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64;
+
+const SIGNED_TX: &str = "CpIBCo8BChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEm8KLWNvc21vczFta3k2OWNuOGVrdHd5MDg0NXZlYzl1cHNkcGhrdHh0MDNna3dseBItY29zbW9zMThzMGhkbnNsbGdjY2x3ZXU5YXltdzRuZ2t0cjJrMHJreWdkemRwGg8KBXVhdG9tEgY0MDAwMDASZQpOCkYKHy9jb3Ntb3MuY3J5cHRvLnNlY3AyNTZrMS5QdWJLZXkSIwohAuzvXOQ3owLGf5VGjeSzHzbpEfRn1+alK0HB4T4dVjZJEgQKAggBEhMKDQoFdWF0b20SBDEwMDAQwJoMGkCvvVE6d29P30cO9/lnXyGunWMPxNY12NuqDcCnFkNM0H4CUQdl1Gc9+ogIJbro5nyzZzlv9rl2/GsZox/JXoCX";
+
+#[test]
+fn test_cosmos_transaction_hash() {
+    let signed_tx = base64::decode(SIGNED_TX, false).unwrap();
+    assert_eq!(
+        tx_id(CoinType::Cosmos, &signed_tx).unwrap(),
+        "85392373F54577562067030BF0D61596C91188AA5E6CA8FFE731BD0349296411"
+    );
+}
+
+#[test]
+fn test_cosmos_transaction_hash_not_supported() {
+    // Binance Chain does not support computing transaction IDs yet.
+    let signed_tx = base64::decode(SIGNED_TX, false).unwrap();
+    assert_eq!(tx_id(CoinType::Binance, &signed_tx), None);
+}
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_summary_utils::TransactionSummarizerHelper;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_proto::Cosmos::Proto;
+use tw_proto::Cosmos::Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+use tw_proto::TxSummary::Proto as SummaryProto;
+
+fn uatom(amount: &str) -> Proto::Amount<'_> {
+    Proto::Amount {
+        denom: "uatom".into(),
+        amount: amount.into(),
+    }
+}
+
+#[test]
+fn test_cosmos_transaction_summary() {
+    let send_msg = Proto::mod_Message::Send {
+        from_address: "cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx".into(),
+        to_address: "cosmos18s0hdnsllgcclweu9aymw4ngktr2k0rkygdzdp".into(),
+        amounts: vec![uatom("400000")],
+        ..Proto::mod_Message::Send::default()
+    };
+    let delegate_msg = Proto::mod_Message::Delegate {
+        delegator_address: "cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx".into(),
+        validator_address: "cosmosvaloper1gxrvntjlvaplfgjlh4cnpk3lpumz6yvf0eutu6".into(),
+        amount: Some(uatom("1000000")),
+        ..Proto::mod_Message::Delegate::default()
+    };
+    let input = Proto::SigningInput {
+        signing_mode: Proto::SigningMode::Protobuf,
+        account_number: 546179,
+        chain_id: "cosmoshub-4".into(),
+        fee: Some(Proto::Fee {
+            gas: 200000,
+            amounts: vec![uatom("1000")],
+        }),
+        messages: vec![
+            Proto::Message {
+                message_oneof: MessageEnum::send_coins_message(send_msg),
+            },
+            Proto::Message {
+                message_oneof: MessageEnum::stake_message(delegate_msg),
+            },
+        ],
+        ..Proto::SigningInput::default()
+    };
+
+    let mut summarizer = TransactionSummarizerHelper::default();
+    let summary = summarizer.summarize(CoinType::Cosmos, &input);
+    assert_eq!(summary.error, SigningErrorType::OK);
+    assert!(summary.warnings.is_empty());
+
+    assert_eq!(summary.actions.len(), 2);
+    assert_eq!(
+        summary.actions[0].type_pb,
+        SummaryProto::ActionType::Transfer
+    );
+    assert_eq!(
+        summary.actions[0].description,
+        "Send 0.4 ATOM to cosmos18s0hdnsllgcclweu9aymw4ngktr2k0rkygdzdp"
+    );
+    assert_eq!(summary.actions[1].type_pb, SummaryProto::ActionType::Stake);
+    assert_eq!(
+        summary.actions[1].recipient,
+        "cosmosvaloper1gxrvntjlvaplfgjlh4cnpk3lpumz6yvf0eutu6"
+    );
+
+    let fee = summary.fee.as_ref().unwrap();
+    assert_eq!(fee.asset.as_ref().unwrap().symbol, "ATOM");
+    assert_eq!(fee.amount.as_ref().unwrap().value, "1000");
+    assert_eq!(fee.amount.as_ref().unwrap().formatted, "0.001");
+}
+
+#[test]
+fn test_cosmos_transaction_summary_known_token() {
+    let ibc_denom = "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+    let send_msg = Proto::mod_Message::Send {
+        from_address: "cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx".into(),
+        to_address: "cosmos18s0hdnsllgcclweu9aymw4ngktr2k0rkygdzdp".into(),
+        amounts: vec![Proto::Amount {
+            denom: ibc_denom.into(),
+            amount: "2500000".into(),
+        }],
+        ..Proto::mod_Message::Send::default()
+    };
+    let input = Proto::SigningInput {
+        chain_id: "cosmoshub-4".into(),
+        messages: vec![Proto::Message {
+            message_oneof: MessageEnum::send_coins_message(send_msg),
+        }],
+        ..Proto::SigningInput::default()
+    };
+    let tokens = SummaryProto::TokenList {
+        tokens: vec![SummaryProto::TokenInfo {
+            token: ibc_denom.into(),
+            symbol: "USDC".into(),
+            decimals: 6,
+        }],
+    };
+
+    let mut summarizer = TransactionSummarizerHelper::default();
+    let summary = summarizer.summarize_with_tokens(CoinType::Cosmos, &input, &tokens);
+    assert_eq!(summary.error, SigningErrorType::OK);
+
+    assert_eq!(summary.actions.len(), 1);
+    let asset = summary.actions[0].asset.as_ref().unwrap();
+    assert_eq!(asset.symbol, "USDC");
+    assert_eq!(asset.token, ibc_denom);
+    assert_eq!(
+        summary.actions[0].description,
+        "Send 2.5 USDC to cosmos18s0hdnsllgcclweu9aymw4ngktr2k0rkygdzdp"
+    );
+}
+
+#[test]
+fn test_cosmos_transaction_summary_no_messages() {
+    let input = Proto::SigningInput {
+        chain_id: "cosmoshub-4".into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut summarizer = TransactionSummarizerHelper::default();
+    let summary = summarizer.summarize(CoinType::Cosmos, &input);
+    assert_eq!(summary.error, SigningErrorType::Error_invalid_params);
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_verify_utils::TransactionVerifierHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TxVerifier::Proto;
+
+/// Signed `TxRaw` from `test_any_signer_sign_cosmos`.
+const SIGNED_TX: &str = "CpIBCo8BChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEm8KLWNvc21vczFta3k2OWNuOGVrdHd5MDg0NXZlYzl1cHNkcGhrdHh0MDNna3dseBItY29zbW9zMThzMGhkbnNsbGdjY2x3ZXU5YXltdzRuZ2t0cjJrMHJreWdkemRwGg8KBXVhdG9tEgY0MDAwMDASZQpOCkYKHy9jb3Ntb3MuY3J5cHRvLnNlY3AyNTZrMS5QdWJLZXkSIwohAuzvXOQ3owLGf5VGjeSzHzbpEfRn1+alK0HB4T4dVjZJEgQKAggBEhMKDQoFdWF0b20SBDEwMDAQwJoMGkCvvVE6d29P30cO9/lnXyGunWMPxNY12NuqDcCnFkNM0H4CUQdl1Gc9+ogIJbro5nyzZzlv9rl2/GsZox/JXoCX";
+const SIGNER_PUBLIC_KEY: &str =
+    "02ecef5ce437a302c67f95468de4b31f36e911f467d7e6a52b41c1e13e1d563649";
+
+#[test]
+fn test_cosmos_verify_transaction() {
+    let input = Proto::VerifyingInput {
+        transaction: base64::decode(SIGNED_TX, false).unwrap().into(),
+        public_keys: vec![SIGNER_PUBLIC_KEY.decode_hex().unwrap().into()],
+        chain_id: "cosmoshub-4".into(),
+        account_numbers: vec![546179],
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Cosmos, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+    assert!(output.valid);
+
+    assert_eq!(output.signatures.len(), 1);
+    let signature = &output.signatures[0];
+    assert!(signature.valid);
+    assert_eq!(signature.public_key.to_hex(), SIGNER_PUBLIC_KEY);
+    assert_eq!(
+        signature.signature.to_hex(),
+        "afbd513a776f4fdf470ef7f9675f21ae9d630fc4d635d8dbaa0dc0a716434cd07e02510765d4673dfa880825bae8e67cb367396ff6b976fc6b19a31fc95e8097"
+    );
+}
+
+#[test]
+fn test_cosmos_verify_transaction_wrong_account_number() {
+    let input = Proto::VerifyingInput {
+        transaction: base64::decode(SIGNED_TX, false).unwrap().into(),
+        chain_id: "cosmoshub-4".into(),
+        account_numbers: vec![546180],
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Cosmos, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(!output.valid);
+    assert_eq!(output.signatures.len(), 1);
+    assert!(!output.signatures[0].valid);
+}
+
+#[test]
+fn test_cosmos_verify_transaction_missing_account_number() {
+    let input = Proto::VerifyingInput {
+        transaction: base64::decode(SIGNED_TX, false).unwrap().into(),
+        chain_id: "cosmoshub-4".into(),
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Cosmos, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+    assert!(!output.valid);
+}
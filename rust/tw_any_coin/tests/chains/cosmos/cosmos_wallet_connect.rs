@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde_json::json;
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_any_coin::test_utils::wallet_connect_utils::WalletConnectRequestHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Cosmos::Proto;
+use tw_proto::Cosmos::Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+
+const SIGNER_ADDRESS: &str = "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02";
+const PRIVATE_KEY: &str = "80e81ea269e66a0a05b11236df7919fb7fbeedba87452d667489d7403a02f005";
+
+fn wc_input(method: WCProto::Method, payload: String) -> WCProto::ParseRequestInput<'static> {
+    WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method,
+        payload: payload.into(),
+    }
+}
+
+#[test]
+fn test_cosmos_wallet_connect_sign_amino() {
+    let payload = json!({
+        "signerAddress": SIGNER_ADDRESS,
+        "signDoc": {
+            "chain_id": "gaia-13003",
+            "account_number": "1037",
+            "sequence": "8",
+            "fee": {
+                "amount": [{ "denom": "muon", "amount": "200" }],
+                "gas": "200000"
+            },
+            "msgs": [{ "type": "test", "value": { "test": "hello" } }],
+            "memo": ""
+        }
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Cosmos,
+        &wc_input(WCProto::Method::CosmosSignAmino, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::OK);
+
+    let mut signing_input = match output.signing_input_oneof {
+        SigningInputEnum::cosmos(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_input.signing_mode, Proto::SigningMode::JSON);
+    assert_eq!(signing_input.account_number, 1037);
+    assert_eq!(signing_input.sequence, 8);
+    match signing_input.messages[0].message_oneof {
+        MessageEnum::raw_json_message(ref raw) => {
+            assert_eq!(raw.type_pb, "test");
+            assert_eq!(raw.value, r#"{"test":"hello"}"#);
+        },
+        _ => unreachable!(),
+    }
+
+    // Set missing private key.
+    signing_input.private_key = PRIVATE_KEY.decode_hex().unwrap().into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let signing_output = signer.sign(CoinType::Cosmos, signing_input);
+
+    assert_eq!(signing_output.error, SigningError::OK);
+    assert_eq!(signing_output.signature.to_hex(), "aa1c7108e3225613fb7bb331fbdf07519234b790cd3855f0cc8a8d433f9f4fa843291fde6d6d2ea1cb189c4e428813446a598172ce6048825e80d907860c8498");
+}
+
+#[test]
+fn test_cosmos_wallet_connect_sign_direct() {
+    let payload = json!({
+        "signerAddress": SIGNER_ADDRESS,
+        "signDoc": {
+            "chainId": "gaia-13003",
+            "accountNumber": "1037",
+            "bodyBytes": "CokBChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEmkKLWNvc21vczFoc2s2anJ5eXFqZmhwNWRoYzU1dGM5anRja3lneDBlcGg2ZGQwMhItY29zbW9zMXp0NTBhenVwYW5xbGZhbTVhZmh2M2hleHd5dXRudWtlaDRjNTczGgkKBG11b24SATE=",
+            "authInfoBytes": "ClAKRgofL2Nvc21vcy5jcnlwdG8uc2VjcDI1NmsxLlB1YktleRIjCiECVyhuw/N9M1V7u6oACyd0SskCOqmWfK51oYHR/5H6ncUSBAoCCAEYCBIRCgsKBG11b24SAzIwMBDAmgw="
+        }
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Cosmos,
+        &wc_input(WCProto::Method::CosmosSignDirect, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::OK);
+
+    let mut signing_input = match output.signing_input_oneof {
+        SigningInputEnum::cosmos(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_input.signing_mode, Proto::SigningMode::Protobuf);
+    assert_eq!(signing_input.chain_id, "gaia-13003");
+
+    // Set missing private key.
+    signing_input.private_key = PRIVATE_KEY.decode_hex().unwrap().into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let signing_output = signer.sign(CoinType::Cosmos, signing_input);
+
+    // real-world tx: https://www.mintscan.io/cosmos/txs/817101F3D96314AD028733248B28BAFAD535024D7D2C8875D3FE31DC159F096B
+    assert_eq!(signing_output.error, SigningError::OK);
+    assert_eq!(signing_output.signature.to_hex(), "f9e1f4001657a42009c4eb6859625d2e41e961fc72efd2842909c898e439fc1f549916e4ecac676ee353c7d54c5ae30a29b4210b8bff0ebfdcb375e105002f47");
+}
+
+#[test]
+fn test_cosmos_wallet_connect_sign_amino_fee_granter() {
+    let payload = json!({
+        "signerAddress": SIGNER_ADDRESS,
+        "signDoc": {
+            "chain_id": "gaia-13003",
+            "account_number": "1037",
+            "sequence": "8",
+            "fee": {
+                "amount": [{ "denom": "muon", "amount": "200" }],
+                "gas": "200000",
+                "granter": "cosmos18s0hdnsllgcclweu9aymw4ngktr2k0rkygdzdp"
+            },
+            "msgs": [{ "type": "test", "value": { "test": "hello" } }],
+            "memo": ""
+        }
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Cosmos,
+        &wc_input(WCProto::Method::CosmosSignAmino, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::Error_not_supported);
+}
+
+#[test]
+fn test_cosmos_wallet_connect_invalid_signer_address() {
+    // Osmosis address is not valid for Cosmos Hub.
+    let payload = json!({
+        "signerAddress": "osmo1hsk6jryyqjfhp5dhc55tc9jtckygx0eplp7aec",
+        "signDoc": {
+            "chainId": "gaia-13003",
+            "accountNumber": "1037",
+            "bodyBytes": "",
+            "authInfoBytes": ""
+        }
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Cosmos,
+        &wc_input(WCProto::Method::CosmosSignDirect, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::Error_invalid_address);
+}
+
+#[test]
+fn test_cosmos_wallet_connect_unknown_method() {
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Cosmos,
+        &wc_input(WCProto::Method::EthSignTransaction, "[]".to_string()),
+    );
+    assert_eq!(output.error, SigningError::Error_not_supported);
+}
@@ -3,4 +3,10 @@
 // Copyright © 2017 Trust Wallet.
 
 mod cosmos_address;
+mod cosmos_decode;
+mod cosmos_message_sign;
 mod cosmos_sign;
+mod cosmos_transaction_hash;
+mod cosmos_transaction_summary;
+mod cosmos_transaction_verify;
+mod cosmos_wallet_connect;
@@ -3,12 +3,18 @@
 // Copyright © 2017 Trust Wallet.
 
 use std::borrow::Cow;
-use tw_any_coin::ffi::tw_any_signer::tw_any_signer_sign;
+use tw_any_coin::ffi::tw_any_signer::{
+    tw_any_signer_sign, tw_any_signer_sign_batch, tw_any_signer_sign_json,
+    tw_any_signer_supports_json,
+};
 use tw_coin_entry::error::prelude::*;
 use tw_coin_registry::coin_type::CoinType;
 use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::test_utils::tw_data_vector_helper::TWDataVectorHelper;
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
 use tw_number::U256;
+use tw_proto::json::json_to_message_data;
 use tw_proto::{deserialize, serialize};
 
 #[test]
@@ -52,3 +58,145 @@ fn test_any_signer_sign_eth() {
     let expected = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
     assert_eq!(output.encoded.to_hex(), expected);
 }
+
+#[test]
+fn test_any_signer_sign_eth_eip2930() {
+    use tw_proto::Ethereum::Proto;
+
+    let private = "0x4646464646464646464646464646464646464646464646464646464646464646"
+        .decode_hex()
+        .unwrap();
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        data: Cow::default(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(9),
+        tx_mode: Proto::TransactionMode::Eip2930,
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(21_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output = TWDataHelper::wrap(unsafe {
+        tw_any_signer_sign(input_data.ptr(), CoinType::Ethereum as u32)
+    })
+    .to_vec()
+    .expect("!tw_any_signer_sign returned nullptr");
+
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "01f86e01098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080c001a0a55f6a8616e3d6d2f09ee945cdad2a21a7d357225fcfc46df9e8421420954f43a01d7bd8c3892f4271f397c91e6c4216fb4284bc75c8d320b952bdb069aaa8120e";
+    assert_eq!(output.encoded.to_hex(), expected);
+    assert_eq!(output.v.to_hex(), "01");
+}
+
+#[test]
+fn test_any_signer_sign_batch_eth() {
+    use tw_proto::BatchSigner::Proto as BatchProto;
+    use tw_proto::Ethereum::Proto;
+
+    let private = "0x4646464646464646464646464646464646464646464646464646464646464646"
+        .decode_hex()
+        .unwrap();
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        data: Cow::default(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(9),
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(21_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+    let input_data = serialize(&input).unwrap();
+
+    // The second input is not a valid `SigningInput`.
+    let inputs = TWDataVectorHelper::create([input_data.clone(), vec![0xFF], input_data]);
+
+    let output = TWDataHelper::wrap(unsafe {
+        tw_any_signer_sign_batch(inputs.ptr(), CoinType::Ethereum as u32)
+    })
+    .to_vec()
+    .expect("!tw_any_signer_sign_batch returned nullptr");
+
+    let output: BatchProto::BatchSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.results.len(), 3);
+
+    let expected = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+    for idx in [0, 2] {
+        let result = &output.results[idx];
+        assert_eq!(result.error, SigningErrorType::OK);
+
+        let signed: Proto::SigningOutput = deserialize(&result.output).unwrap();
+        assert_eq!(signed.error, SigningErrorType::OK);
+        assert_eq!(signed.encoded.to_hex(), expected);
+    }
+
+    let invalid = &output.results[1];
+    assert_eq!(invalid.error, SigningErrorType::Error_input_parse);
+    assert!(invalid.output.is_empty());
+}
+
+#[test]
+fn test_any_signer_sign_json_eth() {
+    use tw_proto::Ethereum::Proto;
+
+    assert!(unsafe { tw_any_signer_supports_json(CoinType::Ethereum as u32) });
+
+    let private = "0x4646464646464646464646464646464646464646464646464646464646464646"
+        .decode_hex()
+        .unwrap();
+    let input_json = r#"{
+        "chainId": "AQ==",
+        "nonce": "CQ==",
+        "gasPrice": "BKgXyAA=",
+        "gasLimit": "Ugg=",
+        "toAddress": "0x3535353535353535353535353535353535353535",
+        "transaction": {
+            "transfer": {
+                "amount": "DeC2s6dkAAA="
+            }
+        }
+    }"#;
+
+    let input_json = TWStringHelper::create(input_json);
+    let private = TWDataHelper::create(private);
+
+    let output_json = TWStringHelper::wrap(unsafe {
+        tw_any_signer_sign_json(input_json.ptr(), private.ptr(), CoinType::Ethereum as u32)
+    })
+    .to_string()
+    .expect("!tw_any_signer_sign_json returned nullptr");
+
+    let output_json: serde_json::Value = serde_json::from_str(&output_json).unwrap();
+    let output = json_to_message_data::<Proto::SigningOutput>(&output_json).unwrap();
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+    assert_eq!(output.encoded.to_hex(), expected);
+}
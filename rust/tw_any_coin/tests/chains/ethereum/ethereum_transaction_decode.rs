@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_number::U256;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Ethereum::Proto;
+use tw_proto::Ethereum::Proto::mod_Transaction::OneOftransaction_oneof as TransactionType;
+
+#[test]
+fn test_ethereum_decode_legacy_transaction() {
+    let encoded_tx = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Ethereum, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(output.tx_type, 0);
+    assert_eq!(output.sender, "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+    assert_eq!(output.v.to_hex(), "25");
+    assert_eq!(
+        output.r.to_hex(),
+        "28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276"
+    );
+    assert_eq!(
+        output.s.to_hex(),
+        "67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+    );
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.chain_id, U256::encode_be_compact(1));
+    assert_eq!(tx.nonce, U256::encode_be_compact(9));
+    assert_eq!(tx.tx_mode, Proto::TransactionMode::Legacy);
+    assert_eq!(tx.gas_price, U256::encode_be_compact(20_000_000_000));
+    assert_eq!(tx.gas_limit, U256::encode_be_compact(21_000));
+    assert_eq!(tx.to_address, "0x3535353535353535353535353535353535353535");
+
+    match tx.transaction.unwrap().transaction_oneof {
+        TransactionType::transfer(transfer) => {
+            assert_eq!(
+                transfer.amount,
+                U256::encode_be_compact(1_000_000_000_000_000_000)
+            );
+            assert!(transfer.data.is_empty());
+        },
+        other => panic!("Expected a transfer, found: {other:?}"),
+    }
+}
+
+#[test]
+fn test_ethereum_decode_eip2930_transaction() {
+    // Signed in `test_any_signer_sign_eth_eip2930`.
+    let encoded_tx = "01f86e01098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080c001a0a55f6a8616e3d6d2f09ee945cdad2a21a7d357225fcfc46df9e8421420954f43a01d7bd8c3892f4271f397c91e6c4216fb4284bc75c8d320b952bdb069aaa8120e"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Ethereum, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(output.tx_type, 1);
+    assert!(output.access_list.is_empty());
+    assert_eq!(output.sender, "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+    assert_eq!(output.v.to_hex(), "01");
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.chain_id, U256::encode_be_compact(1));
+    assert_eq!(tx.nonce, U256::encode_be_compact(9));
+    assert_eq!(tx.tx_mode, Proto::TransactionMode::Eip2930);
+    assert_eq!(tx.gas_price, U256::encode_be_compact(20_000_000_000));
+    assert_eq!(tx.gas_limit, U256::encode_be_compact(21_000));
+    assert_eq!(tx.to_address, "0x3535353535353535353535353535353535353535");
+
+    match tx.transaction.unwrap().transaction_oneof {
+        TransactionType::transfer(transfer) => {
+            assert_eq!(
+                transfer.amount,
+                U256::encode_be_compact(1_000_000_000_000_000_000)
+            );
+            assert!(transfer.data.is_empty());
+        },
+        other => panic!("Expected a transfer, found: {other:?}"),
+    }
+}
+
+#[test]
+fn test_ethereum_decode_eip1559_erc20_transfer() {
+    let encoded_tx = "02f8b00180847735940084b2d05e00830130b9946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000001bc16d674ec80000c080a0adfcfdf98d4ed35a8967a0c1d78b42adb7c5d831cf5a3272654ec8f8bcd7be2ea011641e065684f6aa476f4fd250aa46cd0b44eccdb0a6e1650d658d1998684cdf"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Ethereum, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(output.tx_type, 2);
+    assert!(output.access_list.is_empty());
+    assert_eq!(output.sender, "0xAa9d131E33158bE2Cb1603b61fD17aD4f9E178ce");
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.chain_id, U256::encode_be_compact(1));
+    assert_eq!(tx.nonce, U256::encode_be_compact(0));
+    assert_eq!(tx.tx_mode, Proto::TransactionMode::Enveloped);
+    assert_eq!(
+        tx.max_inclusion_fee_per_gas,
+        U256::encode_be_compact(2_000_000_000)
+    );
+    assert_eq!(tx.max_fee_per_gas, U256::encode_be_compact(3_000_000_000));
+    assert_eq!(tx.gas_limit, U256::encode_be_compact(78_009));
+    // DAI
+    assert_eq!(tx.to_address, "0x6B175474E89094C44Da98b954EedeAC495271d0F");
+
+    match tx.transaction.unwrap().transaction_oneof {
+        TransactionType::erc20_transfer(transfer) => {
+            assert_eq!(transfer.to, "0x5322B34c88Ed0691971Bf52A7047448f0F4eFC84");
+            assert_eq!(
+                transfer.amount,
+                U256::encode_be_compact(2_000_000_000_000_000_000)
+            );
+        },
+        other => panic!("Expected an ERC20 transfer, found: {other:?}"),
+    }
+}
+
+#[test]
+fn test_ethereum_decode_transaction_error() {
+    // Unsupported transaction type.
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Ethereum, vec![0x03, 0xc0]);
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(output.transaction.is_none());
+
+    // Trailing bytes after the legacy transaction list.
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Ethereum, "c00102".decode_hex().unwrap());
+    assert_eq!(output.error, SigningError::Error_input_parse);
+}
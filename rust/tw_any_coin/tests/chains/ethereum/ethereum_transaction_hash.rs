@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_hash_utils::tx_id;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+
+/// https://eips.ethereum.org/EIPS/eip-155#example
+const SIGNED_LEGACY_TX: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+const UNSIGNED_LEGACY_TX: &str =
+    "ec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080";
+
+#[test]
+fn test_ethereum_transaction_hash() {
+    let signed_tx = SIGNED_LEGACY_TX.decode_hex().unwrap();
+    assert_eq!(
+        tx_id(CoinType::Ethereum, &signed_tx).unwrap(),
+        "0x33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788"
+    );
+}
+
+#[test]
+fn test_ethereum_transaction_hash_unsigned() {
+    let unsigned_tx = UNSIGNED_LEGACY_TX.decode_hex().unwrap();
+    assert_eq!(tx_id(CoinType::Ethereum, &unsigned_tx), None);
+}
@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_any_coin::ffi::tw_transaction_summarizer::tw_transaction_summarizer_summarize;
+use tw_any_coin::test_utils::transaction_summary_utils::TransactionSummarizerHelper;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_number::U256;
+use tw_proto::serialize;
+use tw_proto::Ethereum::Proto;
+use tw_proto::TxSummary::Proto as SummaryProto;
+
+#[test]
+fn test_ethereum_transaction_summary_transfer() {
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        data: Cow::default(),
+    };
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(9),
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(21_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut summarizer = TransactionSummarizerHelper::default();
+    let summary = summarizer.summarize(CoinType::Ethereum, &input);
+    assert_eq!(summary.error, SigningErrorType::OK);
+    assert!(summary.warnings.is_empty());
+
+    assert_eq!(summary.actions.len(), 1);
+    let action = &summary.actions[0];
+    assert_eq!(action.type_pb, SummaryProto::ActionType::Transfer);
+    assert_eq!(action.asset.as_ref().unwrap().symbol, "ETH");
+    assert_eq!(action.amount.as_ref().unwrap().formatted, "1");
+    assert_eq!(
+        action.recipient,
+        "0x3535353535353535353535353535353535353535"
+    );
+    assert_eq!(
+        action.description,
+        "Send 1 ETH to 0x3535353535353535353535353535353535353535"
+    );
+
+    let fee = summary.fee.as_ref().unwrap();
+    assert_eq!(fee.amount.as_ref().unwrap().value, "420000000000000");
+    assert_eq!(fee.amount.as_ref().unwrap().formatted, "0.00042");
+}
+
+#[test]
+fn test_ethereum_transaction_summary_unlimited_approval() {
+    let approve = Proto::mod_Transaction::ERC20Approve {
+        spender: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        amount: U256::MAX.to_big_endian_compact().into(),
+    };
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: Proto::TransactionMode::Enveloped,
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(10_000_000_000),
+        gas_limit: U256::encode_be_compact(60_000),
+        to_address: "0xdAC17F958D2ee523a2206206994597C13D831ec7".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::erc20_approve(
+                approve,
+            ),
+        }),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut summarizer = TransactionSummarizerHelper::default();
+    let summary = summarizer.summarize(CoinType::Ethereum, &input);
+    assert_eq!(summary.error, SigningErrorType::OK);
+
+    assert_eq!(summary.actions.len(), 1);
+    let action = &summary.actions[0];
+    assert_eq!(action.type_pb, SummaryProto::ActionType::TokenApproval);
+    assert_eq!(
+        action.asset.as_ref().unwrap().token,
+        "0xdAC17F958D2ee523a2206206994597C13D831ec7"
+    );
+    assert_eq!(
+        action.recipient,
+        "0x5322b34c88ed0691971bf52a7047448f0f4efc84"
+    );
+
+    assert_eq!(summary.warnings.len(), 1);
+    assert_eq!(
+        summary.warnings[0].type_pb,
+        SummaryProto::WarningType::UnlimitedApproval
+    );
+
+    let fee = summary.fee.as_ref().unwrap();
+    assert_eq!(fee.amount.as_ref().unwrap().formatted, "0.0006");
+}
+
+#[test]
+fn test_ethereum_transaction_summary_known_token() {
+    let transfer = Proto::mod_Transaction::ERC20Transfer {
+        to: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        amount: U256::encode_be_compact(2_500_000),
+    };
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(60_000),
+        to_address: "0xdAC17F958D2ee523a2206206994597C13D831ec7".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::erc20_transfer(
+                transfer,
+            ),
+        }),
+        ..Proto::SigningInput::default()
+    };
+    // The contract address is matched case-insensitively.
+    let tokens = SummaryProto::TokenList {
+        tokens: vec![SummaryProto::TokenInfo {
+            token: "0xdac17f958d2ee523a2206206994597c13d831ec7".into(),
+            symbol: "USDT".into(),
+            decimals: 6,
+        }],
+    };
+
+    let mut summarizer = TransactionSummarizerHelper::default();
+    let summary = summarizer.summarize_with_tokens(CoinType::Ethereum, &input, &tokens);
+    assert_eq!(summary.error, SigningErrorType::OK);
+
+    assert_eq!(summary.actions.len(), 1);
+    let action = &summary.actions[0];
+    assert_eq!(action.type_pb, SummaryProto::ActionType::TokenTransfer);
+    assert_eq!(action.asset.as_ref().unwrap().symbol, "USDT");
+    assert_eq!(action.amount.as_ref().unwrap().formatted, "2.5");
+    assert_eq!(
+        action.description,
+        "Send 2.5 USDT to 0x5322b34c88ed0691971bf52a7047448f0f4efc84"
+    );
+}
+
+#[test]
+fn test_ethereum_transaction_summary_invalid_token_decimals() {
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        ..Proto::SigningInput::default()
+    };
+    let tokens = SummaryProto::TokenList {
+        tokens: vec![SummaryProto::TokenInfo {
+            token: "0xdAC17F958D2ee523a2206206994597C13D831ec7".into(),
+            symbol: "USDT".into(),
+            decimals: 256,
+        }],
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let tokens_data = TWDataHelper::create(serialize(&tokens).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_transaction_summarizer_summarize(
+            CoinType::Ethereum as u32,
+            input_data.ptr(),
+            tokens_data.ptr(),
+        )
+    });
+    assert!(output.is_null());
+}
+
+#[test]
+fn test_ethereum_transaction_summary_ambiguous_transfer_from() {
+    // `transferFrom(0x5322b34c88ed0691971bf52a7047448f0f4efc84, 0x3535353535353535353535353535353535353535, 1000)`
+    // may be either an ERC-20 or an ERC-721 call.
+    let contract_generic = Proto::mod_Transaction::ContractGeneric {
+        amount: Cow::default(),
+        data: "23b872dd0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc84000000000000000000000000353535353535353535353535353535353535353500000000000000000000000000000000000000000000000000000000000003e8"
+            .decode_hex()
+            .unwrap()
+            .into(),
+    };
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(80_000),
+        to_address: "0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::contract_generic(
+                contract_generic,
+            ),
+        }),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut summarizer = TransactionSummarizerHelper::default();
+    let summary = summarizer.summarize(CoinType::Ethereum, &input);
+    assert_eq!(summary.error, SigningErrorType::OK);
+
+    assert_eq!(summary.actions.len(), 1);
+    let action = &summary.actions[0];
+    assert_eq!(action.type_pb, SummaryProto::ActionType::ContractCall);
+    assert_eq!(
+        action.recipient,
+        "0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D"
+    );
+
+    assert_eq!(summary.warnings.len(), 1);
+    assert_eq!(
+        summary.warnings[0].type_pb,
+        SummaryProto::WarningType::AmbiguousContractCall
+    );
+}
+
+#[test]
+fn test_ethereum_transaction_summary_no_transaction() {
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut summarizer = TransactionSummarizerHelper::default();
+    let summary = summarizer.summarize(CoinType::Ethereum, &input);
+    assert_eq!(summary.error, SigningErrorType::Error_invalid_params);
+}
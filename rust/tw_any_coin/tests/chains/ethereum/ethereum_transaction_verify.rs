@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_verify_utils::TransactionVerifierHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TxVerifier::Proto;
+
+/// https://eips.ethereum.org/EIPS/eip-155#example
+const SIGNED_LEGACY_TX: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+const SIGNER_PUBLIC_KEY: &str = "044bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382ce28cab79ad7119ee1ad3ebcdb98a16805211530ecc6cfefa1b88e6dff99232a";
+
+#[test]
+fn test_ethereum_verify_legacy_transaction() {
+    let input = Proto::VerifyingInput {
+        transaction: SIGNED_LEGACY_TX.decode_hex().unwrap().into(),
+        // Compressed public key is also allowed.
+        public_keys: vec![
+            "024bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382"
+                .decode_hex()
+                .unwrap()
+                .into(),
+        ],
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Ethereum, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+    assert!(output.valid);
+
+    assert_eq!(output.signatures.len(), 1);
+    let signature = &output.signatures[0];
+    assert!(signature.valid);
+    assert_eq!(signature.public_key.to_hex(), SIGNER_PUBLIC_KEY);
+    assert_eq!(
+        signature.signature.to_hex(),
+        "28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa63627667cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d8300"
+    );
+}
+
+#[test]
+fn test_ethereum_verify_transaction_unexpected_signer() {
+    let input = Proto::VerifyingInput {
+        transaction: SIGNED_LEGACY_TX.decode_hex().unwrap().into(),
+        public_keys: vec![
+            "038e97ca50d3aa7b8c051dd4d55d9bca7e1636bfc8d4d3af88003723974351e256"
+                .decode_hex()
+                .unwrap()
+                .into(),
+        ],
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Ethereum, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(!output.valid);
+    assert_eq!(output.signatures.len(), 1);
+    assert!(!output.signatures[0].valid);
+    assert_eq!(output.signatures[0].public_key.to_hex(), SIGNER_PUBLIC_KEY);
+}
+
+#[test]
+fn test_ethereum_verify_unsigned_transaction() {
+    // EIP-155 unsigned transaction with `[chainId, 0, 0]` instead of the signature.
+    let input = Proto::VerifyingInput {
+        transaction: "ec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        public_keys: vec![SIGNER_PUBLIC_KEY.decode_hex().unwrap().into()],
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Ethereum, input);
+
+    assert_eq!(output.error, SigningError::Error_signing);
+    assert!(!output.valid);
+    assert!(output.signatures.is_empty());
+}
+
+#[test]
+fn test_ethereum_verify_transaction_no_expected_public_keys() {
+    let input = Proto::VerifyingInput {
+        transaction: SIGNED_LEGACY_TX.decode_hex().unwrap().into(),
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Ethereum, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+    assert!(!output.valid);
+    assert!(output.signatures.is_empty());
+}
@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde_json::json;
+use tw_any_coin::ffi::tw_message_signer::tw_message_signer_sign;
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_any_coin::test_utils::wallet_connect_utils::WalletConnectRequestHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_number::U256;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Ethereum::Proto;
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+use tw_proto::{deserialize, serialize};
+
+const SIGNER_ADDRESS: &str = "0x9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f";
+
+fn wc_input(method: WCProto::Method, payload: String) -> WCProto::ParseRequestInput<'static> {
+    WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method,
+        payload: payload.into(),
+    }
+}
+
+#[test]
+fn test_ethereum_wallet_connect_sign_transaction_legacy() {
+    let payload = json!([{
+        "from": SIGNER_ADDRESS,
+        "to": "0x3535353535353535353535353535353535353535",
+        "gas": "0x5208",
+        "gasPrice": "0x4a817c800",
+        "value": "0xde0b6b3a7640000",
+        "nonce": "0x9",
+        "chainId": "0x1",
+    }]);
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Ethereum,
+        &wc_input(WCProto::Method::EthSignTransaction, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::OK);
+
+    let mut signing_input = match output.signing_input_oneof {
+        SigningInputEnum::ethereum(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_input.tx_mode, Proto::TransactionMode::Legacy);
+    assert_eq!(
+        signing_input.to_address,
+        "0x3535353535353535353535353535353535353535"
+    );
+
+    // Set missing private key.
+    signing_input.private_key = "4646464646464646464646464646464646464646464646464646464646464646"
+        .decode_hex()
+        .unwrap()
+        .into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let signing_output = signer.sign(CoinType::Ethereum, signing_input);
+
+    assert_eq!(signing_output.error, SigningError::OK);
+    assert_eq!(signing_output.encoded.to_hex(), "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83");
+}
+
+#[test]
+fn test_ethereum_wallet_connect_send_transaction_eip1559_contract_call() {
+    let payload = json!([{
+        "from": SIGNER_ADDRESS,
+        "to": "0x6b175474e89094c44da98b954eedeac495271d0f",
+        "data": "0x095ea7b3000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        "gas": "0xb71b",
+        "maxFeePerGas": "0x12a05f200",
+        "maxPriorityFeePerGas": "0x3b9aca00",
+    }]);
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Ethereum,
+        &wc_input(WCProto::Method::EthSendTransaction, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::OK);
+
+    let signing_input = match output.signing_input_oneof {
+        SigningInputEnum::ethereum(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_input.tx_mode, Proto::TransactionMode::Enveloped);
+    // Chain ID is taken from the coin, nonce must be completed by the app.
+    assert_eq!(signing_input.chain_id, U256::encode_be_compact(1));
+    assert!(signing_input.nonce.is_empty());
+    assert!(signing_input.gas_price.is_empty());
+    assert_eq!(signing_input.gas_limit, U256::encode_be_compact(46_875));
+    assert_eq!(
+        signing_input.max_fee_per_gas,
+        U256::encode_be_compact(5_000_000_000)
+    );
+    assert_eq!(
+        signing_input.max_inclusion_fee_per_gas,
+        U256::encode_be_compact(1_000_000_000)
+    );
+
+    // `approve(address,uint256)` call is recognized.
+    match signing_input.transaction.unwrap().transaction_oneof {
+        Proto::mod_Transaction::OneOftransaction_oneof::erc20_approve(approve) => {
+            assert_eq!(
+                approve.spender,
+                "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+            );
+            assert_eq!(approve.amount.to_hex(), "ff".repeat(32));
+        },
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_ethereum_wallet_connect_sign_transaction_invalid() {
+    // Params must be an array.
+    let payload = json!({ "to": "0x3535353535353535353535353535353535353535" });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Ethereum,
+        &wc_input(WCProto::Method::EthSignTransaction, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::Error_input_parse);
+
+    let payload = json!([{ "to": "0x3535353535353535353535353535353535353535", "value": "0xZZ" }]);
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Ethereum,
+        &wc_input(WCProto::Method::EthSignTransaction, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::Error_input_parse);
+}
+
+#[test]
+fn test_ethereum_wallet_connect_personal_sign() {
+    // "Foo" hex-encoded.
+    let payload = json!(["0x466f6f", SIGNER_ADDRESS]);
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Ethereum,
+        &wc_input(WCProto::Method::PersonalSign, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::OK);
+
+    let mut signing_input = match output.signing_input_oneof {
+        SigningInputEnum::ethereum_message(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        signing_input.message_type,
+        Proto::MessageType::MessageType_legacy
+    );
+
+    // Set missing private key.
+    signing_input.private_key = "03a9ca895dca1623c7dfd69693f7b4111f5d819d2e145536e0b03c136025a25d"
+        .decode_hex()
+        .unwrap()
+        .into();
+
+    let input_data = TWDataHelper::create(serialize(&signing_input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(input_data.ptr(), CoinType::Ethereum as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningError::OK);
+    // Same as signing "Foo" string.
+    assert_eq!(output.signature, "21a779d499957e7fd39392d49a079679009e60e492d9654a148829be43d2490736ec72bc4a5644047d979c3cf4ebe2c1c514044cf436b063cb89fc6676be71101b");
+}
+
+#[test]
+fn test_ethereum_wallet_connect_sign_typed_data_v4() {
+    let typed_data = r#"{"types":{"EIP712Domain":[{"name":"name","type":"string"}],"Mail":[{"name":"contents","type":"string"}]},"primaryType":"Mail","domain":{"name":"Ether Mail"},"message":{"contents":"Hello, Bob!"}}"#;
+
+    // Typed data as a JSON-encoded string.
+    let payload = json!([SIGNER_ADDRESS, typed_data]);
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Ethereum,
+        &wc_input(WCProto::Method::EthSignTypedDataV4, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::OK);
+    match output.signing_input_oneof {
+        SigningInputEnum::ethereum_message(input) => {
+            assert_eq!(input.message_type, Proto::MessageType::MessageType_typed);
+            assert_eq!(input.message, typed_data);
+        },
+        _ => unreachable!(),
+    }
+
+    // Typed data as a JSON object.
+    let typed_data_json: serde_json::Value = serde_json::from_str(typed_data).unwrap();
+    let payload = json!([SIGNER_ADDRESS, typed_data_json]);
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Ethereum,
+        &wc_input(WCProto::Method::EthSignTypedDataV4, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::OK);
+    match output.signing_input_oneof {
+        SigningInputEnum::ethereum_message(input) => {
+            let actual: serde_json::Value = serde_json::from_str(&input.message).unwrap();
+            assert_eq!(actual, typed_data_json);
+        },
+        _ => unreachable!(),
+    }
+
+    // Invalid signer address.
+    let payload = json!(["0x123", typed_data]);
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Ethereum,
+        &wc_input(WCProto::Method::EthSignTypedDataV4, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::Error_invalid_address);
+}
+
+#[test]
+fn test_ethereum_wallet_connect_unknown_method() {
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Ethereum,
+        &wc_input(WCProto::Method::SolanaSignTransaction, "[]".to_string()),
+    );
+    assert_eq!(output.error, SigningError::Error_not_supported);
+}
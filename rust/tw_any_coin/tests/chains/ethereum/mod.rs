@@ -6,3 +6,8 @@ mod ethereum_address;
 mod ethereum_compile;
 mod ethereum_message_sign;
 mod ethereum_sign;
+mod ethereum_transaction_decode;
+mod ethereum_transaction_hash;
+mod ethereum_transaction_summary;
+mod ethereum_transaction_verify;
+mod ethereum_wallet_connect;
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::greenfield::PUBLIC_KEY_15560;
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64;
+use tw_encoding::hex::ToHex;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Greenfield::Proto;
+use tw_proto::Greenfield::Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+
+const SENDER: &str = "0xF1DB7D5256d721fE3C144F5c1ed4b3A3A94Dc444";
+const SENDER_PUBLIC_KEY: &str =
+    "0309bb9f42211f07467a0db1f70504e3bf3fc38d359b8b388f8bbd365acd816c96";
+const SENDER_SIGNATURE: &str = "f90735852cda8311d3f65ab985eff0d77ff5b6eb438c37db73f81a9c843b48fc21a74c544f1392623a948c781ff84f49ba46f7046baa16597f34eca92bf9ac381b";
+
+#[test]
+fn test_greenfield_decode_send_and_transfer_out() {
+    // Signed in `test_greenfield_sign_multiple_messages_e3539e`.
+    let encoded_tx = base64::decode("CqsCCpABChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEnAKKjB4RjFEQjdENTI1NmQ3MjFmRTNDMTQ0RjVjMWVkNGIzQTNBOTREYzQ0NBIqMHg5ZDFkOTdhREZjZDMyNEJiZDYwM0QzODcyQkQ3OGUwNDA5ODUxMGIxGhYKA0JOQhIPMTAwMDAwMDAwMDAwMDAwCpUBCiEvZ3JlZW5maWVsZC5icmlkZ2UuTXNnVHJhbnNmZXJPdXQScAoqMHhGMURCN0Q1MjU2ZDcyMWZFM0MxNDRGNWMxZWQ0YjNBM0E5NERjNDQ0EioweEYxREI3RDUyNTZkNzIxZkUzQzE0NEY1YzFlZDRiM0EzQTk0RGM0NDQaFgoDQk5CEg8yMDAwMDAwMDAwMDAwMDASdgpYCk0KJi9jb3Ntb3MuY3J5cHRvLmV0aC5ldGhzZWNwMjU2azEuUHViS2V5EiMKIQMJu59CIR8HRnoNsfcFBOO/P8ONNZuLOI+LvTZazYFslhIFCgMIyAUYAhIaChUKA0JOQhIOMTUwMDAwMDAwMDAwMDAQuBcaQfkHNYUs2oMR0/ZauYXv8Nd/9bbrQ4w323P4GpyEO0j8IadMVE8TkmI6lIx4H/hPSbpG9wRrqhZZfzTsqSv5rDgb", false).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Greenfield, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(output.signatures.len(), 1);
+    assert_eq!(output.signatures[0].to_hex(), SENDER_SIGNATURE);
+
+    assert_eq!(output.signers.len(), 1);
+    assert_eq!(output.signers[0].public_key.to_hex(), SENDER_PUBLIC_KEY);
+    assert_eq!(output.signers[0].sequence, 2);
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.encoding_mode, Proto::EncodingMode::Protobuf);
+    assert_eq!(tx.signing_mode, Proto::SigningMode::Eip712);
+    assert_eq!(tx.sequence, 2);
+    assert_eq!(tx.public_key.to_hex(), SENDER_PUBLIC_KEY);
+    assert!(tx.memo.is_empty());
+
+    let fee = tx.fee.unwrap();
+    assert_eq!(fee.gas, 3000);
+    assert_eq!(fee.amounts.len(), 1);
+    assert_eq!(fee.amounts[0].denom, "BNB");
+    assert_eq!(fee.amounts[0].amount, "15000000000000");
+
+    assert_eq!(tx.messages.len(), 2);
+    let MessageEnum::send_coins_message(ref send) = tx.messages[0].message_oneof else {
+        panic!("Expected a send message, found: {:?}", tx.messages[0]);
+    };
+    assert_eq!(send.from_address, SENDER);
+    assert_eq!(
+        send.to_address,
+        "0x9d1d97aDFcd324Bbd603D3872BD78e04098510b1"
+    );
+    assert_eq!(send.amounts.len(), 1);
+    assert_eq!(send.amounts[0].denom, "BNB");
+    assert_eq!(send.amounts[0].amount, "100000000000000");
+
+    let MessageEnum::bridge_transfer_out(ref transfer_out) = tx.messages[1].message_oneof else {
+        panic!(
+            "Expected a transfer out message, found: {:?}",
+            tx.messages[1]
+        );
+    };
+    assert_eq!(transfer_out.from_address, SENDER);
+    assert_eq!(transfer_out.to_address, SENDER);
+    let amount = transfer_out.amount.as_ref().unwrap();
+    assert_eq!(amount.denom, "BNB");
+    assert_eq!(amount.amount, "200000000000000");
+}
+
+#[test]
+fn test_greenfield_decode_multiple_signers() {
+    // The transaction above with a second signer info and a dummy signature appended.
+    let encoded_tx = base64::decode("CqsCCpABChwvY29zbW9zLmJhbmsudjFiZXRhMS5Nc2dTZW5kEnAKKjB4RjFEQjdENTI1NmQ3MjFmRTNDMTQ0RjVjMWVkNGIzQTNBOTREYzQ0NBIqMHg5ZDFkOTdhREZjZDMyNEJiZDYwM0QzODcyQkQ3OGUwNDA5ODUxMGIxGhYKA0JOQhIPMTAwMDAwMDAwMDAwMDAwCpUBCiEvZ3JlZW5maWVsZC5icmlkZ2UuTXNnVHJhbnNmZXJPdXQScAoqMHhGMURCN0Q1MjU2ZDcyMWZFM0MxNDRGNWMxZWQ0YjNBM0E5NERjNDQ0EioweEYxREI3RDUyNTZkNzIxZkUzQzE0NEY1YzFlZDRiM0EzQTk0RGM0NDQaFgoDQk5CEg8yMDAwMDAwMDAwMDAwMDAS0AEKWApNCiYvY29zbW9zLmNyeXB0by5ldGguZXRoc2VjcDI1NmsxLlB1YktleRIjCiEDCbufQiEfB0Z6DbH3BQTjvz/DjTWbiziPi702Ws2BbJYSBQoDCMgFGAIKWApNCiYvY29zbW9zLmNyeXB0by5ldGguZXRoc2VjcDI1NmsxLlB1YktleRIjCiECee80Bk2hDbBGPHBIBha6AgcD7DpFAm3ve+vSCC9db8gSBQoDCMgFGAcSGgoVCgNCTkISDjE1MDAwMDAwMDAwMDAwELgXGkH5BzWFLNqDEdP2WrmF7/DXf/W260OMN9tz+BqchDtI/CGnTFRPE5JiOpSMeB/4T0m6RvcEa6oWWX807Kkr+aw4GxpBAAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4vMDEyMzQ1Njc4OTo7PD0+P0A=", false).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Greenfield, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+
+    assert_eq!(output.signatures.len(), 2);
+    assert_eq!(output.signatures[0].to_hex(), SENDER_SIGNATURE);
+
+    assert_eq!(output.signers.len(), 2);
+    assert_eq!(output.signers[0].public_key.to_hex(), SENDER_PUBLIC_KEY);
+    assert_eq!(output.signers[0].sequence, 2);
+    assert_eq!(output.signers[1].public_key.to_hex(), PUBLIC_KEY_15560);
+    assert_eq!(output.signers[1].sequence, 7);
+
+    // The signing input describes the first signer only.
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.sequence, 2);
+    assert_eq!(tx.public_key.to_hex(), SENDER_PUBLIC_KEY);
+    assert_eq!(tx.messages.len(), 2);
+}
@@ -6,6 +6,7 @@ use tw_proto::Greenfield::Proto;
 
 mod greenfield_address;
 mod greenfield_compile;
+mod greenfield_decode;
 mod greenfield_sign;
 
 const PRIVATE_KEY_15560: &str = "9066aa168c379a403becb235c15e7129c133c244e56a757ab07bc369288bcab0";
@@ -3,4 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 mod native_evmos_address;
+mod native_evmos_message_sign;
 mod native_evmos_sign;
+
+const NATIVE_EVMOS_COIN_TYPE: u32 = 20009001;
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::native_evmos::NATIVE_EVMOS_COIN_TYPE;
+use tw_any_coin::ffi::tw_message_signer::{tw_message_signer_sign, tw_message_signer_verify};
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Cosmos::Proto;
+use tw_proto::{deserialize, serialize};
+
+const SIGNER: &str = "evmos1hsk6jryyqjfhp5dhc55tc9jtckygx0ep4mur4z";
+const SIGNATURE: &str = "78376c45250d72117c0ff685b69e5d59b023ecdf4bd2192b29eb78de2780bff879baec436fe04e721dab18243377066710e7f149ac5ed9db930dfd37d27b0c56";
+
+#[test]
+fn test_native_evmos_message_sign_adr036() {
+    let input = Proto::MessageSigningInput {
+        private_key: "80e81ea269e66a0a05b11236df7919fb7fbeedba87452d667489d7403a02f005"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        signer: SIGNER.into(),
+        message: b"Hello, Evmos!".as_slice().into(),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(input_data.ptr(), NATIVE_EVMOS_COIN_TYPE)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    // Ethermint-based chains hash the sign doc with Keccak256.
+    assert_eq!(output.signature.to_hex(), SIGNATURE);
+    assert_eq!(
+        output.signature_json,
+        r#"{"pub_key":{"type":"ethermint/PubKeyEthSecp256k1","value":"AlcobsPzfTNVe7uqAAsndErJAjqplnyudaGB0f+R+p3F"},"signature":"eDdsRSUNchF8D/aFtp5dWbAj7N9L0hkrKet43ieAv/h5uuxDb+BOch2rGCQzdwZnEOfxSaxe2duTDf030nsMVg=="}"#
+    );
+}
+
+#[test]
+fn test_native_evmos_message_verify_adr036() {
+    let input = Proto::MessageVerifyingInput {
+        signer: SIGNER.into(),
+        message: b"Hello, Evmos!".as_slice().into(),
+        public_key: "0257286ec3f37d33557bbbaa000b27744ac9023aa9967cae75a181d1ff91fa9dc5"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        signature: SIGNATURE.decode_hex().unwrap().into(),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    assert!(unsafe { tw_message_signer_verify(input_data.ptr(), NATIVE_EVMOS_COIN_TYPE) });
+}
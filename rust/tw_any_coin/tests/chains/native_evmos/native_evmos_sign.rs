@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::chains::native_evmos::NATIVE_EVMOS_COIN_TYPE;
 use std::borrow::Cow;
 use tw_any_coin::ffi::tw_any_signer::tw_any_signer_sign;
 use tw_coin_entry::error::prelude::*;
@@ -12,8 +13,6 @@ use tw_proto::Cosmos::Proto;
 use tw_proto::Cosmos::Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
 use tw_proto::{deserialize, serialize};
 
-const NATIVE_EVMOS_COIN_TYPE: u32 = 20009001;
-
 fn account_1037_private_key() -> Cow<'static, [u8]> {
     "80e81ea269e66a0a05b11236df7919fb7fbeedba87452d667489d7403a02f005"
         .decode_hex()
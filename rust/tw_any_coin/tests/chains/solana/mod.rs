@@ -4,6 +4,10 @@
 
 mod solana_address;
 mod solana_compile;
+mod solana_message_sign;
 mod solana_sign;
 mod solana_transaction;
+mod solana_transaction_hash;
+mod solana_transaction_summary;
+mod solana_transaction_verify;
 mod solana_wallet_connect;
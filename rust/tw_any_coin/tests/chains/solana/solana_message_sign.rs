@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Solana::Proto::MessageType;
+use tw_proto::{deserialize, serialize, Solana, TxCompiler};
+
+const PRIVATE_KEY: &str = "833a053c59e78138a3ed090459bc6743cca6a9cbc2809a7bf5dbc7939b8775c8";
+/// 6pEfiZjMycJY4VA2FtAbKgYvRwzXDpxY58Xp4b7FQCz9
+const PUBLIC_KEY: &str = "56642fc3ce4403e6d78f8b07714db5afb53e97ac19f9770f24f1516f1c04eab6";
+
+fn sign_message(message: &[u8], message_type: MessageType) -> Solana::Proto::MessageSigningOutput {
+    let input = Solana::Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: message.into(),
+        message_type,
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(input_data.ptr(), CoinType::Solana as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    deserialize(&output).unwrap()
+}
+
+fn verify_message(message: &[u8], signature: &str, message_type: MessageType) -> bool {
+    let input = Solana::Proto::MessageVerifyingInput {
+        message: message.into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: signature.into(),
+        message_type,
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    unsafe { tw_message_signer_verify(input_data.ptr(), CoinType::Solana as u32) }
+}
+
+#[test]
+fn test_solana_message_sign_off_chain() {
+    let output = sign_message(b"Hello, Solana!", MessageType::MessageType_off_chain);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(
+        output.signature,
+        "4dwFq6JkuvKN2qUe5xXUQUtsPpfHnsG5eHAwx85njRtjm4zPFt9LC5q6mRTyPUduGRswfnCC6jKD7Amajab8Zc1t"
+    );
+}
+
+#[test]
+fn test_solana_message_sign_off_chain_utf8() {
+    let output = sign_message("Привет".as_bytes(), MessageType::MessageType_off_chain);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(
+        output.signature,
+        "4VZeViaBZXRBQPdjaVxJ7r3KjBALXsTj5BxLc7EewaDudpBfPKz3it3bBefWxJdt5HJ94jw65X3v9yaeGZpR4biT"
+    );
+}
+
+#[test]
+fn test_solana_message_sign_off_chain_invalid_utf8() {
+    let output = sign_message(&[0xff, 0xfe], MessageType::MessageType_off_chain);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+    assert!(output.signature.is_empty());
+}
+
+#[test]
+fn test_solana_message_sign_raw() {
+    let output = sign_message(b"Hello, Solana!", MessageType::MessageType_raw);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(
+        output.signature,
+        "62s7JLEM64CqtUDxWtXVSFNhkR4PaACitAPrNvhyDDpu6UjqC3f9pcz5opJkDGayihZRCyh2bUMaX8DDtCxx82Wb"
+    );
+}
+
+#[test]
+fn test_solana_message_verify() {
+    assert!(verify_message(
+        b"Hello, Solana!",
+        "4dwFq6JkuvKN2qUe5xXUQUtsPpfHnsG5eHAwx85njRtjm4zPFt9LC5q6mRTyPUduGRswfnCC6jKD7Amajab8Zc1t",
+        MessageType::MessageType_off_chain
+    ));
+    assert!(verify_message(
+        b"Hello, Solana!",
+        "62s7JLEM64CqtUDxWtXVSFNhkR4PaACitAPrNvhyDDpu6UjqC3f9pcz5opJkDGayihZRCyh2bUMaX8DDtCxx82Wb",
+        MessageType::MessageType_raw
+    ));
+}
+
+#[test]
+fn test_solana_message_verify_invalid() {
+    // Off-chain signature verified as a raw message.
+    assert!(!verify_message(
+        b"Hello, Solana!",
+        "4dwFq6JkuvKN2qUe5xXUQUtsPpfHnsG5eHAwx85njRtjm4zPFt9LC5q6mRTyPUduGRswfnCC6jKD7Amajab8Zc1t",
+        MessageType::MessageType_raw
+    ));
+    // Another message.
+    assert!(!verify_message(
+        b"Hello, Solana?",
+        "62s7JLEM64CqtUDxWtXVSFNhkR4PaACitAPrNvhyDDpu6UjqC3f9pcz5opJkDGayihZRCyh2bUMaX8DDtCxx82Wb",
+        MessageType::MessageType_raw
+    ));
+}
+
+#[test]
+fn test_solana_message_pre_image_hashes() {
+    let input = Solana::Proto::MessageSigningInput {
+        private_key: Default::default(),
+        message: b"Hello, Solana!".into(),
+        message_type: MessageType::MessageType_off_chain,
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(input_data.ptr(), CoinType::Solana as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "ff736f6c616e61206f6666636861696e00000e0048656c6c6f2c20536f6c616e6121"
+    );
+}
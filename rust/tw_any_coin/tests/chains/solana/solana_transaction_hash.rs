@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_hash_utils::tx_id;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64;
+
+/// Transaction signed by both the fee payer and the sender.
+const ENCODED_TX: &str = "Ajzc/Tke0CG8Cew5qFa6xZI/7Ya3DN0M8Ige6tKPsGzhg8Bw9DqL18KUrEZZ1F4YqZBo4Rv+FsDT8A7Nss7p4A6BNVZzzGprCJqYQeNg0EVIbmPc6mDitNniHXGeKgPZ6QZbM4FElw9O7IOFTpOBPvQFeqy0vZf/aayncL8EK/UEAgACBssq8Im1alV3N7wXGODL8jLPWwLhTuCqfGZ1Iz9fb5tXlMOJD6jUvASrKmdtLK/qXNyJns2Vqcvlk+nfJYdZaFpIWiT/tAcEYbttfxyLdYxrLckAKdVRtf1OrNgtZeMCII4SAn6SYaaidrX/AN3s/aVn/zrlEKW0cEUIatHVDKtXO0Qss5EhV/E6kz0BNCgtAytf/s0Botvxt3kGCN8ALqcG3fbh12Whk9nL4UbO63msHLSF7V9bN5E6jPWFfv8AqbHiki6ThNH3auuyZPQpJntnN0mA//56nMpK/6HIuu8xAQUEAgQDAQoMoA8AAAAAAAAG";
+
+#[test]
+fn test_solana_transaction_hash() {
+    // Transaction ID is the fee payer signature.
+    let signed_tx = base64::decode(ENCODED_TX, false).unwrap();
+    assert_eq!(
+        tx_id(CoinType::Solana, &signed_tx).unwrap(),
+        "2DaVFTx5i3fuvBvyt3s3ti4FajmYa8DCcur5Bjf9cgoFA9CnQRM6RNLTrMQ537WmRkQw9Qw3iojfxVrzQy7jWxT7"
+    );
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_summary_utils::TransactionSummarizerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Solana::Proto;
+use tw_proto::TxSummary::Proto as SummaryProto;
+
+use tw_proto::Solana::Proto::mod_SigningInput::OneOftransaction_type as TransactionType;
+
+#[test]
+fn test_solana_transaction_summary_transfer_with_priority_fee() {
+    let transfer = Proto::Transfer {
+        recipient: "6pEfiZjMycJY4VA2FtAbKgYvRwzXDpxY58Xp4b7FQCz9".into(),
+        value: 5000,
+        ..Proto::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        sender: "EN2sCsJ1WDV8UFqsiTXHcUPUxQ4juE71eCknHYYMifkd".into(),
+        recent_blockhash: "HxKwWFTHixCu8aw35J1uxAX6yUhLHkFCdJJdK4y98Gyj".into(),
+        transaction_type: TransactionType::transfer_transaction(transfer),
+        priority_fee_price: Some(Proto::PriorityFeePrice { price: 10_000 }),
+        priority_fee_limit: Some(Proto::PriorityFeeLimit { limit: 200_000 }),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut summarizer = TransactionSummarizerHelper::default();
+    let summary = summarizer.summarize(CoinType::Solana, &input);
+    assert_eq!(summary.error, SigningError::OK);
+    assert!(summary.warnings.is_empty());
+
+    // Compute budget instructions are not described as actions.
+    assert_eq!(summary.actions.len(), 1);
+    let action = &summary.actions[0];
+    assert_eq!(action.type_pb, SummaryProto::ActionType::Transfer);
+    assert_eq!(
+        action.sender,
+        "EN2sCsJ1WDV8UFqsiTXHcUPUxQ4juE71eCknHYYMifkd"
+    );
+    assert_eq!(
+        action.recipient,
+        "6pEfiZjMycJY4VA2FtAbKgYvRwzXDpxY58Xp4b7FQCz9"
+    );
+    assert_eq!(
+        action.description,
+        "Send 0.000005 SOL to 6pEfiZjMycJY4VA2FtAbKgYvRwzXDpxY58Xp4b7FQCz9"
+    );
+
+    // 5000 lamports per signature + 10_000 micro-lamports * 200_000 compute units.
+    let fee = summary.fee.as_ref().unwrap();
+    assert_eq!(fee.amount.as_ref().unwrap().value, "7000");
+}
+
+#[test]
+fn test_solana_transaction_summary_external_fee_payer() {
+    let transfer = Proto::Transfer {
+        recipient: "6pEfiZjMycJY4VA2FtAbKgYvRwzXDpxY58Xp4b7FQCz9".into(),
+        value: 5000,
+        ..Proto::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        sender: "EN2sCsJ1WDV8UFqsiTXHcUPUxQ4juE71eCknHYYMifkd".into(),
+        fee_payer: "Eg5jqooyG6ySaXKbQUu4Lpvu2SqUPZrNkM4zXs9iUDLJ".into(),
+        recent_blockhash: "HxKwWFTHixCu8aw35J1uxAX6yUhLHkFCdJJdK4y98Gyj".into(),
+        transaction_type: TransactionType::transfer_transaction(transfer),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut summarizer = TransactionSummarizerHelper::default();
+    let summary = summarizer.summarize(CoinType::Solana, &input);
+    assert_eq!(summary.error, SigningError::OK);
+
+    assert_eq!(summary.warnings.len(), 1);
+    assert_eq!(
+        summary.warnings[0].type_pb,
+        SummaryProto::WarningType::ExternalFeePayer
+    );
+    // Both the sender and the fee payer sign the transaction.
+    let fee = summary.fee.as_ref().unwrap();
+    assert_eq!(fee.amount.as_ref().unwrap().value, "10000");
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_verify_utils::TransactionVerifierHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base58::Alphabet;
+use tw_encoding::{base58, base64};
+use tw_memory::Data;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TxVerifier::Proto;
+
+const SENDER_PUBLIC_KEY: &str = "B1iGmDJdvmxyUiYM8UEo2Uw2D58EmUrw4KyLYMmrhf8V";
+const FEE_PAYER_PUBLIC_KEY: &str = "Eg5jqooyG6ySaXKbQUu4Lpvu2SqUPZrNkM4zXs9iUDLJ";
+const SENDER_SIGNATURE: &str =
+    "3aqBe5B9uj9PBdhLwbdDkH5X9Wa2E49bDvjaMJyZWj68TeQvAavw9dizTuUkRLLqucgA4NP9SDtT261bPXoHAHEj";
+const FEE_PAYER_SIGNATURE: &str =
+    "2DaVFTx5i3fuvBvyt3s3ti4FajmYa8DCcur5Bjf9cgoFA9CnQRM6RNLTrMQ537WmRkQw9Qw3iojfxVrzQy7jWxT7";
+
+/// Transaction signed by both the fee payer and the sender.
+const ENCODED_TX: &str = "Ajzc/Tke0CG8Cew5qFa6xZI/7Ya3DN0M8Ige6tKPsGzhg8Bw9DqL18KUrEZZ1F4YqZBo4Rv+FsDT8A7Nss7p4A6BNVZzzGprCJqYQeNg0EVIbmPc6mDitNniHXGeKgPZ6QZbM4FElw9O7IOFTpOBPvQFeqy0vZf/aayncL8EK/UEAgACBssq8Im1alV3N7wXGODL8jLPWwLhTuCqfGZ1Iz9fb5tXlMOJD6jUvASrKmdtLK/qXNyJns2Vqcvlk+nfJYdZaFpIWiT/tAcEYbttfxyLdYxrLckAKdVRtf1OrNgtZeMCII4SAn6SYaaidrX/AN3s/aVn/zrlEKW0cEUIatHVDKtXO0Qss5EhV/E6kz0BNCgtAytf/s0Botvxt3kGCN8ALqcG3fbh12Whk9nL4UbO63msHLSF7V9bN5E6jPWFfv8AqbHiki6ThNH3auuyZPQpJntnN0mA//56nMpK/6HIuu8xAQUEAgQDAQoMoA8AAAAAAAAG";
+
+fn b58(s: &str) -> Data {
+    base58::decode(s, Alphabet::Bitcoin).unwrap()
+}
+
+#[test]
+fn test_solana_verify_transaction() {
+    let input = Proto::VerifyingInput {
+        transaction: base64::decode(ENCODED_TX, false).unwrap().into(),
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Solana, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+    assert!(output.valid);
+
+    let expected = vec![
+        Proto::SignatureVerification {
+            public_key: b58(FEE_PAYER_PUBLIC_KEY).into(),
+            signature: b58(FEE_PAYER_SIGNATURE).into(),
+            valid: true,
+        },
+        Proto::SignatureVerification {
+            public_key: b58(SENDER_PUBLIC_KEY).into(),
+            signature: b58(SENDER_SIGNATURE).into(),
+            valid: true,
+        },
+    ];
+    assert_eq!(output.signatures, expected);
+}
+
+#[test]
+fn test_solana_verify_transaction_invalid_signature() {
+    let mut tx = base64::decode(ENCODED_TX, false).unwrap();
+    // Corrupt the fee payer signature that follows the signatures count.
+    tx[1] ^= 0xff;
+
+    let input = Proto::VerifyingInput {
+        transaction: tx.into(),
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Solana, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(!output.valid);
+    assert_eq!(output.signatures.len(), 2);
+    assert!(!output.signatures[0].valid);
+    assert!(output.signatures[1].valid);
+}
+
+#[test]
+fn test_solana_verify_transaction_unexpected_signer() {
+    let input = Proto::VerifyingInput {
+        transaction: base64::decode(ENCODED_TX, false).unwrap().into(),
+        public_keys: vec![b58(SENDER_PUBLIC_KEY).into()],
+        ..Proto::VerifyingInput::default()
+    };
+
+    let mut verifier = TransactionVerifierHelper::default();
+    let output = verifier.verify(CoinType::Solana, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(!output.valid);
+    assert_eq!(output.signatures.len(), 2);
+    // The fee payer is not among the expected signers.
+    assert!(!output.signatures[0].valid);
+    assert!(output.signatures[1].valid);
+}
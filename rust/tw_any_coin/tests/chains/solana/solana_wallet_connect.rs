@@ -4,14 +4,18 @@
 
 use serde_json::json;
 use std::borrow::Cow;
+use tw_any_coin::ffi::tw_message_signer::tw_message_signer_sign;
 use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
 use tw_any_coin::test_utils::wallet_connect_utils::WalletConnectRequestHelper;
 use tw_coin_registry::coin_type::CoinType;
 use tw_encoding::base58;
 use tw_encoding::base58::Alphabet;
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
 use tw_proto::Common::Proto::SigningError;
 use tw_proto::Solana::Proto;
 use tw_proto::WalletConnect::Proto as WCProto;
+use tw_proto::{deserialize, serialize};
 
 fn b58(s: &str) -> Cow<'static, [u8]> {
     base58::decode(s, Alphabet::Bitcoin).unwrap().into()
@@ -107,3 +111,124 @@ fn test_solana_sign_wallet_connect_with_external_fee_payer() {
     ];
     assert_eq!(signing_output.signatures, expected_signatures);
 }
+
+#[test]
+fn test_solana_sign_wallet_connect_all_transactions() {
+    // The same transaction encoded in Base64 and Base58.
+    let tx_base64_to_sign = "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAEDZsL1CMnFVcrMn7JtiOiN1U4hC7WovOVof2DX51xM0H/GizyJTHgrBanCf8bGbrFNTn0x3pCGq30hKbywSTr6AgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAgIAAQwCAAAAKgAAAAAAAAA=";
+    let tx_base58_to_sign = "3md7BBV9wFjYGnMWcMNyAZcjca2HGfXWZkrU8vvho66z2sJMZFcx6HZdBiAddjo2kzgBv3uZoac3domBRjJJSXkbBvokxREe5dK9oXiZAKPUnXGU6aVjmzSWgxABjB8yoBW6fbkVo37HBZ36DCanBHnBEqwnm2yY5cuFHrcMK3vTQvFbY2N6ZrD9eDZLcJSL2MpEfqUbc327MbeH4t2RJnRUhgfkjoUnKGnR3QoGK6zuK8QeKH9JDCGBWtE3kHj6RxyEBsRY7LAY1JdqBNsoKBJCFHSEL5vcTgasq";
+
+    let request_params = json!({
+        "transactions": [tx_base64_to_sign, tx_base58_to_sign]
+    });
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::SolanaSignAllTransactions,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Solana, &input);
+    assert_eq!(parsing_output.error, SigningError::OK);
+
+    let signing_inputs = match parsing_output.signing_input_oneof {
+        WCProto::mod_ParseRequestOutput::OneOfsigning_input_oneof::solana_all(all) => all.inputs,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_inputs.len(), 2);
+    assert_eq!(signing_inputs[0].tx_encoding, Proto::Encoding::Base64);
+    assert_eq!(signing_inputs[1].tx_encoding, Proto::Encoding::Base58);
+
+    let expected_encoded = [
+        "AQPWaOi7dMdmQpXi8HyQQKwiqIftrg1igGQxGtZeT50ksn4wAnyH4DtDrkkuE0fqgx80LTp4LwNN9a440SrmoA8BAAEDZsL1CMnFVcrMn7JtiOiN1U4hC7WovOVof2DX51xM0H/GizyJTHgrBanCf8bGbrFNTn0x3pCGq30hKbywSTr6AgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAgIAAQwCAAAAKgAAAAAAAAA=",
+        "3p2kzZ1DvquqC6LApPuxpTg5CCDVPqJFokGSnGhnBHrta4uq7S2EyehV1XNUVXp51D69GxGzQZUjikfDzbWBG2aFtG3gHT1QfLzyFKHM4HQtMQMNXqay1NAeiiYZjNhx9UvMX4uAQZ4Q6rx6m2AYfQ7aoMUrejq298q1wBFdtS9XVB5QTiStnzC7zs97FUEK2T4XapjF1519EyFBViTfHpGpnf5bfizDzsW9kYUtRDW1UC2LgHr7npgq5W9TBmHf9hSmRgM9XXucjXLqubNWE7HUMhbKjuBqkirRM",
+    ];
+    for (mut signing_input, expected) in signing_inputs.into_iter().zip(expected_encoded) {
+        // Set missing private key.
+        signing_input.private_key = b58("A7psj2GW7ZMdY4E5hJq14KMeYg7HFjULSsWSrTXZLvYr");
+
+        let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+        let signing_output = signer.sign(CoinType::Solana, signing_input);
+
+        assert_eq!(signing_output.error, SigningError::OK);
+        assert_eq!(signing_output.encoded, expected);
+    }
+}
+
+#[test]
+fn test_solana_sign_wallet_connect_all_transactions_invalid() {
+    let request_params = json!({
+        "transactions": ["AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAED", "0x00"]
+    });
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::SolanaSignAllTransactions,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Solana, &input);
+    assert_eq!(parsing_output.error, SigningError::Error_input_parse);
+
+    // A valid transaction followed by trailing bytes.
+    let request_params = json!({
+        "transactions": ["AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAEDZsL1CMnFVcrMn7JtiOiN1U4hC7WovOVof2DX51xM0H/GizyJTHgrBanCf8bGbrFNTn0x3pCGq30hKbywSTr6AgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAgIAAQwCAAAAKgAAAAAAAAAAAAA="]
+    });
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::SolanaSignAllTransactions,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Solana, &input);
+    assert_eq!(parsing_output.error, SigningError::Error_input_parse);
+}
+
+#[test]
+fn test_solana_sign_wallet_connect_message() {
+    // "Hello, Solana!" Base58-encoded.
+    let request_params = json!({
+        "message": "TcgsE5e9XK6Li74G8r4",
+        "pubkey": "6pEfiZjMycJY4VA2FtAbKgYvRwzXDpxY58Xp4b7FQCz9"
+    });
+    let input = WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method: WCProto::Method::SolanaSignMessage,
+        payload: request_params.to_string().into(),
+    };
+
+    let mut parser = WalletConnectRequestHelper::default();
+    let parsing_output = parser.parse(CoinType::Solana, &input);
+    assert_eq!(parsing_output.error, SigningError::OK);
+
+    let mut signing_input = match parsing_output.signing_input_oneof {
+        WCProto::mod_ParseRequestOutput::OneOfsigning_input_oneof::solana_message(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_input.message, b"Hello, Solana!".as_slice());
+    assert_eq!(
+        signing_input.message_type,
+        Proto::MessageType::MessageType_raw
+    );
+
+    // Set missing private key.
+    signing_input.private_key = "833a053c59e78138a3ed090459bc6743cca6a9cbc2809a7bf5dbc7939b8775c8"
+        .decode_hex()
+        .unwrap()
+        .into();
+
+    let input_data = TWDataHelper::create(serialize(&signing_input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(input_data.ptr(), CoinType::Solana as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(
+        output.signature,
+        "62s7JLEM64CqtUDxWtXVSFNhkR4PaACitAPrNvhyDDpu6UjqC3f9pcz5opJkDGayihZRCyh2bUMaX8DDtCxx82Wb"
+    );
+}
@@ -6,7 +6,11 @@ use tw_proto::Sui::Proto;
 
 mod sui_address;
 mod sui_compile;
+mod sui_decode;
+mod sui_message_sign;
 mod sui_sign;
+mod sui_transaction_hash;
+mod sui_wallet_connect;
 mod test_cases;
 
 fn object_ref(id: &'static str, version: u64, digest: &'static str) -> Proto::ObjectRef<'static> {
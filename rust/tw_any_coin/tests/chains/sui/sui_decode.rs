@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64;
+use tw_encoding::hex::ToHex;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Sui::Proto;
+use tw_proto::Sui::Proto::mod_Argument::{NestedResult, OneOfargument as ArgumentType};
+use tw_proto::Sui::Proto::mod_CallArg::OneOfcall_arg as CallArgType;
+use tw_proto::Sui::Proto::mod_Command::OneOfcommand as CommandType;
+use tw_proto::Sui::Proto::mod_TransactionData::OneOfexpiration as ExpirationType;
+
+fn argument(argument: ArgumentType) -> Proto::Argument {
+    Proto::Argument { argument }
+}
+
+#[test]
+fn test_sui_decode_transfer() {
+    // Signed in `test_sui_sign_direct_transfer`.
+    let encoded_tx = base64::decode("AAACAAgQJwAAAAAAAAAgJZ/4B0q0Jcu0ifI24Y4I8D8aeFa998eih3vWT3OLUBUCAgABAQAAAQEDAAAAAAEBANV1rX8Y6UhGKlz2mPVk7zlKdSpx/sYkk6+KBVwBLA1QAQbywsjB2JZN8QGdZhbpcFcZvrq9kx2idVy5SM635olk7AIAAAAAAAAgYEVuxmf1zRBGdoDr+VDtMpIFF12s2Ua7I2ru1XyGF8/Vda1/GOlIRipc9pj1ZO85SnUqcf7GJJOvigVcASwNUAEAAAAAAAAA0AcAAAAAAAAA", false).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Sui, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(
+        tx.sender,
+        "0xd575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d50"
+    );
+    assert_eq!(tx.expiration, ExpirationType::None);
+
+    let gas_data = tx.gas_data.unwrap();
+    assert_eq!(gas_data.owner, tx.sender);
+    assert_eq!(gas_data.price, 1);
+    assert_eq!(gas_data.budget, 2000);
+    assert_eq!(gas_data.payment.len(), 1);
+    assert_eq!(
+        gas_data.payment[0].object_id,
+        "0x06f2c2c8c1d8964df1019d6616e9705719bebabd931da2755cb948ceb7e68964"
+    );
+    assert_eq!(gas_data.payment[0].version, 748);
+    assert_eq!(
+        gas_data.payment[0].object_digest,
+        "7UoYeVzREVT17ZyYbRTsKzRCec5xJWm6FMh8AKaDPdDx"
+    );
+
+    assert_eq!(tx.inputs.len(), 2);
+    let CallArgType::pure(ref amount) = tx.inputs[0].call_arg else {
+        panic!("Expected a pure input, found: {:?}", tx.inputs[0]);
+    };
+    assert_eq!(amount.to_hex(), "1027000000000000");
+    let CallArgType::pure(ref recipient) = tx.inputs[1].call_arg else {
+        panic!("Expected a pure input, found: {:?}", tx.inputs[1]);
+    };
+    assert_eq!(
+        recipient.to_hex(),
+        "259ff8074ab425cbb489f236e18e08f03f1a7856bdf7c7a2877bd64f738b5015"
+    );
+
+    assert_eq!(tx.commands.len(), 2);
+    let CommandType::split_coins(ref split) = tx.commands[0].command else {
+        panic!("Expected a SplitCoins command, found: {:?}", tx.commands[0]);
+    };
+    assert_eq!(split.coin, Some(argument(ArgumentType::gas_coin(true))));
+    assert_eq!(split.amounts, vec![argument(ArgumentType::input(0))]);
+
+    let CommandType::transfer_objects(ref transfer) = tx.commands[1].command else {
+        panic!(
+            "Expected a TransferObjects command, found: {:?}",
+            tx.commands[1]
+        );
+    };
+    let nested = NestedResult {
+        result: 0,
+        index: 0,
+    };
+    assert_eq!(
+        transfer.objects,
+        vec![argument(ArgumentType::nested_result(nested))]
+    );
+    assert_eq!(transfer.address, Some(argument(ArgumentType::input(1))));
+}
+
+#[test]
+fn test_sui_decode_add_stake_with_intent() {
+    // Signed in `test_sui_sign_delegate_sui`.
+    let unsigned_tx = base64::decode("AAAFAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABQEAAAAAAAAAAQEA/xr2LTVlSVaWRDeIKzPTJWqtICFPGKI0xiteJYyhY+6R7/QEAAAAACDSjWt6fM4gT8LU9OmUKUD0oeVAN3195wXyRgLAAkj/RgEAXvd9IMfWdF09m19p54JarnM/pcij+C9xknSeMWl5HIzPih4FAAAAACDQmsUAK2qhMxauQja6zUchci2O+VpXNpKHQPa5uzG92wAJAfBIqTsAAAAAACBhlT6nJwnu1y9EQd2UTuxJoRtKyr/I4EAV6JxjvoG2qwIFAAIBAQABAgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMKc3VpX3N5c3RlbRpyZXF1ZXN0X2FkZF9zdGFrZV9tdWxfY29pbgAEAQAAAgAAAQMAAQQAVOgNdteQwnf1pE886S9T0m9YlIkr85Xe5jdZiIdr5rIBECBUt2dqRrG65yQTTcli23KfM4ms9509bzwnugGKBARXFyAFAAAAACDmoHkZ4Q2u0tMpkkJOmnK9WHxAXfwVxtKnoGoU3ZecTFToDXbXkMJ39aRPPOkvU9JvWJSJK/OV3uY3WYiHa+ay7gIAAAAAAABAVIkAAAAAAAA=", false).unwrap();
+    // Prepend the `TransactionData` intent.
+    let encoded_tx = [vec![0, 0, 0], unsigned_tx].concat();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Sui, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.error_message.is_empty());
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(
+        tx.sender,
+        "0x54e80d76d790c277f5a44f3ce92f53d26f5894892bf395dee6375988876be6b2"
+    );
+    let gas_data = tx.gas_data.unwrap();
+    assert_eq!(gas_data.price, 750);
+    assert_eq!(gas_data.budget, 9000000);
+
+    assert_eq!(tx.inputs.len(), 5);
+    let CallArgType::shared_object(ref system_state) = tx.inputs[0].call_arg else {
+        panic!("Expected a shared object input, found: {:?}", tx.inputs[0]);
+    };
+    assert_eq!(
+        system_state.object_id,
+        "0x0000000000000000000000000000000000000000000000000000000000000005"
+    );
+    assert_eq!(system_state.initial_shared_version, 1);
+    assert!(system_state.mutable);
+
+    let CallArgType::imm_or_owned_object(ref coin) = tx.inputs[1].call_arg else {
+        panic!("Expected an owned object input, found: {:?}", tx.inputs[1]);
+    };
+    assert_eq!(
+        coin.object_id,
+        "0xff1af62d35654956964437882b33d3256aad20214f18a234c62b5e258ca163ee"
+    );
+    assert_eq!(coin.version, 83160977);
+    assert_eq!(
+        coin.object_digest,
+        "FAugxdfWPQrMu57mMc9FmgNSjkt613pixR6V5M9nashw"
+    );
+
+    assert_eq!(tx.commands.len(), 2);
+    let CommandType::make_move_vec(ref make_vec) = tx.commands[0].command else {
+        panic!(
+            "Expected a MakeMoveVec command, found: {:?}",
+            tx.commands[0]
+        );
+    };
+    assert!(make_vec.type_tag.is_empty());
+    assert_eq!(
+        make_vec.elements,
+        vec![
+            argument(ArgumentType::input(1)),
+            argument(ArgumentType::input(2))
+        ]
+    );
+
+    let CommandType::move_call(ref move_call) = tx.commands[1].command else {
+        panic!("Expected a MoveCall command, found: {:?}", tx.commands[1]);
+    };
+    assert_eq!(
+        move_call.package,
+        "0x0000000000000000000000000000000000000000000000000000000000000003"
+    );
+    assert_eq!(move_call.module, "sui_system");
+    assert_eq!(move_call.function, "request_add_stake_mul_coin");
+    assert!(move_call.type_arguments.is_empty());
+    assert_eq!(
+        move_call.arguments,
+        vec![
+            argument(ArgumentType::input(0)),
+            argument(ArgumentType::result(0)),
+            argument(ArgumentType::input(3)),
+            argument(ArgumentType::input(4)),
+        ]
+    );
+}
+
+#[test]
+fn test_sui_decode_invalid_transaction() {
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Sui, vec![0, 0, 5, 1]);
+
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(output.transaction.is_none());
+}
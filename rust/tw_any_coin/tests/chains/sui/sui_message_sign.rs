@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Sui::Proto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+
+const PRIVATE_KEY: &str = "3823dce5288ab55dd1c00d97e91933c613417fdb282a0b8b01a7f5f5a533b266";
+const ADDRESS: &str = "0xd575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d50";
+const MESSAGE: &str = "Hello, world!";
+const SIGNATURE: &str = "AFNr4PD004/fj26+jyC4RswoRu1AvDVA3sAKFYg5GY9hzlZrTZkJGgMbrYQfmAFgAUu5DmkRVQ2gdUk+v592Bg5qfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg==";
+
+fn sign_message(message: &[u8]) -> Proto::MessageSigningOutput<'static> {
+    let input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: message.to_vec().into(),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(input_data.ptr(), CoinType::Sui as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    Proto::MessageSigningOutput {
+        error: output.error,
+        error_message: output.error_message.to_string().into(),
+        signature: output.signature.to_string().into(),
+    }
+}
+
+fn verify_message(address: &str, message: &[u8], signature: &str) -> bool {
+    let input = Proto::MessageVerifyingInput {
+        address: address.into(),
+        message: message.into(),
+        signature: signature.into(),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    unsafe { tw_message_signer_verify(input_data.ptr(), CoinType::Sui as u32) }
+}
+
+#[test]
+fn test_sui_message_sign() {
+    let output = sign_message(MESSAGE.as_bytes());
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature, SIGNATURE);
+}
+
+#[test]
+fn test_sui_message_sign_empty() {
+    let output = sign_message(&[]);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.signature,
+        "AJTCAgDBv9/1R1w2pXjgzVGyg5K3fhv6I0OAt10zHji0GI3jm8eMk+HSK/lyccPmEgttPAMyaTrZMplfIM6N7QhqfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg=="
+    );
+    assert!(verify_message(ADDRESS, &[], &output.signature));
+}
+
+#[test]
+fn test_sui_message_pre_image_hashes() {
+    let input = Proto::MessageSigningInput {
+        message: MESSAGE.as_bytes().into(),
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(input_data.ptr(), CoinType::Sui as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    // PersonalMessage intent || BCS `vector<u8>`.
+    assert_eq!(output.data.to_hex(), "0300000d48656c6c6f2c20776f726c6421");
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "b3a82fa7909fb9c9add005616e4024f8bc85a484a5623d44762db301cb2ad2d3"
+    );
+}
+
+#[test]
+fn test_sui_message_verify() {
+    assert!(verify_message(ADDRESS, MESSAGE.as_bytes(), SIGNATURE));
+}
+
+#[test]
+fn test_sui_message_verify_invalid() {
+    // Another message.
+    assert!(!verify_message(ADDRESS, b"Hello, world?", SIGNATURE));
+    // Another address.
+    assert!(!verify_message(
+        "0x259ff8074ab425cbb489f236e18e08f03f1a7856bdf7c7a2877bd64f738b5015",
+        MESSAGE.as_bytes(),
+        SIGNATURE
+    ));
+    // Invalid signature.
+    assert!(!verify_message(ADDRESS, MESSAGE.as_bytes(), "AFNr4PD004"));
+}
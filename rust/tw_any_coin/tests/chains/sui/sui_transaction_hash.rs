@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::sui::test_cases::transfer_d4ay9tdb;
+use tw_any_coin::test_utils::transaction_hash_utils::tx_id;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64;
+
+/// The transaction intent prefix `[IntentScope::TransactionData, IntentVersion::V0, AppId::Sui]`.
+const TRANSACTION_DATA_INTENT: [u8; 3] = [0, 0, 0];
+
+#[test]
+fn test_sui_transaction_hash() {
+    let tx_data = base64::decode(transfer_d4ay9tdb::UNSIGNED_TX, false).unwrap();
+    assert_eq!(
+        tx_id(CoinType::Sui, &tx_data).unwrap(),
+        "D4Ay9TdBJjXkGmrZSstZakpEWskEQHaWURP6xWPRXbAm"
+    );
+}
+
+#[test]
+fn test_sui_transaction_hash_with_intent() {
+    let mut intent_msg = TRANSACTION_DATA_INTENT.to_vec();
+    intent_msg.extend(base64::decode(transfer_d4ay9tdb::UNSIGNED_TX, false).unwrap());
+    assert_eq!(
+        tx_id(CoinType::Sui, &intent_msg).unwrap(),
+        "D4Ay9TdBJjXkGmrZSstZakpEWskEQHaWURP6xWPRXbAm"
+    );
+}
+
+#[test]
+fn test_sui_transaction_hash_with_signature() {
+    // Signatures are not accepted as they do not affect the digest.
+    let mut signed_tx = base64::decode(transfer_d4ay9tdb::UNSIGNED_TX, false).unwrap();
+    signed_tx.extend(base64::decode(transfer_d4ay9tdb::SIGNATURE, false).unwrap());
+    assert_eq!(tx_id(CoinType::Sui, &signed_tx), None);
+}
@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde_json::json;
+use tw_any_coin::ffi::tw_message_signer::tw_message_signer_sign;
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_any_coin::test_utils::wallet_connect_utils::WalletConnectRequestHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Sui::Proto::{self, mod_SigningInput::OneOftransaction_payload as TransactionType};
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+use tw_proto::{deserialize, serialize};
+
+const PRIVATE_KEY: &str = "3823dce5288ab55dd1c00d97e91933c613417fdb282a0b8b01a7f5f5a533b266";
+const SIGNER_ADDRESS: &str = "0xd575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d50";
+
+fn wc_input(method: WCProto::Method, payload: String) -> WCProto::ParseRequestInput<'static> {
+    WCProto::ParseRequestInput {
+        protocol: WCProto::Protocol::V2,
+        method,
+        payload: payload.into(),
+    }
+}
+
+#[test]
+fn test_sui_wallet_connect_sign_transaction() {
+    let unsigned_tx = "AAACAAgQJwAAAAAAAAAgJZ/4B0q0Jcu0ifI24Y4I8D8aeFa998eih3vWT3OLUBUCAgABAQAAAQEDAAAAAAEBANV1rX8Y6UhGKlz2mPVk7zlKdSpx/sYkk6+KBVwBLA1QAQbywsjB2JZN8QGdZhbpcFcZvrq9kx2idVy5SM635olk7AIAAAAAAAAgYEVuxmf1zRBGdoDr+VDtMpIFF12s2Ua7I2ru1XyGF8/Vda1/GOlIRipc9pj1ZO85SnUqcf7GJJOvigVcASwNUAEAAAAAAAAA0AcAAAAAAAAA";
+    let payload = json!({
+        "transaction": unsigned_tx,
+        "address": SIGNER_ADDRESS,
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Sui,
+        &wc_input(WCProto::Method::SuiSignTransaction, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::OK);
+
+    let mut signing_input = match output.signing_input_oneof {
+        SigningInputEnum::sui(input) => input,
+        _ => unreachable!(),
+    };
+    assert_eq!(signing_input.signer, SIGNER_ADDRESS);
+    match signing_input.transaction_payload {
+        TransactionType::sign_direct_message(ref direct) => {
+            assert_eq!(direct.unsigned_tx_msg, unsigned_tx)
+        },
+        _ => unreachable!(),
+    }
+
+    // Set missing private key.
+    signing_input.private_key = PRIVATE_KEY.decode_hex().unwrap().into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let signing_output = signer.sign(CoinType::Sui, signing_input);
+
+    assert_eq!(signing_output.error, SigningError::OK);
+    assert_eq!(signing_output.signature, "APxPduNVvHj2CcRcHOtiP2aBR9qP3vO2Cb0g12PI64QofDB6ks33oqe/i/iCTLcop2rBrkczwrayZuJOdi7gvwNqfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg==");
+}
+
+#[test]
+fn test_sui_wallet_connect_sign_transaction_invalid() {
+    // Transaction is not Base64-encoded.
+    let payload = json!({
+        "transaction": "Not a Base64 string!",
+        "address": SIGNER_ADDRESS,
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Sui,
+        &wc_input(WCProto::Method::SuiSignTransaction, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::Error_input_parse);
+
+    let payload = json!({
+        "transaction": "AAACAAgQJwAAAAAAAAAg",
+        "address": "0x123z",
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Sui,
+        &wc_input(WCProto::Method::SuiSignTransaction, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::Error_invalid_address);
+}
+
+#[test]
+fn test_sui_wallet_connect_sign_personal_message() {
+    let payload = json!({
+        "message": "Hello, world!",
+        "address": SIGNER_ADDRESS,
+    });
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Sui,
+        &wc_input(WCProto::Method::SuiSignPersonalMessage, payload.to_string()),
+    );
+    assert_eq!(output.error, SigningError::OK);
+
+    let mut signing_input = match output.signing_input_oneof {
+        SigningInputEnum::sui_message(input) => input,
+        _ => unreachable!(),
+    };
+
+    // Set missing private key.
+    signing_input.private_key = PRIVATE_KEY.decode_hex().unwrap().into();
+
+    let input_data = TWDataHelper::create(serialize(&signing_input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(input_data.ptr(), CoinType::Sui as u32)
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.signature, "AFNr4PD004/fj26+jyC4RswoRu1AvDVA3sAKFYg5GY9hzlZrTZkJGgMbrYQfmAFgAUu5DmkRVQ2gdUk+v592Bg5qfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg==");
+}
+
+#[test]
+fn test_sui_wallet_connect_unknown_method() {
+    let mut parser = WalletConnectRequestHelper::default();
+    let output = parser.parse(
+        CoinType::Sui,
+        &wc_input(WCProto::Method::AptosSignTransaction, "{}".to_string()),
+    );
+    assert_eq!(output.error, SigningError::Error_not_supported);
+}
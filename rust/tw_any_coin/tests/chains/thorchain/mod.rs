@@ -6,3 +6,4 @@ mod test_cases;
 mod thorchain_address;
 mod thorchain_compile;
 mod thorchain_sign;
+mod thorchain_transaction_summary;
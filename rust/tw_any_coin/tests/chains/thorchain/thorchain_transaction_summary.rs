@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_summary_utils::TransactionSummarizerHelper;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_proto::Cosmos::Proto;
+use tw_proto::Cosmos::Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+use tw_proto::TxSummary::Proto as SummaryProto;
+
+#[test]
+fn test_thorchain_transaction_summary_send() {
+    let send_msg = Proto::mod_Message::THORChainSend {
+        // thor1z53wwe7md6cewz9sqwqzn0aavpaun0gw0exn2r
+        from_address: "1522e767db6eb19708b0038029bfbd607bc9bd0e"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        // thor1e2ryt8asq4gu0h6z2sx9u7rfrykgxwkmr9upxn
+        to_address: "ca86459fb00551c7df42540c5e7869192c833adb"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        amounts: vec![Proto::Amount {
+            denom: "rune".into(),
+            amount: "38000000".into(),
+        }],
+    };
+    let input = Proto::SigningInput {
+        chain_id: "thorchain-mainnet-v1".into(),
+        fee: Some(Proto::Fee {
+            gas: 2500000,
+            amounts: vec![Proto::Amount {
+                denom: "rune".into(),
+                amount: "200".into(),
+            }],
+        }),
+        messages: vec![Proto::Message {
+            message_oneof: MessageEnum::thorchain_send_message(send_msg),
+        }],
+        ..Proto::SigningInput::default()
+    };
+
+    let mut summarizer = TransactionSummarizerHelper::default();
+    let summary = summarizer.summarize(CoinType::THORChain, &input);
+    assert_eq!(summary.error, SigningErrorType::OK);
+
+    assert_eq!(summary.actions.len(), 1);
+    let action = &summary.actions[0];
+    assert_eq!(action.type_pb, SummaryProto::ActionType::Transfer);
+    assert_eq!(action.sender, "thor1z53wwe7md6cewz9sqwqzn0aavpaun0gw0exn2r");
+    assert_eq!(
+        action.recipient,
+        "thor1e2ryt8asq4gu0h6z2sx9u7rfrykgxwkmr9upxn"
+    );
+    assert_eq!(
+        action.description,
+        "Send 0.38 RUNE to thor1e2ryt8asq4gu0h6z2sx9u7rfrykgxwkmr9upxn"
+    );
+
+    let fee = summary.fee.as_ref().unwrap();
+    assert_eq!(fee.asset.as_ref().unwrap().symbol, "RUNE");
+    assert_eq!(fee.amount.as_ref().unwrap().formatted, "0.000002");
+}
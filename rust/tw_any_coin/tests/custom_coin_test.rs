@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_any_coin::ffi::tw_any_address::tw_any_address_is_valid;
+use tw_any_coin::ffi::tw_any_signer::tw_any_signer_sign;
+use tw_any_coin::ffi::tw_coin_registry::{
+    tw_coin_registry_register_custom_coin, tw_coin_registry_unregister_custom_coin,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
+use tw_number::U256;
+use tw_proto::{deserialize, serialize};
+
+const CUSTOM_EVM_COIN: u32 = 90_000_001;
+const CUSTOM_COSMOS_COIN: u32 = 90_000_002;
+const UNREGISTERED_COIN: u32 = 90_000_003;
+const CUSTOM_RONIN_COIN: u32 = 90_000_005;
+const CUSTOM_INJECTIVE_COIN: u32 = 90_000_006;
+
+fn register_custom_coin(item_json: &str) -> bool {
+    let item_json = TWStringHelper::create(item_json);
+    unsafe { tw_coin_registry_register_custom_coin(item_json.ptr()) }
+}
+
+fn is_address_valid(address: &str, coin: u32) -> bool {
+    let address = TWStringHelper::create(address);
+    unsafe { tw_any_address_is_valid(address.ptr(), coin) }
+}
+
+fn custom_evm_coin_json(coin_id: u32) -> String {
+    format!(
+        r#"{{
+            "id": "custom-evm",
+            "name": "Custom EVM",
+            "coinId": {coin_id},
+            "symbol": "CEVM",
+            "decimals": 18,
+            "blockchain": "Ethereum",
+            "chainId": "1",
+            "derivation": [{{ "path": "m/44'/60'/0'/0/0" }}],
+            "publicKeyType": "secp256k1Extended",
+            "addressHasher": "keccak256"
+        }}"#
+    )
+}
+
+#[test]
+fn test_custom_evm_coin_address_and_sign() {
+    use tw_proto::Ethereum::Proto;
+
+    assert!(register_custom_coin(&custom_evm_coin_json(CUSTOM_EVM_COIN)));
+
+    assert!(is_address_valid(
+        "0x3535353535353535353535353535353535353535",
+        CUSTOM_EVM_COIN
+    ));
+    assert!(!is_address_valid(
+        "cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx",
+        CUSTOM_EVM_COIN
+    ));
+
+    let private = "0x4646464646464646464646464646464646464646464646464646464646464646"
+        .decode_hex()
+        .unwrap();
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        data: Cow::default(),
+    };
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(9),
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(21_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        private_key: private.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    // Returns the error and the hex-encoded transaction.
+    let sign = |input: &Proto::SigningInput| -> (SigningErrorType, String) {
+        let input_data = TWDataHelper::create(serialize(input).unwrap());
+        let output =
+            TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), CUSTOM_EVM_COIN) })
+                .to_vec()
+                .expect("!tw_any_signer_sign returned nullptr");
+        let output: Proto::SigningOutput = deserialize(&output).unwrap();
+        (output.error, output.encoded.to_hex())
+    };
+
+    // The same transaction as signed with `CoinType::Ethereum`.
+    let expected = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+    assert_eq!(sign(&input), (SigningErrorType::OK, expected.to_string()));
+
+    // The chain ID of the coin is used by default.
+    let no_chain_id = Proto::SigningInput {
+        chain_id: Cow::default(),
+        ..input.clone()
+    };
+    assert_eq!(
+        sign(&no_chain_id),
+        (SigningErrorType::OK, expected.to_string())
+    );
+
+    // A different chain ID is not allowed.
+    let other_chain_id = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(56),
+        ..input.clone()
+    };
+    assert_eq!(
+        sign(&other_chain_id),
+        (SigningErrorType::Error_invalid_params, String::new())
+    );
+
+    assert!(unsafe { tw_coin_registry_unregister_custom_coin(CUSTOM_EVM_COIN) });
+    assert!(!is_address_valid(
+        "0x3535353535353535353535353535353535353535",
+        CUSTOM_EVM_COIN
+    ));
+}
+
+#[test]
+fn test_custom_cosmos_coin_address() {
+    let item_json = format!(
+        r#"{{
+            "id": "custom-stargaze",
+            "name": "Custom Stargaze",
+            "coinId": {CUSTOM_COSMOS_COIN},
+            "symbol": "STARS",
+            "decimals": 6,
+            "blockchain": "Cosmos",
+            "chainId": "stargaze-1",
+            "derivation": [{{ "path": "m/44'/118'/0'/0/0" }}],
+            "publicKeyType": "secp256k1",
+            "addressHasher": "sha256ripemd",
+            "hrp": "stars"
+        }}"#
+    );
+    assert!(register_custom_coin(&item_json));
+
+    assert!(is_address_valid(
+        "stars1ten42eesehw0ktddcp0fws7d3ycsqez3tcyzth",
+        CUSTOM_COSMOS_COIN
+    ));
+    assert!(!is_address_valid(
+        "cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx",
+        CUSTOM_COSMOS_COIN
+    ));
+
+    assert!(unsafe { tw_coin_registry_unregister_custom_coin(CUSTOM_COSMOS_COIN) });
+}
+
+#[test]
+fn test_custom_ronin_coin_address() {
+    let item_json = custom_evm_coin_json(CUSTOM_RONIN_COIN)
+        .replace(r#""blockchain": "Ethereum""#, r#""blockchain": "Ronin""#);
+    assert!(register_custom_coin(&item_json));
+
+    assert!(is_address_valid(
+        "ronin:ec49280228b0d05aa8e8b756503254e1ee7835ab",
+        CUSTOM_RONIN_COIN
+    ));
+
+    assert!(unsafe { tw_coin_registry_unregister_custom_coin(CUSTOM_RONIN_COIN) });
+}
+
+#[test]
+fn test_custom_injective_coin_address() {
+    let item_json = format!(
+        r#"{{
+            "id": "custom-injective",
+            "name": "Custom Injective",
+            "coinId": {CUSTOM_INJECTIVE_COIN},
+            "symbol": "INJ",
+            "decimals": 18,
+            "blockchain": "NativeInjective",
+            "derivation": [{{ "path": "m/44'/60'/0'/0/0" }}],
+            "publicKeyType": "secp256k1Extended",
+            "addressHasher": "keccak256",
+            "hrp": "inj"
+        }}"#
+    );
+    assert!(register_custom_coin(&item_json));
+
+    assert!(is_address_valid(
+        "inj14py36sx57ud82t9yrks9z6hdsrpn5x6k8tf7m3",
+        CUSTOM_INJECTIVE_COIN
+    ));
+    assert!(!is_address_valid(
+        "cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx",
+        CUSTOM_INJECTIVE_COIN
+    ));
+
+    assert!(unsafe { tw_coin_registry_unregister_custom_coin(CUSTOM_INJECTIVE_COIN) });
+}
+
+#[test]
+fn test_custom_coin_invalid() {
+    // Conflicts with a built-in coin type.
+    assert!(!register_custom_coin(&custom_evm_coin_json(
+        CoinType::Ethereum as u32
+    )));
+    assert!(!register_custom_coin("{}"));
+    assert!(!register_custom_coin("not a json"));
+
+    // UTXO custom coins are not supported, as they require p2pkh/p2sh prefixes.
+    let bitcoin_json = custom_evm_coin_json(UNREGISTERED_COIN)
+        .replace(r#""blockchain": "Ethereum""#, r#""blockchain": "Bitcoin""#);
+    assert!(!register_custom_coin(&bitcoin_json));
+
+    // Blockchains with Bech32 addresses require HRP.
+    for blockchain in [
+        "Binance",
+        "Cosmos",
+        "NativeEvmos",
+        "NativeInjective",
+        "Thorchain",
+    ] {
+        let bech32_json = custom_evm_coin_json(UNREGISTERED_COIN).replace(
+            r#""blockchain": "Ethereum""#,
+            &format!(r#""blockchain": "{blockchain}""#),
+        );
+        assert!(
+            !register_custom_coin(&bech32_json),
+            "'{blockchain}' custom coin without HRP must be rejected"
+        );
+    }
+
+    // EVM chain ID must be a number.
+    let evm_json = custom_evm_coin_json(UNREGISTERED_COIN)
+        .replace(r#""chainId": "1""#, r#""chainId": "base-1""#);
+    assert!(!register_custom_coin(&evm_json));
+
+    // Chain ID is not supported by the blockchain.
+    let solana_json = custom_evm_coin_json(UNREGISTERED_COIN)
+        .replace(r#""blockchain": "Ethereum""#, r#""blockchain": "Solana""#);
+    assert!(!register_custom_coin(&solana_json));
+
+    assert!(!unsafe { tw_coin_registry_unregister_custom_coin(UNREGISTERED_COIN) });
+    assert!(!is_address_valid(
+        "0x3535353535353535353535353535353535353535",
+        UNREGISTERED_COIN
+    ));
+}
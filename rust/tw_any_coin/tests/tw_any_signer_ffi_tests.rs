@@ -2,8 +2,13 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use tw_any_coin::ffi::tw_any_signer::tw_any_signer_sign;
+use tw_any_coin::ffi::tw_any_signer::{tw_any_signer_sign, tw_any_signer_sign_batch};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
 use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::test_utils::tw_data_vector_helper::TWDataVectorHelper;
+use tw_proto::deserialize;
+use tw_proto::BatchSigner::Proto as BatchProto;
 
 #[test]
 fn test_any_signer_sign_unknown_coin() {
@@ -14,3 +19,31 @@ fn test_any_signer_sign_unknown_coin() {
         TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), unsupported_coin) });
     assert!(output.is_null());
 }
+
+#[test]
+fn test_any_signer_sign_batch_unknown_coin() {
+    let unsupported_coin = u32::MAX;
+
+    let inputs = TWDataVectorHelper::create([vec![]]);
+    let output =
+        TWDataHelper::wrap(unsafe { tw_any_signer_sign_batch(inputs.ptr(), unsupported_coin) })
+            .to_vec()
+            .expect("!tw_any_signer_sign_batch returned nullptr");
+
+    let output: BatchProto::BatchSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+    assert!(!output.error_message.is_empty());
+    assert!(output.results.is_empty());
+}
+
+#[test]
+fn test_any_signer_sign_batch_no_inputs() {
+    let output = TWDataHelper::wrap(unsafe {
+        tw_any_signer_sign_batch(std::ptr::null(), CoinType::Ethereum as u32)
+    })
+    .to_vec()
+    .expect("!tw_any_signer_sign_batch returned nullptr");
+
+    let output: BatchProto::BatchSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
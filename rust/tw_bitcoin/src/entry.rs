@@ -1,14 +1,17 @@
 use crate::modules::compiler::BitcoinCompiler;
+use crate::modules::message_signer::BitcoinMessageSigner;
 use crate::modules::planner::BitcoinPlanner;
 use crate::modules::signer::BitcoinSigner;
+use crate::modules::transaction_decoder::BitcoinTransactionDecoder;
+use crate::modules::transaction_hasher::BitcoinTransactionHasher;
+use crate::modules::transaction_summarizer::BitcoinTransactionSummarizer;
+use crate::modules::transaction_verifier::BitcoinTransactionVerifier;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
-use tw_coin_entry::modules::json_signer::NoJsonSigner;
-use tw_coin_entry::modules::message_signer::NoMessageSigner;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
+use tw_coin_entry::modules::json_signer::ProtoJsonSigner;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_keypair::tw::PublicKey;
 use tw_proto::BitcoinV2::Proto;
@@ -24,11 +27,14 @@ impl CoinEntry for BitcoinEntry {
     type PreSigningOutput = Proto::PreSigningOutput<'static>;
 
     // Optional modules:
-    type JsonSigner = NoJsonSigner;
+    type JsonSigner = ProtoJsonSigner<Self>;
     type PlanBuilder = BitcoinPlanner;
-    type MessageSigner = NoMessageSigner;
+    type MessageSigner = BitcoinMessageSigner;
     type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type TransactionDecoder = BitcoinTransactionDecoder;
+    type TransactionVerifier = BitcoinTransactionVerifier;
+    type TransactionHasher = BitcoinTransactionHasher;
+    type TransactionSummarizer = BitcoinTransactionSummarizer;
 
     #[inline]
     fn parse_address(
@@ -85,8 +91,38 @@ impl CoinEntry for BitcoinEntry {
         BitcoinCompiler::compile(coin, proto, signatures, public_keys)
     }
 
+    #[inline]
+    fn json_signer(&self) -> Option<Self::JsonSigner> {
+        Some(ProtoJsonSigner::new(BitcoinEntry))
+    }
+
     #[inline]
     fn plan_builder(&self) -> Option<Self::PlanBuilder> {
         Some(BitcoinPlanner)
     }
+
+    #[inline]
+    fn message_signer(&self) -> Option<Self::MessageSigner> {
+        Some(BitcoinMessageSigner)
+    }
+
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(BitcoinTransactionDecoder)
+    }
+
+    #[inline]
+    fn transaction_verifier(&self) -> Option<Self::TransactionVerifier> {
+        Some(BitcoinTransactionVerifier)
+    }
+
+    #[inline]
+    fn transaction_hasher(&self) -> Option<Self::TransactionHasher> {
+        Some(BitcoinTransactionHasher)
+    }
+
+    #[inline]
+    fn transaction_summarizer(&self) -> Option<Self::TransactionSummarizer> {
+        Some(BitcoinTransactionSummarizer)
+    }
 }
@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::base64;
+use tw_hash::hasher::{sha256_d, sha256_ripemd};
+use tw_hash::{H160, H256};
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_memory::Data;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+use tw_utxo::address::legacy::LegacyAddress;
+use tw_utxo::address::segwit::SegwitAddress;
+use tw_utxo::address::standard_bitcoin::StandardBitcoinAddress;
+use tw_utxo::encode::stream::Stream;
+use tw_utxo::script::standard_script::conditions;
+
+/// The magic prefix of a "Bitcoin Signed Message" preimage.
+pub const MESSAGE_PREFIX: &str = "Bitcoin Signed Message:\n";
+
+/// The length of a compact signature: header byte + R + S.
+const COMPACT_SIGNATURE_LEN: usize = 65;
+/// Each address type occupies 4 consecutive header values, one per recovery ID.
+const RECOVERY_IDS_COUNT: u8 = 4;
+
+const HEADER_P2PKH_UNCOMPRESSED: u8 = 27;
+const HEADER_P2PKH_COMPRESSED: u8 = 31;
+const HEADER_P2SH_P2WPKH: u8 = 35;
+const HEADER_P2WPKH: u8 = 39;
+
+/// Signs and verifies messages in the "Bitcoin Signed Message" format (also known as BIP-137 or Electrum format).
+/// https://github.com/bitcoin/bips/blob/master/bip-0137.mediawiki
+pub struct Bip137Signer;
+
+impl Bip137Signer {
+    pub fn preimage_hashes(
+        input: &Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let preimage = Self::message_preimage(&input.message);
+        let preimage_hash = Self::message_hash(&input.message);
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::from(preimage),
+            data_hash: Cow::from(preimage_hash.into_vec()),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    pub fn sign(
+        coin: &dyn CoinContext,
+        input: &Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())?;
+        let address = Self::derive_address(coin, &private_key.public(), input.address_type)?;
+
+        let signature = private_key.sign(Self::message_hash(&input.message))?;
+
+        let mut compact_signature = Vec::with_capacity(COMPACT_SIGNATURE_LEN);
+        compact_signature.push(Self::header_base(input.address_type) + signature.v());
+        compact_signature.extend_from_slice(signature.r().as_slice());
+        compact_signature.extend_from_slice(signature.s().as_slice());
+
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::from(base64::encode(&compact_signature, false)),
+            address: Cow::from(address.to_string()),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    pub fn verify(
+        coin: &dyn CoinContext,
+        input: &Proto::MessageVerifyingInput<'_>,
+    ) -> SigningResult<bool> {
+        let expected_address = StandardBitcoinAddress::from_str_checked(coin, &input.address)?;
+
+        let compact_signature = base64::decode(&input.signature, false)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Invalid Base64 signature")?;
+        if compact_signature.len() != COMPACT_SIGNATURE_LEN {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Compact signature must be 65 bytes long");
+        }
+
+        let (header_type, recovery_id) = Self::parse_header(compact_signature[0])?;
+        let address_type = Self::resolve_address_type(coin, header_type, &expected_address);
+
+        let r = H256::try_from(&compact_signature[1..33]).expect("Expected 32 bytes");
+        let s = H256::try_from(&compact_signature[33..65]).expect("Expected 32 bytes");
+        let signature = secp256k1::Signature::try_from_parts(r, s, recovery_id)?;
+
+        let public_key =
+            secp256k1::PublicKey::recover(signature, Self::message_hash(&input.message))?;
+        let recovered_address = Self::derive_address(coin, &public_key, address_type)?;
+        Ok(recovered_address == expected_address)
+    }
+
+    /// Returns `varint(prefix.len()) || prefix || varint(message.len()) || message`.
+    fn message_preimage(message: &str) -> Data {
+        let mut stream = Stream::new();
+        stream
+            .append(&MESSAGE_PREFIX.as_bytes().to_vec())
+            .append(&message.as_bytes().to_vec());
+        stream.out()
+    }
+
+    fn message_hash(message: &str) -> H256 {
+        let hash = sha256_d(&Self::message_preimage(message));
+        H256::try_from(hash.as_slice()).expect("sha256d must return 32 bytes")
+    }
+
+    fn header_base(address_type: Proto::MessageAddressType) -> u8 {
+        match address_type {
+            Proto::MessageAddressType::P2PKHCompressed => HEADER_P2PKH_COMPRESSED,
+            Proto::MessageAddressType::P2PKHUncompressed => HEADER_P2PKH_UNCOMPRESSED,
+            Proto::MessageAddressType::P2SHP2WPKH => HEADER_P2SH_P2WPKH,
+            Proto::MessageAddressType::P2WPKH => HEADER_P2WPKH,
+        }
+    }
+
+    /// Returns the address type and the recovery ID encoded into the header byte.
+    fn parse_header(header: u8) -> SigningResult<(Proto::MessageAddressType, u8)> {
+        let Some(offset) = header.checked_sub(HEADER_P2PKH_UNCOMPRESSED) else {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context(format!("Invalid signature header byte: {header}"));
+        };
+
+        let recovery_id = offset % RECOVERY_IDS_COUNT;
+        let address_type = match header - recovery_id {
+            HEADER_P2PKH_UNCOMPRESSED => Proto::MessageAddressType::P2PKHUncompressed,
+            HEADER_P2PKH_COMPRESSED => Proto::MessageAddressType::P2PKHCompressed,
+            HEADER_P2SH_P2WPKH => Proto::MessageAddressType::P2SHP2WPKH,
+            HEADER_P2WPKH => Proto::MessageAddressType::P2WPKH,
+            _ => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context(format!("Invalid signature header byte: {header}"))
+            },
+        };
+        Ok((address_type, recovery_id))
+    }
+
+    /// Electrum and some hardware wallets sign messages for segwit addresses using the P2PKH compressed header,
+    /// so in that case the address type is inferred from the expected address.
+    fn resolve_address_type(
+        coin: &dyn CoinContext,
+        header_type: Proto::MessageAddressType,
+        expected_address: &StandardBitcoinAddress,
+    ) -> Proto::MessageAddressType {
+        if header_type != Proto::MessageAddressType::P2PKHCompressed {
+            return header_type;
+        }
+        match expected_address {
+            StandardBitcoinAddress::Segwit(_) => Proto::MessageAddressType::P2WPKH,
+            StandardBitcoinAddress::Legacy(legacy)
+                if Some(legacy.prefix()) == coin.p2sh_prefix() =>
+            {
+                Proto::MessageAddressType::P2SHP2WPKH
+            },
+            _ => header_type,
+        }
+    }
+
+    fn derive_address(
+        coin: &dyn CoinContext,
+        public_key: &secp256k1::PublicKey,
+        address_type: Proto::MessageAddressType,
+    ) -> SigningResult<StandardBitcoinAddress> {
+        let compressed_key_hash = sha256_ripemd(public_key.compressed().as_slice());
+
+        let address = match address_type {
+            Proto::MessageAddressType::P2PKHCompressed => {
+                let p2pkh_prefix = coin.p2pkh_prefix().ok_or(AddressError::InvalidRegistry)?;
+                StandardBitcoinAddress::Legacy(LegacyAddress::new(
+                    p2pkh_prefix,
+                    &compressed_key_hash,
+                )?)
+            },
+            Proto::MessageAddressType::P2PKHUncompressed => {
+                let p2pkh_prefix = coin.p2pkh_prefix().ok_or(AddressError::InvalidRegistry)?;
+                let uncompressed_key_hash = sha256_ripemd(public_key.uncompressed().as_slice());
+                StandardBitcoinAddress::Legacy(LegacyAddress::new(
+                    p2pkh_prefix,
+                    &uncompressed_key_hash,
+                )?)
+            },
+            Proto::MessageAddressType::P2SHP2WPKH => {
+                let p2sh_prefix = coin.p2sh_prefix().ok_or(AddressError::InvalidRegistry)?;
+                let pubkey_hash = H160::try_from(compressed_key_hash.as_slice())
+                    .expect("sha256ripemd must return 20 bytes");
+                let redeem_script = conditions::new_p2wpkh(&pubkey_hash);
+                StandardBitcoinAddress::Legacy(LegacyAddress::p2sh_with_prefix_byte(
+                    &redeem_script,
+                    p2sh_prefix,
+                )?)
+            },
+            Proto::MessageAddressType::P2WPKH => {
+                let hrp = coin.hrp().ok_or(AddressError::InvalidRegistry)?;
+                StandardBitcoinAddress::Segwit(SegwitAddress::new(hrp, compressed_key_hash)?)
+            },
+        };
+        Ok(address)
+    }
+}
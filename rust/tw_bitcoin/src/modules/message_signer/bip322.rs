@@ -0,0 +1,439 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::spender::{
+    is_p2sh_p2wpkh_script_sig, new_p2wsh, parse_multisig_script, parse_p2pkh_script_sig,
+    split_ecdsa_signature, split_schnorr_signature, Spender,
+};
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::base64;
+use tw_hash::hasher::sha256_d;
+use tw_hash::sha2::sha256;
+use tw_hash::{H160, H256};
+use tw_keypair::{ecdsa, schnorr};
+use tw_proto::BitcoinV2::Proto;
+use tw_utxo::address::standard_bitcoin::StandardBitcoinAddress;
+use tw_utxo::encode::{decode, encode};
+use tw_utxo::modules::keys_manager::KeysManager;
+use tw_utxo::modules::sighash_computer::{SighashComputer, TxPreimage};
+use tw_utxo::modules::sighash_verifier::SighashVerifier;
+use tw_utxo::modules::tx_signer::TxSigner;
+use tw_utxo::script::standard_script::conditions;
+use tw_utxo::script::standard_script::opcodes::{OP_PUSHBYTES_0, OP_RETURN};
+use tw_utxo::script::{Script, Witness};
+use tw_utxo::sighash::SighashType;
+use tw_utxo::transaction::standard_transaction::builder::{TransactionBuilder, UtxoBuilder};
+use tw_utxo::transaction::standard_transaction::{
+    Transaction, TransactionInput, TransactionOutput,
+};
+use tw_utxo::transaction::transaction_parts::OutPoint;
+use tw_utxo::transaction::unsigned_transaction::UnsignedTransaction;
+use tw_utxo::transaction::UtxoToSign;
+
+/// The tag of the BIP-322 message hash.
+const MESSAGE_HASH_TAG: &[u8] = b"BIP0322-signed-message";
+/// Both virtual transactions have version 0.
+const VIRTUAL_TX_VERSION: u32 = 0;
+/// Both virtual transaction inputs have sequence 0.
+const VIRTUAL_TX_SEQUENCE: u32 = 0;
+
+/// Signs and verifies BIP-322 generic message proofs of P2PKH, P2SH-P2WPKH, P2WPKH, P2WSH and P2TR (key-path) addresses.
+/// https://github.com/bitcoin/bips/blob/master/bip-0322.mediawiki
+///
+/// The proof is a `to_sign` virtual transaction spending the output of a `to_spend` virtual transaction,
+/// which commits to the message hash and the address script pubkey (aka `message_challenge`).
+/// Please note the "simple" format can only be used for segwit (including P2SH-P2WPKH) and taproot addresses.
+/// A P2SH-P2WPKH `to_sign` scriptSig is not a part of the simple proof, it's implied by the witness public key.
+///
+/// P2WSH proofs can be signed for a `<pubkey> OP_CHECKSIG` witness script only,
+/// while `OP_m <pubkey_1> ... <pubkey_n> OP_n OP_CHECKMULTISIG` witness scripts are supported by the verifier too.
+pub struct Bip322Signer;
+
+impl Bip322Signer {
+    pub fn sign(
+        coin: &dyn CoinContext,
+        input: &Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let address = StandardBitcoinAddress::from_str_checked(coin, &input.address)?;
+        let message_challenge = Self::message_challenge(coin, &address)?;
+
+        let ecdsa_private = ecdsa::secp256k1::PrivateKey::try_from(input.private_key.as_ref())?;
+        let mut keys_manager = KeysManager::default();
+
+        let spender = match address {
+            StandardBitcoinAddress::Legacy(_) if conditions::is_p2sh(&message_challenge) => {
+                Spender::P2SHP2WPKH(ecdsa_private.public())
+            },
+            StandardBitcoinAddress::Legacy(_) => Spender::P2PKH(ecdsa_private.public()),
+            StandardBitcoinAddress::Segwit(_) if conditions::is_p2wsh(&message_challenge) => {
+                Spender::P2WSH(ecdsa_private.public())
+            },
+            StandardBitcoinAddress::Segwit(_) => Spender::P2WPKH(ecdsa_private.public()),
+            StandardBitcoinAddress::Taproot(_) => {
+                let schnorr_private = schnorr::PrivateKey::try_from(input.private_key.as_ref())?;
+                let tweaked_pubkey = schnorr_private.public().tweak(None).x_only();
+                if input.dangerous_use_fixed_schnorr_rng {
+                    keys_manager.add_schnorr_private(schnorr_private.no_aux_rand());
+                } else {
+                    keys_manager.add_schnorr_private(schnorr_private);
+                }
+                Spender::P2TR(tweaked_pubkey)
+            },
+        };
+        keys_manager.add_ecdsa_private(ecdsa_private);
+
+        if spender.script_pubkey() != message_challenge {
+            return SigningError::err(SigningErrorType::Error_invalid_address)
+                .context("The given private key does not correspond to the address");
+        }
+        if input.format == Proto::MessageFormat::BIP322Simple {
+            if let Spender::P2PKH(_) = spender {
+                return SigningError::err(SigningErrorType::Error_not_supported).context(
+                    "BIP-322 simple format is not supported for legacy addresses. Consider using the full format",
+                );
+            }
+        }
+
+        let to_spend = Self::to_spend(&message_challenge, &input.message);
+        let unsigned_to_sign = Self::to_sign(&to_spend, &spender, SighashType::default())?;
+        let to_sign = TxSigner::sign_tx(unsigned_to_sign, &keys_manager)
+            .context("Error signing BIP-322 'to_sign' transaction")?;
+
+        let proof = match input.format {
+            Proto::MessageFormat::BIP322Full => to_sign.encode_out(),
+            _ => encode(&to_sign.inputs[0].witness),
+        };
+
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::from(base64::encode(&proof, false)),
+            address: Cow::from(address.to_string()),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    pub fn verify(
+        coin: &dyn CoinContext,
+        input: &Proto::MessageVerifyingInput<'_>,
+    ) -> SigningResult<bool> {
+        let address = StandardBitcoinAddress::from_str_checked(coin, &input.address)?;
+        let message_challenge = Self::message_challenge(coin, &address)?;
+
+        let proof = base64::decode(&input.signature, false)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Invalid Base64 signature")?;
+
+        let to_spend = Self::to_spend(&message_challenge, &input.message);
+        let (script_sig, witness) = match input.format {
+            Proto::MessageFormat::BIP322Full => {
+                let to_sign: Transaction = decode(&proof)?;
+                let to_sign_input = Self::check_to_sign(&to_sign, &to_spend)?;
+                (
+                    Some(to_sign_input.script_sig.clone()),
+                    to_sign_input.witness.clone(),
+                )
+            },
+            _ => (None, decode::<Witness>(&proof)?),
+        };
+
+        if let StandardBitcoinAddress::Segwit(_) = address {
+            let witness_script = witness.as_items().last();
+            if let Some((threshold, public_keys)) = witness_script.and_then(parse_multisig_script) {
+                return Self::verify_multisig(
+                    &to_spend,
+                    &message_challenge,
+                    script_sig.as_ref(),
+                    &witness,
+                    threshold,
+                    &public_keys,
+                );
+            }
+        }
+
+        let (spender, signature, sighash_ty) =
+            Self::parse_spending_data(&address, script_sig.as_ref(), &witness)?;
+        if spender.script_pubkey() != message_challenge {
+            return Ok(false);
+        }
+
+        let unsigned_to_sign = Self::to_sign(&to_spend, &spender, sighash_ty)?;
+        let TxPreimage { sighashes } = SighashComputer::preimage_tx(&unsigned_to_sign)?;
+
+        let utxo_args = &unsigned_to_sign.input_args()[0];
+        Ok(
+            SighashVerifier::<Transaction>::verify_signature(&sighashes[0], utxo_args, &signature)
+                .is_ok(),
+        )
+    }
+
+    /// Returns `SHA256(SHA256(tag) || SHA256(tag) || message)`.
+    pub fn message_hash(message: &str) -> H256 {
+        let tag_hash = sha256(MESSAGE_HASH_TAG);
+
+        let mut preimage = Vec::with_capacity(tag_hash.len() * 2 + message.len());
+        preimage.extend_from_slice(&tag_hash);
+        preimage.extend_from_slice(&tag_hash);
+        preimage.extend_from_slice(message.as_bytes());
+
+        H256::try_from(sha256(&preimage).as_slice()).expect("sha256 must return 32 bytes")
+    }
+
+    /// Returns the script pubkey of the address (aka `message_challenge`) if supported.
+    fn message_challenge(
+        coin: &dyn CoinContext,
+        address: &StandardBitcoinAddress,
+    ) -> SigningResult<Script> {
+        match address {
+            StandardBitcoinAddress::Legacy(legacy)
+                if Some(legacy.prefix()) == coin.p2pkh_prefix() =>
+            {
+                Ok(conditions::new_p2pkh(&legacy.payload()))
+            },
+            // The address is expected to be P2SH-P2WPKH. Otherwise, it won't match the spender script pubkey.
+            StandardBitcoinAddress::Legacy(legacy)
+                if Some(legacy.prefix()) == coin.p2sh_prefix() =>
+            {
+                Ok(conditions::new_p2sh(&legacy.payload()))
+            },
+            StandardBitcoinAddress::Segwit(segwit) => {
+                let witness_program = segwit.witness_program();
+                if let Ok(script_hash) = H256::try_from(witness_program) {
+                    return Ok(conditions::new_p2wsh(&script_hash));
+                }
+                let pubkey_hash = H160::try_from(witness_program)
+                    .tw_err(|_| SigningErrorType::Error_not_supported)
+                    .context(
+                        "Only P2WPKH and P2WSH segwit addresses are supported by BIP-322 signer",
+                    )?;
+                Ok(conditions::new_p2wpkh(&pubkey_hash))
+            },
+            StandardBitcoinAddress::Taproot(taproot) => {
+                let tweaked_pubkey = H256::try_from(taproot.witness_program())
+                    .tw_err(|_| SigningErrorType::Error_invalid_address)
+                    .context("Taproot address must have a 32 bytes witness program")?;
+                Ok(conditions::new_p2tr_dangerous_assume_tweaked(
+                    &tweaked_pubkey,
+                ))
+            },
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Unexpected legacy address prefix"),
+        }
+    }
+
+    /// Builds the `to_spend` virtual transaction.
+    fn to_spend(message_challenge: &Script, message: &str) -> Transaction {
+        let mut script_sig = Script::new();
+        script_sig.push(OP_PUSHBYTES_0);
+        script_sig.push_slice(Self::message_hash(message).as_slice());
+
+        Transaction {
+            version: VIRTUAL_TX_VERSION as i32,
+            inputs: vec![TransactionInput {
+                previous_output: OutPoint {
+                    hash: H256::default(),
+                    index: u32::MAX,
+                },
+                sequence: VIRTUAL_TX_SEQUENCE,
+                script_sig,
+                witness: Witness::default(),
+            }],
+            outputs: vec![TransactionOutput {
+                value: 0,
+                script_pubkey: message_challenge.clone(),
+            }],
+            locktime: 0,
+        }
+    }
+
+    /// Verifies a `[<empty>, <sig_1>, ..., <sig_m>, <witness_script>]` proof of a P2WSH multisig address.
+    /// As with `OP_CHECKMULTISIG`, the signatures must be ordered the same way as their public keys.
+    fn verify_multisig(
+        to_spend: &Transaction,
+        message_challenge: &Script,
+        script_sig: Option<&Script>,
+        witness: &Witness,
+        threshold: usize,
+        public_keys: &[ecdsa::secp256k1::PublicKey],
+    ) -> SigningResult<bool> {
+        let invalid_spending_data = || {
+            SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Unexpected BIP-322 scriptSig or witness")
+        };
+
+        // The first item is the dummy element consumed by `OP_CHECKMULTISIG`.
+        let [dummy, signatures @ .., witness_script] = witness.as_items() else {
+            return invalid_spending_data();
+        };
+        if !dummy.is_empty() || script_sig.is_some_and(|script_sig| !script_sig.is_empty()) {
+            return invalid_spending_data();
+        }
+        if new_p2wsh(witness_script) != *message_challenge {
+            return Ok(false);
+        }
+        if signatures.len() != threshold {
+            return Ok(false);
+        }
+        let Some(any_public_key) = public_keys.first() else {
+            return Ok(false);
+        };
+
+        let mut public_keys = public_keys.iter();
+        for signature in signatures {
+            let (signature, sighash_ty) = split_ecdsa_signature(signature.as_slice())?;
+
+            // The sighash doesn't depend on the public key, but on the witness script only.
+            let utxo_builder = Self::to_sign_utxo_builder(to_spend, sighash_ty);
+            let (utxo, utxo_args) = utxo_builder.p2wsh(witness_script.clone(), any_public_key)?;
+            let unsigned_to_sign = Self::build_to_sign(utxo, utxo_args)?;
+            let TxPreimage { sighashes } = SighashComputer::preimage_tx(&unsigned_to_sign)?;
+
+            let is_signed_by_next_key = public_keys.any(|public_key| {
+                SighashVerifier::<Transaction>::verify_ecdsa_signature(
+                    &sighashes[0],
+                    public_key.compressed().as_slice(),
+                    &signature,
+                )
+                .is_ok()
+            });
+            if !is_signed_by_next_key {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Builds the unsigned `to_sign` virtual transaction spending the `to_spend` output.
+    fn to_sign(
+        to_spend: &Transaction,
+        spender: &Spender,
+        sighash_ty: SighashType,
+    ) -> SigningResult<UnsignedTransaction<Transaction>> {
+        let utxo_builder = Self::to_sign_utxo_builder(to_spend, sighash_ty);
+        let (utxo, utxo_args) = spender.build_utxo(utxo_builder)?;
+        Self::build_to_sign(utxo, utxo_args)
+    }
+
+    /// Returns the builder of the `to_sign` input spending the `to_spend` output.
+    fn to_sign_utxo_builder(to_spend: &Transaction, sighash_ty: SighashType) -> UtxoBuilder {
+        UtxoBuilder::new()
+            .prev_txid(Self::tx_hash(to_spend))
+            .prev_index(0)
+            .sequence(VIRTUAL_TX_SEQUENCE)
+            .amount(0)
+            .sighash_type(sighash_ty)
+    }
+
+    /// Builds the unsigned `to_sign` virtual transaction with the given input.
+    fn build_to_sign(
+        utxo: TransactionInput,
+        utxo_args: UtxoToSign,
+    ) -> SigningResult<UnsignedTransaction<Transaction>> {
+        let mut op_return = Script::new();
+        op_return.push(OP_RETURN);
+
+        let mut builder = TransactionBuilder::new();
+        builder
+            .version(VIRTUAL_TX_VERSION)
+            .push_input(utxo, utxo_args)
+            .push_output(TransactionOutput {
+                value: 0,
+                script_pubkey: op_return,
+            });
+        builder.build()
+    }
+
+    /// Checks if the given `to_sign` transaction spends the `to_spend` output,
+    /// and returns the only `to_sign` input.
+    fn check_to_sign<'a>(
+        to_sign: &'a Transaction,
+        to_spend: &Transaction,
+    ) -> SigningResult<&'a TransactionInput> {
+        let [to_sign_input] = to_sign.inputs.as_slice() else {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("BIP-322 proofs of funds are not supported");
+        };
+        let is_valid = to_sign.version == VIRTUAL_TX_VERSION as i32
+            && to_sign.locktime == 0
+            && to_sign_input.previous_output.hash == Self::tx_hash(to_spend)
+            && to_sign_input.previous_output.index == 0
+            && to_sign_input.sequence == VIRTUAL_TX_SEQUENCE
+            && to_sign.outputs.len() == 1
+            && to_sign.outputs[0].value == 0
+            && to_sign.outputs[0].script_pubkey.as_slice() == [OP_RETURN];
+        if !is_valid {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Invalid BIP-322 'to_sign' transaction");
+        }
+        Ok(to_sign_input)
+    }
+
+    /// Returns the spender, the signature without a sighash type, and the sighash type.
+    /// `script_sig` is `None` if the proof is given in the simple format, i.e as a witness only.
+    fn parse_spending_data(
+        address: &StandardBitcoinAddress,
+        script_sig: Option<&Script>,
+        witness: &Witness,
+    ) -> SigningResult<(Spender, Vec<u8>, SighashType)> {
+        let invalid_spending_data = || {
+            SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Unexpected BIP-322 scriptSig or witness")
+        };
+        let empty_script_sig = script_sig.map_or(true, Script::is_empty);
+
+        match (address, witness.as_items()) {
+            // <sig> <pubkey>
+            (StandardBitcoinAddress::Legacy(_), []) => {
+                let Some((signature, public_key)) = script_sig.and_then(parse_p2pkh_script_sig)
+                else {
+                    return invalid_spending_data();
+                };
+                let public_key = ecdsa::secp256k1::PublicKey::try_from(public_key)?;
+                let (signature, sighash_ty) = split_ecdsa_signature(signature)?;
+                Ok((Spender::P2PKH(public_key), signature, sighash_ty))
+            },
+            // <redeem_script> [<sig>, <pubkey>]
+            (StandardBitcoinAddress::Legacy(_), [signature, public_key]) => {
+                let public_key = ecdsa::secp256k1::PublicKey::try_from(public_key.as_slice())?;
+                if let Some(script_sig) = script_sig {
+                    if !is_p2sh_p2wpkh_script_sig(script_sig, &public_key) {
+                        return invalid_spending_data();
+                    }
+                }
+                let (signature, sighash_ty) = split_ecdsa_signature(signature.as_slice())?;
+                Ok((Spender::P2SHP2WPKH(public_key), signature, sighash_ty))
+            },
+            // [<sig>, <pubkey OP_CHECKSIG>]
+            (StandardBitcoinAddress::Segwit(segwit), [signature, witness_script])
+                if empty_script_sig && segwit.witness_program().len() == H256::LEN =>
+            {
+                let Some(public_key) = conditions::match_p2pk(witness_script) else {
+                    return invalid_spending_data();
+                };
+                let public_key = ecdsa::secp256k1::PublicKey::try_from(public_key.as_slice())?;
+                let (signature, sighash_ty) = split_ecdsa_signature(signature.as_slice())?;
+                Ok((Spender::P2WSH(public_key), signature, sighash_ty))
+            },
+            // [<sig>, <pubkey>]
+            (StandardBitcoinAddress::Segwit(_), [signature, public_key]) if empty_script_sig => {
+                let public_key = ecdsa::secp256k1::PublicKey::try_from(public_key.as_slice())?;
+                let (signature, sighash_ty) = split_ecdsa_signature(signature.as_slice())?;
+                Ok((Spender::P2WPKH(public_key), signature, sighash_ty))
+            },
+            // [<sig>]
+            (StandardBitcoinAddress::Taproot(taproot), [signature]) if empty_script_sig => {
+                let tweaked_pubkey = schnorr::XOnlyPublicKey::try_from(taproot.witness_program())?;
+                let (signature, sighash_ty) = split_schnorr_signature(signature.as_slice())?;
+                Ok((Spender::P2TR(tweaked_pubkey), signature, sighash_ty))
+            },
+            _ => invalid_spending_data(),
+        }
+    }
+
+    /// Returns the transaction hash in the internal byte order, as referenced by `OutPoint::hash`.
+    fn tx_hash(tx: &Transaction) -> H256 {
+        let hash = sha256_d(&tx.encode_out());
+        H256::try_from(hash.as_slice()).expect("sha256d must return 32 bytes")
+    }
+}
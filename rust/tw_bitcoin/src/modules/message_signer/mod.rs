@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::message_signer::bip137::Bip137Signer;
+use crate::modules::message_signer::bip322::Bip322Signer;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+pub mod bip137;
+pub mod bip322;
+
+/// Signs and verifies Bitcoin messages in one of the [`Proto::MessageFormat`] formats.
+pub struct BitcoinMessageSigner;
+
+impl MessageSigner for BitcoinMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(coin, input).unwrap_or_default()
+    }
+}
+
+impl BitcoinMessageSigner {
+    fn message_preimage_hashes_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        match input.format {
+            Proto::MessageFormat::BIP137 => Bip137Signer::preimage_hashes(&input),
+            Proto::MessageFormat::BIP322Simple | Proto::MessageFormat::BIP322Full => {
+                SigningError::err(SigningErrorType::Error_not_supported)
+                    .context("BIP-322 proofs can only be signed with a private key")
+            },
+        }
+    }
+
+    fn sign_message_impl(
+        coin: &dyn CoinContext,
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        match input.format {
+            Proto::MessageFormat::BIP137 => Bip137Signer::sign(coin, &input),
+            Proto::MessageFormat::BIP322Simple | Proto::MessageFormat::BIP322Full => {
+                Bip322Signer::sign(coin, &input)
+            },
+        }
+    }
+
+    fn verify_message_impl(
+        coin: &dyn CoinContext,
+        input: Proto::MessageVerifyingInput<'_>,
+    ) -> SigningResult<bool> {
+        match input.format {
+            Proto::MessageFormat::BIP137 => Bip137Signer::verify(coin, &input),
+            Proto::MessageFormat::BIP322Simple | Proto::MessageFormat::BIP322Full => {
+                Bip322Signer::verify(coin, &input)
+            },
+        }
+    }
+}
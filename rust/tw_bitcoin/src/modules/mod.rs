@@ -3,8 +3,14 @@
 // Copyright © 2017 Trust Wallet.
 
 pub mod compiler;
+pub mod message_signer;
 pub mod planner;
 pub mod protobuf_builder;
 pub mod signer;
 pub mod signing_request;
+pub mod spender;
+pub mod transaction_decoder;
+pub mod transaction_hasher;
+pub mod transaction_summarizer;
+pub mod transaction_verifier;
 pub mod tx_builder;
@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Single-key spenders and the helpers to parse their scriptSig and witness,
+//! shared by the BIP-322 message signer and the transaction verifier.
+
+use tw_coin_entry::error::prelude::*;
+use tw_hash::ripemd::bitcoin_hash_160;
+use tw_hash::sha2::sha256;
+use tw_hash::{H160, H256};
+use tw_keypair::{ecdsa, schnorr};
+use tw_utxo::script::standard_script::opcodes::{OP_CHECKMULTISIG, OP_PUSHNUM_1, OP_PUSHNUM_16};
+use tw_utxo::script::standard_script::{claims, conditions};
+use tw_utxo::script::Script;
+use tw_utxo::sighash::SighashType;
+use tw_utxo::transaction::standard_transaction::builder::UtxoBuilder;
+use tw_utxo::transaction::standard_transaction::TransactionInput;
+use tw_utxo::transaction::UtxoToSign;
+
+/// The max length of an item pushed by a single `OP_PUSHBYTES_N` opcode.
+const MAX_PUSHBYTES_LEN: usize = 75;
+
+/// A single-key spender of a script pubkey.
+pub enum Spender {
+    P2PKH(ecdsa::secp256k1::PublicKey),
+    P2WPKH(ecdsa::secp256k1::PublicKey),
+    /// Nested segwit spender, i.e P2WPKH wrapped in P2SH.
+    P2SHP2WPKH(ecdsa::secp256k1::PublicKey),
+    /// P2WSH spender with a `<pubkey> OP_CHECKSIG` witness script.
+    P2WSH(ecdsa::secp256k1::PublicKey),
+    /// Taproot key-path spender with an already tweaked public key.
+    P2TR(schnorr::XOnlyPublicKey),
+}
+
+impl Spender {
+    /// Returns the script pubkey the spender is able to spend.
+    pub fn script_pubkey(&self) -> Script {
+        match self {
+            Spender::P2PKH(public_key) => conditions::new_p2pkh(&Self::pubkey_hash(public_key)),
+            Spender::P2WPKH(public_key) => conditions::new_p2wpkh(&Self::pubkey_hash(public_key)),
+            Spender::P2SHP2WPKH(public_key) => {
+                let redeem_script = conditions::new_p2wpkh(&Self::pubkey_hash(public_key));
+                let redeem_hash = bitcoin_hash_160(redeem_script.as_slice());
+                conditions::new_p2sh(
+                    &H160::try_from(redeem_hash.as_slice()).expect("hash length is 20 bytes"),
+                )
+            },
+            Spender::P2WSH(public_key) => new_p2wsh(&Self::p2wsh_witness_script(public_key)),
+            Spender::P2TR(tweaked_pubkey) => {
+                conditions::new_p2tr_dangerous_assume_tweaked(&tweaked_pubkey.bytes())
+            },
+        }
+    }
+
+    /// Builds an unsigned input spending the [`Spender::script_pubkey`].
+    pub fn build_utxo(
+        &self,
+        utxo_builder: UtxoBuilder,
+    ) -> SigningResult<(TransactionInput, UtxoToSign)> {
+        match self {
+            Spender::P2PKH(public_key) => utxo_builder.p2pkh(public_key),
+            Spender::P2WPKH(public_key) => utxo_builder.p2wpkh(public_key),
+            Spender::P2SHP2WPKH(public_key) => utxo_builder.p2sh_p2wpkh(public_key),
+            Spender::P2WSH(public_key) => {
+                utxo_builder.p2wsh(Self::p2wsh_witness_script(public_key), public_key)
+            },
+            Spender::P2TR(tweaked_pubkey) => {
+                utxo_builder.p2tr_key_path_with_tweaked_pubkey(tweaked_pubkey)
+            },
+        }
+    }
+
+    /// Returns the `<pubkey> OP_CHECKSIG` witness script of the [`Spender::P2WSH`] spender.
+    pub fn p2wsh_witness_script(public_key: &ecdsa::secp256k1::PublicKey) -> Script {
+        conditions::new_p2pk(&public_key.compressed())
+    }
+
+    fn pubkey_hash(public_key: &ecdsa::secp256k1::PublicKey) -> H160 {
+        let hash = bitcoin_hash_160(public_key.compressed().as_slice());
+        H160::try_from(hash.as_slice()).expect("hash length is 20 bytes")
+    }
+}
+
+/// Parses a `<push><sig><push><pubkey>` script.
+pub fn parse_p2pkh_script_sig(script_sig: &Script) -> Option<(&[u8], &[u8])> {
+    let (signature, rest) = read_pushbytes(script_sig.as_slice())?;
+    let (public_key, rest) = read_pushbytes(rest)?;
+    rest.is_empty().then_some((signature, public_key))
+}
+
+/// Checks if the script is a `<push><OP_0 <push><pubkey_hash>>` P2SH-P2WPKH scriptSig of the public key.
+pub fn is_p2sh_p2wpkh_script_sig(
+    script_sig: &Script,
+    public_key: &ecdsa::secp256k1::PublicKey,
+) -> bool {
+    *script_sig == claims::new_p2sh_p2wpkh(&Spender::pubkey_hash(public_key))
+}
+
+/// Returns the P2WSH script pubkey of the witness script.
+pub fn new_p2wsh(witness_script: &Script) -> Script {
+    let hash = sha256(witness_script.as_slice());
+    conditions::new_p2wsh(&H256::try_from(hash.as_slice()).expect("hash length is 32 bytes"))
+}
+
+/// Parses an `OP_m <pubkey_1> ... <pubkey_n> OP_n OP_CHECKMULTISIG` script.
+/// Returns the number of required signatures and the public keys.
+pub fn parse_multisig_script(script: &Script) -> Option<(usize, Vec<ecdsa::secp256k1::PublicKey>)> {
+    let (op_m, rest) = script.as_slice().split_first()?;
+    let (op_checkmultisig, rest) = rest.split_last()?;
+    let (op_n, mut keys) = rest.split_last()?;
+    if *op_checkmultisig != OP_CHECKMULTISIG {
+        return None;
+    }
+    let threshold = read_pushnum(*op_m)?;
+    let keys_count = read_pushnum(*op_n)?;
+
+    let mut public_keys = Vec::with_capacity(keys_count);
+    while !keys.is_empty() {
+        let (public_key, rest) = read_pushbytes(keys)?;
+        public_keys.push(ecdsa::secp256k1::PublicKey::try_from(public_key).ok()?);
+        keys = rest;
+    }
+
+    (public_keys.len() == keys_count && threshold <= keys_count).then_some((threshold, public_keys))
+}
+
+/// Reads a number pushed by one of `OP_PUSHNUM_1 .. OP_PUSHNUM_16` opcodes.
+fn read_pushnum(opcode: u8) -> Option<usize> {
+    (OP_PUSHNUM_1..=OP_PUSHNUM_16)
+        .contains(&opcode)
+        .then(|| (opcode - OP_PUSHNUM_1 + 1) as usize)
+}
+
+fn read_pushbytes(script: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (len, rest) = script.split_first()?;
+    let len = *len as usize;
+    if len > MAX_PUSHBYTES_LEN || rest.len() < len {
+        return None;
+    }
+    Some(rest.split_at(len))
+}
+
+/// Splits a DER-encoded signature and a sighash type byte.
+pub fn split_ecdsa_signature(signature: &[u8]) -> SigningResult<(Vec<u8>, SighashType)> {
+    let (sighash_ty, der_signature) = signature
+        .split_last()
+        .or_tw_err(SigningErrorType::Error_invalid_params)
+        .context("Empty ecdsa signature")?;
+    Ok((
+        der_signature.to_vec(),
+        SighashType::from_u32(*sighash_ty as u32)?,
+    ))
+}
+
+/// Splits a schnorr signature and an optional sighash type byte.
+/// An explicit `SIGHASH_ALL` (0x01) byte is committed to as is, so it's not the same as `SIGHASH_DEFAULT`.
+pub fn split_schnorr_signature(signature: &[u8]) -> SigningResult<(Vec<u8>, SighashType)> {
+    match signature.len() {
+        64 => Ok((signature.to_vec(), SighashType::default())),
+        65 => Ok((
+            signature[..64].to_vec(),
+            SighashType::from_taproot_u8(signature[64])?,
+        )),
+        _ => SigningError::err(SigningErrorType::Error_invalid_params)
+            .context("Schnorr signature must be either 64 or 65 bytes long"),
+    }
+}
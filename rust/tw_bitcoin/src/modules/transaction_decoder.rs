@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::protobuf_builder::ProtobufBuilder;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_proto::BitcoinV2::Proto;
+use tw_utxo::address::legacy::LegacyAddress;
+use tw_utxo::address::segwit::SegwitAddress;
+use tw_utxo::address::standard_bitcoin::StandardBitcoinAddress;
+use tw_utxo::address::taproot::TaprootAddress;
+use tw_utxo::encode::decode;
+use tw_utxo::script::standard_script::conditions;
+use tw_utxo::script::Script;
+use tw_utxo::transaction::standard_transaction::Transaction;
+
+pub struct BitcoinTransactionDecoder;
+
+impl TransactionDecoder for BitcoinTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(coin, tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl BitcoinTransactionDecoder {
+    fn decode_transaction_impl(
+        coin: &dyn CoinContext,
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let tx: Transaction = decode(tx).context("Error decoding Bitcoin transaction")?;
+
+        let output_addresses = tx
+            .outputs
+            .iter()
+            .map(|output| {
+                Self::output_address(coin, &output.script_pubkey)
+                    .map(|address| Cow::from(address.to_string()))
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(ProtobufBuilder::tx_to_proto(&tx)),
+            txid: Cow::from(tx.txid()),
+            output_addresses,
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+
+    /// Classifies the given output script and returns a recipient address of the `coin` network.
+    /// Returns `None` if the script is non-standard, or the `coin` does not have a corresponding address prefix.
+    /// P2PK scripts do not have an address either, so they should be represented as raw scripts.
+    pub(crate) fn output_address(
+        coin: &dyn CoinContext,
+        script: &Script,
+    ) -> Option<StandardBitcoinAddress> {
+        if let Some(pubkey_hash) = conditions::match_p2pkh(script) {
+            return LegacyAddress::new(coin.p2pkh_prefix()?, pubkey_hash.as_slice())
+                .ok()
+                .map(StandardBitcoinAddress::Legacy);
+        }
+        if let Some(script_hash) = conditions::match_p2sh(script) {
+            return LegacyAddress::new(coin.p2sh_prefix()?, script_hash.as_slice())
+                .ok()
+                .map(StandardBitcoinAddress::Legacy);
+        }
+        if let Some(pubkey_hash) = conditions::match_p2wpkh(script) {
+            return SegwitAddress::new(coin.hrp()?, pubkey_hash.to_vec())
+                .ok()
+                .map(StandardBitcoinAddress::Segwit);
+        }
+        if let Some(script_hash) = conditions::match_p2wsh(script) {
+            return SegwitAddress::new(coin.hrp()?, script_hash.to_vec())
+                .ok()
+                .map(StandardBitcoinAddress::Segwit);
+        }
+        if let Some(output_key) = conditions::match_p2tr(script) {
+            return TaprootAddress::new(coin.hrp()?, output_key.to_vec())
+                .ok()
+                .map(StandardBitcoinAddress::Taproot);
+        }
+        None
+    }
+}
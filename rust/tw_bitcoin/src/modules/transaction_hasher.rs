@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_hasher::TransactionHasher;
+use tw_encoding::hex::ToHex;
+use tw_utxo::encode::decode;
+use tw_utxo::transaction::standard_transaction::Transaction;
+
+/// Computes the transaction ID as double `SHA256` of the transaction without witness data,
+/// represented in the reversed byte order.
+pub struct BitcoinTransactionHasher;
+
+impl TransactionHasher for BitcoinTransactionHasher {
+    fn tx_id(&self, _coin: &dyn CoinContext, signed_tx: &[u8]) -> SigningResult<String> {
+        let tx: Transaction = decode(signed_tx).context("Error decoding Bitcoin transaction")?;
+        Ok(tx.txid().to_hex())
+    }
+}
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::signing_request::SigningRequestBuilder;
+use crate::modules::transaction_decoder::BitcoinTransactionDecoder;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_summarizer::{
+    action, fee, native_amount, native_asset, warning, KnownTokens, TransactionSummarizer,
+};
+use tw_coin_entry::signing_output_error;
+use tw_encoding::hex::ToHex;
+use tw_number::U256;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::TxSummary::Proto as SummaryProto;
+use tw_utxo::modules::tx_planner::TxPlanner;
+use tw_utxo::modules::utxo_selector::SelectResult;
+use tw_utxo::transaction::transaction_parts::Amount;
+
+use SummaryProto::{ActionType, WarningType};
+
+/// Describes Bitcoin transactions by the outputs of the transaction planned from `SigningInput`.
+///
+/// The change output is described separately from the transfers, and the fee is the planned fee estimate.
+pub struct BitcoinTransactionSummarizer;
+
+impl TransactionSummarizer for BitcoinTransactionSummarizer {
+    type SigningInput<'a> = Proto::SigningInput<'a>;
+
+    fn summarize_transaction(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::SigningInput<'_>,
+        _tokens: &KnownTokens,
+    ) -> SummaryProto::TransactionSummary<'static> {
+        Self::summarize_transaction_impl(coin, &input)
+            .unwrap_or_else(|e| signing_output_error!(SummaryProto::TransactionSummary, e))
+    }
+}
+
+impl BitcoinTransactionSummarizer {
+    fn summarize_transaction_impl(
+        coin: &dyn CoinContext,
+        input: &Proto::SigningInput<'_>,
+    ) -> SigningResult<SummaryProto::TransactionSummary<'static>> {
+        let request = SigningRequestBuilder::build(coin, input)?;
+        let SelectResult { unsigned_tx, plan } = TxPlanner::plan(request)?;
+
+        let outputs = &unsigned_tx.transaction().outputs;
+        // The change output is pushed to the end of the planned transaction if applied.
+        let change_idx = (input.change_output.is_some() && plan.change > 0)
+            .then(|| outputs.len().checked_sub(1))
+            .flatten();
+
+        let mut summary = SummaryProto::TransactionSummary::default();
+        for (idx, output) in outputs.iter().enumerate() {
+            let recipient = BitcoinTransactionDecoder::output_address(coin, &output.script_pubkey)
+                .map(|address| address.to_string());
+            let value = native_amount(coin, satoshis(output.value)?);
+
+            let Some(recipient) = recipient else {
+                summary.warnings.push(warning(
+                    WarningType::Other,
+                    format!("Output #{idx} has a non-standard script"),
+                ));
+                let mut custom = action(
+                    ActionType::ContractCall,
+                    native_asset(coin),
+                    Some(value),
+                    "",
+                    output.script_pubkey.as_slice().to_hex(),
+                );
+                custom.description = format!("Output #{idx} with a custom script").into();
+                summary.actions.push(custom);
+                continue;
+            };
+
+            let action_type = if Some(idx) == change_idx {
+                ActionType::ChangeTransfer
+            } else {
+                ActionType::Transfer
+            };
+            summary.actions.push(action(
+                action_type,
+                native_asset(coin),
+                Some(value),
+                "",
+                recipient,
+            ));
+        }
+
+        summary.fee = Some(fee(
+            native_asset(coin),
+            native_amount(coin, satoshis(plan.fee_estimate)?),
+        ));
+        Ok(summary)
+    }
+}
+
+fn satoshis(amount: Amount) -> SigningResult<U256> {
+    u64::try_from(amount)
+        .map(U256::from)
+        .tw_err(|_| SigningErrorType::Error_invalid_params)
+        .context("Negative amount")
+}
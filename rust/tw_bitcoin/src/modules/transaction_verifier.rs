@@ -0,0 +1,287 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::spender::{
+    is_p2sh_p2wpkh_script_sig, parse_p2pkh_script_sig, split_ecdsa_signature,
+    split_schnorr_signature, Spender,
+};
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_verifier::{verifying_output, TransactionVerifier};
+use tw_coin_entry::signing_output_error;
+use tw_hash::hasher::Hasher;
+use tw_keypair::{ecdsa, schnorr};
+use tw_memory::Data;
+use tw_proto::TxVerifier::Proto as VerifierProto;
+use tw_utxo::encode::decode;
+use tw_utxo::modules::sighash_computer::{SighashComputer, TxPreimage};
+use tw_utxo::modules::sighash_verifier::SighashVerifier;
+use tw_utxo::script::standard_script::conditions;
+use tw_utxo::script::{Script, Witness};
+use tw_utxo::sighash::SighashType;
+use tw_utxo::signing_mode::SigningMethod;
+use tw_utxo::spending_data::{standard_constructor, SpendingDataConstructor};
+use tw_utxo::transaction::standard_transaction::builder::{TransactionBuilder, UtxoBuilder};
+use tw_utxo::transaction::standard_transaction::{Transaction, TransactionInput};
+use tw_utxo::transaction::transaction_parts::Amount;
+use tw_utxo::transaction::UtxoToSign;
+
+/// Verifies signatures of every input of a signed Bitcoin transaction.
+///
+/// Inputs spending P2PKH, P2SH-P2WPKH, P2WPKH and P2TR (key-path) outputs can be verified only.
+/// Other inputs, e.g. P2SH or P2WSH multisig, and inputs with malformed spending data are reported as invalid.
+/// The spent outputs must be provided via `VerifyingInput.previous_outputs` as they are committed to by sighashes.
+pub struct BitcoinTransactionVerifier;
+
+impl TransactionVerifier for BitcoinTransactionVerifier {
+    fn verify_transaction(
+        &self,
+        _coin: &dyn CoinContext,
+        input: VerifierProto::VerifyingInput<'_>,
+    ) -> VerifierProto::VerifyingOutput<'static> {
+        Self::verify_transaction_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(VerifierProto::VerifyingOutput, e))
+    }
+}
+
+/// Spending data of a transaction input.
+struct SpentInput {
+    spender: Spender,
+    /// The signature without a sighash type.
+    signature: Data,
+    /// Whether the spender is able to spend the previous output script.
+    spends_prev_output: bool,
+}
+
+impl BitcoinTransactionVerifier {
+    fn verify_transaction_impl(
+        input: VerifierProto::VerifyingInput<'_>,
+    ) -> SigningResult<VerifierProto::VerifyingOutput<'static>> {
+        let expected_keys = input
+            .public_keys
+            .iter()
+            .map(|key| ecdsa::secp256k1::PublicKey::try_from(key.as_ref()))
+            .collect::<Result<Vec<_>, _>>()
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .context("Invalid expected public key")?;
+
+        let tx: Transaction =
+            decode(&input.transaction).context("Error decoding Bitcoin transaction")?;
+
+        let inputs_count = tx.inputs.len();
+        if input.previous_outputs.len() != inputs_count {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!(
+                    "Expected '{inputs_count}' previous outputs, provided '{}'",
+                    input.previous_outputs.len()
+                )
+            });
+        }
+
+        let mut builder = TransactionBuilder::new();
+        builder.version(tx.version as u32).lock_time(tx.locktime);
+
+        let mut spent_inputs = Vec::with_capacity(inputs_count);
+        for (tx_input, prev_output) in tx.inputs.iter().zip(input.previous_outputs.iter()) {
+            let prev_script = Script::from(prev_output.script_pubkey.to_vec());
+
+            let Ok((spent_input, sighash_ty)) = Self::parse_spent_input(tx_input, &prev_script)
+            else {
+                // The input cannot be verified, but it's still committed to by sighashes of the other inputs.
+                let (utxo, utxo_args) =
+                    Self::unverifiable_utxo(tx_input, prev_script, prev_output.value);
+                builder.push_input(utxo, utxo_args);
+                spent_inputs.push(None);
+                continue;
+            };
+
+            let utxo_builder = UtxoBuilder::new()
+                .prev_txid(tx_input.previous_output.hash)
+                .prev_index(tx_input.previous_output.index)
+                .sequence(tx_input.sequence)
+                .amount(prev_output.value)
+                .sighash_type(sighash_ty);
+            let (utxo, utxo_args) = spent_input.spender.build_utxo(utxo_builder)?;
+
+            builder.push_input(utxo, utxo_args);
+            spent_inputs.push(Some(spent_input));
+        }
+        for output in tx.outputs.iter() {
+            builder.push_output(output.clone());
+        }
+
+        let unsigned_tx = builder.build()?;
+        let TxPreimage { sighashes } = SighashComputer::preimage_tx(&unsigned_tx)?;
+
+        let signatures = sighashes
+            .iter()
+            .zip(unsigned_tx.input_args())
+            .zip(spent_inputs)
+            .map(|((sighash, utxo_args), spent_input)| {
+                let Some(spent_input) = spent_input else {
+                    return VerifierProto::SignatureVerification::default();
+                };
+
+                let valid = spent_input.spends_prev_output
+                    && Self::is_expected_spender(&spent_input.spender, &expected_keys)
+                    && SighashVerifier::<Transaction>::verify_signature(
+                        sighash,
+                        utxo_args,
+                        &spent_input.signature,
+                    )
+                    .is_ok();
+
+                VerifierProto::SignatureVerification {
+                    public_key: Cow::Owned(Self::spender_public_key(&spent_input.spender)),
+                    signature: Cow::Owned(spent_input.signature),
+                    valid,
+                }
+            })
+            .collect();
+
+        Ok(verifying_output(signatures))
+    }
+
+    /// Returns an unsigned input and its signing arguments that are used to compute sighashes
+    /// of the other inputs only.
+    fn unverifiable_utxo(
+        tx_input: &TransactionInput,
+        prev_script: Script,
+        amount: Amount,
+    ) -> (TransactionInput, UtxoToSign) {
+        let utxo = TransactionInput {
+            script_sig: Script::default(),
+            witness: Witness::default(),
+            ..tx_input.clone()
+        };
+        let utxo_args = UtxoToSign {
+            script_pubkey: prev_script,
+            signing_method: SigningMethod::Legacy,
+            spending_data_constructor: SpendingDataConstructor::ecdsa(standard_constructor::P2PK),
+            spender_public_key: Data::default(),
+            amount,
+            leaf_hash_code_separator: None,
+            tx_hasher: Hasher::Sha256d,
+            sighash_ty: SighashType::default(),
+        };
+        (utxo, utxo_args)
+    }
+
+    /// Returns the input spending data and the sighash type.
+    fn parse_spent_input(
+        tx_input: &TransactionInput,
+        prev_script: &Script,
+    ) -> SigningResult<(SpentInput, SighashType)> {
+        let (spender, signature, sighash_ty) =
+            Self::parse_spending_data(prev_script, &tx_input.script_sig, &tx_input.witness)?;
+        let spends_prev_output = spender.script_pubkey() == *prev_script;
+
+        let spent_input = SpentInput {
+            spender,
+            signature,
+            spends_prev_output,
+        };
+        Ok((spent_input, sighash_ty))
+    }
+
+    /// Returns the spender, the signature without a sighash type, and the sighash type.
+    fn parse_spending_data(
+        prev_script: &Script,
+        script_sig: &Script,
+        witness: &Witness,
+    ) -> SigningResult<(Spender, Data, SighashType)> {
+        let invalid_spending_data = || {
+            SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Unexpected input scriptSig or witness")
+        };
+
+        // <sig> <pubkey>
+        if conditions::is_p2pkh(prev_script) {
+            if !witness.is_empty() {
+                return invalid_spending_data();
+            }
+            let Some((signature, public_key)) = parse_p2pkh_script_sig(script_sig) else {
+                return invalid_spending_data();
+            };
+            let public_key = ecdsa::secp256k1::PublicKey::try_from(public_key)?;
+            let (signature, sighash_ty) = split_ecdsa_signature(signature)?;
+            return Ok((Spender::P2PKH(public_key), signature, sighash_ty));
+        }
+
+        // <redeem_script> [<sig>, <pubkey>]
+        if conditions::is_p2sh(prev_script) {
+            let [signature, public_key] = witness.as_items() else {
+                return invalid_spending_data();
+            };
+            let public_key = ecdsa::secp256k1::PublicKey::try_from(public_key.as_slice())?;
+            if !is_p2sh_p2wpkh_script_sig(script_sig, &public_key) {
+                return invalid_spending_data();
+            }
+            let (signature, sighash_ty) = split_ecdsa_signature(signature.as_slice())?;
+            return Ok((Spender::P2SHP2WPKH(public_key), signature, sighash_ty));
+        }
+
+        if !script_sig.is_empty() {
+            return invalid_spending_data();
+        }
+
+        // [<sig>, <pubkey>]
+        if conditions::is_p2wpkh(prev_script) {
+            let [signature, public_key] = witness.as_items() else {
+                return invalid_spending_data();
+            };
+            let public_key = ecdsa::secp256k1::PublicKey::try_from(public_key.as_slice())?;
+            let (signature, sighash_ty) = split_ecdsa_signature(signature.as_slice())?;
+            return Ok((Spender::P2WPKH(public_key), signature, sighash_ty));
+        }
+
+        // [<sig>]
+        if let Some(tweaked_pubkey) = conditions::match_p2tr(prev_script) {
+            let [signature] = witness.as_items() else {
+                return invalid_spending_data();
+            };
+            let tweaked_pubkey = schnorr::XOnlyPublicKey::try_from(tweaked_pubkey.as_slice())?;
+            let (signature, sighash_ty) = split_schnorr_signature(signature.as_slice())?;
+            return Ok((Spender::P2TR(tweaked_pubkey), signature, sighash_ty));
+        }
+
+        SigningError::err(SigningErrorType::Error_not_supported)
+            .context("Only P2PKH, P2SH-P2WPKH, P2WPKH and P2TR key-path inputs can be verified")
+    }
+
+    /// Checks if the spender belongs to one of the `expected_keys` if specified.
+    /// Taproot spenders are compared with the expected keys tweaked without a merkle root.
+    fn is_expected_spender(
+        spender: &Spender,
+        expected_keys: &[ecdsa::secp256k1::PublicKey],
+    ) -> bool {
+        if expected_keys.is_empty() {
+            return true;
+        }
+
+        match spender {
+            Spender::P2PKH(public_key)
+            | Spender::P2SHP2WPKH(public_key)
+            | Spender::P2WPKH(public_key)
+            | Spender::P2WSH(public_key) => expected_keys.contains(public_key),
+            Spender::P2TR(tweaked_pubkey) => expected_keys.iter().any(|expected| {
+                schnorr::PublicKey::try_from(expected.compressed().as_slice())
+                    .map(|expected| expected.tweak(None).x_only().bytes() == tweaked_pubkey.bytes())
+                    .unwrap_or_default()
+            }),
+        }
+    }
+
+    /// Returns a compressed public key for ecdsa spenders, and a tweaked x-only public key for taproot ones.
+    fn spender_public_key(spender: &Spender) -> Data {
+        match spender {
+            Spender::P2PKH(public_key)
+            | Spender::P2SHP2WPKH(public_key)
+            | Spender::P2WPKH(public_key)
+            | Spender::P2WSH(public_key) => public_key.compressed().to_vec(),
+            Spender::P2TR(tweaked_pubkey) => tweaked_pubkey.bytes().to_vec(),
+        }
+    }
+}
@@ -25,4 +25,14 @@ pub trait CoinContext {
 
     /// Returns coin derivations.
     fn derivations(&self) -> &[DerivationWithPath];
+
+    /// Number of decimals of the native coin.
+    fn decimals(&self) -> u8;
+
+    /// Ticker of the native coin, e.g. "BTC".
+    fn symbol(&self) -> &str;
+
+    /// Chain ID the transactions of the coin are bound to, e.g. "8453" or "stargaze-1".
+    /// Set for custom coins only, built-in coins accept any chain ID specified in the signing input.
+    fn chain_id(&self) -> Option<&str>;
 }
@@ -15,6 +15,9 @@ use tw_proto::{MessageRead, MessageWrite};
 
 use crate::modules::message_signer::MessageSigner;
 use crate::modules::transaction_decoder::TransactionDecoder;
+use crate::modules::transaction_hasher::TransactionHasher;
+use crate::modules::transaction_summarizer::TransactionSummarizer;
+use crate::modules::transaction_verifier::TransactionVerifier;
 use crate::modules::wallet_connector::WalletConnector;
 pub use tw_proto::{ProtoError, ProtoResult};
 
@@ -48,8 +51,10 @@ pub trait CoinEntry {
     /// Protobuf message - result of the request to obtain a transaction preimage hashes.
     type PreSigningOutput: MessageWrite;
 
-    /// Not supported at this moment.
-    /// Use `NoJsonSigner`.
+    /// JSON Signer - the module allows to sign a transaction specified by the proto3 JSON representation of `SigningInput`.
+    /// Use `ProtoJsonSigner` to convert JSON into `SigningInput` and `SigningOutput` into JSON automatically.
+    ///
+    /// **Optional**. Use `NoJsonSigner` if the blockchain does not support JSON signing.
     type JsonSigner: JsonSigner;
     /// Transaction Planner - the module provides transaction planning functionality.
     /// Used mostly in Bitcoin and UTXO-based chains.
@@ -68,6 +73,18 @@ pub trait CoinEntry {
     ///
     /// **Optional**. Use `NoTransactionDecoder` if the blockchain does not support transaction decoding yet.
     type TransactionDecoder: TransactionDecoder;
+    /// TransactionVerifier - the module allows to verify signatures of a signed transaction.
+    ///
+    /// **Optional**. Use `NoTransactionVerifier` if the blockchain does not support transaction verification yet.
+    type TransactionVerifier: TransactionVerifier;
+    /// TransactionHasher - the module allows to compute a transaction hash (ID) from signed transaction bytes.
+    ///
+    /// **Optional**. Use `NoTransactionHasher` if the blockchain does not support transaction hashing yet.
+    type TransactionHasher: TransactionHasher;
+    /// TransactionSummarizer - the module allows to describe what a transaction specified by `SigningInput` will do.
+    ///
+    /// **Optional**. Use `NoTransactionSummarizer` if the blockchain does not support transaction summaries yet.
+    type TransactionSummarizer: TransactionSummarizer;
 
     /// Tries to parse `Self::Address` from the given `address` string by `coin` type and address `prefix`.
     fn parse_address(
@@ -147,4 +164,25 @@ pub trait CoinEntry {
     fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
         None
     }
+
+    /// It is optional, Signed transaction verification.
+    /// Returns `Ok(None)` if the blockchain does not support transaction verification yet.
+    #[inline]
+    fn transaction_verifier(&self) -> Option<Self::TransactionVerifier> {
+        None
+    }
+
+    /// It is optional, Transaction hash (ID) computation.
+    /// Returns `Ok(None)` if the blockchain does not support transaction hashing yet.
+    #[inline]
+    fn transaction_hasher(&self) -> Option<Self::TransactionHasher> {
+        None
+    }
+
+    /// It is optional, Human-readable transaction summary.
+    /// Returns `Ok(None)` if the blockchain does not support transaction summaries yet.
+    #[inline]
+    fn transaction_summarizer(&self) -> Option<Self::TransactionSummarizer> {
+        None
+    }
 }
@@ -10,10 +10,14 @@ use crate::modules::json_signer::JsonSigner;
 use crate::modules::message_signer::MessageSigner;
 use crate::modules::plan_builder::PlanBuilder;
 use crate::modules::transaction_decoder::TransactionDecoder;
+use crate::modules::transaction_hasher::TransactionHasher;
+use crate::modules::transaction_summarizer::{KnownTokens, TransactionSummarizer};
+use crate::modules::transaction_verifier::TransactionVerifier;
 use crate::modules::wallet_connector::WalletConnector;
 use crate::prefix::AddressPrefix;
 use tw_keypair::tw::{PrivateKey, PublicKey};
 use tw_memory::Data;
+use tw_proto::TxVerifier::Proto as VerifierProto;
 use tw_proto::WalletConnect::Proto as WCProto;
 use tw_proto::{deserialize, serialize, ProtoResult};
 
@@ -94,6 +98,20 @@ pub trait CoinEntryExt {
 
     /// Decodes a transaction from binary representation.
     fn decode_transaction(&self, coin: &dyn CoinContext, tx: &[u8]) -> SigningResult<Data>;
+
+    /// Verifies signatures of a signed transaction.
+    fn verify_transaction(&self, coin: &dyn CoinContext, input: &[u8]) -> SigningResult<Data>;
+
+    /// Computes the transaction hash (ID) from the signed transaction bytes.
+    fn tx_id(&self, coin: &dyn CoinContext, signed_tx: &[u8]) -> SigningResult<String>;
+
+    /// Returns a human-readable summary of a transaction specified by the signing input.
+    fn summarize_transaction(
+        &self,
+        coin: &dyn CoinContext,
+        input: &[u8],
+        tokens: &KnownTokens,
+    ) -> SigningResult<Data>;
 }
 
 impl<T> CoinEntryExt for T
@@ -242,4 +260,38 @@ where
         let output = tx_decoder.decode_transaction(coin, tx);
         serialize(&output).map_err(SigningError::from)
     }
+
+    fn verify_transaction(&self, coin: &dyn CoinContext, input: &[u8]) -> SigningResult<Data> {
+        let Some(tx_verifier) = self.transaction_verifier() else {
+            return TWError::err(SigningErrorType::Error_not_supported);
+        };
+
+        let input: VerifierProto::VerifyingInput = deserialize(input)?;
+        let output = tx_verifier.verify_transaction(coin, input);
+        serialize(&output).map_err(SigningError::from)
+    }
+
+    fn tx_id(&self, coin: &dyn CoinContext, signed_tx: &[u8]) -> SigningResult<String> {
+        let Some(tx_hasher) = self.transaction_hasher() else {
+            return TWError::err(SigningErrorType::Error_not_supported);
+        };
+
+        tx_hasher.tx_id(coin, signed_tx)
+    }
+
+    fn summarize_transaction(
+        &self,
+        coin: &dyn CoinContext,
+        input: &[u8],
+        tokens: &KnownTokens,
+    ) -> SigningResult<Data> {
+        let Some(tx_summarizer) = self.transaction_summarizer() else {
+            return TWError::err(SigningErrorType::Error_not_supported);
+        };
+
+        let input: <T::TransactionSummarizer as TransactionSummarizer>::SigningInput<'_> =
+            deserialize(input)?;
+        let output = tx_summarizer.summarize_transaction(coin, input, tokens);
+        serialize(&output).map_err(SigningError::from)
+    }
 }
@@ -2,6 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use std::fmt;
+
 pub type AddressResult<T> = Result<T, AddressError>;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -22,3 +24,26 @@ pub enum AddressError {
     InvalidWitnessProgram,
     Internal,
 }
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let err = match self {
+            AddressError::UnknownCoinType => "Unknown coin type",
+            AddressError::Unsupported => "Operation not supported for the chain",
+            AddressError::MissingPrefix => "Address prefix is missing",
+            AddressError::FromHexError => "Invalid hex address",
+            AddressError::FromBase58Error => "Invalid Base58 address",
+            AddressError::FromBech32Error => "Invalid Bech32 address",
+            AddressError::PublicKeyTypeMismatch => "Public key type does not match the chain",
+            AddressError::UnexpectedAddressPrefix => "Unexpected address prefix",
+            AddressError::UnexpectedHasher => "Unexpected address hasher",
+            AddressError::InvalidHrp => "Invalid Bech32 human-readable part",
+            AddressError::InvalidRegistry => "Invalid coin registry",
+            AddressError::InvalidInput => "Invalid address",
+            AddressError::InvalidChecksum => "Invalid address checksum",
+            AddressError::InvalidWitnessProgram => "Invalid witness program",
+            AddressError::Internal => "Internal error",
+        };
+        write!(f, "{err}")
+    }
+}
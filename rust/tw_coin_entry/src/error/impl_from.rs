@@ -7,6 +7,7 @@ use crate::error::prelude::*;
 use tw_encoding::EncodingError;
 use tw_keypair::KeyPairError;
 use tw_number::NumberError;
+use tw_proto::json::JsonError;
 use tw_proto::ProtoError;
 
 impl From<NumberError> for SigningError {
@@ -57,3 +58,15 @@ impl From<ProtoError> for SigningError {
         TWError::new(SigningErrorType::Error_input_parse)
     }
 }
+
+impl From<JsonError> for SigningError {
+    fn from(e: JsonError) -> Self {
+        let error_type = match e {
+            JsonError::UnknownType(_) => SigningErrorType::Error_internal,
+            JsonError::UnknownField(_)
+            | JsonError::InvalidValue(_)
+            | JsonError::InvalidProtobuf(_) => SigningErrorType::Error_input_parse,
+        };
+        TWError::new(error_type).context(e)
+    }
+}
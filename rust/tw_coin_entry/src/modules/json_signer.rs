@@ -3,8 +3,14 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::coin_context::CoinContext;
+use crate::coin_entry::CoinEntry;
 use crate::error::prelude::*;
+use serde_json::Value as Json;
+use tw_encoding::base64;
 use tw_keypair::tw::PrivateKey;
+use tw_proto::json::descriptor::{find_message, FieldKind, FieldLabel};
+use tw_proto::json::{json_to_proto, proto_to_json};
+use tw_proto::{deserialize, serialize, MessageInfo};
 
 pub trait JsonSigner {
     /// Signs the given JSON input with the private key.
@@ -29,3 +35,77 @@ impl JsonSigner for NoJsonSigner {
         panic!("`NoJsonSigner` should never be constructed and used")
     }
 }
+
+/// A generic JSON signer that works with the canonical proto3 JSON representation
+/// of the `Entry::SigningInput` and `Entry::SigningOutput` messages.
+///
+/// The given private key is set to the `private_key` (or `private_keys`) field of the signing input,
+/// replacing the key specified in JSON if any.
+pub struct ProtoJsonSigner<Entry> {
+    entry: Entry,
+}
+
+impl<Entry> ProtoJsonSigner<Entry> {
+    pub fn new(entry: Entry) -> Self {
+        ProtoJsonSigner { entry }
+    }
+}
+
+impl<Entry> JsonSigner for ProtoJsonSigner<Entry>
+where
+    Entry: CoinEntry,
+    for<'a> Entry::SigningInput<'a>: MessageInfo,
+    Entry::SigningOutput: MessageInfo,
+{
+    fn sign_json(
+        &self,
+        coin: &dyn CoinContext,
+        input_json: &str,
+        key: &PrivateKey,
+    ) -> SigningResult<String> {
+        let input_path = <Entry::SigningInput<'static> as MessageInfo>::PATH;
+        let output_path = <Entry::SigningOutput as MessageInfo>::PATH;
+
+        let mut input_json: Json = serde_json::from_str(input_json)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Expected a valid JSON object")?;
+        set_private_key(input_path, &mut input_json, key)?;
+
+        let input_data = json_to_proto(input_path, &input_json)?;
+        let input: Entry::SigningInput<'_> = deserialize(&input_data)?;
+
+        let output = self.entry.sign(coin, input);
+        let output_data = serialize(&output)?;
+
+        let output_json = proto_to_json(output_path, &output_data)?;
+        Ok(output_json.to_string())
+    }
+}
+
+/// Sets the given `key` to the `private_key: bytes` or `private_keys: repeated bytes` field.
+fn set_private_key(message_name: &str, input: &mut Json, key: &PrivateKey) -> SigningResult<()> {
+    let descriptor = find_message(message_name)
+        .or_tw_err(SigningErrorType::Error_internal)
+        .with_context(|| format!("'{message_name}' message descriptor not found"))?;
+    let object = input
+        .as_object_mut()
+        .or_tw_err(SigningErrorType::Error_input_parse)
+        .context("Expected a JSON object")?;
+
+    let key_field = ["private_key", "private_keys"]
+        .into_iter()
+        .filter_map(|name| descriptor.field_by_name(name))
+        .find(|field| matches!(field.kind, FieldKind::Bytes))
+        .or_tw_err(SigningErrorType::Error_not_supported)
+        .with_context(|| format!("'{message_name}' does not contain a private key field"))?;
+
+    let key_value = Json::String(base64::encode(key.bytes(), false));
+    let key_value = match key_field.label {
+        FieldLabel::Singular => key_value,
+        FieldLabel::Repeated => Json::Array(vec![key_value]),
+    };
+
+    object.remove(key_field.name);
+    object.insert(key_field.json_name.to_string(), key_value);
+    Ok(())
+}
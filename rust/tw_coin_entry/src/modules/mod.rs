@@ -8,4 +8,7 @@ pub mod json_signer;
 pub mod message_signer;
 pub mod plan_builder;
 pub mod transaction_decoder;
+pub mod transaction_hasher;
+pub mod transaction_summarizer;
+pub mod transaction_verifier;
 pub mod wallet_connector;
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::coin_context::CoinContext;
+use crate::error::prelude::*;
+
+pub trait TransactionHasher {
+    /// Computes the transaction hash (aka transaction ID) from the signed transaction bytes.
+    /// The result is formatted the same way as blockchain explorers and nodes display it.
+    fn tx_id(&self, coin: &dyn CoinContext, signed_tx: &[u8]) -> SigningResult<String>;
+}
+
+/// `NoTransactionHasher` can't be created since there are no enum variants.
+pub enum NoTransactionHasher {}
+
+impl TransactionHasher for NoTransactionHasher {
+    fn tx_id(&self, _coin: &dyn CoinContext, _signed_tx: &[u8]) -> SigningResult<String> {
+        panic!("`NoTransactionHasher` should never be constructed and used")
+    }
+}
@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::coin_context::CoinContext;
+use crate::error::prelude::*;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use tw_number::U256;
+use tw_proto::TxSummary::Proto as SummaryProto;
+use tw_proto::{DummyMessage, MessageRead};
+
+use SummaryProto::ActionType;
+
+pub trait TransactionSummarizer {
+    type SigningInput<'a>: MessageRead<'a>;
+
+    /// Returns a human-readable summary of what the transaction specified by `input` will do.
+    /// Token amounts are formatted with the decimals of `tokens` if known.
+    fn summarize_transaction(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::SigningInput<'_>,
+        tokens: &KnownTokens,
+    ) -> SummaryProto::TransactionSummary<'static>;
+}
+
+/// `NoTransactionSummarizer` can't be created since there are no enum variants.
+pub enum NoTransactionSummarizer {}
+
+impl TransactionSummarizer for NoTransactionSummarizer {
+    type SigningInput<'a> = DummyMessage;
+
+    fn summarize_transaction(
+        &self,
+        _coin: &dyn CoinContext,
+        _input: Self::SigningInput<'_>,
+        _tokens: &KnownTokens,
+    ) -> SummaryProto::TransactionSummary<'static> {
+        panic!("`NoTransactionSummarizer` should never be constructed and used")
+    }
+}
+
+#[derive(Clone)]
+struct KnownToken {
+    symbol: String,
+    decimals: u8,
+}
+
+/// Tokens whose symbols and decimals are specified by the caller, see `TW.TxSummary.Proto.TokenList`.
+#[derive(Default)]
+pub struct KnownTokens {
+    tokens: HashMap<String, KnownToken>,
+}
+
+impl KnownTokens {
+    pub fn from_proto(list: &SummaryProto::TokenList<'_>) -> SigningResult<KnownTokens> {
+        let mut tokens = HashMap::with_capacity(list.tokens.len());
+        for token in list.tokens.iter() {
+            let decimals = u8::try_from(token.decimals)
+                .tw_err(|_| SigningErrorType::Error_invalid_params)
+                .with_context(|| format!("Invalid decimals of the '{}' token", token.token))?;
+            let known = KnownToken {
+                symbol: token.symbol.to_string(),
+                decimals,
+            };
+            tokens.insert(token.token.to_string(), known);
+        }
+        Ok(KnownTokens { tokens })
+    }
+
+    /// Returns the tokens identified by `normalize(token)`, e.g. by checksummed contract addresses.
+    pub fn normalized<F>(&self, normalize: F) -> KnownTokens
+    where
+        F: Fn(&str) -> String,
+    {
+        let tokens = self
+            .tokens
+            .iter()
+            .map(|(token, known)| (normalize(token), known.clone()))
+            .collect();
+        KnownTokens { tokens }
+    }
+
+    /// Returns a token asset with the symbol if the token is known.
+    pub fn asset(&self, token: impl ToString) -> SummaryProto::Asset<'static> {
+        let token = token.to_string();
+        let symbol = self
+            .tokens
+            .get(&token)
+            .map(|known| known.symbol.clone())
+            .unwrap_or_default();
+        SummaryProto::Asset {
+            symbol: Cow::Owned(symbol),
+            ..token_asset(token)
+        }
+    }
+
+    /// Returns an amount of the token formatted with the token decimals if the token is known.
+    pub fn amount(&self, token: &str, value: U256) -> SummaryProto::Amount<'static> {
+        amount(value, self.tokens.get(token).map(|known| known.decimals))
+    }
+}
+
+/// Returns the native coin asset.
+pub fn native_asset(coin: &dyn CoinContext) -> SummaryProto::Asset<'static> {
+    SummaryProto::Asset {
+        symbol: Cow::Owned(coin.symbol().to_string()),
+        ..SummaryProto::Asset::default()
+    }
+}
+
+/// Returns a token asset identified by a contract address, mint address or denomination.
+pub fn token_asset(token: impl ToString) -> SummaryProto::Asset<'static> {
+    SummaryProto::Asset {
+        token: Cow::Owned(token.to_string()),
+        ..SummaryProto::Asset::default()
+    }
+}
+
+/// Returns an amount of the native coin formatted with the coin decimals.
+pub fn native_amount(coin: &dyn CoinContext, value: U256) -> SummaryProto::Amount<'static> {
+    amount(value, Some(coin.decimals()))
+}
+
+/// Returns an amount in the smallest units.
+/// The amount is formatted only if `decimals` are known.
+pub fn amount(value: U256, decimals: Option<u8>) -> SummaryProto::Amount<'static> {
+    let value = value.to_string();
+    match decimals {
+        Some(decimals) => SummaryProto::Amount {
+            formatted: Cow::Owned(format_units(&value, decimals)),
+            value: Cow::Owned(value),
+            decimals: decimals as u32,
+        },
+        None => SummaryProto::Amount {
+            value: Cow::Owned(value),
+            ..SummaryProto::Amount::default()
+        },
+    }
+}
+
+/// Formats a decimal `value` in the smallest units with the given number of `decimals`,
+/// e.g. `format_units("1500000", 6) == "1.5"`.
+pub fn format_units(value: &str, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    if decimals == 0 {
+        return value.to_string();
+    }
+
+    let padded = format!("{value:0>width$}", width = decimals + 1);
+    let (integer, fraction) = padded.split_at(padded.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+/// Returns an action with a human-readable description.
+pub fn action(
+    ty: ActionType,
+    asset: SummaryProto::Asset<'static>,
+    amount: Option<SummaryProto::Amount<'static>>,
+    sender: impl ToString,
+    recipient: impl ToString,
+) -> SummaryProto::Action<'static> {
+    let mut action = SummaryProto::Action {
+        type_pb: ty,
+        asset: Some(asset),
+        amount,
+        sender: Cow::Owned(sender.to_string()),
+        recipient: Cow::Owned(recipient.to_string()),
+        description: Cow::default(),
+    };
+    action.description = Cow::Owned(describe_action(&action));
+    action
+}
+
+/// Returns a warning of the given type.
+pub fn warning(
+    ty: SummaryProto::WarningType,
+    message: impl ToString,
+) -> SummaryProto::Warning<'static> {
+    SummaryProto::Warning {
+        type_pb: ty,
+        message: Cow::Owned(message.to_string()),
+    }
+}
+
+/// Returns a fee charged in the given asset.
+pub fn fee(
+    asset: SummaryProto::Asset<'static>,
+    amount: SummaryProto::Amount<'static>,
+) -> SummaryProto::Fee<'static> {
+    SummaryProto::Fee {
+        asset: Some(asset),
+        amount: Some(amount),
+    }
+}
+
+fn describe_action(action: &SummaryProto::Action) -> String {
+    let what = describe_asset_amount(action);
+    let recipient = &action.recipient;
+    let sender = &action.sender;
+
+    match action.type_pb {
+        ActionType::Transfer | ActionType::TokenTransfer | ActionType::NftTransfer => {
+            format!("Send {what} to {recipient}")
+        },
+        ActionType::ChangeTransfer => format!("Return change of {what} to {recipient}"),
+        ActionType::TokenApproval => format!("Approve {recipient} to spend {what}"),
+        ActionType::Stake => format!("Stake {what} with {recipient}"),
+        ActionType::Unstake => format!("Unstake {what} from {recipient}"),
+        ActionType::Restake => format!("Restake {what} from {sender} to {recipient}"),
+        ActionType::ClaimRewards => format!("Claim staking rewards from {recipient}"),
+        ActionType::Withdraw => format!("Withdraw {what} from {sender}"),
+        ActionType::CreateAccount => format!("Create account {recipient}"),
+        ActionType::ContractCall => match action.amount {
+            Some(ref amount) if amount.value != "0" => {
+                format!("Call contract {recipient} with {what}")
+            },
+            _ => format!("Call contract {recipient}"),
+        },
+    }
+}
+
+/// Describes the asset amount, e.g. "1.5 ETH", "1000 0xdAC1...1ec7" or "token #1 of 0xBC4C...f13D".
+fn describe_asset_amount(action: &SummaryProto::Action) -> String {
+    let default_asset = SummaryProto::Asset::default();
+    let asset = action.asset.as_ref().unwrap_or(&default_asset);
+
+    let asset_name = if asset.symbol.is_empty() {
+        asset.token.to_string()
+    } else {
+        asset.symbol.to_string()
+    };
+
+    let amount = action.amount.as_ref().map(|amount| {
+        if amount.formatted.is_empty() {
+            amount.value.to_string()
+        } else {
+            amount.formatted.to_string()
+        }
+    });
+
+    match (asset.token_id.is_empty(), amount) {
+        (false, Some(amount)) => format!("{amount} of token #{} of {asset_name}", asset.token_id),
+        (false, None) => format!("token #{} of {asset_name}", asset.token_id),
+        (true, Some(amount)) => format!("{amount} {asset_name}"),
+        (true, None) => format!("all {asset_name}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_units() {
+        assert_eq!(format_units("1500000", 6), "1.5");
+        assert_eq!(format_units("1000000000000000000", 18), "1");
+        assert_eq!(format_units("2000000000000000", 18), "0.002");
+        assert_eq!(format_units("0", 8), "0");
+        assert_eq!(format_units("123", 0), "123");
+        assert_eq!(format_units("1", 8), "0.00000001");
+    }
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::coin_context::CoinContext;
+use tw_proto::TxVerifier::Proto as VerifierProto;
+
+pub trait TransactionVerifier {
+    /// Verifies signatures of a signed transaction.
+    fn verify_transaction(
+        &self,
+        coin: &dyn CoinContext,
+        input: VerifierProto::VerifyingInput<'_>,
+    ) -> VerifierProto::VerifyingOutput<'static>;
+}
+
+/// `NoTransactionVerifier` can't be created since there are no enum variants.
+pub enum NoTransactionVerifier {}
+
+impl TransactionVerifier for NoTransactionVerifier {
+    fn verify_transaction(
+        &self,
+        _coin: &dyn CoinContext,
+        _input: VerifierProto::VerifyingInput<'_>,
+    ) -> VerifierProto::VerifyingOutput<'static> {
+        panic!("`NoTransactionVerifier` should never be constructed and used")
+    }
+}
+
+/// Builds [`VerifierProto::VerifyingOutput`] from the given signature verification results.
+/// The transaction is considered valid if it has at least one signature and all of them are valid.
+pub fn verifying_output(
+    signatures: Vec<VerifierProto::SignatureVerification<'static>>,
+) -> VerifierProto::VerifyingOutput<'static> {
+    let valid = !signatures.is_empty() && signatures.iter().all(|sig| sig.valid);
+    VerifierProto::VerifyingOutput {
+        valid,
+        signatures,
+        ..VerifierProto::VerifyingOutput::default()
+    }
+}
@@ -15,6 +15,9 @@ pub struct TestCoinContext {
     pub hrp: Option<String>,
     pub p2pkh: Option<u8>,
     pub p2sh: Option<u8>,
+    pub decimals: Option<u8>,
+    pub symbol: Option<String>,
+    pub chain_id: Option<String>,
 }
 
 impl TestCoinContext {
@@ -54,4 +57,19 @@ impl CoinContext for TestCoinContext {
     fn derivations(&self) -> &[DerivationWithPath] {
         unimplemented!()
     }
+
+    fn decimals(&self) -> u8 {
+        self.decimals
+            .expect("EmptyCoinContext::decimals was not set")
+    }
+
+    fn symbol(&self) -> &str {
+        self.symbol
+            .as_deref()
+            .expect("EmptyCoinContext::symbol was not set")
+    }
+
+    fn chain_id(&self) -> Option<&str> {
+        self.chain_id.as_deref()
+    }
 }
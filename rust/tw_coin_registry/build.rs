@@ -48,6 +48,12 @@ impl {ENUM_NAME} {{
     }}
 }}
 
+impl From<{ENUM_NAME}> for {RAW_TYPE} {{
+    fn from(coin: {ENUM_NAME}) -> {RAW_TYPE} {{
+        coin as {RAW_TYPE}
+    }}
+}}
+
 impl TryFrom<{RAW_TYPE}> for {ENUM_NAME} {{
     type Error = ();
 
@@ -2,51 +2,110 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::custom_registry::CustomCoinItem;
 use crate::registry::CoinItem;
+use std::sync::Arc;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::derivation::DerivationWithPath;
 use tw_hash::hasher::Hasher;
 use tw_keypair::tw::PublicKeyType;
 
+enum RegistryItem {
+    BuiltIn(&'static CoinItem),
+    Custom(Arc<CustomCoinItem>),
+}
+
 pub struct CoinRegistryContext {
-    item: &'static CoinItem,
+    item: RegistryItem,
 }
 
 impl CoinRegistryContext {
     #[inline]
     pub fn with_coin_item(item: &'static CoinItem) -> CoinRegistryContext {
-        CoinRegistryContext { item }
+        CoinRegistryContext {
+            item: RegistryItem::BuiltIn(item),
+        }
+    }
+
+    #[inline]
+    pub fn with_custom_coin_item(item: Arc<CustomCoinItem>) -> CoinRegistryContext {
+        CoinRegistryContext {
+            item: RegistryItem::Custom(item),
+        }
     }
 }
 
 impl CoinContext for CoinRegistryContext {
     #[inline]
     fn public_key_type(&self) -> PublicKeyType {
-        self.item.public_key_type
+        match self.item {
+            RegistryItem::BuiltIn(item) => item.public_key_type,
+            RegistryItem::Custom(ref item) => item.public_key_type,
+        }
     }
 
     #[inline]
     fn address_hasher(&self) -> Option<Hasher> {
-        self.item.address_hasher
+        match self.item {
+            RegistryItem::BuiltIn(item) => item.address_hasher,
+            RegistryItem::Custom(ref item) => item.address_hasher,
+        }
     }
 
     #[inline]
     fn hrp(&self) -> Option<String> {
-        self.item.hrp.clone()
+        match self.item {
+            RegistryItem::BuiltIn(item) => item.hrp.clone(),
+            RegistryItem::Custom(ref item) => item.hrp.clone(),
+        }
     }
 
     #[inline]
     fn p2pkh_prefix(&self) -> Option<u8> {
-        self.item.p2pkh_prefix
+        match self.item {
+            RegistryItem::BuiltIn(item) => item.p2pkh_prefix,
+            // Custom coins are limited to account-based chains.
+            RegistryItem::Custom(_) => None,
+        }
     }
 
     #[inline]
     fn p2sh_prefix(&self) -> Option<u8> {
-        self.item.p2sh_prefix
+        match self.item {
+            RegistryItem::BuiltIn(item) => item.p2sh_prefix,
+            RegistryItem::Custom(_) => None,
+        }
     }
 
     #[inline]
     fn derivations(&self) -> &[DerivationWithPath] {
-        &self.item.derivation
+        match self.item {
+            RegistryItem::BuiltIn(item) => &item.derivation,
+            RegistryItem::Custom(ref item) => &item.derivation,
+        }
+    }
+
+    #[inline]
+    fn decimals(&self) -> u8 {
+        match self.item {
+            RegistryItem::BuiltIn(item) => item.decimals,
+            RegistryItem::Custom(ref item) => item.decimals,
+        }
+    }
+
+    #[inline]
+    fn symbol(&self) -> &str {
+        match self.item {
+            RegistryItem::BuiltIn(item) => &item.symbol,
+            RegistryItem::Custom(ref item) => &item.symbol,
+        }
+    }
+
+    #[inline]
+    fn chain_id(&self) -> Option<&str> {
+        match self.item {
+            RegistryItem::BuiltIn(_) => None,
+            RegistryItem::Custom(ref item) => item.chain_id.as_deref(),
+        }
     }
 }
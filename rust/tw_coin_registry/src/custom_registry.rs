@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::blockchain_type::BlockchainType;
+use crate::coin_type::CoinType;
+use crate::error::{RegistryError, RegistryResult};
+use lazy_static::lazy_static;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, PoisonError, RwLock};
+use tw_coin_entry::derivation::DerivationWithPath;
+use tw_hash::hasher::Hasher;
+use tw_keypair::tw::PublicKeyType;
+use tw_number::U256;
+
+type CustomRegistryMap = HashMap<u32, Arc<CustomCoinItem>>;
+
+lazy_static! {
+    static ref CUSTOM_REGISTRY: RwLock<CustomRegistryMap> = RwLock::default();
+}
+
+/// A coin registered at runtime, e.g. a new EVM L2 or a Cosmos appchain.
+/// Can be deserialized from the same JSON format as `registry.json` items.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomCoinItem {
+    pub id: String,
+    pub name: String,
+    /// Must not conflict with any of the built-in [`CoinType`]s.
+    pub coin_id: u32,
+    pub symbol: String,
+    pub decimals: u8,
+    /// Must be one of the account-based blockchains supported in Rust, see [`is_custom_blockchain_supported`].
+    pub blockchain: BlockchainType,
+    /// Chain ID the transactions of the coin are bound to.
+    /// It's used as the default `SigningInput.chain_id`, and the signing input must not specify a different one.
+    /// Supported by the EVM blockchains (a decimal or `0x` prefixed number) and Cosmos SDK blockchains only.
+    #[serde(default)]
+    pub chain_id: Option<String>,
+    pub derivation: Vec<DerivationWithPath>,
+    pub public_key_type: PublicKeyType,
+    #[serde(default)]
+    pub address_hasher: Option<Hasher>,
+    #[serde(default)]
+    pub hrp: Option<String>,
+}
+
+/// Registers a custom coin or replaces a custom coin previously registered with the same `coin_id`.
+pub fn register_custom_coin(item: CustomCoinItem) -> RegistryResult<()> {
+    if CoinType::try_from(item.coin_id).is_ok() {
+        return Err(RegistryError::CoinTypeAlreadyExists);
+    }
+    if !is_custom_blockchain_supported(item.blockchain) {
+        return Err(RegistryError::Unsupported);
+    }
+    if item.derivation.is_empty() || matches!(item.hrp.as_deref(), Some("")) {
+        return Err(RegistryError::InvalidCoinItem);
+    }
+    if is_bech32_blockchain(item.blockchain) && item.hrp.is_none() {
+        return Err(RegistryError::InvalidCoinItem);
+    }
+    if let Some(ref chain_id) = item.chain_id {
+        if !is_chain_id_valid(item.blockchain, chain_id) {
+            return Err(RegistryError::InvalidCoinItem);
+        }
+    }
+
+    CUSTOM_REGISTRY
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(item.coin_id, Arc::new(item));
+    Ok(())
+}
+
+/// Checks whether custom coins of the given blockchain can be registered.
+///
+/// Every account-based blockchain supported in Rust is allowed.
+/// `Bitcoin` is not, as UTXO addresses require p2pkh/p2sh prefixes that custom coin items do not have.
+pub fn is_custom_blockchain_supported(blockchain: BlockchainType) -> bool {
+    match blockchain {
+        BlockchainType::Aptos
+        | BlockchainType::Binance
+        | BlockchainType::Cosmos
+        | BlockchainType::Ethereum
+        | BlockchainType::Greenfield
+        | BlockchainType::InternetComputer
+        | BlockchainType::NativeEvmos
+        | BlockchainType::NativeInjective
+        | BlockchainType::Ronin
+        | BlockchainType::Solana
+        | BlockchainType::Sui
+        | BlockchainType::Thorchain => true,
+        BlockchainType::Bitcoin | BlockchainType::Unsupported => false,
+    }
+}
+
+/// Whether addresses of the blockchain are Bech32 encoded, so custom coins require HRP.
+fn is_bech32_blockchain(blockchain: BlockchainType) -> bool {
+    matches!(
+        blockchain,
+        BlockchainType::Binance
+            | BlockchainType::Cosmos
+            | BlockchainType::NativeEvmos
+            | BlockchainType::NativeInjective
+            | BlockchainType::Thorchain
+    )
+}
+
+/// Checks whether the chain ID is valid for the blockchain and is taken into account on signing.
+fn is_chain_id_valid(blockchain: BlockchainType, chain_id: &str) -> bool {
+    match blockchain {
+        BlockchainType::Ethereum | BlockchainType::Ronin => U256::from_str(chain_id).is_ok(),
+        BlockchainType::Cosmos
+        | BlockchainType::NativeEvmos
+        | BlockchainType::NativeInjective
+        | BlockchainType::Thorchain => !chain_id.is_empty(),
+        _ => false,
+    }
+}
+
+/// Parses a custom coin from the JSON representation and registers it.
+pub fn register_custom_coin_json(item_json: &str) -> RegistryResult<()> {
+    let item: CustomCoinItem =
+        serde_json::from_str(item_json).map_err(|_| RegistryError::InvalidCoinItem)?;
+    register_custom_coin(item)
+}
+
+/// Removes a custom coin from the registry.
+/// Returns `false` if there is no custom coin registered with the given `coin_id`.
+pub fn unregister_custom_coin(coin_id: u32) -> bool {
+    CUSTOM_REGISTRY
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&coin_id)
+        .is_some()
+}
+
+#[inline]
+pub fn get_custom_coin_item(coin_id: u32) -> RegistryResult<Arc<CustomCoinItem>> {
+    CUSTOM_REGISTRY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&coin_id)
+        .cloned()
+        .ok_or(RegistryError::UnknownCoinType)
+}
+
+/// Returns all registered custom coins.
+pub fn custom_coin_items() -> Vec<Arc<CustomCoinItem>> {
+    CUSTOM_REGISTRY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .values()
+        .cloned()
+        .collect()
+}
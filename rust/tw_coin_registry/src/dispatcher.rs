@@ -5,6 +5,7 @@
 use crate::blockchain_type::BlockchainType;
 use crate::coin_context::CoinRegistryContext;
 use crate::coin_type::CoinType;
+use crate::custom_registry::get_custom_coin_item;
 use crate::error::{RegistryError, RegistryResult};
 use crate::registry::get_coin_item;
 use tw_aptos::entry::AptosEntry;
@@ -63,18 +64,34 @@ pub fn blockchain_dispatcher(blockchain: BlockchainType) -> RegistryResult<CoinE
     }
 }
 
+/// Dispatches the coin by either a built-in [`CoinType`] or an ID of a custom coin
+/// registered via [`register_custom_coin`](crate::custom_registry::register_custom_coin).
 pub fn coin_dispatcher(
-    coin: CoinType,
+    coin: impl Into<u32>,
 ) -> RegistryResult<(CoinRegistryContext, CoinEntryExtStaticRef)> {
-    let item = get_coin_item(coin)?;
+    let coin_id = coin.into();
+    if let Ok(coin) = CoinType::try_from(coin_id) {
+        let item = get_coin_item(coin)?;
+        let coin_entry = blockchain_dispatcher(item.blockchain)?;
+        let coin_context = CoinRegistryContext::with_coin_item(item);
+        return Ok((coin_context, coin_entry));
+    }
+
+    let item = get_custom_coin_item(coin_id)?;
     let coin_entry = blockchain_dispatcher(item.blockchain)?;
-    let coin_context = CoinRegistryContext::with_coin_item(item);
+    let coin_context = CoinRegistryContext::with_custom_coin_item(item);
     Ok((coin_context, coin_entry))
 }
 
-pub fn evm_dispatcher(coin: CoinType) -> RegistryResult<EvmEntryExtStaticRef> {
-    let item = get_coin_item(coin)?;
-    match item.blockchain {
+/// Dispatches the EVM coin by either a built-in [`CoinType`] or an ID of a custom coin
+/// registered via [`register_custom_coin`](crate::custom_registry::register_custom_coin).
+pub fn evm_dispatcher(coin: impl Into<u32>) -> RegistryResult<EvmEntryExtStaticRef> {
+    let coin_id = coin.into();
+    let blockchain = match CoinType::try_from(coin_id) {
+        Ok(coin) => get_coin_item(coin)?.blockchain,
+        Err(_) => get_custom_coin_item(coin_id)?.blockchain,
+    };
+    match blockchain {
         BlockchainType::Ethereum => Ok(&ETHEREUM),
         BlockchainType::Ronin => Ok(&RONIN),
         _ => Err(RegistryError::Unsupported),
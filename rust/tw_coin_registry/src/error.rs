@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use std::fmt;
 use tw_coin_entry::error::prelude::*;
 
 pub type RegistryResult<T> = Result<T, RegistryError>;
@@ -10,6 +11,20 @@ pub type RegistryResult<T> = Result<T, RegistryError>;
 pub enum RegistryError {
     UnknownCoinType,
     Unsupported,
+    CoinTypeAlreadyExists,
+    InvalidCoinItem,
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let err = match self {
+            RegistryError::UnknownCoinType => "Unknown coin type",
+            RegistryError::Unsupported => "Requested coin type is not supported in Rust yet",
+            RegistryError::CoinTypeAlreadyExists => "Coin type is already used by a built-in coin",
+            RegistryError::InvalidCoinItem => "Invalid custom coin item",
+        };
+        write!(f, "{err}")
+    }
 }
 
 impl From<RegistryError> for SigningError {
@@ -22,6 +37,14 @@ impl From<RegistryError> for SigningError {
             },
             RegistryError::Unsupported => SigningError::new(SigningErrorType::Error_internal)
                 .context("Requested coin type is not supported in Rust yet"),
+            RegistryError::CoinTypeAlreadyExists => {
+                SigningError::new(SigningErrorType::Error_invalid_params)
+                    .context("Coin type is already used by a built-in coin")
+            },
+            RegistryError::InvalidCoinItem => {
+                SigningError::new(SigningErrorType::Error_invalid_params)
+                    .context("Invalid custom coin item")
+            },
         }
     }
 }
@@ -29,6 +29,8 @@ pub struct CoinItem {
     pub id: String,
     pub name: String,
     pub coin_id: CoinType,
+    pub symbol: String,
+    pub decimals: u8,
     pub blockchain: BlockchainType,
     pub derivation: Vec<DerivationWithPath>,
     pub public_key_type: PublicKeyType,
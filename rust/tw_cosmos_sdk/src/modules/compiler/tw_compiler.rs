@@ -51,6 +51,7 @@ impl<Context: CosmosContext> TWTransactionCompiler<Context> {
         coin: &dyn CoinContext,
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let input = TxBuilder::<Context>::with_coin_chain_id(coin, input)?;
         match input.signing_mode {
             Proto::SigningMode::JSON => Self::preimage_hashes_as_json(coin, input),
             Proto::SigningMode::Protobuf => Self::preimage_hashes_as_protobuf(coin, input),
@@ -105,6 +106,7 @@ impl<Context: CosmosContext> TWTransactionCompiler<Context> {
         signatures: Vec<SignatureBytes>,
         public_keys: Vec<PublicKeyBytes>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let input = TxBuilder::<Context>::with_coin_chain_id(coin, input)?;
         match input.signing_mode {
             Proto::SigningMode::JSON => Self::compile_as_json(coin, input, signatures, public_keys),
             Proto::SigningMode::Protobuf => {
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::context::CosmosContext;
+use crate::modules::serializer::json_serializer::{
+    AnyMsg, FeeJson, JsonSerializer, UnsignedTxJson,
+};
+use crate::private_key::CosmosPrivateKey;
+use crate::public_key::CosmosPublicKey;
+use serde_json::json;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base64::Base64Encoded;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::VerifyingKeyTrait;
+use tw_misc::traits::ToBytesVec;
+use tw_proto::Cosmos::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+/// cbindgen:ignore
+pub const MSG_SIGN_DATA_TYPE: &str = "sign/MsgSignData";
+
+pub struct ArbitraryMsgPreimage {
+    pub encoded_doc: String,
+    pub doc_hash: H256,
+}
+
+/// Signs and verifies arbitrary messages following ADR-036.
+/// https://github.com/cosmos/cosmos-sdk/blob/main/docs/architecture/adr-036-arbitrary-signature.md
+pub struct CosmosMessageSigner<Context: CosmosContext> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: CosmosContext> Default for CosmosMessageSigner<Context> {
+    fn default() -> Self {
+        CosmosMessageSigner {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Context: CosmosContext> MessageSigner for CosmosMessageSigner<Context> {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(input).unwrap_or_default()
+    }
+}
+
+impl<Context: CosmosContext> CosmosMessageSigner<Context> {
+    fn message_preimage_hashes_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let ArbitraryMsgPreimage {
+            encoded_doc,
+            doc_hash,
+        } = Self::preimage(&input.signer, &input.message)?;
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::from(encoded_doc.into_bytes()),
+            data_hash: Cow::from(doc_hash.into_vec()),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        coin: &dyn CoinContext,
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let private_key = Context::PrivateKey::try_from(&input.private_key)?;
+        let public_key = Context::PublicKey::from_private_key(coin, private_key.as_ref(), None)?;
+
+        let preimage = Self::preimage(&input.signer, &input.message)?;
+        let signature_data = private_key.sign_tx_hash(preimage.doc_hash.as_slice())?;
+        let signature = Context::Signature::try_from(&signature_data)?;
+
+        let signature_json =
+            JsonSerializer::<Context>::serialize_signature(&public_key, signature.to_vec());
+        let signature_json = serde_json::to_string(&signature_json)
+            .tw_err(|_| SigningErrorType::Error_internal)
+            .context("Error serializing signature as JSON")?;
+
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::from(signature.to_vec()),
+            signature_json: Cow::from(signature_json),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(input: Proto::MessageVerifyingInput<'_>) -> SigningResult<bool> {
+        let public_key = secp256k1::PublicKey::try_from(input.public_key.as_ref())?;
+        let signature = secp256k1::VerifySignature::try_from(input.signature.as_ref())?;
+
+        let preimage = Self::preimage(&input.signer, &input.message)?;
+        Ok(public_key.verify(signature, preimage.doc_hash))
+    }
+
+    /// Builds an amino JSON sign doc with a single `sign/MsgSignData` message, zero fee,
+    /// empty chain ID, and zero account number and sequence.
+    pub fn preimage(signer: &str, data: &[u8]) -> SigningResult<ArbitraryMsgPreimage> {
+        let sign_doc = UnsignedTxJson {
+            account_number: "0".to_string(),
+            chain_id: String::default(),
+            fee: FeeJson {
+                amount: Vec::default(),
+                gas: "0".to_string(),
+            },
+            memo: String::default(),
+            msgs: vec![AnyMsg {
+                msg_type: MSG_SIGN_DATA_TYPE.to_string(),
+                value: json!({
+                    "data": Base64Encoded(data.to_vec()),
+                    "signer": signer,
+                }),
+            }],
+            sequence: "0".to_string(),
+            timeout_height: None,
+        };
+
+        let encoded_doc = serde_json::to_string(&sign_doc)
+            .tw_err(|_| SigningErrorType::Error_internal)
+            .context("Error serializing ADR-036 sign doc as JSON")?;
+        let doc_hash = Context::default_tx_hasher().hash(encoded_doc.as_bytes());
+        let doc_hash = H256::try_from(doc_hash.as_slice())
+            .tw_err(|_| SigningErrorType::Error_internal)
+            .context("Expected a 32-byte sign doc hash")?;
+
+        Ok(ArbitraryMsgPreimage {
+            encoded_doc,
+            doc_hash,
+        })
+    }
+}
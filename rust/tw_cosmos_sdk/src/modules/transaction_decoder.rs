@@ -0,0 +1,456 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::proto::cosmos::base::v1beta1 as base_proto;
+use crate::proto::cosmos::tx::v1beta1 as tx_proto;
+use crate::proto::{cosmos, cosmwasm, ethermint, ibc, injective, stride, terra, types};
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_proto::Cosmos::Proto;
+use tw_proto::{deserialize, google, type_url, MessageInfo, MessageRead};
+
+use Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+
+const STAKE_AUTHORIZATION_MSG_TYPE: &str = "/cosmos.staking.v1beta1.StakeAuthorization";
+
+/// `CosmosTransactionDecoder` decodes a Protobuf-serialized [`tx_proto::TxRaw`] transaction
+/// and represents it as [`Proto::SigningInput`].
+pub struct CosmosTransactionDecoder;
+
+impl TransactionDecoder for CosmosTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, _coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl CosmosTransactionDecoder {
+    fn decode_transaction_impl(
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let tx_raw: tx_proto::TxRaw = deserialize(tx)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding Cosmos TxRaw")?;
+        let tx_body: tx_proto::TxBody = deserialize(&tx_raw.body_bytes)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding Cosmos TxBody")?;
+        let auth_info: tx_proto::AuthInfo = deserialize(&tx_raw.auth_info_bytes)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding Cosmos AuthInfo")?;
+
+        let messages = tx_body
+            .messages
+            .iter()
+            .map(Self::message_from_any)
+            .collect::<SigningResult<_>>()?;
+
+        let signers: Vec<_> = auth_info
+            .signer_infos
+            .iter()
+            .map(Self::signer_to_proto)
+            .collect::<SigningResult<_>>()?;
+
+        let (sequence, public_key) = match signers.first() {
+            Some(signer) => (signer.sequence, signer.public_key.clone()),
+            None => (0, Cow::default()),
+        };
+
+        let transaction = Proto::SigningInput {
+            signing_mode: Proto::SigningMode::Protobuf,
+            fee: auth_info.fee.as_ref().map(Self::fee_to_proto),
+            memo: Cow::from(tx_body.memo),
+            sequence,
+            messages,
+            public_key,
+            timeout_height: tx_body.timeout_height,
+            ..Proto::SigningInput::default()
+        };
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(transaction),
+            signers,
+            signatures: tx_raw.signatures.into_iter().map(Cow::from).collect(),
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+
+    /// Returns the public key bytes of a standard or Ethereum-compatible secp256k1 public key.
+    /// Returns an empty vector if the public key is of a different type (e.g. a multisig one).
+    pub fn public_key_from_signer(signer: &tx_proto::SignerInfo) -> SigningResult<Vec<u8>> {
+        let Some(ref public_key) = signer.public_key else {
+            return Ok(Vec::default());
+        };
+
+        let secp256k1_type_urls = [
+            type_url::<cosmos::crypto::secp256k1::PubKey>(),
+            type_url::<ethermint::crypto::v1::ethsecp256k1::PubKey>(),
+            type_url::<injective::crypto::v1beta1::ethsecp256k1::PubKey>(),
+            type_url::<cosmos::crypto::eth::ethsecp256k1::PubKey>(),
+        ];
+        if !secp256k1_type_urls.contains(&public_key.type_url) {
+            return Ok(Vec::default());
+        }
+
+        // All of the public key types above are represented as `{ bytes key = 1; }`.
+        let public_key: cosmos::crypto::secp256k1::PubKey = deserialize(&public_key.value)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding signer public key")?;
+        Ok(public_key.key)
+    }
+
+    fn signer_to_proto(
+        signer: &tx_proto::SignerInfo,
+    ) -> SigningResult<Proto::TransactionSigner<'static>> {
+        Ok(Proto::TransactionSigner {
+            public_key: Cow::from(Self::public_key_from_signer(signer)?),
+            sequence: signer.sequence,
+        })
+    }
+
+    fn fee_to_proto(fee: &tx_proto::Fee) -> Proto::Fee<'static> {
+        Proto::Fee {
+            amounts: fee.amount.iter().map(Self::coin_to_proto).collect(),
+            gas: fee.gas_limit,
+        }
+    }
+
+    fn coin_to_proto(coin: &base_proto::Coin) -> Proto::Amount<'static> {
+        Proto::Amount {
+            denom: Cow::from(coin.denom.clone()),
+            amount: Cow::from(coin.amount.clone()),
+        }
+    }
+
+    /// Maps the given Protobuf message to the corresponding [`Proto::Message`].
+    /// Messages that are not supported or cannot be represented without loss of information
+    /// are returned as [`Proto::mod_Message::RawProtobuf`].
+    fn message_from_any(any: &google::protobuf::Any) -> SigningResult<Proto::Message<'static>> {
+        use cosmos::authz::v1beta1::{MsgGrant, MsgRevoke};
+        use cosmos::bank::v1beta1::MsgSend;
+        use cosmos::distribution::v1beta1::{MsgSetWithdrawAddress, MsgWithdrawDelegatorReward};
+        use cosmos::gov::v1beta1::MsgVote;
+        use cosmos::staking::v1beta1::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate};
+        use ibc::applications::transfer::v1::MsgTransfer;
+        use stride::stakeibc::{MsgLiquidStake, MsgRedeemStake};
+
+        let message_oneof = if is_type::<MsgSend>(any) {
+            Some(Self::send_msg_to_proto(decode_any(any)?))
+        } else if is_type::<MsgTransfer>(any) {
+            Some(Self::transfer_tokens_msg_to_proto(decode_any(any)?))
+        } else if is_type::<MsgDelegate>(any) {
+            Some(Self::delegate_msg_to_proto(decode_any(any)?))
+        } else if is_type::<MsgUndelegate>(any) {
+            Some(Self::undelegate_msg_to_proto(decode_any(any)?))
+        } else if is_type::<MsgBeginRedelegate>(any) {
+            Some(Self::redelegate_msg_to_proto(decode_any(any)?))
+        } else if is_type::<MsgWithdrawDelegatorReward>(any) {
+            Some(Self::withdraw_reward_msg_to_proto(decode_any(any)?))
+        } else if is_type::<MsgSetWithdrawAddress>(any) {
+            Some(Self::set_withdraw_address_msg_to_proto(decode_any(any)?))
+        } else if is_type::<cosmwasm::wasm::v1::MsgExecuteContract>(any) {
+            Self::wasm_execute_contract_msg_to_proto(decode_any(any)?)
+        } else if is_type::<terra::wasm::v1beta1::MsgExecuteContract>(any) {
+            Self::wasm_terra_execute_contract_msg_to_proto(decode_any(any)?)
+        } else if is_type::<MsgGrant>(any) {
+            Self::auth_grant_msg_to_proto(decode_any(any)?)?
+        } else if is_type::<MsgRevoke>(any) {
+            Some(Self::auth_revoke_msg_to_proto(decode_any(any)?))
+        } else if is_type::<MsgVote>(any) {
+            Some(Self::vote_msg_to_proto(decode_any(any)?))
+        } else if is_type::<MsgLiquidStake>(any) {
+            Some(Self::stride_stake_msg_to_proto(decode_any(any)?))
+        } else if is_type::<MsgRedeemStake>(any) {
+            Some(Self::stride_redeem_msg_to_proto(decode_any(any)?))
+        } else if is_type::<types::MsgSend>(any) {
+            Some(Self::thorchain_send_msg_to_proto(decode_any(any)?))
+        } else if is_type::<types::MsgDeposit>(any) {
+            Some(Self::thorchain_deposit_msg_to_proto(decode_any(any)?))
+        } else {
+            None
+        };
+
+        let message_oneof = message_oneof.unwrap_or_else(|| Self::raw_msg_to_proto(any));
+        Ok(Proto::Message { message_oneof })
+    }
+
+    fn raw_msg_to_proto(any: &google::protobuf::Any) -> MessageEnum<'static> {
+        MessageEnum::raw_protobuf_message(Proto::mod_Message::RawProtobuf {
+            type_url: Cow::from(any.type_url.clone()),
+            value: Cow::from(any.value.clone()),
+        })
+    }
+
+    fn send_msg_to_proto(msg: cosmos::bank::v1beta1::MsgSend) -> MessageEnum<'static> {
+        MessageEnum::send_coins_message(Proto::mod_Message::Send {
+            from_address: Cow::from(msg.from_address),
+            to_address: Cow::from(msg.to_address),
+            amounts: msg.amount.iter().map(Self::coin_to_proto).collect(),
+            ..Proto::mod_Message::Send::default()
+        })
+    }
+
+    fn transfer_tokens_msg_to_proto(
+        msg: ibc::applications::transfer::v1::MsgTransfer,
+    ) -> MessageEnum<'static> {
+        let timeout_height = msg.timeout_height.map(|height| Proto::Height {
+            revision_number: height.revision_number,
+            revision_height: height.revision_height,
+        });
+
+        MessageEnum::transfer_tokens_message(Proto::mod_Message::Transfer {
+            source_port: Cow::from(msg.source_port),
+            source_channel: Cow::from(msg.source_channel),
+            token: msg.token.as_ref().map(Self::coin_to_proto),
+            sender: Cow::from(msg.sender),
+            receiver: Cow::from(msg.receiver),
+            timeout_height,
+            timeout_timestamp: msg.timeout_timestamp,
+        })
+    }
+
+    fn delegate_msg_to_proto(msg: cosmos::staking::v1beta1::MsgDelegate) -> MessageEnum<'static> {
+        MessageEnum::stake_message(Proto::mod_Message::Delegate {
+            delegator_address: Cow::from(msg.delegator_address),
+            validator_address: Cow::from(msg.validator_address),
+            amount: msg.amount.as_ref().map(Self::coin_to_proto),
+            ..Proto::mod_Message::Delegate::default()
+        })
+    }
+
+    fn undelegate_msg_to_proto(
+        msg: cosmos::staking::v1beta1::MsgUndelegate,
+    ) -> MessageEnum<'static> {
+        MessageEnum::unstake_message(Proto::mod_Message::Undelegate {
+            delegator_address: Cow::from(msg.delegator_address),
+            validator_address: Cow::from(msg.validator_address),
+            amount: msg.amount.as_ref().map(Self::coin_to_proto),
+            ..Proto::mod_Message::Undelegate::default()
+        })
+    }
+
+    fn redelegate_msg_to_proto(
+        msg: cosmos::staking::v1beta1::MsgBeginRedelegate,
+    ) -> MessageEnum<'static> {
+        MessageEnum::restake_message(Proto::mod_Message::BeginRedelegate {
+            delegator_address: Cow::from(msg.delegator_address),
+            validator_src_address: Cow::from(msg.validator_src_address),
+            validator_dst_address: Cow::from(msg.validator_dst_address),
+            amount: msg.amount.as_ref().map(Self::coin_to_proto),
+            ..Proto::mod_Message::BeginRedelegate::default()
+        })
+    }
+
+    fn withdraw_reward_msg_to_proto(
+        msg: cosmos::distribution::v1beta1::MsgWithdrawDelegatorReward,
+    ) -> MessageEnum<'static> {
+        MessageEnum::withdraw_stake_reward_message(Proto::mod_Message::WithdrawDelegationReward {
+            delegator_address: Cow::from(msg.delegator_address),
+            validator_address: Cow::from(msg.validator_address),
+            ..Proto::mod_Message::WithdrawDelegationReward::default()
+        })
+    }
+
+    fn set_withdraw_address_msg_to_proto(
+        msg: cosmos::distribution::v1beta1::MsgSetWithdrawAddress,
+    ) -> MessageEnum<'static> {
+        MessageEnum::set_withdraw_address_message(Proto::mod_Message::SetWithdrawAddress {
+            delegator_address: Cow::from(msg.delegator_address),
+            withdraw_address: Cow::from(msg.withdraw_address),
+            ..Proto::mod_Message::SetWithdrawAddress::default()
+        })
+    }
+
+    /// Returns `None` if the execute message is not a valid UTF-8 string.
+    fn wasm_execute_contract_msg_to_proto(
+        msg: cosmwasm::wasm::v1::MsgExecuteContract,
+    ) -> Option<MessageEnum<'static>> {
+        let execute_msg = String::from_utf8(msg.msg).ok()?;
+        Some(MessageEnum::wasm_execute_contract_generic(
+            Proto::mod_Message::WasmExecuteContractGeneric {
+                sender_address: Cow::from(msg.sender),
+                contract_address: Cow::from(msg.contract),
+                execute_msg: Cow::from(execute_msg),
+                coins: msg.funds.iter().map(Self::coin_to_proto).collect(),
+            },
+        ))
+    }
+
+    /// Returns `None` if the execute message is not a valid UTF-8 string.
+    fn wasm_terra_execute_contract_msg_to_proto(
+        msg: terra::wasm::v1beta1::MsgExecuteContract,
+    ) -> Option<MessageEnum<'static>> {
+        let execute_msg = String::from_utf8(msg.execute_msg).ok()?;
+        Some(MessageEnum::wasm_terra_execute_contract_generic(
+            Proto::mod_Message::WasmTerraExecuteContractGeneric {
+                sender_address: Cow::from(msg.sender),
+                contract_address: Cow::from(msg.contract),
+                execute_msg: Cow::from(execute_msg),
+                coins: msg.coins.iter().map(Self::coin_to_proto).collect(),
+            },
+        ))
+    }
+
+    /// Returns `None` if the grant is not a staking authorization.
+    fn auth_grant_msg_to_proto(
+        msg: cosmos::authz::v1beta1::MsgGrant,
+    ) -> SigningResult<Option<MessageEnum<'static>>> {
+        use Proto::mod_Message::mod_AuthGrant::OneOfgrant_type as ProtoGrantType;
+
+        let Some(grant) = msg.grant else {
+            return Ok(None);
+        };
+        let Some(authorization) = grant.authorization else {
+            return Ok(None);
+        };
+        if authorization.type_url != STAKE_AUTHORIZATION_MSG_TYPE {
+            return Ok(None);
+        }
+
+        let stake: Proto::mod_Message::StakeAuthorization = deserialize(&authorization.value)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding Grant Stake Protobuf message")?;
+
+        Ok(Some(MessageEnum::auth_grant(
+            Proto::mod_Message::AuthGrant {
+                granter: Cow::from(msg.granter),
+                grantee: Cow::from(msg.grantee),
+                grant_type: ProtoGrantType::grant_stake(Self::stake_authorization_to_owned(stake)),
+                expiration: grant
+                    .expiration
+                    .map(|time| time.seconds)
+                    .unwrap_or_default(),
+            },
+        )))
+    }
+
+    fn stake_authorization_to_owned(
+        stake: Proto::mod_Message::StakeAuthorization<'_>,
+    ) -> Proto::mod_Message::StakeAuthorization<'static> {
+        use Proto::mod_Message::mod_StakeAuthorization::{
+            OneOfvalidators as ValidatorsEnum, Validators,
+        };
+
+        let validators_to_owned = |validators: Validators<'_>| Validators {
+            address: validators
+                .address
+                .into_iter()
+                .map(|address| Cow::from(address.into_owned()))
+                .collect(),
+        };
+
+        let validators = match stake.validators {
+            ValidatorsEnum::allow_list(allow) => {
+                ValidatorsEnum::allow_list(validators_to_owned(allow))
+            },
+            ValidatorsEnum::deny_list(deny) => ValidatorsEnum::deny_list(validators_to_owned(deny)),
+            ValidatorsEnum::None => ValidatorsEnum::None,
+        };
+        let max_tokens = stake.max_tokens.map(|amount| Proto::Amount {
+            denom: Cow::from(amount.denom.into_owned()),
+            amount: Cow::from(amount.amount.into_owned()),
+        });
+
+        Proto::mod_Message::StakeAuthorization {
+            max_tokens,
+            validators,
+            authorization_type: stake.authorization_type,
+        }
+    }
+
+    fn auth_revoke_msg_to_proto(msg: cosmos::authz::v1beta1::MsgRevoke) -> MessageEnum<'static> {
+        MessageEnum::auth_revoke(Proto::mod_Message::AuthRevoke {
+            granter: Cow::from(msg.granter),
+            grantee: Cow::from(msg.grantee),
+            msg_type_url: Cow::from(msg.msg_type_url),
+        })
+    }
+
+    fn vote_msg_to_proto(msg: cosmos::gov::v1beta1::MsgVote) -> MessageEnum<'static> {
+        use cosmos::gov::v1beta1::VoteOption;
+        use Proto::mod_Message::VoteOption as ProtoVoteOption;
+
+        let option = match msg.option {
+            VoteOption::VOTE_OPTION_UNSPECIFIED => ProtoVoteOption::_UNSPECIFIED,
+            VoteOption::VOTE_OPTION_YES => ProtoVoteOption::YES,
+            VoteOption::VOTE_OPTION_ABSTAIN => ProtoVoteOption::ABSTAIN,
+            VoteOption::VOTE_OPTION_NO => ProtoVoteOption::NO,
+            VoteOption::VOTE_OPTION_NO_WITH_VETO => ProtoVoteOption::NO_WITH_VETO,
+        };
+
+        MessageEnum::msg_vote(Proto::mod_Message::MsgVote {
+            proposal_id: msg.proposal_id,
+            voter: Cow::from(msg.voter),
+            option,
+        })
+    }
+
+    fn stride_stake_msg_to_proto(msg: stride::stakeibc::MsgLiquidStake) -> MessageEnum<'static> {
+        MessageEnum::msg_stride_liquid_staking_stake(
+            Proto::mod_Message::MsgStrideLiquidStakingStake {
+                creator: Cow::from(msg.creator),
+                amount: Cow::from(msg.amount),
+                host_denom: Cow::from(msg.host_denom),
+            },
+        )
+    }
+
+    fn stride_redeem_msg_to_proto(msg: stride::stakeibc::MsgRedeemStake) -> MessageEnum<'static> {
+        MessageEnum::msg_stride_liquid_staking_redeem(
+            Proto::mod_Message::MsgStrideLiquidStakingRedeem {
+                creator: Cow::from(msg.creator),
+                amount: Cow::from(msg.amount),
+                host_zone: Cow::from(msg.host_zone),
+                receiver: Cow::from(msg.receiver),
+            },
+        )
+    }
+
+    fn thorchain_send_msg_to_proto(msg: types::MsgSend) -> MessageEnum<'static> {
+        MessageEnum::thorchain_send_message(Proto::mod_Message::THORChainSend {
+            from_address: Cow::from(msg.from_address),
+            to_address: Cow::from(msg.to_address),
+            amounts: msg.amount.iter().map(Self::coin_to_proto).collect(),
+        })
+    }
+
+    fn thorchain_deposit_msg_to_proto(msg: types::MsgDeposit) -> MessageEnum<'static> {
+        let coins = msg
+            .coins
+            .into_iter()
+            .map(|coin| Proto::THORChainCoin {
+                asset: coin.asset.map(|asset| Proto::THORChainAsset {
+                    chain: Cow::from(asset.chain),
+                    symbol: Cow::from(asset.symbol),
+                    ticker: Cow::from(asset.ticker),
+                    synth: asset.synth,
+                }),
+                amount: Cow::from(coin.amount),
+                decimals: coin.decimals,
+            })
+            .collect();
+
+        MessageEnum::thorchain_deposit_message(Proto::mod_Message::THORChainDeposit {
+            coins,
+            memo: Cow::from(msg.memo),
+            signer: Cow::from(msg.signer),
+        })
+    }
+}
+
+/// Checks if the given `Any` message is of the `T` type.
+pub fn is_type<T: MessageInfo>(any: &google::protobuf::Any) -> bool {
+    any.type_url == type_url::<T>()
+}
+
+/// Decodes the given `Any` message as `T`.
+pub fn decode_any<'a, T: MessageRead<'a>>(any: &'a google::protobuf::Any) -> SigningResult<T> {
+    deserialize(&any.value)
+        .tw_err(|_| SigningErrorType::Error_input_parse)
+        .with_context(|| format!("Error decoding '{}' message", any.type_url))
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::proto::cosmos::tx::v1beta1 as tx_proto;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_hasher::TransactionHasher;
+use tw_encoding::hex::ToHex;
+use tw_hash::sha2::sha256;
+use tw_proto::deserialize;
+
+/// Computes the transaction hash as `SHA256` of the Protobuf-serialized [`tx_proto::TxRaw`].
+/// The hash is represented as an uppercase hex string, the same way as Cosmos SDK nodes do.
+pub struct CosmosTransactionHasher;
+
+impl TransactionHasher for CosmosTransactionHasher {
+    fn tx_id(&self, _coin: &dyn CoinContext, signed_tx: &[u8]) -> SigningResult<String> {
+        let tx_raw: tx_proto::TxRaw = deserialize(signed_tx)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding Cosmos TxRaw")?;
+        if tx_raw.signatures.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Transaction is not signed");
+        }
+        Ok(sha256(signed_tx).to_hex().to_uppercase())
+    }
+}
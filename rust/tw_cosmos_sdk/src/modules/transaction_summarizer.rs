@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_summarizer::{
+    action, fee, native_amount, native_asset, warning, KnownTokens, TransactionSummarizer,
+};
+use tw_coin_entry::signing_output_error;
+use tw_number::U256;
+use tw_proto::Cosmos::Proto;
+use tw_proto::TxSummary::Proto as SummaryProto;
+
+use SummaryProto::{ActionType, WarningType};
+
+/// Describes Cosmos transactions by the messages of `SigningInput`.
+///
+/// Amounts of the native denomination are formatted with the coin decimals,
+/// other denominations and CosmWasm tokens are formatted only if specified in [`KnownTokens`].
+/// Bank and IBC transfers, staking and CosmWasm token transfers are recognized.
+pub struct CosmosTransactionSummarizer;
+
+impl TransactionSummarizer for CosmosTransactionSummarizer {
+    type SigningInput<'a> = Proto::SigningInput<'a>;
+
+    fn summarize_transaction(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::SigningInput<'_>,
+        tokens: &KnownTokens,
+    ) -> SummaryProto::TransactionSummary<'static> {
+        Self::summarize_transaction_impl(coin, tokens, &input)
+            .unwrap_or_else(|e| signing_output_error!(SummaryProto::TransactionSummary, e))
+    }
+}
+
+impl CosmosTransactionSummarizer {
+    fn summarize_transaction_impl(
+        coin: &dyn CoinContext,
+        tokens: &KnownTokens,
+        input: &Proto::SigningInput<'_>,
+    ) -> SigningResult<SummaryProto::TransactionSummary<'static>> {
+        if input.messages.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("No TX messages provided");
+        }
+
+        let mut summary = SummaryProto::TransactionSummary::default();
+        for message in input.messages.iter() {
+            Self::summarize_message(coin, tokens, &mut summary, message)?;
+        }
+
+        if let Some(fee_amount) = input.fee.as_ref().and_then(|fee| fee.amounts.first()) {
+            let (asset, fee_amount) = coin_from_proto(coin, tokens, fee_amount)?;
+            summary.fee = Some(fee(asset, fee_amount));
+        }
+        Ok(summary)
+    }
+
+    fn summarize_message(
+        coin: &dyn CoinContext,
+        tokens: &KnownTokens,
+        summary: &mut SummaryProto::TransactionSummary<'static>,
+        message: &Proto::Message<'_>,
+    ) -> SigningResult<()> {
+        use Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+
+        match message.message_oneof {
+            MessageEnum::send_coins_message(ref send) => {
+                for coin_amount in send.amounts.iter() {
+                    let (asset, coin_amount) = coin_from_proto(coin, tokens, coin_amount)?;
+                    summary.actions.push(action(
+                        ActionType::Transfer,
+                        asset,
+                        Some(coin_amount),
+                        &send.from_address,
+                        &send.to_address,
+                    ));
+                }
+            },
+            MessageEnum::thorchain_send_message(ref send) => {
+                let from_address =
+                    Address::from_key_hash_with_coin(coin, send.from_address.to_vec())
+                        .into_tw()
+                        .context("Invalid 'from' address")?;
+                let to_address = Address::from_key_hash_with_coin(coin, send.to_address.to_vec())
+                    .into_tw()
+                    .context("Invalid 'to' address")?;
+                for coin_amount in send.amounts.iter() {
+                    let (asset, coin_amount) = coin_from_proto(coin, tokens, coin_amount)?;
+                    summary.actions.push(action(
+                        ActionType::Transfer,
+                        asset,
+                        Some(coin_amount),
+                        &from_address,
+                        &to_address,
+                    ));
+                }
+            },
+            MessageEnum::transfer_tokens_message(ref transfer) => {
+                let token = transfer
+                    .token
+                    .as_ref()
+                    .or_tw_err(SigningErrorType::Error_invalid_params)
+                    .context("No token specified")?;
+                let (asset, token_amount) = coin_from_proto(coin, tokens, token)?;
+                summary.actions.push(action(
+                    ActionType::TokenTransfer,
+                    asset,
+                    Some(token_amount),
+                    &transfer.sender,
+                    &transfer.receiver,
+                ));
+            },
+            MessageEnum::stake_message(ref delegate) => {
+                let (asset, stake_amount) =
+                    optional_coin_from_proto(coin, tokens, &delegate.amount)?;
+                summary.actions.push(action(
+                    ActionType::Stake,
+                    asset,
+                    stake_amount,
+                    &delegate.delegator_address,
+                    &delegate.validator_address,
+                ));
+            },
+            MessageEnum::unstake_message(ref undelegate) => {
+                let (asset, unstake_amount) =
+                    optional_coin_from_proto(coin, tokens, &undelegate.amount)?;
+                summary.actions.push(action(
+                    ActionType::Unstake,
+                    asset,
+                    unstake_amount,
+                    &undelegate.delegator_address,
+                    &undelegate.validator_address,
+                ));
+            },
+            MessageEnum::restake_message(ref redelegate) => {
+                let (asset, restake_amount) =
+                    optional_coin_from_proto(coin, tokens, &redelegate.amount)?;
+                summary.actions.push(action(
+                    ActionType::Restake,
+                    asset,
+                    restake_amount,
+                    &redelegate.validator_src_address,
+                    &redelegate.validator_dst_address,
+                ));
+            },
+            MessageEnum::withdraw_stake_reward_message(ref withdraw) => {
+                summary.actions.push(action(
+                    ActionType::ClaimRewards,
+                    native_asset(coin),
+                    None,
+                    &withdraw.delegator_address,
+                    &withdraw.validator_address,
+                ));
+            },
+            MessageEnum::wasm_execute_contract_transfer_message(ref transfer) => {
+                let token_amount = U256::from_big_endian_slice(&transfer.amount)
+                    .into_tw()
+                    .context("Invalid token amount")?;
+                summary.actions.push(action(
+                    ActionType::TokenTransfer,
+                    tokens.asset(&transfer.contract_address),
+                    Some(tokens.amount(&transfer.contract_address, token_amount)),
+                    &transfer.sender_address,
+                    &transfer.recipient_address,
+                ));
+            },
+            MessageEnum::wasm_terra_execute_contract_transfer_message(ref transfer) => {
+                let token_amount = U256::from_big_endian_slice(&transfer.amount)
+                    .into_tw()
+                    .context("Invalid token amount")?;
+                summary.actions.push(action(
+                    ActionType::TokenTransfer,
+                    tokens.asset(&transfer.contract_address),
+                    Some(tokens.amount(&transfer.contract_address, token_amount)),
+                    &transfer.sender_address,
+                    &transfer.recipient_address,
+                ));
+            },
+            MessageEnum::None => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("No TX message provided")
+            },
+            _ => {
+                summary.warnings.push(warning(
+                    WarningType::UnknownContractCall,
+                    "The transaction contains a message that cannot be described",
+                ));
+                let mut unknown =
+                    action(ActionType::ContractCall, native_asset(coin), None, "", "");
+                unknown.description = "Execute an unknown message".into();
+                summary.actions.push(unknown);
+            },
+        }
+        Ok(())
+    }
+}
+
+/// Returns the native asset if `input` is of the native denomination,
+/// otherwise an asset identified by the denomination.
+fn coin_from_proto(
+    coin: &dyn CoinContext,
+    tokens: &KnownTokens,
+    input: &Proto::Amount<'_>,
+) -> SigningResult<(SummaryProto::Asset<'static>, SummaryProto::Amount<'static>)> {
+    let value = U256::from_str(&input.amount)
+        .into_tw()
+        .context("Invalid amount, expected string decimal")?;
+    if is_native_denom(coin, &input.denom) {
+        return Ok((native_asset(coin), native_amount(coin, value)));
+    }
+    Ok((
+        tokens.asset(&input.denom),
+        tokens.amount(&input.denom, value),
+    ))
+}
+
+/// Returns whether `denom` is the native denomination of the coin.
+/// The denomination is expected to be the lowercase symbol prefixed with the SI unit prefix
+/// matching the coin decimals, e.g. "uatom" for ATOM, "aevmos" for EVMOS, "inj" or "rune".
+fn is_native_denom(coin: &dyn CoinContext, denom: &str) -> bool {
+    let symbol = coin.symbol().to_lowercase();
+    let denom = denom.to_lowercase();
+    match coin.decimals() {
+        6 => denom == format!("u{symbol}"),
+        18 => denom == format!("a{symbol}") || denom == symbol,
+        _ => denom == symbol,
+    }
+}
+
+fn optional_coin_from_proto(
+    coin: &dyn CoinContext,
+    tokens: &KnownTokens,
+    input: &Option<Proto::Amount<'_>>,
+) -> SigningResult<(
+    SummaryProto::Asset<'static>,
+    Option<SummaryProto::Amount<'static>>,
+)> {
+    match input {
+        Some(input) => {
+            let (asset, value) = coin_from_proto(coin, tokens, input)?;
+            Ok((asset, Some(value)))
+        },
+        None => Ok((native_asset(coin), None)),
+    }
+}
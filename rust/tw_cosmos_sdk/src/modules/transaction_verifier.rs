@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::context::CosmosContext;
+use crate::modules::transaction_decoder::CosmosTransactionDecoder;
+use crate::proto::cosmos::tx::v1beta1 as tx_proto;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_verifier::{verifying_output, TransactionVerifier};
+use tw_coin_entry::signing_output_error;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::VerifyingKeyTrait;
+use tw_proto::TxVerifier::Proto as VerifierProto;
+use tw_proto::{deserialize, serialize};
+
+/// Verifies signatures of a Protobuf-serialized [`tx_proto::TxRaw`] transaction signed in `SIGN_MODE_DIRECT`.
+///
+/// Every signature is verified against the corresponding `AuthInfo.signer_infos` public key
+/// over the [`tx_proto::SignDoc`] hash computed with [`CosmosContext::default_tx_hasher`].
+pub struct CosmosTransactionVerifier<Context: CosmosContext> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: CosmosContext> Default for CosmosTransactionVerifier<Context> {
+    fn default() -> Self {
+        CosmosTransactionVerifier {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Context: CosmosContext> TransactionVerifier for CosmosTransactionVerifier<Context> {
+    fn verify_transaction(
+        &self,
+        _coin: &dyn CoinContext,
+        input: VerifierProto::VerifyingInput<'_>,
+    ) -> VerifierProto::VerifyingOutput<'static> {
+        Self::verify_transaction_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(VerifierProto::VerifyingOutput, e))
+    }
+}
+
+impl<Context: CosmosContext> CosmosTransactionVerifier<Context> {
+    fn verify_transaction_impl(
+        input: VerifierProto::VerifyingInput<'_>,
+    ) -> SigningResult<VerifierProto::VerifyingOutput<'static>> {
+        let expected_keys = input
+            .public_keys
+            .iter()
+            .map(|key| secp256k1::PublicKey::try_from(key.as_ref()))
+            .collect::<Result<Vec<_>, _>>()
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .context("Invalid expected public key")?;
+
+        let tx_raw: tx_proto::TxRaw = deserialize(&input.transaction)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding Cosmos TxRaw")?;
+        let auth_info: tx_proto::AuthInfo = deserialize(&tx_raw.auth_info_bytes)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding Cosmos AuthInfo")?;
+
+        let signers_count = auth_info.signer_infos.len();
+        if tx_raw.signatures.len() != signers_count {
+            return SigningError::err(SigningErrorType::Error_signatures_count).with_context(
+                || {
+                    format!(
+                        "Expected '{signers_count}' signatures, found '{}'",
+                        tx_raw.signatures.len()
+                    )
+                },
+            );
+        }
+        if input.account_numbers.len() != signers_count {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!(
+                    "Expected '{signers_count}' account numbers, provided '{}'",
+                    input.account_numbers.len()
+                )
+            });
+        }
+
+        let signatures = auth_info
+            .signer_infos
+            .iter()
+            .zip(tx_raw.signatures.iter())
+            .zip(input.account_numbers.iter())
+            .map(|((signer, signature), account_number)| {
+                let public_key = CosmosTransactionDecoder::public_key_from_signer(signer)?;
+
+                let sign_doc = tx_proto::SignDoc {
+                    body_bytes: tx_raw.body_bytes.clone(),
+                    auth_info_bytes: tx_raw.auth_info_bytes.clone(),
+                    chain_id: input.chain_id.to_string(),
+                    account_number: *account_number,
+                };
+                let sign_doc_hash = Context::default_tx_hasher().hash(
+                    &serialize(&sign_doc).expect("Unexpected error on SignDoc serialization"),
+                );
+
+                let valid =
+                    Self::verify_signature(&public_key, signature, &sign_doc_hash, &expected_keys);
+                Ok(VerifierProto::SignatureVerification {
+                    public_key: Cow::Owned(public_key),
+                    signature: Cow::Owned(signature.clone()),
+                    valid,
+                })
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        Ok(verifying_output(signatures))
+    }
+
+    /// Returns `false` if the signer public key is not a `secp256k1` one (e.g. a multisig),
+    /// or it does not belong to the `expected_keys` if specified.
+    fn verify_signature(
+        public_key: &[u8],
+        signature: &[u8],
+        sign_doc_hash: &[u8],
+        expected_keys: &[secp256k1::PublicKey],
+    ) -> bool {
+        let Ok(public_key) = secp256k1::PublicKey::try_from(public_key) else {
+            return false;
+        };
+        if !expected_keys.is_empty() && !expected_keys.contains(&public_key) {
+            return false;
+        }
+        let (Ok(signature), Ok(hash)) = (
+            secp256k1::VerifySignature::try_from(signature),
+            H256::try_from(sign_doc_hash),
+        ) else {
+            return false;
+        };
+        public_key.verify(signature, hash)
+    }
+}
@@ -6,9 +6,10 @@ use crate::address::Address;
 use crate::context::CosmosContext;
 use crate::modules::serializer::protobuf_serializer::SignDirectArgs;
 use crate::public_key::{CosmosPublicKey, PublicKeyParams};
-use crate::transaction::message::cosmos_generic_message::JsonRawMessage;
+use crate::transaction::message::cosmos_generic_message::{JsonRawMessage, ProtobufRawMessage};
 use crate::transaction::message::{CosmosMessage, CosmosMessageBox};
 use crate::transaction::{Coin, Fee, SignMode, SignerInfo, TxBody, UnsignedTransaction};
+use std::borrow::Cow;
 use std::marker::PhantomData;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
@@ -28,6 +29,29 @@ impl<Context> TxBuilder<Context>
 where
     Context: CosmosContext,
 {
+    /// Binds the signing input to the chain ID of the coin, if the coin has one (see [`CoinContext::chain_id`]).
+    /// The chain ID of the coin is used if the input does not specify any, otherwise both must be equal.
+    pub fn with_coin_chain_id<'a>(
+        coin: &dyn CoinContext,
+        mut input: Proto::SigningInput<'a>,
+    ) -> SigningResult<Proto::SigningInput<'a>> {
+        let Some(coin_chain_id) = coin.chain_id() else {
+            return Ok(input);
+        };
+
+        if input.chain_id.is_empty() {
+            input.chain_id = Cow::from(coin_chain_id.to_string());
+        } else if input.chain_id != coin_chain_id {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!(
+                    "Chain ID '{}' does not match the chain ID '{coin_chain_id}' of the coin",
+                    input.chain_id
+                )
+            });
+        }
+        Ok(input)
+    }
+
     /// Please note that [`Proto::SigningInput::public_key`] must be set.
     /// If the public key should be derived from a private key, please do it before this method is called.
     pub fn unsigned_tx_from_proto(
@@ -220,6 +244,9 @@ where
             MessageEnum::thorchain_deposit_message(ref deposit) => {
                 Self::thorchain_deposit_msg_from_proto(coin, deposit)
             },
+            MessageEnum::raw_protobuf_message(ref raw) => {
+                Self::protobuf_raw_msg_from_proto(coin, raw)
+            },
             MessageEnum::None => SigningError::err(SigningErrorType::Error_invalid_params)
                 .context("No TX message provided"),
         }
@@ -419,6 +446,17 @@ where
         Ok(msg.into_boxed())
     }
 
+    pub fn protobuf_raw_msg_from_proto(
+        _coin: &dyn CoinContext,
+        raw: &Proto::mod_Message::RawProtobuf<'_>,
+    ) -> SigningResult<CosmosMessageBox> {
+        let msg = ProtobufRawMessage {
+            type_url: raw.type_url.to_string(),
+            value: raw.value.to_vec(),
+        };
+        Ok(msg.into_boxed())
+    }
+
     pub fn wasm_terra_execute_contract_transfer_msg_from_proto(
         _coin: &dyn CoinContext,
         transfer: &Proto::mod_Message::WasmTerraExecuteContractTransfer<'_>,
@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use crate::modules::wallet_connect::request::{
+    SignAminoRequest, SignDirectRequest, StdFee, StdSignDoc,
+};
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::wallet_connector::WalletConnector;
+use tw_coin_entry::signing_output_error;
+use tw_proto::Cosmos::Proto;
+use tw_proto::Cosmos::Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+
+/// Parses `cosmos_signAmino` and `cosmos_signDirect` requests into [`Proto::SigningInput`].
+/// Please note that the private key must be set by the caller.
+pub struct CosmosWalletConnector;
+
+impl WalletConnector for CosmosWalletConnector {
+    fn parse_request(
+        &self,
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> WCProto::ParseRequestOutput<'static> {
+        Self::parse_request_impl(coin, request)
+            .unwrap_or_else(|e| signing_output_error!(WCProto::ParseRequestOutput, e))
+    }
+}
+
+impl CosmosWalletConnector {
+    fn parse_request_impl(
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        match request.method {
+            WCProto::Method::CosmosSignAmino => Self::parse_sign_amino_request(coin, request),
+            WCProto::Method::CosmosSignDirect => Self::parse_sign_direct_request(coin, request),
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Unknown WalletConnect method"),
+        }
+    }
+
+    pub fn parse_sign_amino_request(
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let amino: SignAminoRequest = parse_params(&request.payload)?;
+        check_address(coin, &amino.signer_address)?;
+
+        let signing_input = Self::signing_input_from_std_doc(amino.sign_doc)?;
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::cosmos(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+
+    pub fn parse_sign_direct_request(
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let direct: SignDirectRequest = parse_params(&request.payload)?;
+        check_address(coin, &direct.signer_address)?;
+
+        let sign_doc = direct.sign_doc;
+        let sign_direct = Proto::mod_Message::SignDirect {
+            body_bytes: Cow::from(sign_doc.body_bytes.0),
+            auth_info_bytes: Cow::from(sign_doc.auth_info_bytes.0),
+        };
+        // Fee, sequence and memo are already encoded in `auth_info_bytes` and `body_bytes`.
+        let signing_input = Proto::SigningInput {
+            signing_mode: Proto::SigningMode::Protobuf,
+            account_number: sign_doc.account_number,
+            chain_id: sign_doc.chain_id.into(),
+            messages: vec![Proto::Message {
+                message_oneof: MessageEnum::sign_direct_message(sign_direct),
+            }],
+            ..Proto::SigningInput::default()
+        };
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::cosmos(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+
+    fn signing_input_from_std_doc(
+        sign_doc: StdSignDoc,
+    ) -> SigningResult<Proto::SigningInput<'static>> {
+        let timeout_height = match sign_doc.timeout_height {
+            Some(height) => height
+                .parse()
+                .tw_err(|_| SigningErrorType::Error_input_parse)
+                .with_context(|| format!("Invalid 'timeout_height': {height}"))?,
+            None => 0,
+        };
+
+        // Amino messages are signed as they are.
+        let messages = sign_doc
+            .msgs
+            .into_iter()
+            .map(|msg| Proto::Message {
+                message_oneof: MessageEnum::raw_json_message(Proto::mod_Message::RawJSON {
+                    type_pb: msg.msg_type.into(),
+                    value: msg.value.to_string().into(),
+                }),
+            })
+            .collect();
+
+        Ok(Proto::SigningInput {
+            signing_mode: Proto::SigningMode::JSON,
+            account_number: sign_doc.account_number,
+            chain_id: sign_doc.chain_id.into(),
+            fee: Some(Self::fee_from_std_fee(sign_doc.fee)?),
+            memo: sign_doc.memo.into(),
+            sequence: sign_doc.sequence,
+            messages,
+            timeout_height,
+            ..Proto::SigningInput::default()
+        })
+    }
+
+    /// Returns an error if the fee has a payer or a granter,
+    /// as they cannot be represented by [`Proto::Fee`] and would be dropped from the signed document.
+    fn fee_from_std_fee(fee: StdFee) -> SigningResult<Proto::Fee<'static>> {
+        let is_set = |field: &Option<String>| field.as_ref().is_some_and(|value| !value.is_empty());
+        if is_set(&fee.payer) || is_set(&fee.granter) {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Fee 'payer' and 'granter' are not supported");
+        }
+
+        let amounts = fee
+            .amount
+            .into_iter()
+            .map(|coin| Proto::Amount {
+                denom: coin.denom.into(),
+                amount: coin.amount.into(),
+            })
+            .collect();
+        Ok(Proto::Fee {
+            amounts,
+            gas: fee.gas,
+        })
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(payload: &str) -> SigningResult<T> {
+    serde_json::from_str(payload)
+        .tw_err(|_| SigningErrorType::Error_input_parse)
+        .context("Error parsing WalletConnect request params as JSON")
+}
+
+fn check_address(coin: &dyn CoinContext, address: &str) -> SigningResult<()> {
+    Address::from_str_with_coin_and_prefix(coin, address.to_string(), None)
+        .map(|_| ())
+        .into_tw()
+        .context("Invalid signer address")
+}
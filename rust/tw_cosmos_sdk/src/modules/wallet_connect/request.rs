@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::Deserialize;
+use serde_json::Value as Json;
+use tw_encoding::base64::Base64Encoded;
+use tw_misc::serde::as_string;
+
+/// `cosmos_signAmino` request params.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/cosmos-rpc#cosmos_signamino
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignAminoRequest {
+    pub signer_address: String,
+    pub sign_doc: StdSignDoc,
+}
+
+/// `cosmos_signDirect` request params.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/cosmos-rpc#cosmos_signdirect
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignDirectRequest {
+    pub signer_address: String,
+    pub sign_doc: DirectSignDoc,
+}
+
+/// Amino JSON sign document.
+#[derive(Deserialize)]
+pub struct StdSignDoc {
+    pub chain_id: String,
+    #[serde(with = "as_string")]
+    pub account_number: u64,
+    #[serde(with = "as_string")]
+    pub sequence: u64,
+    pub fee: StdFee,
+    pub msgs: Vec<AminoMsg>,
+    #[serde(default)]
+    pub memo: String,
+    #[serde(default)]
+    pub timeout_height: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct StdFee {
+    pub amount: Vec<CoinJson>,
+    #[serde(with = "as_string")]
+    pub gas: u64,
+    #[serde(default)]
+    pub payer: Option<String>,
+    #[serde(default)]
+    pub granter: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct CoinJson {
+    pub denom: String,
+    pub amount: String,
+}
+
+#[derive(Deserialize)]
+pub struct AminoMsg {
+    #[serde(rename = "type")]
+    pub msg_type: String,
+    pub value: Json,
+}
+
+/// Protobuf sign document with the already serialized `TxBody` and `AuthInfo`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectSignDoc {
+    pub chain_id: String,
+    #[serde(with = "as_string")]
+    pub account_number: u64,
+    pub body_bytes: Base64Encoded,
+    pub auth_info_bytes: Base64Encoded,
+}
@@ -48,6 +48,20 @@ impl Function {
         decode_params(&self.inputs, data)
     }
 
+    /// Parses the ABI function call data, i.e. the function short signature followed by the encoded input.
+    /// Returns an error if the call data does not start with the signature of this function.
+    pub fn decode_call(&self, data: &[u8]) -> AbiResult<Vec<NamedToken>> {
+        let input_param_types: Vec<_> =
+            self.inputs.iter().map(|param| param.kind.clone()).collect();
+        let expected_signature = short_signature(&self.name, &input_param_types);
+
+        match data.strip_prefix(expected_signature.as_slice()) {
+            Some(encoded_input) => self.decode_input(encoded_input),
+            None => AbiError::err(AbiErrorKind::Error_abi_mismatch)
+                .with_context(|| format!("Call data does not match the '{}' function", self.name)),
+        }
+    }
+
     /// Encodes function input to Eth ABI binary.
     pub fn encode_input(&self, tokens: &[Token]) -> AbiResult<Data> {
         // Check if the given tokens match `Self::inputs` ABI.
@@ -3,10 +3,12 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::abi::contract::Contract;
+use crate::abi::prebuild::decode_call_tokens;
 use crate::abi::token::Token;
-use crate::abi::AbiResult;
+use crate::abi::{AbiErrorKind, AbiResult};
 use crate::address::Address;
 use lazy_static::lazy_static;
+use tw_coin_entry::error::prelude::*;
 use tw_memory::Data;
 use tw_number::U256;
 
@@ -37,4 +39,36 @@ impl Erc1155 {
             Token::Bytes(data),
         ])
     }
+
+    /// Decodes the `safeTransferFrom` function call data.
+    pub fn decode_safe_transfer_from(data: &[u8]) -> AbiResult<Erc1155SafeTransferArgs> {
+        let func = ERC1155.function("safeTransferFrom")?;
+        let [from, to, token_id, value, transfer_data] = decode_call_tokens(func, data)?;
+        Ok(Erc1155SafeTransferArgs {
+            from: from
+                .into_address()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+            to: to
+                .into_address()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+            token_id: token_id
+                .into_u256()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+            value: value
+                .into_u256()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+            data: transfer_data
+                .into_bytes()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+        })
+    }
+}
+
+/// Arguments of the `safeTransferFrom` function.
+pub struct Erc1155SafeTransferArgs {
+    pub from: Address,
+    pub to: Address,
+    pub token_id: U256,
+    pub value: U256,
+    pub data: Data,
 }
@@ -3,10 +3,12 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::abi::contract::Contract;
+use crate::abi::prebuild::decode_call_tokens;
 use crate::abi::token::Token;
-use crate::abi::AbiResult;
+use crate::abi::{AbiErrorKind, AbiResult};
 use crate::address::Address;
 use lazy_static::lazy_static;
+use tw_coin_entry::error::prelude::*;
 use tw_memory::Data;
 use tw_number::U256;
 
@@ -30,4 +32,32 @@ impl Erc20 {
         let func = ERC20.function("approve")?;
         func.encode_input(&[Token::Address(spender), Token::u256(amount)])
     }
+
+    /// Decodes the `transfer` function call data into the recipient and amount.
+    pub fn decode_transfer(data: &[u8]) -> AbiResult<(Address, U256)> {
+        let func = ERC20.function("transfer")?;
+        let [recipient, amount] = decode_call_tokens(func, data)?;
+        Ok((
+            recipient
+                .into_address()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+            amount
+                .into_u256()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+        ))
+    }
+
+    /// Decodes the `approve` function call data into the spender and amount.
+    pub fn decode_approve(data: &[u8]) -> AbiResult<(Address, U256)> {
+        let func = ERC20.function("approve")?;
+        let [spender, amount] = decode_call_tokens(func, data)?;
+        Ok((
+            spender
+                .into_address()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+            amount
+                .into_u256()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+        ))
+    }
 }
@@ -4,10 +4,12 @@
 
 use crate::abi::contract::Contract;
 use crate::abi::param_type::ParamType;
+use crate::abi::prebuild::decode_call_tokens;
 use crate::abi::token::Token;
-use crate::abi::AbiResult;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use crate::address::Address;
 use lazy_static::lazy_static;
+use tw_coin_entry::error::prelude::*;
 use tw_memory::Data;
 use tw_number::U256;
 
@@ -66,4 +68,59 @@ impl Erc4337SimpleAccount {
             Token::array(ParamType::Bytes, datas),
         ])
     }
+
+    /// Decodes the `execute` function call data.
+    pub fn decode_execute(data: &[u8]) -> AbiResult<ExecuteArgs> {
+        let func = ERC4337_SIMPLE_ACCOUNT.function("execute")?;
+        let [to, value, data] = decode_call_tokens(func, data)?;
+        Ok(ExecuteArgs {
+            to: to
+                .into_address()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+            value: value
+                .into_u256()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+            data: data
+                .into_bytes()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+        })
+    }
+
+    /// Decodes the `executeBatch` function call data.
+    pub fn decode_execute_batch(data: &[u8]) -> AbiResult<Vec<ExecuteArgs>> {
+        let func = ERC4337_SIMPLE_ACCOUNT.function("executeBatch")?;
+        let [addresses, values, datas] = decode_call_tokens(func, data)?;
+
+        let (
+            Token::Array { arr: addresses, .. },
+            Token::Array { arr: values, .. },
+            Token::Array { arr: datas, .. },
+        ) = (addresses, values, datas)
+        else {
+            return AbiError::err(AbiErrorKind::Error_abi_mismatch);
+        };
+        if addresses.len() != values.len() || addresses.len() != datas.len() {
+            return AbiError::err(AbiErrorKind::Error_abi_mismatch)
+                .context("'executeBatch' arrays must have the same length");
+        }
+
+        addresses
+            .into_iter()
+            .zip(values)
+            .zip(datas)
+            .map(|((to, value), data)| {
+                Ok(ExecuteArgs {
+                    to: to
+                        .into_address()
+                        .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+                    value: value
+                        .into_u256()
+                        .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+                    data: data
+                        .into_bytes()
+                        .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+                })
+            })
+            .collect()
+    }
 }
@@ -3,10 +3,12 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::abi::contract::Contract;
+use crate::abi::prebuild::decode_call_tokens;
 use crate::abi::token::Token;
-use crate::abi::AbiResult;
+use crate::abi::{AbiErrorKind, AbiResult};
 use crate::address::Address;
 use lazy_static::lazy_static;
+use tw_coin_entry::error::prelude::*;
 use tw_memory::Data;
 use tw_number::U256;
 
@@ -29,4 +31,19 @@ impl Erc721 {
             Token::u256(token_id),
         ])
     }
+
+    /// Decodes the `transferFrom` function call data into the sender, recipient and token ID.
+    pub fn decode_transfer_from(data: &[u8]) -> AbiResult<(Address, Address, U256)> {
+        let func = ERC721.function("transferFrom")?;
+        let [from, to, token_id] = decode_call_tokens(func, data)?;
+        Ok((
+            from.into_address()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+            to.into_address()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+            token_id
+                .into_u256()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)?,
+        ))
+    }
 }
@@ -2,7 +2,28 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::abi::function::Function;
+use crate::abi::token::Token;
+use crate::abi::{AbiErrorKind, AbiResult};
+use tw_coin_entry::error::prelude::*;
+
 pub mod erc1155;
 pub mod erc20;
 pub mod erc4337;
 pub mod erc721;
+
+/// Decodes the call data of the given `func` function and returns exactly `N` input values.
+pub(crate) fn decode_call_tokens<const N: usize>(
+    func: &Function,
+    data: &[u8],
+) -> AbiResult<[Token; N]> {
+    let tokens: Vec<_> = func
+        .decode_call(data)?
+        .into_iter()
+        .map(|named| named.value)
+        .collect();
+    <[Token; N]>::try_from(tokens)
+        .ok()
+        .or_tw_err(AbiErrorKind::Error_abi_mismatch)
+        .with_context(|| format!("Unexpected number of '{}' function inputs", func.name))
+}
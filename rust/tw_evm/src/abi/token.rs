@@ -180,6 +180,30 @@ impl Token {
         }
     }
 
+    /// Returns the address if the token is [`Token::Address`].
+    pub fn into_address(self) -> Option<Address> {
+        match self {
+            Token::Address(addr) => Some(addr),
+            _ => None,
+        }
+    }
+
+    /// Returns the unsigned integer if the token is [`Token::Uint`].
+    pub fn into_u256(self) -> Option<U256> {
+        match self {
+            Token::Uint { uint, .. } => Some(uint),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes if the token is [`Token::Bytes`].
+    pub fn into_bytes(self) -> Option<Data> {
+        match self {
+            Token::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
     pub fn type_short(&self) -> String {
         self.to_param_type().to_type_short()
     }
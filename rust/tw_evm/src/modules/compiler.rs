@@ -6,6 +6,7 @@ use crate::evm_context::EvmContext;
 use crate::modules::tx_builder::TxBuilder;
 use std::borrow::Cow;
 use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
 use tw_coin_entry::error::prelude::*;
@@ -38,6 +39,30 @@ impl<Context: EvmContext> Compiler<Context> {
             .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
     }
 
+    /// Computes preimage hashes for the chain the `coin` is bound to, see [`TxBuilder::with_coin_chain_id`].
+    #[inline]
+    pub fn preimage_hashes_with_coin(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> CompilerProto::PreSigningOutput<'static> {
+        TxBuilder::<Context>::with_coin_chain_id(coin, input)
+            .map(Self::preimage_hashes)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    /// Compiles the transaction for the chain the `coin` is bound to, see [`TxBuilder::with_coin_chain_id`].
+    #[inline]
+    pub fn compile_with_coin(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Proto::SigningOutput<'static> {
+        TxBuilder::<Context>::with_coin_chain_id(coin, input)
+            .map(|input| Self::compile(input, signatures, public_keys))
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
     fn preimage_hashes_impl(
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
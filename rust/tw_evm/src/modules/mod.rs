@@ -7,4 +7,9 @@ pub mod compiler;
 pub mod message_signer;
 pub mod rlp_encoder;
 pub mod signer;
+pub mod transaction_decoder;
+pub mod transaction_hasher;
+pub mod transaction_summarizer;
+pub mod transaction_verifier;
 pub mod tx_builder;
+pub mod wallet_connect;
@@ -6,6 +6,7 @@ use crate::evm_context::EvmContext;
 use crate::modules::tx_builder::TxBuilder;
 use std::borrow::Cow;
 use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
 use tw_keypair::ecdsa::secp256k1;
@@ -26,6 +27,17 @@ impl<Context: EvmContext> Signer<Context> {
             .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
     }
 
+    /// Signs the transaction for the chain the `coin` is bound to, see [`TxBuilder::with_coin_chain_id`].
+    #[inline]
+    pub fn sign_proto_with_coin(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> Proto::SigningOutput<'static> {
+        TxBuilder::<Context>::with_coin_chain_id(coin, input)
+            .map(Self::sign_proto)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
     fn sign_proto_impl(
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
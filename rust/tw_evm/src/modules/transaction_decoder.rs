@@ -0,0 +1,491 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::abi::prebuild::erc1155::Erc1155;
+use crate::abi::prebuild::erc20::Erc20;
+use crate::abi::prebuild::erc721::Erc721;
+use crate::abi::AbiResult;
+use crate::address::Address;
+use crate::rlp::list::RlpList;
+use crate::rlp::{Rlp, RlpDecode};
+use crate::signature::ETHEREUM_SIGNATURE_V_OFFSET;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_hash::sha3::keccak256;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+
+/// cbindgen:ignore
+const LEGACY_TX_TYPE: u8 = 0x00;
+/// cbindgen:ignore
+const EIP2930_TX_TYPE: u8 = 0x01;
+/// cbindgen:ignore
+const EIP1559_TX_TYPE: u8 = 0x02;
+/// An RLP list header starts from `0xc0`. Any byte less than that is considered as a transaction type.
+/// cbindgen:ignore
+const RLP_LIST_OFFSET: u8 = 0xc0;
+/// EIP-155 `v = chain_id * 2 + 35 + recovery_id`.
+/// cbindgen:ignore
+const EIP155_V_OFFSET: u64 = 35;
+
+/// Number of the legacy transaction fields preceding the signature:
+/// `[nonce, gasPrice, gasLimit, to, value, data]`.
+const LEGACY_FIELDS: usize = 6;
+/// `[chainId, nonce, gasPrice, gasLimit, to, value, data, accessList]`.
+const EIP2930_FIELDS: usize = 8;
+/// `[chainId, nonce, maxPriorityFeePerGas, maxFeePerGas, gasLimit, to, value, data, accessList]`.
+const EIP1559_FIELDS: usize = 9;
+/// `[v, r, s]` or `[yParity, r, s]`.
+const SIGNATURE_FIELDS: usize = 3;
+
+/// Decodes raw legacy, EIP-2930 and EIP-1559 transactions, either signed or unsigned.
+///
+/// Please note the access list is returned separately from `SigningInput`,
+/// so typed transactions with a non-empty access list cannot be round-tripped.
+pub struct EthTransactionDecoder;
+
+impl TransactionDecoder for EthTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, _coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl EthTransactionDecoder {
+    fn decode_transaction_impl(
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        Self::decode(tx)?.into_proto()
+    }
+
+    /// Decodes the transaction signature and recovers the signer public key.
+    /// Returns `Ok(None)` if the transaction is not signed.
+    pub(crate) fn decode_signature(tx: &[u8]) -> SigningResult<Option<DecodedSignature>> {
+        Self::decode(tx).map(|decoded| decoded.signature)
+    }
+
+    fn decode(tx: &[u8]) -> SigningResult<DecodedTransaction> {
+        match tx.first() {
+            Some(&EIP2930_TX_TYPE) => Self::decode_eip2930(&tx[1..]),
+            Some(&EIP1559_TX_TYPE) => Self::decode_eip1559(&tx[1..]),
+            Some(first) if *first >= RLP_LIST_OFFSET => Self::decode_legacy(tx),
+            Some(tx_type) => SigningError::err(SigningErrorType::Error_input_parse)
+                .with_context(|| format!("Unsupported transaction type: {tx_type}")),
+            None => {
+                SigningError::err(SigningErrorType::Error_input_parse).context("Empty transaction")
+            },
+        }
+    }
+
+    fn decode_legacy(tx: &[u8]) -> SigningResult<DecodedTransaction> {
+        let rlp = Self::tx_rlp(tx)?;
+        let items_count = rlp.item_count().map_err(rlp_to_signing_error)?;
+
+        let mut decoded = DecodedTransaction {
+            tx_type: LEGACY_TX_TYPE,
+            chain_id: U256::zero(),
+            nonce: decode_at(&rlp, 0, "nonce")?,
+            gas_price: decode_at(&rlp, 1, "gas price")?,
+            max_inclusion_fee_per_gas: U256::zero(),
+            max_fee_per_gas: U256::zero(),
+            gas_limit: decode_at(&rlp, 2, "gas limit")?,
+            to: decode_at(&rlp, 3, "destination address")?,
+            amount: decode_at(&rlp, 4, "amount")?,
+            payload: decode_at(&rlp, 5, "data")?,
+            access_list: Vec::new(),
+            signature: None,
+        };
+
+        match items_count {
+            // Unsigned pre-EIP-155 transaction.
+            LEGACY_FIELDS => Ok(decoded),
+            n if n == LEGACY_FIELDS + SIGNATURE_FIELDS => {
+                let v: U256 = decode_at(&rlp, LEGACY_FIELDS, "V")?;
+                let r: U256 = decode_at(&rlp, LEGACY_FIELDS + 1, "R")?;
+                let s: U256 = decode_at(&rlp, LEGACY_FIELDS + 2, "S")?;
+
+                // Unsigned EIP-155 transaction contains `[chainId, 0, 0]` instead of the signature.
+                if r.is_zero() && s.is_zero() {
+                    decoded.chain_id = v;
+                    return Ok(decoded);
+                }
+
+                let (chain_id, recovery_id) = Self::legacy_chain_id_and_recovery_id(v)?;
+                decoded.chain_id = chain_id;
+
+                // Pre-EIP-155 transaction is signed without the chain ID.
+                let mut unsigned = Self::raw_list_prefix(&rlp, LEGACY_FIELDS)?;
+                if !chain_id.is_zero() {
+                    unsigned
+                        .append(chain_id)
+                        .append(U256::zero())
+                        .append(U256::zero());
+                }
+                let pre_hash = hash(&unsigned.finish());
+
+                decoded.signature =
+                    Some(DecodedSignature::recover(v, r, s, recovery_id, pre_hash)?);
+                Ok(decoded)
+            },
+            _ => SigningError::err(SigningErrorType::Error_input_parse).with_context(|| {
+                format!("Unexpected number of legacy transaction fields: {items_count}")
+            }),
+        }
+    }
+
+    fn decode_eip2930(tx: &[u8]) -> SigningResult<DecodedTransaction> {
+        let rlp = Self::tx_rlp(tx)?;
+
+        let mut decoded = DecodedTransaction {
+            tx_type: EIP2930_TX_TYPE,
+            chain_id: decode_at(&rlp, 0, "chain ID")?,
+            nonce: decode_at(&rlp, 1, "nonce")?,
+            gas_price: decode_at(&rlp, 2, "gas price")?,
+            max_inclusion_fee_per_gas: U256::zero(),
+            max_fee_per_gas: U256::zero(),
+            gas_limit: decode_at(&rlp, 3, "gas limit")?,
+            to: decode_at(&rlp, 4, "destination address")?,
+            amount: decode_at(&rlp, 5, "amount")?,
+            payload: decode_at(&rlp, 6, "data")?,
+            access_list: Self::decode_access_list(&rlp, 7)?,
+            signature: None,
+        };
+        decoded.signature = Self::decode_typed_signature(&rlp, EIP2930_TX_TYPE, EIP2930_FIELDS)?;
+        Ok(decoded)
+    }
+
+    fn decode_eip1559(tx: &[u8]) -> SigningResult<DecodedTransaction> {
+        let rlp = Self::tx_rlp(tx)?;
+
+        let mut decoded = DecodedTransaction {
+            tx_type: EIP1559_TX_TYPE,
+            chain_id: decode_at(&rlp, 0, "chain ID")?,
+            nonce: decode_at(&rlp, 1, "nonce")?,
+            gas_price: U256::zero(),
+            max_inclusion_fee_per_gas: decode_at(&rlp, 2, "max inclusion fee per gas")?,
+            max_fee_per_gas: decode_at(&rlp, 3, "max fee per gas")?,
+            gas_limit: decode_at(&rlp, 4, "gas limit")?,
+            to: decode_at(&rlp, 5, "destination address")?,
+            amount: decode_at(&rlp, 6, "amount")?,
+            payload: decode_at(&rlp, 7, "data")?,
+            access_list: Self::decode_access_list(&rlp, 8)?,
+            signature: None,
+        };
+        decoded.signature = Self::decode_typed_signature(&rlp, EIP1559_TX_TYPE, EIP1559_FIELDS)?;
+        Ok(decoded)
+    }
+
+    /// Checks if the given `tx` is a single RLP list without trailing bytes.
+    fn tx_rlp(tx: &[u8]) -> SigningResult<Rlp<'_>> {
+        let rlp = Rlp::new(tx);
+        let payload_info = rlp.payload_info().map_err(rlp_to_signing_error)?;
+        if !rlp.is_list() || payload_info.total() != tx.len() {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Transaction is expected to be a single RLP list");
+        }
+        Ok(rlp)
+    }
+
+    /// Decodes the signature of an EIP-2718 typed transaction if present.
+    /// The typed transaction is signed as `keccak256(tx_type || rlp([fields...]))`.
+    fn decode_typed_signature(
+        rlp: &Rlp,
+        tx_type: u8,
+        fields_count: usize,
+    ) -> SigningResult<Option<DecodedSignature>> {
+        let items_count = rlp.item_count().map_err(rlp_to_signing_error)?;
+        if items_count == fields_count {
+            return Ok(None);
+        }
+        if items_count != fields_count + SIGNATURE_FIELDS {
+            return SigningError::err(SigningErrorType::Error_input_parse).with_context(|| {
+                format!("Unexpected number of type {tx_type} transaction fields: {items_count}")
+            });
+        }
+
+        let v: U256 = decode_at(rlp, fields_count, "Y parity")?;
+        let r: U256 = decode_at(rlp, fields_count + 1, "R")?;
+        let s: U256 = decode_at(rlp, fields_count + 2, "S")?;
+        let recovery_id = u8::try_from(v)
+            .ok()
+            .filter(|y_parity| *y_parity <= 1)
+            .or_tw_err(SigningErrorType::Error_input_parse)
+            .context("Invalid Y parity")?;
+
+        let unsigned = Self::raw_list_prefix(rlp, fields_count)?.finish();
+        let mut preimage = Vec::with_capacity(unsigned.len() + 1);
+        preimage.push(tx_type);
+        preimage.extend_from_slice(&unsigned);
+
+        DecodedSignature::recover(v, r, s, recovery_id, hash(&preimage)).map(Some)
+    }
+
+    fn decode_access_list(
+        rlp: &Rlp,
+        index: usize,
+    ) -> SigningResult<Vec<Proto::AccessListItem<'static>>> {
+        let access_list = rlp.at(index).map_err(rlp_to_signing_error)?;
+        if !access_list.is_list() {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Access list is expected to be an RLP list");
+        }
+
+        access_list
+            .iter()
+            .map(|item| {
+                let address: Address = decode_at(&item, 0, "access list address")?;
+                let storage_keys = item.at(1).map_err(rlp_to_signing_error)?;
+                let storage_keys = storage_keys
+                    .iter()
+                    .map(|key| {
+                        H256::rlp_decode(&key)
+                            .map(|key| Cow::Owned(key.into_vec()))
+                            .map_err(rlp_to_signing_error)
+                            .context("Invalid access list storage key")
+                    })
+                    .collect::<SigningResult<Vec<_>>>()?;
+
+                Ok(Proto::AccessListItem {
+                    address: address.to_string().into(),
+                    storage_keys,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns `chain_id` and `recovery_id` from the legacy `v` signature component.
+    fn legacy_chain_id_and_recovery_id(v: U256) -> SigningResult<(U256, u8)> {
+        let v = u64::try_from(v)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Invalid V")?;
+
+        let pre_eip155_v = ETHEREUM_SIGNATURE_V_OFFSET as u64;
+        match v {
+            v if v == pre_eip155_v || v == pre_eip155_v + 1 => {
+                Ok((U256::zero(), (v - pre_eip155_v) as u8))
+            },
+            v if v >= EIP155_V_OFFSET => {
+                let chain_id = (v - EIP155_V_OFFSET) / 2;
+                let recovery_id = ((v - EIP155_V_OFFSET) % 2) as u8;
+                Ok((U256::from(chain_id), recovery_id))
+            },
+            _ => SigningError::err(SigningErrorType::Error_input_parse)
+                .with_context(|| format!("Invalid V: {v}")),
+        }
+    }
+
+    /// Creates an RLP list that consists of the first `count` items of the given `rlp` list as is.
+    fn raw_list_prefix(rlp: &Rlp, count: usize) -> SigningResult<RlpList> {
+        let mut list = RlpList::new();
+        for i in 0..count {
+            let item = rlp.at(i).map_err(rlp_to_signing_error)?;
+            list.append_raw_encoded(item.as_raw());
+        }
+        Ok(list)
+    }
+}
+
+pub(crate) struct DecodedSignature {
+    v: U256,
+    r: U256,
+    s: U256,
+    sender: Address,
+    pub(crate) signature: secp256k1::Signature,
+    pub(crate) public_key: secp256k1::PublicKey,
+}
+
+impl DecodedSignature {
+    fn recover(v: U256, r: U256, s: U256, recovery_id: u8, pre_hash: H256) -> SigningResult<Self> {
+        let signature = secp256k1::Signature::try_from_parts(
+            r.to_big_endian(),
+            s.to_big_endian(),
+            recovery_id,
+        )?;
+        let public_key = secp256k1::PublicKey::recover(signature.clone(), pre_hash)
+            .map_err(SigningError::from)
+            .context("Error recovering the sender public key")?;
+
+        Ok(DecodedSignature {
+            v,
+            r,
+            s,
+            sender: Address::with_secp256k1_pubkey(&public_key),
+            signature,
+            public_key,
+        })
+    }
+}
+
+struct DecodedTransaction {
+    tx_type: u8,
+    chain_id: U256,
+    nonce: U256,
+    gas_price: U256,
+    max_inclusion_fee_per_gas: U256,
+    max_fee_per_gas: U256,
+    gas_limit: U256,
+    to: Option<Address>,
+    amount: U256,
+    payload: Data,
+    access_list: Vec<Proto::AccessListItem<'static>>,
+    signature: Option<DecodedSignature>,
+}
+
+impl DecodedTransaction {
+    fn into_proto(self) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let tx_mode = match self.tx_type {
+            LEGACY_TX_TYPE => Proto::TransactionMode::Legacy,
+            EIP2930_TX_TYPE => Proto::TransactionMode::Eip2930,
+            EIP1559_TX_TYPE => Proto::TransactionMode::Enveloped,
+            tx_type => {
+                return SigningError::err(SigningErrorType::Error_not_supported)
+                    .with_context(|| format!("Type {tx_type} transactions cannot be decoded"));
+            },
+        };
+
+        let transaction = Proto::SigningInput {
+            chain_id: u256_to_proto(&self.chain_id),
+            nonce: u256_to_proto(&self.nonce),
+            tx_mode,
+            gas_price: u256_to_proto(&self.gas_price),
+            gas_limit: u256_to_proto(&self.gas_limit),
+            max_inclusion_fee_per_gas: u256_to_proto(&self.max_inclusion_fee_per_gas),
+            max_fee_per_gas: u256_to_proto(&self.max_fee_per_gas),
+            to_address: self.to.map(|to| to.to_string()).unwrap_or_default().into(),
+            transaction: Some(Proto::Transaction {
+                transaction_oneof: transaction_from_call(self.to, self.amount, self.payload),
+            }),
+            ..Proto::SigningInput::default()
+        };
+
+        let mut output = Proto::DecodingTransactionOutput {
+            transaction: Some(transaction),
+            tx_type: self.tx_type as u32,
+            access_list: self.access_list,
+            ..Proto::DecodingTransactionOutput::default()
+        };
+
+        if let Some(signature) = self.signature {
+            output.v = u256_to_proto(&signature.v);
+            output.r = u256_to_proto(&signature.r);
+            output.s = u256_to_proto(&signature.s);
+            output.sender = signature.sender.to_string().into();
+        }
+        Ok(output)
+    }
+}
+
+/// Maps the call data to one of the known `Transaction` variants.
+/// Falls back to [`Proto::mod_Transaction::ContractGeneric`] if the call data is not recognized
+/// or cannot be re-encoded exactly the same way.
+pub(crate) fn transaction_from_call(
+    to: Option<Address>,
+    amount: U256,
+    payload: Data,
+) -> Proto::mod_Transaction::OneOftransaction_oneof<'static> {
+    use Proto::mod_Transaction as Tx;
+    use Proto::mod_Transaction::OneOftransaction_oneof as TxOneof;
+
+    // Contract creation.
+    if to.is_none() {
+        return TxOneof::contract_generic(Tx::ContractGeneric {
+            amount: u256_to_proto(&amount),
+            data: payload.into(),
+        });
+    }
+
+    if payload.is_empty() {
+        return TxOneof::transfer(Tx::Transfer {
+            amount: u256_to_proto(&amount),
+            data: Cow::default(),
+        });
+    }
+
+    // Token calls are expected not to transfer ETH.
+    if amount.is_zero() {
+        if let Ok((recipient, token_amount)) = Erc20::decode_transfer(&payload) {
+            if encodes_to(Erc20::transfer(recipient, token_amount), &payload) {
+                return TxOneof::erc20_transfer(Tx::ERC20Transfer {
+                    to: recipient.to_string().into(),
+                    amount: u256_to_proto(&token_amount),
+                });
+            }
+        }
+
+        if let Ok((spender, token_amount)) = Erc20::decode_approve(&payload) {
+            if encodes_to(Erc20::approve(spender, token_amount), &payload) {
+                return TxOneof::erc20_approve(Tx::ERC20Approve {
+                    spender: spender.to_string().into(),
+                    amount: u256_to_proto(&token_amount),
+                });
+            }
+        }
+
+        if let Ok((from, to, token_id)) = Erc721::decode_transfer_from(&payload) {
+            if encodes_to(Erc721::encode_transfer_from(from, to, token_id), &payload) {
+                return TxOneof::erc721_transfer(Tx::ERC721Transfer {
+                    from: from.to_string().into(),
+                    to: to.to_string().into(),
+                    token_id: u256_to_proto(&token_id),
+                });
+            }
+        }
+
+        if let Ok(args) = Erc1155::decode_safe_transfer_from(&payload) {
+            let reencoded = Erc1155::encode_safe_transfer_from(
+                args.from,
+                args.to,
+                args.token_id,
+                args.value,
+                args.data.clone(),
+            );
+            if encodes_to(reencoded, &payload) {
+                return TxOneof::erc1155_transfer(Tx::ERC1155Transfer {
+                    from: args.from.to_string().into(),
+                    to: args.to.to_string().into(),
+                    token_id: u256_to_proto(&args.token_id),
+                    value: u256_to_proto(&args.value),
+                    data: args.data.into(),
+                });
+            }
+        }
+    }
+
+    TxOneof::contract_generic(Tx::ContractGeneric {
+        amount: u256_to_proto(&amount),
+        data: payload.into(),
+    })
+}
+
+/// Checks if the call data is encoded exactly as the given `payload`.
+fn encodes_to(encoded: AbiResult<Data>, payload: &[u8]) -> bool {
+    matches!(encoded, Ok(encoded) if encoded == payload)
+}
+
+fn decode_at<T: RlpDecode>(rlp: &Rlp, index: usize, field: &str) -> SigningResult<T> {
+    rlp.at(index)
+        .and_then(|item| T::rlp_decode(&item))
+        .map_err(rlp_to_signing_error)
+        .with_context(|| format!("Error decoding transaction {field}"))
+}
+
+fn rlp_to_signing_error(err: crate::rlp::RlpDecodeError) -> SigningError {
+    SigningError::new(SigningErrorType::Error_input_parse).context(format!("{err:?}"))
+}
+
+fn u256_to_proto(num: &U256) -> Cow<'static, [u8]> {
+    Cow::Owned(num.to_big_endian_compact())
+}
+
+fn hash(data: &[u8]) -> H256 {
+    H256::try_from(keccak256(data).as_slice()).expect("keccak256 returns 32 bytes")
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::transaction_decoder::EthTransactionDecoder;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_hasher::TransactionHasher;
+use tw_encoding::hex::ToHex;
+use tw_hash::sha3::keccak256;
+
+/// Computes the transaction hash as `keccak256` of the signed RLP-encoded
+/// legacy, EIP-2930 or EIP-1559 transaction.
+pub struct EthTransactionHasher;
+
+impl TransactionHasher for EthTransactionHasher {
+    fn tx_id(&self, _coin: &dyn CoinContext, signed_tx: &[u8]) -> SigningResult<String> {
+        // Make sure the given bytes are a valid signed transaction.
+        if EthTransactionDecoder::decode_signature(signed_tx)?.is_none() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Transaction is not signed");
+        }
+        Ok(keccak256(signed_tx).to_hex_prefixed())
+    }
+}
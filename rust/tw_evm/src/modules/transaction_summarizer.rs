@@ -0,0 +1,348 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::abi::prebuild::erc1155::Erc1155;
+use crate::abi::prebuild::erc20::Erc20;
+use crate::abi::prebuild::erc4337::Erc4337SimpleAccount;
+use crate::abi::prebuild::erc721::Erc721;
+use crate::address::Address;
+use std::borrow::Cow;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_summarizer::{
+    action, amount, fee, native_amount, native_asset, warning, KnownTokens, TransactionSummarizer,
+};
+use tw_coin_entry::signing_output_error;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+use tw_proto::TxSummary::Proto as SummaryProto;
+
+use SummaryProto::{ActionType, WarningType};
+
+/// Describes EVM transactions, including User Operations (ERC-4337).
+///
+/// Contract calls are recognized by their call data:
+/// ERC-20 `transfer` and `approve`, ERC-1155 `safeTransferFrom`,
+/// and ERC-4337 account `execute` and `executeBatch` that are summarized recursively.
+/// `transferFrom` is reported as an ambiguous contract call since ERC-20 and ERC-721 share its selector,
+/// use `Transaction.erc721_transfer` to describe an NFT transfer.
+pub struct EthTransactionSummarizer;
+
+impl TransactionSummarizer for EthTransactionSummarizer {
+    type SigningInput<'a> = Proto::SigningInput<'a>;
+
+    fn summarize_transaction(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::SigningInput<'_>,
+        tokens: &KnownTokens,
+    ) -> SummaryProto::TransactionSummary<'static> {
+        let tokens = tokens.normalized(normalize_address);
+        Self::summarize_transaction_impl(coin, &tokens, &input)
+            .unwrap_or_else(|e| signing_output_error!(SummaryProto::TransactionSummary, e))
+    }
+}
+
+impl EthTransactionSummarizer {
+    fn summarize_transaction_impl(
+        coin: &dyn CoinContext,
+        tokens: &KnownTokens,
+        input: &Proto::SigningInput<'_>,
+    ) -> SigningResult<SummaryProto::TransactionSummary<'static>> {
+        use Proto::mod_Transaction::OneOftransaction_oneof as Tx;
+
+        let Some(ref transaction) = input.transaction else {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("No transaction specified");
+        };
+
+        let mut summary = SummaryProto::TransactionSummary::default();
+        let contract = normalize_address(&input.to_address);
+
+        match transaction.transaction_oneof {
+            Tx::transfer(ref transfer) => {
+                let value = parse_u256(&transfer.amount).context("Invalid amount")?;
+                Self::summarize_call(coin, tokens, &mut summary, contract, value, &transfer.data);
+            },
+            Tx::erc20_transfer(ref erc20_transfer) => {
+                let value = parse_u256(&erc20_transfer.amount).context("Invalid amount")?;
+                summary.actions.push(action(
+                    ActionType::TokenTransfer,
+                    tokens.asset(&contract),
+                    Some(tokens.amount(&contract, value)),
+                    "",
+                    &erc20_transfer.to,
+                ));
+            },
+            Tx::erc20_approve(ref erc20_approve) => {
+                let value = parse_u256(&erc20_approve.amount).context("Invalid amount")?;
+                Self::push_approval(
+                    tokens,
+                    &mut summary,
+                    contract,
+                    &erc20_approve.spender,
+                    value,
+                );
+            },
+            Tx::erc721_transfer(ref erc721_transfer) => {
+                let token_id = parse_u256(&erc721_transfer.token_id).context("Invalid token ID")?;
+                summary.actions.push(action(
+                    ActionType::NftTransfer,
+                    nft_asset(tokens, &contract, token_id),
+                    None,
+                    &erc721_transfer.from,
+                    &erc721_transfer.to,
+                ));
+            },
+            Tx::erc1155_transfer(ref erc1155_transfer) => {
+                let token_id =
+                    parse_u256(&erc1155_transfer.token_id).context("Invalid token ID")?;
+                let value = parse_u256(&erc1155_transfer.value).context("Invalid value")?;
+                summary.actions.push(action(
+                    ActionType::NftTransfer,
+                    nft_asset(tokens, &contract, token_id),
+                    Some(amount(value, Some(0))),
+                    &erc1155_transfer.from,
+                    &erc1155_transfer.to,
+                ));
+            },
+            Tx::contract_generic(ref contract_generic) => {
+                let value = parse_u256(&contract_generic.amount).context("Invalid amount")?;
+                Self::summarize_call(
+                    coin,
+                    tokens,
+                    &mut summary,
+                    contract,
+                    value,
+                    &contract_generic.data,
+                );
+            },
+            Tx::batch(ref batch) => {
+                for call in batch.calls.iter() {
+                    let value = parse_u256(&call.amount).context("Invalid 'BatchedCall' amount")?;
+                    Self::summarize_call(
+                        coin,
+                        tokens,
+                        &mut summary,
+                        normalize_address(&call.address),
+                        value,
+                        &call.payload,
+                    );
+                }
+            },
+            Tx::None => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("No transaction specified")
+            },
+        }
+
+        summary.fee = Some(fee(
+            native_asset(coin),
+            native_amount(coin, Self::max_fee(input)?),
+        ));
+        if let Some(ref user_op) = input.user_operation {
+            if input.tx_mode == Proto::TransactionMode::UserOp
+                && !user_op.paymaster_and_data.is_empty()
+            {
+                summary.warnings.push(warning(
+                    WarningType::ExternalFeePayer,
+                    "The fee may be paid by a paymaster",
+                ));
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Recognizes the call by its `data` and pushes the corresponding actions.
+    fn summarize_call(
+        coin: &dyn CoinContext,
+        tokens: &KnownTokens,
+        summary: &mut SummaryProto::TransactionSummary<'static>,
+        to: String,
+        value: U256,
+        data: &[u8],
+    ) {
+        if data.is_empty() {
+            summary.actions.push(action(
+                ActionType::Transfer,
+                native_asset(coin),
+                Some(native_amount(coin, value)),
+                "",
+                to,
+            ));
+            return;
+        }
+
+        if let Ok(calls) = Erc4337SimpleAccount::decode_execute_batch(data) {
+            for call in calls {
+                let to = call.to.to_string();
+                Self::summarize_call(coin, tokens, summary, to, call.value, &call.data);
+            }
+            return Self::push_attached_value(coin, summary, to, value);
+        }
+        if let Ok(call) = Erc4337SimpleAccount::decode_execute(data) {
+            let to = call.to.to_string();
+            Self::summarize_call(coin, tokens, summary, to, call.value, &call.data);
+            return Self::push_attached_value(coin, summary, to, value);
+        }
+
+        if let Ok((recipient, token_amount)) = Erc20::decode_transfer(data) {
+            summary.actions.push(action(
+                ActionType::TokenTransfer,
+                tokens.asset(&to),
+                Some(tokens.amount(&to, token_amount)),
+                "",
+                recipient,
+            ));
+        } else if let Ok((spender, token_amount)) = Erc20::decode_approve(data) {
+            Self::push_approval(tokens, summary, to.clone(), spender, token_amount);
+        } else if let Ok((from, recipient, token_id)) = Erc721::decode_transfer_from(data) {
+            // ERC-20 `transferFrom(address,address,uint256)` has the same selector,
+            // so the last argument is either a token amount or a token ID.
+            summary.warnings.push(warning(
+                WarningType::AmbiguousContractCall,
+                format!(
+                    "The {to} contract is called to transfer either {token_id} ERC-20 tokens \
+                     or ERC-721 token #{token_id} from {from} to {recipient}"
+                ),
+            ));
+            summary.actions.push(action(
+                ActionType::ContractCall,
+                native_asset(coin),
+                Some(native_amount(coin, value)),
+                "",
+                to,
+            ));
+            return;
+        } else if let Ok(args) = Erc1155::decode_safe_transfer_from(data) {
+            summary.actions.push(action(
+                ActionType::NftTransfer,
+                nft_asset(tokens, &to, args.token_id),
+                Some(amount(args.value, Some(0))),
+                args.from,
+                args.to,
+            ));
+        } else {
+            summary.warnings.push(warning(
+                WarningType::UnknownContractCall,
+                format!("Unknown call of the {to} contract"),
+            ));
+            summary.actions.push(action(
+                ActionType::ContractCall,
+                native_asset(coin),
+                Some(native_amount(coin, value)),
+                "",
+                to,
+            ));
+            return;
+        }
+
+        Self::push_attached_value(coin, summary, to, value);
+    }
+
+    /// Pushes a native transfer if a recognized contract call is made with a non-zero value.
+    fn push_attached_value(
+        coin: &dyn CoinContext,
+        summary: &mut SummaryProto::TransactionSummary<'static>,
+        to: String,
+        value: U256,
+    ) {
+        if value.is_zero() {
+            return;
+        }
+        summary.actions.push(action(
+            ActionType::Transfer,
+            native_asset(coin),
+            Some(native_amount(coin, value)),
+            "",
+            to,
+        ));
+    }
+
+    fn push_approval(
+        tokens: &KnownTokens,
+        summary: &mut SummaryProto::TransactionSummary<'static>,
+        contract: String,
+        spender: impl ToString,
+        value: U256,
+    ) {
+        if value == U256::MAX {
+            summary.warnings.push(warning(
+                WarningType::UnlimitedApproval,
+                format!(
+                    "Unlimited allowance of the {contract} token is granted to {}",
+                    spender.to_string()
+                ),
+            ));
+        }
+        summary.actions.push(action(
+            ActionType::TokenApproval,
+            tokens.asset(&contract),
+            Some(tokens.amount(&contract, value)),
+            "",
+            spender,
+        ));
+    }
+
+    /// Returns the maximum fee the transaction may charge in the smallest units.
+    fn max_fee(input: &Proto::SigningInput<'_>) -> SigningResult<U256> {
+        let gas_limit = parse_u256(&input.gas_limit).context("Invalid gas limit")?;
+
+        let (gas_limit, gas_price) = match input.tx_mode {
+            Proto::TransactionMode::Legacy | Proto::TransactionMode::Eip2930 => {
+                let gas_price = parse_u256(&input.gas_price).context("Invalid gas price")?;
+                (gas_limit, gas_price)
+            },
+            Proto::TransactionMode::Enveloped => {
+                let max_fee_per_gas =
+                    parse_u256(&input.max_fee_per_gas).context("Invalid max fee per gas")?;
+                (gas_limit, max_fee_per_gas)
+            },
+            Proto::TransactionMode::UserOp => {
+                let Some(ref user_op) = input.user_operation else {
+                    return SigningError::err(SigningErrorType::Error_invalid_params)
+                        .context("No user operation specified");
+                };
+                let max_fee_per_gas =
+                    parse_u256(&input.max_fee_per_gas).context("Invalid max fee per gas")?;
+                let verification_gas_limit = parse_u256(&user_op.verification_gas_limit)
+                    .context("Invalid verification gas limit")?;
+                let pre_verification_gas = parse_u256(&user_op.pre_verification_gas)
+                    .context("Invalid pre-verification gas")?;
+
+                let total_gas = gas_limit
+                    .checked_add(verification_gas_limit)
+                    .and_then(|gas| gas.checked_add(pre_verification_gas))
+                    .into_tw()
+                    .context("Total gas overflow")?;
+                (total_gas, max_fee_per_gas)
+            },
+        };
+
+        gas_limit
+            .checked_mul(gas_price)
+            .into_tw()
+            .context("Max fee overflow")
+    }
+}
+
+fn parse_u256(bytes: &[u8]) -> SigningResult<U256> {
+    U256::from_big_endian_slice(bytes).into_tw()
+}
+
+/// Returns the checksummed address if `address` is a valid EVM address, so the contract addresses
+/// of the summary and [`KnownTokens`] are compared case-insensitively.
+fn normalize_address(address: &str) -> String {
+    Address::from_str(address)
+        .map(|address| address.to_string())
+        .unwrap_or_else(|_| address.to_string())
+}
+
+fn nft_asset(tokens: &KnownTokens, contract: &str, token_id: U256) -> SummaryProto::Asset<'static> {
+    SummaryProto::Asset {
+        token_id: Cow::Owned(token_id.to_string()),
+        ..tokens.asset(contract)
+    }
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::transaction_decoder::EthTransactionDecoder;
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_verifier::{verifying_output, TransactionVerifier};
+use tw_coin_entry::signing_output_error;
+use tw_keypair::ecdsa::secp256k1;
+use tw_misc::traits::ToBytesVec;
+use tw_proto::TxVerifier::Proto as VerifierProto;
+
+/// Verifies signed legacy, EIP-2930 and EIP-1559 transactions.
+///
+/// EVM transactions do not contain the signer public key, so it is recovered from the signature.
+/// The signature is considered valid if the public key is recovered successfully
+/// and belongs to one of the expected public keys, so the expected public keys are required.
+pub struct EthTransactionVerifier;
+
+impl TransactionVerifier for EthTransactionVerifier {
+    fn verify_transaction(
+        &self,
+        _coin: &dyn CoinContext,
+        input: VerifierProto::VerifyingInput<'_>,
+    ) -> VerifierProto::VerifyingOutput<'static> {
+        Self::verify_transaction_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(VerifierProto::VerifyingOutput, e))
+    }
+}
+
+impl EthTransactionVerifier {
+    fn verify_transaction_impl(
+        input: VerifierProto::VerifyingInput<'_>,
+    ) -> SigningResult<VerifierProto::VerifyingOutput<'static>> {
+        let expected_keys = input
+            .public_keys
+            .iter()
+            .map(|key| secp256k1::PublicKey::try_from(key.as_ref()))
+            .collect::<Result<Vec<_>, _>>()
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .context("Invalid expected public key")?;
+        if expected_keys.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Expected public keys are required to verify an EVM transaction");
+        }
+
+        let Some(decoded) = EthTransactionDecoder::decode_signature(&input.transaction)? else {
+            return SigningError::err(SigningErrorType::Error_signing)
+                .context("Transaction is not signed");
+        };
+
+        let valid = expected_keys.contains(&decoded.public_key);
+        let signature = VerifierProto::SignatureVerification {
+            public_key: Cow::Owned(decoded.public_key.uncompressed().to_vec()),
+            signature: Cow::Owned(decoded.signature.to_vec()),
+            valid,
+        };
+        Ok(verifying_output(vec![signature]))
+    }
+}
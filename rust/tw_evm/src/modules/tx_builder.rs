@@ -10,11 +10,14 @@ use crate::abi::prebuild::erc721::Erc721;
 use crate::address::{Address, EvmAddress};
 use crate::evm_context::EvmContext;
 use crate::transaction::transaction_eip1559::TransactionEip1559;
+use crate::transaction::transaction_eip2930::TransactionEip2930;
 use crate::transaction::transaction_non_typed::TransactionNonTyped;
 use crate::transaction::user_operation::UserOperation;
 use crate::transaction::UnsignedTransactionBox;
+use std::borrow::Cow;
 use std::marker::PhantomData;
 use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::error::prelude::*;
 use tw_memory::Data;
 use tw_number::U256;
@@ -26,6 +29,37 @@ pub struct TxBuilder<Context: EvmContext> {
 }
 
 impl<Context: EvmContext> TxBuilder<Context> {
+    /// Binds the signing input to the chain ID of the coin, if the coin has one (see [`CoinContext::chain_id`]).
+    /// The chain ID of the coin is used if the input does not specify any, otherwise both must be equal.
+    pub fn with_coin_chain_id<'a>(
+        coin: &dyn CoinContext,
+        mut input: Proto::SigningInput<'a>,
+    ) -> SigningResult<Proto::SigningInput<'a>> {
+        let Some(coin_chain_id) = coin.chain_id() else {
+            return Ok(input);
+        };
+        let coin_chain_id = U256::from_str(coin_chain_id)
+            .into_tw()
+            .context("Invalid chain ID of the coin")?;
+
+        if input.chain_id.is_empty() {
+            input.chain_id = Cow::from(coin_chain_id.to_big_endian_compact());
+            return Ok(input);
+        }
+
+        let chain_id = U256::from_big_endian_slice(&input.chain_id)
+            .into_tw()
+            .context("Invalid chain ID")?;
+        if chain_id != coin_chain_id {
+            return SigningError::err(CommonError::Error_invalid_params).with_context(|| {
+                format!(
+                    "Chain ID {chain_id} does not match the chain ID {coin_chain_id} of the coin"
+                )
+            });
+        }
+        Ok(input)
+    }
+
     pub fn tx_from_proto(
         input: &Proto::SigningInput<'_>,
     ) -> SigningResult<Box<dyn UnsignedTransactionBox>> {
@@ -162,6 +196,9 @@ impl<Context: EvmContext> TxBuilder<Context> {
             TxMode::Enveloped => {
                 Self::transaction_eip1559_from_proto(input, eth_amount, payload, to)?.into_boxed()
             },
+            TxMode::Eip2930 => {
+                Self::transaction_eip2930_from_proto(input, eth_amount, payload, to)?.into_boxed()
+            },
             TxMode::UserOp => {
                 let to = to
                     .or_tw_err(SigningErrorType::Error_invalid_address)
@@ -260,6 +297,35 @@ impl<Context: EvmContext> TxBuilder<Context> {
         })
     }
 
+    #[inline]
+    fn transaction_eip2930_from_proto(
+        input: &Proto::SigningInput,
+        eth_amount: U256,
+        payload: Data,
+        to_address: Option<Address>,
+    ) -> SigningResult<TransactionEip2930> {
+        let nonce = U256::from_big_endian_slice(&input.nonce)
+            .into_tw()
+            .context("Invalid nonce")?;
+
+        let gas_price = U256::from_big_endian_slice(&input.gas_price)
+            .into_tw()
+            .context("Invalid gas price")?;
+
+        let gas_limit = U256::from_big_endian_slice(&input.gas_limit)
+            .into_tw()
+            .context("Invalid gas limit")?;
+
+        Ok(TransactionEip2930 {
+            nonce,
+            gas_price,
+            gas_limit,
+            to: to_address,
+            amount: eth_amount,
+            payload,
+        })
+    }
+
     fn user_operation_from_proto(
         input: &Proto::SigningInput,
         erc4337_payload: Data,
@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use crate::modules::transaction_decoder::transaction_from_call;
+use crate::modules::wallet_connect::request::{
+    PersonalSignRequest, SignTransactionRequest, SignTypedDataRequest, TransactionObject,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value as Json;
+use std::borrow::Cow;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::wallet_connector::WalletConnector;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::hex::DecodeHex;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+use tw_proto::WalletConnect::Proto::{
+    self as WCProto, mod_ParseRequestOutput::OneOfsigning_input_oneof as SigningInputEnum,
+};
+
+pub struct EthWalletConnector;
+
+impl WalletConnector for EthWalletConnector {
+    fn parse_request(
+        &self,
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> WCProto::ParseRequestOutput<'static> {
+        Self::parse_request_impl(coin, request)
+            .unwrap_or_else(|e| signing_output_error!(WCProto::ParseRequestOutput, e))
+    }
+}
+
+impl EthWalletConnector {
+    fn parse_request_impl(
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        match request.method {
+            WCProto::Method::EthSignTransaction | WCProto::Method::EthSendTransaction => {
+                Self::parse_sign_transaction_request(coin, request)
+            },
+            WCProto::Method::EthSignTypedDataV4 => {
+                Self::parse_sign_typed_data_request(coin, request)
+            },
+            WCProto::Method::PersonalSign => Self::parse_personal_sign_request(coin, request),
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Unknown WalletConnect method"),
+        }
+    }
+
+    pub fn parse_sign_transaction_request(
+        coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let (tx,): SignTransactionRequest = parse_params(&request.payload)?;
+        let signing_input = Self::signing_input_from_tx(coin, tx)?;
+
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::ethereum(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+
+    pub fn parse_sign_typed_data_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let (address, typed_data): SignTypedDataRequest = parse_params(&request.payload)?;
+        check_address(&address)?;
+
+        let message = match typed_data {
+            Json::String(typed_data) => typed_data,
+            typed_data @ Json::Object(_) => typed_data.to_string(),
+            _ => {
+                return SigningError::err(SigningErrorType::Error_input_parse)
+                    .context("Expected typed data as either a JSON object or a string")
+            },
+        };
+
+        let signing_input = Proto::MessageSigningInput {
+            message: message.into(),
+            message_type: Proto::MessageType::MessageType_typed,
+            ..Proto::MessageSigningInput::default()
+        };
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::ethereum_message(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+
+    pub fn parse_personal_sign_request(
+        _coin: &dyn CoinContext,
+        request: WCProto::ParseRequestInput<'_>,
+    ) -> SigningResult<WCProto::ParseRequestOutput<'static>> {
+        let (message, address): PersonalSignRequest = parse_params(&request.payload)?;
+        check_address(&address)?;
+
+        // The message is usually hex-encoded, and it's handled by the EIP-191 message signer.
+        let signing_input = Proto::MessageSigningInput {
+            message: message.into(),
+            message_type: Proto::MessageType::MessageType_legacy,
+            ..Proto::MessageSigningInput::default()
+        };
+        Ok(WCProto::ParseRequestOutput {
+            signing_input_oneof: SigningInputEnum::ethereum_message(signing_input),
+            ..WCProto::ParseRequestOutput::default()
+        })
+    }
+
+    fn signing_input_from_tx(
+        coin: &dyn CoinContext,
+        tx: TransactionObject,
+    ) -> SigningResult<Proto::SigningInput<'static>> {
+        let to = tx
+            .to
+            .as_deref()
+            .map(Address::from_str)
+            .transpose()
+            .into_tw()
+            .context("Invalid 'to' address")?;
+        let payload = match tx.data.or(tx.input) {
+            Some(data) => data
+                .decode_hex()
+                .tw_err(|_| SigningErrorType::Error_input_parse)
+                .context("Invalid 'data' hex")?,
+            None => Vec::default(),
+        };
+
+        // dApps may omit `chainId`, so it falls back to the chain ID of the coin.
+        // Otherwise, a zero chain ID would make the transaction valid on any chain.
+        let chain_id = tx
+            .chain_id
+            .or_else(|| coin.chain_id().map(str::to_string))
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("No 'chainId' specified")?;
+
+        // EIP-1559 transactions specify `maxFeePerGas` instead of `gasPrice`.
+        let tx_mode = if tx.max_fee_per_gas.is_some() {
+            Proto::TransactionMode::Enveloped
+        } else {
+            Proto::TransactionMode::Legacy
+        };
+
+        Ok(Proto::SigningInput {
+            chain_id: parse_quantity(Some(chain_id), "chainId")?,
+            nonce: parse_quantity(tx.nonce, "nonce")?,
+            tx_mode,
+            gas_price: parse_quantity(tx.gas_price, "gasPrice")?,
+            gas_limit: parse_quantity(tx.gas.or(tx.gas_limit), "gas")?,
+            max_inclusion_fee_per_gas: parse_quantity(
+                tx.max_priority_fee_per_gas,
+                "maxPriorityFeePerGas",
+            )?,
+            max_fee_per_gas: parse_quantity(tx.max_fee_per_gas, "maxFeePerGas")?,
+            to_address: to.map(|to| to.to_string()).unwrap_or_default().into(),
+            transaction: Some(Proto::Transaction {
+                transaction_oneof: transaction_from_call(
+                    to,
+                    parse_u256(tx.value, "value")?,
+                    payload,
+                ),
+            }),
+            ..Proto::SigningInput::default()
+        })
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(payload: &str) -> SigningResult<T> {
+    serde_json::from_str(payload)
+        .tw_err(|_| SigningErrorType::Error_input_parse)
+        .context("Error parsing WalletConnect request params as JSON")
+}
+
+fn check_address(address: &str) -> SigningResult<()> {
+    Address::from_str(address)
+        .map(|_| ())
+        .into_tw()
+        .context("Invalid signer address")
+}
+
+/// Parses an optional hex (or decimal) quantity as a big-endian compact number.
+/// Zero or missing quantities result in an empty array.
+fn parse_quantity(num: Option<String>, field: &str) -> SigningResult<Cow<'static, [u8]>> {
+    Ok(parse_u256(num, field)?.to_big_endian_compact().into())
+}
+
+fn parse_u256(num: Option<String>, field: &str) -> SigningResult<U256> {
+    match num {
+        Some(num) => U256::from_str(&num)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .with_context(|| format!("Invalid '{field}' quantity: {num}")),
+        None => Ok(U256::zero()),
+    }
+}
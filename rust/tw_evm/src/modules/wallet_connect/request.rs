@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde::Deserialize;
+use serde_json::Value as Json;
+
+/// `eth_signTransaction` and `eth_sendTransaction` request params: `[transaction]`.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/ethereum-rpc#eth_signtransaction
+pub type SignTransactionRequest = (TransactionObject,);
+
+/// `personal_sign` request params: `[message, address]`.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/ethereum-rpc#personal_sign
+pub type PersonalSignRequest = (String, String);
+
+/// `eth_signTypedData_v4` request params: `[address, typedData]`,
+/// where `typedData` is either a JSON object or a JSON-encoded string.
+/// https://docs.walletconnect.com/advanced/multichain/rpc-reference/ethereum-rpc#eth_signtypeddata_v4
+pub type SignTypedDataRequest = (String, Json);
+
+/// A transaction object. All quantities are hex-encoded, e.g. `0x5208`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionObject {
+    /// Destination address. Not set for a contract creation.
+    pub to: Option<String>,
+    pub gas: Option<String>,
+    /// Non-standard alias of [`TransactionObject::gas`] some dApps send.
+    pub gas_limit: Option<String>,
+    pub gas_price: Option<String>,
+    pub max_fee_per_gas: Option<String>,
+    pub max_priority_fee_per_gas: Option<String>,
+    pub value: Option<String>,
+    pub data: Option<String>,
+    /// Alias of [`TransactionObject::data`].
+    pub input: Option<String>,
+    pub nonce: Option<String>,
+    pub chain_id: Option<String>,
+}
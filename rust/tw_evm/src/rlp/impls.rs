@@ -4,7 +4,9 @@
 
 use crate::address::Address;
 use crate::rlp::buffer::RlpBuffer;
-use crate::rlp::RlpEncode;
+use crate::rlp::{Rlp, RlpDecode, RlpDecodeError, RlpDecodeResult, RlpEncode};
+use tw_hash::H256;
+use tw_memory::Data;
 use tw_number::U256;
 
 impl RlpEncode for U256 {
@@ -39,3 +41,40 @@ impl<'a> RlpEncode for &'a str {
         buf.append_data(self.as_bytes())
     }
 }
+
+impl RlpDecode for U256 {
+    fn rlp_decode(rlp: &Rlp) -> RlpDecodeResult<Self> {
+        let bytes = rlp.data()?;
+        if bytes.first() == Some(&0) {
+            return Err(RlpDecodeError::RlpInvalidIndirection);
+        }
+        U256::from_big_endian_slice(bytes).map_err(|_| RlpDecodeError::RlpIsTooBig)
+    }
+}
+
+impl RlpDecode for Address {
+    fn rlp_decode(rlp: &Rlp) -> RlpDecodeResult<Self> {
+        Address::try_from(rlp.data()?).map_err(|_| RlpDecodeError::RlpInvalidLength)
+    }
+}
+
+impl RlpDecode for Option<Address> {
+    fn rlp_decode(rlp: &Rlp) -> RlpDecodeResult<Self> {
+        if rlp.data()?.is_empty() {
+            return Ok(None);
+        }
+        Address::rlp_decode(rlp).map(Some)
+    }
+}
+
+impl RlpDecode for H256 {
+    fn rlp_decode(rlp: &Rlp) -> RlpDecodeResult<Self> {
+        H256::try_from(rlp.data()?).map_err(|_| RlpDecodeError::RlpInvalidLength)
+    }
+}
+
+impl RlpDecode for Data {
+    fn rlp_decode(rlp: &Rlp) -> RlpDecodeResult<Self> {
+        rlp.data().map(<[u8]>::to_vec)
+    }
+}
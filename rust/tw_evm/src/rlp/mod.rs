@@ -4,11 +4,20 @@
 
 use crate::rlp::buffer::RlpBuffer;
 
+pub use rlp::{DecoderError as RlpDecodeError, Rlp};
+
 pub mod buffer;
 pub mod impls;
 pub mod list;
 
+pub type RlpDecodeResult<T> = Result<T, RlpDecodeError>;
+
 /// The trait should be implemented for all types that need to be encoded in RLP.
 pub trait RlpEncode {
     fn rlp_append(&self, buf: &mut RlpBuffer);
 }
+
+/// The trait should be implemented for all types that need to be decoded from RLP.
+pub trait RlpDecode: Sized {
+    fn rlp_decode(rlp: &Rlp) -> RlpDecodeResult<Self>;
+}
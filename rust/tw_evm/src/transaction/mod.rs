@@ -18,6 +18,7 @@ use tw_number::U256;
 
 pub mod signature;
 pub mod transaction_eip1559;
+pub mod transaction_eip2930;
 pub mod transaction_non_typed;
 pub mod user_operation;
 
@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use crate::rlp::list::RlpList;
+use crate::transaction::signature::{EthSignature, Signature};
+use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
+use tw_coin_entry::error::prelude::*;
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+use tw_number::U256;
+
+const EIP2930_TX_TYPE: u8 = 0x01;
+
+/// EIP2930 transaction with an empty access list.
+pub struct TransactionEip2930 {
+    pub nonce: U256,
+    pub gas_price: U256,
+    pub gas_limit: U256,
+    pub to: Option<Address>,
+    pub amount: U256,
+    pub payload: Data,
+}
+
+impl TransactionCommon for TransactionEip2930 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.payload.clone()
+    }
+}
+
+impl UnsignedTransaction for TransactionEip2930 {
+    type SignedTransaction = SignedTransactionEip2930;
+
+    #[inline]
+    fn encode(&self, chain_id: U256) -> Data {
+        encode_transaction(self, chain_id, None)
+    }
+
+    #[inline]
+    fn try_into_signed(
+        self,
+        signature: secp256k1::Signature,
+        chain_id: U256,
+    ) -> SigningResult<Self::SignedTransaction> {
+        Ok(SignedTransactionEip2930 {
+            unsigned: self,
+            signature: Signature::new(signature),
+            chain_id,
+        })
+    }
+}
+
+pub struct SignedTransactionEip2930 {
+    unsigned: TransactionEip2930,
+    signature: Signature,
+    chain_id: U256,
+}
+
+impl TransactionCommon for SignedTransactionEip2930 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.unsigned.payload.clone()
+    }
+}
+
+impl SignedTransaction for SignedTransactionEip2930 {
+    type Signature = Signature;
+
+    #[inline]
+    fn encode(&self) -> Data {
+        encode_transaction(&self.unsigned, self.chain_id, Some(&self.signature))
+    }
+
+    #[inline]
+    fn signature(&self) -> &Self::Signature {
+        &self.signature
+    }
+}
+
+fn encode_transaction(
+    tx: &TransactionEip2930,
+    chain_id: U256,
+    signature: Option<&Signature>,
+) -> Data {
+    let mut list = RlpList::new();
+    list.append(chain_id)
+        .append(tx.nonce)
+        .append(tx.gas_price)
+        .append(tx.gas_limit)
+        .append(tx.to)
+        .append(tx.amount)
+        .append(tx.payload.as_slice())
+        // empty `access_list`.
+        .append_empty_list();
+
+    if let Some(signature) = signature {
+        list.append(signature.v());
+        list.append(signature.r());
+        list.append(signature.s());
+    }
+
+    let tx_encoded = list.finish();
+
+    let mut envelope = Vec::with_capacity(tx_encoded.len() + 1);
+    envelope.push(EIP2930_TX_TYPE);
+    envelope.extend_from_slice(tx_encoded.as_slice());
+    envelope
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex;
+
+    #[test]
+    fn test_encode_transaction_eip2930() {
+        let tx = TransactionEip2930 {
+            nonce: U256::from(9u64),
+            gas_price: U256::from(20_000_000_000u64),
+            gas_limit: U256::from(21000u32),
+            to: Some(Address::from("0x3535353535353535353535353535353535353535")),
+            amount: U256::from(1_000_000_000_000_000_000u64),
+            payload: Data::default(),
+        };
+        let chain_id = U256::from(1u64);
+        let actual = tx.encode(chain_id);
+
+        let expected = "01eb01098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080c0";
+        assert_eq!(hex::encode(actual, false), expected);
+    }
+}
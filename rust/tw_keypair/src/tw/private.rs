@@ -37,6 +37,11 @@ impl PrivateKey {
         Ok(PrivateKey { bytes })
     }
 
+    /// Returns the raw secret bytes the private key was created from.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
     /// Returns the 32 byte array - the essential private key data.
     pub fn key(&self) -> H256 {
         assert!(
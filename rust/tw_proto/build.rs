@@ -2,6 +2,9 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+#[path = "build/descriptors.rs"]
+mod descriptors;
+
 use pb_rs::types::FileDescriptor;
 use pb_rs::ConfigBuilder;
 #[cfg(feature = "fuzz")]
@@ -12,7 +15,8 @@ use std::{env, fs};
 fn main() {
     let proto_ext = Some(Path::new("proto").as_os_str());
 
-    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap()).join("proto");
+    let out_root_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let out_dir = out_root_dir.join("proto");
 
     let proto_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
         .join("..")
@@ -48,9 +52,15 @@ fn main() {
 
     let out_protos = ConfigBuilder::new(&protos, None, Some(&out_dir), &[proto_dir])
         .expect("Error configuring pb-rs builder")
+        .gen_info(true)
         .build();
     FileDescriptor::run(&out_protos).expect("Error generating proto files");
 
+    // Generate message descriptors required to convert messages to/from the proto3 JSON.
+    let descriptors = descriptors::generate(&protos).expect("Error generating proto descriptors");
+    fs::write(out_root_dir.join("descriptors.rs"), descriptors)
+        .expect("Error writing proto descriptors");
+
     #[cfg(feature = "fuzz")]
     add_custom_derives(&out_dir, &["arbitrary::Arbitrary"])
         .expect("Error on adding 'arbitrary::Arbitrary' derive");
@@ -0,0 +1,538 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! A minimal `.proto` parser that generates static message and enum descriptors.
+//! The descriptors are used by `tw_proto::json` to convert Protobuf messages to/from the proto3 JSON mapping,
+//! because `pb-rs` does not generate any reflection information.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::{fs, iter};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Int(i64),
+    Str,
+    Symbol(char),
+}
+
+enum FieldType {
+    Scalar(&'static str),
+    Named(String),
+    Map(Box<FieldType>, Box<FieldType>),
+}
+
+struct Field {
+    name: String,
+    number: u32,
+    repeated: bool,
+    packed: bool,
+    ty: FieldType,
+    oneof: Option<String>,
+}
+
+struct Message {
+    full_name: String,
+    fields: Vec<Field>,
+}
+
+struct Enum {
+    full_name: String,
+    values: Vec<(String, i64)>,
+}
+
+#[derive(Default)]
+struct Descriptors {
+    messages: Vec<Message>,
+    enums: Vec<Enum>,
+}
+
+/// Parses the given `.proto` files and returns Rust source code of the `MESSAGES` and `ENUMS` static descriptors.
+pub fn generate(protos: &[PathBuf]) -> Result<String, String> {
+    let mut descriptors = Descriptors::default();
+    for proto in protos {
+        let content = fs::read_to_string(proto).map_err(|e| format!("{proto:?}: {e}"))?;
+        let tokens = tokenize(&content).map_err(|e| format!("{proto:?}: {e}"))?;
+        Parser { tokens, pos: 0 }
+            .parse_file(&mut descriptors)
+            .map_err(|e| format!("{proto:?}: {e}"))?;
+    }
+
+    descriptors
+        .messages
+        .sort_by(|a, b| a.full_name.cmp(&b.full_name));
+    descriptors
+        .enums
+        .sort_by(|a, b| a.full_name.cmp(&b.full_name));
+
+    let messages: HashSet<&str> = descriptors
+        .messages
+        .iter()
+        .map(|msg| msg.full_name.as_str())
+        .collect();
+    let enums: HashSet<&str> = descriptors
+        .enums
+        .iter()
+        .map(|en| en.full_name.as_str())
+        .collect();
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// Automatically generated by `tw_proto/build.rs`. Do not edit."
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub static MESSAGES: &[MessageDescriptor] = &[").unwrap();
+    for msg in descriptors.messages.iter() {
+        writeln!(out, "    MessageDescriptor {{").unwrap();
+        writeln!(out, "        full_name: {:?},", msg.full_name).unwrap();
+        writeln!(out, "        fields: &[").unwrap();
+        for field in msg.fields.iter() {
+            let kind = render_kind(&field.ty, &msg.full_name, &messages, &enums)?;
+            let label = if field.repeated || matches!(field.ty, FieldType::Map(..)) {
+                "FieldLabel::Repeated"
+            } else {
+                "FieldLabel::Singular"
+            };
+            writeln!(
+                out,
+                "            FieldDescriptor {{ name: {:?}, json_name: {:?}, number: {}, label: {label}, kind: {kind}, packed: {}, oneof: {:?} }},",
+                field.name,
+                json_name(&field.name),
+                field.number,
+                field.packed,
+                field.oneof,
+            )
+            .unwrap();
+        }
+        writeln!(out, "        ],").unwrap();
+        writeln!(out, "    }},").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(out, "pub static ENUMS: &[EnumDescriptor] = &[").unwrap();
+    for en in descriptors.enums.iter() {
+        writeln!(out, "    EnumDescriptor {{").unwrap();
+        writeln!(out, "        full_name: {:?},", en.full_name).unwrap();
+        writeln!(out, "        values: &[").unwrap();
+        for (name, number) in en.values.iter() {
+            writeln!(out, "            ({name:?}, {number}),").unwrap();
+        }
+        writeln!(out, "        ],").unwrap();
+        writeln!(out, "    }},").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    Ok(out)
+}
+
+/// Converts a field name to lowerCamelCase as `protoc` does.
+fn json_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut capitalize_next = false;
+    for ch in name.chars() {
+        if ch == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            result.extend(ch.to_uppercase());
+            capitalize_next = false;
+        } else {
+            result.push(ch);
+        }
+    }
+    result
+}
+
+fn render_kind(
+    ty: &FieldType,
+    scope: &str,
+    messages: &HashSet<&str>,
+    enums: &HashSet<&str>,
+) -> Result<String, String> {
+    match ty {
+        FieldType::Scalar(kind) => Ok(format!("FieldKind::{kind}")),
+        FieldType::Named(name) => {
+            let full_name = resolve_type(name, scope, messages, enums)
+                .ok_or_else(|| format!("Cannot resolve '{name}' type in '{scope}' scope"))?;
+            if messages.contains(full_name.as_str()) {
+                Ok(format!("FieldKind::Message({full_name:?})"))
+            } else {
+                Ok(format!("FieldKind::Enum({full_name:?})"))
+            }
+        },
+        FieldType::Map(key, value) => Ok(format!(
+            "FieldKind::Map(&{}, &{})",
+            render_kind(key, scope, messages, enums)?,
+            render_kind(value, scope, messages, enums)?
+        )),
+    }
+}
+
+/// Resolves a type name according to the Protobuf scoping rules:
+/// the innermost scope is searched first, then the outer ones.
+fn resolve_type(
+    name: &str,
+    scope: &str,
+    messages: &HashSet<&str>,
+    enums: &HashSet<&str>,
+) -> Option<String> {
+    let is_known = |full_name: &str| messages.contains(full_name) || enums.contains(full_name);
+
+    if let Some(absolute) = name.strip_prefix('.') {
+        return is_known(absolute).then(|| absolute.to_string());
+    }
+
+    let mut scope = scope.to_string();
+    loop {
+        let candidate = if scope.is_empty() {
+            name.to_string()
+        } else {
+            format!("{scope}.{name}")
+        };
+        if is_known(&candidate) {
+            return Some(candidate);
+        }
+        if scope.is_empty() {
+            return None;
+        }
+        scope = match scope.rfind('.') {
+            Some(idx) => scope[..idx].to_string(),
+            None => String::new(),
+        };
+    }
+}
+
+fn scalar_kind(ty: &str) -> Option<&'static str> {
+    let kind = match ty {
+        "double" => "Double",
+        "float" => "Float",
+        "int32" => "Int32",
+        "int64" => "Int64",
+        "uint32" => "Uint32",
+        "uint64" => "Uint64",
+        "sint32" => "Sint32",
+        "sint64" => "Sint64",
+        "fixed32" => "Fixed32",
+        "fixed64" => "Fixed64",
+        "sfixed32" => "Sfixed32",
+        "sfixed64" => "Sfixed64",
+        "bool" => "Bool",
+        "string" => "String",
+        "bytes" => "Bytes",
+        _ => return None,
+    };
+    Some(kind)
+}
+
+fn tokenize(content: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            ch if ch.is_whitespace() => (),
+            '/' if chars.peek() == Some(&'/') => {
+                for ch in chars.by_ref() {
+                    if ch == '\n' {
+                        break;
+                    }
+                }
+            },
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for ch in chars.by_ref() {
+                    if prev == '*' && ch == '/' {
+                        break;
+                    }
+                    prev = ch;
+                }
+            },
+            '"' | '\'' => {
+                let quote = ch;
+                let mut escaped = false;
+                for ch in chars.by_ref() {
+                    if !escaped && ch == quote {
+                        break;
+                    }
+                    escaped = !escaped && ch == '\\';
+                }
+                tokens.push(Token::Str);
+            },
+            ch if ch.is_ascii_digit() || ch == '-' => {
+                let literal: String = iter::once(ch)
+                    .chain(iter::from_fn(|| {
+                        chars.next_if(|ch| ch.is_ascii_alphanumeric() || *ch == '.')
+                    }))
+                    .collect();
+                let (negative, digits) = match literal.strip_prefix('-') {
+                    Some(digits) => (true, digits),
+                    None => (false, literal.as_str()),
+                };
+                let value = match digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+                    Some(hex) => i64::from_str_radix(hex, 16),
+                    None => digits.parse(),
+                };
+                match value {
+                    Ok(value) if negative => tokens.push(Token::Int(-value)),
+                    Ok(value) => tokens.push(Token::Int(value)),
+                    // Float literals can only be met in options, so they can be skipped.
+                    Err(_) => tokens.push(Token::Str),
+                }
+            },
+            ch if ch.is_alphabetic() || ch == '_' || ch == '.' => {
+                let ident: String = iter::once(ch)
+                    .chain(iter::from_fn(|| {
+                        chars.next_if(|ch| ch.is_alphanumeric() || *ch == '_' || *ch == '.')
+                    }))
+                    .collect();
+                tokens.push(Token::Ident(ident));
+            },
+            '{' | '}' | ';' | '=' | '<' | '>' | ',' | '[' | ']' | '(' | ')' | ':' => {
+                tokens.push(Token::Symbol(ch))
+            },
+            ch => return Err(format!("Unexpected '{ch}' character")),
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn parse_file(&mut self, descriptors: &mut Descriptors) -> Result<(), String> {
+        let mut package = String::new();
+        while let Some(token) = self.next() {
+            match token {
+                Token::Ident(keyword) if keyword == "package" => {
+                    package = self.expect_ident()?;
+                    self.expect_symbol(';')?;
+                },
+                Token::Ident(keyword) if keyword == "message" => {
+                    self.parse_message(&package, descriptors)?
+                },
+                Token::Ident(keyword) if keyword == "enum" => {
+                    self.parse_enum(&package, descriptors)?
+                },
+                Token::Ident(keyword) if keyword == "service" => {
+                    self.expect_ident()?;
+                    self.skip_block()?;
+                },
+                Token::Ident(_) => self.skip_statement()?,
+                Token::Symbol(';') => (),
+                token => return Err(format!("Unexpected {token:?} token")),
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_message(&mut self, scope: &str, descriptors: &mut Descriptors) -> Result<(), String> {
+        let full_name = format!("{scope}.{}", self.expect_ident()?);
+        self.expect_symbol('{')?;
+
+        let mut fields = Vec::new();
+        loop {
+            match self.next().ok_or("Unexpected end of message")? {
+                Token::Symbol('}') => break,
+                Token::Symbol(';') => (),
+                Token::Ident(keyword) if keyword == "message" => {
+                    self.parse_message(&full_name, descriptors)?
+                },
+                Token::Ident(keyword) if keyword == "enum" => {
+                    self.parse_enum(&full_name, descriptors)?
+                },
+                Token::Ident(keyword) if keyword == "oneof" => {
+                    let oneof = self.expect_ident()?;
+                    self.expect_symbol('{')?;
+                    loop {
+                        match self.next().ok_or("Unexpected end of oneof")? {
+                            Token::Symbol('}') => break,
+                            Token::Symbol(';') => (),
+                            Token::Ident(keyword) if keyword == "option" => {
+                                self.skip_statement()?
+                            },
+                            Token::Ident(ty) => {
+                                fields.push(self.parse_field(ty, Some(oneof.clone()))?)
+                            },
+                            token => return Err(format!("Unexpected {token:?} token in oneof")),
+                        }
+                    }
+                },
+                Token::Ident(keyword)
+                    if matches!(keyword.as_str(), "option" | "reserved" | "extensions") =>
+                {
+                    self.skip_statement()?
+                },
+                Token::Ident(ty) => fields.push(self.parse_field(ty, None)?),
+                token => return Err(format!("Unexpected {token:?} token in message")),
+            }
+        }
+
+        descriptors.messages.push(Message { full_name, fields });
+        Ok(())
+    }
+
+    fn parse_field(&mut self, first: String, oneof: Option<String>) -> Result<Field, String> {
+        let (repeated, ty) = match first.as_str() {
+            "repeated" => (true, self.expect_ident()?),
+            "optional" | "required" => (false, self.expect_ident()?),
+            _ => (false, first),
+        };
+
+        let ty = if ty == "map" {
+            self.expect_symbol('<')?;
+            let key = self.expect_ident()?;
+            self.expect_symbol(',')?;
+            let value = self.expect_ident()?;
+            self.expect_symbol('>')?;
+            FieldType::Map(Box::new(field_type(key)), Box::new(field_type(value)))
+        } else {
+            field_type(ty)
+        };
+
+        let name = self.expect_ident()?;
+        self.expect_symbol('=')?;
+        let number = match self.next() {
+            Some(Token::Int(number)) if number > 0 => number as u32,
+            token => return Err(format!("Expected a field number, found {token:?}")),
+        };
+
+        let mut packed = false;
+        if self.peek() == Some(&Token::Symbol('[')) {
+            let start = self.pos;
+            self.skip_until(']')?;
+            let options = &self.tokens[start..self.pos];
+            packed = options.windows(3).any(|option| {
+                option
+                    == [
+                        Token::Ident("packed".to_string()),
+                        Token::Symbol('='),
+                        Token::Ident("true".to_string()),
+                    ]
+            });
+        }
+        self.expect_symbol(';')?;
+
+        Ok(Field {
+            name,
+            number,
+            repeated,
+            packed,
+            ty,
+            oneof,
+        })
+    }
+
+    fn parse_enum(&mut self, scope: &str, descriptors: &mut Descriptors) -> Result<(), String> {
+        let full_name = format!("{scope}.{}", self.expect_ident()?);
+        self.expect_symbol('{')?;
+
+        let mut values = Vec::new();
+        loop {
+            match self.next().ok_or("Unexpected end of enum")? {
+                Token::Symbol('}') => break,
+                Token::Symbol(';') => (),
+                Token::Ident(keyword) if matches!(keyword.as_str(), "option" | "reserved") => {
+                    self.skip_statement()?
+                },
+                Token::Ident(name) => {
+                    self.expect_symbol('=')?;
+                    let number = match self.next() {
+                        Some(Token::Int(number)) => number,
+                        token => return Err(format!("Expected an enum value, found {token:?}")),
+                    };
+                    if self.peek() == Some(&Token::Symbol('[')) {
+                        self.skip_until(']')?;
+                    }
+                    self.expect_symbol(';')?;
+                    values.push((name, number));
+                },
+                token => return Err(format!("Unexpected {token:?} token in enum")),
+            }
+        }
+
+        descriptors.enums.push(Enum { full_name, values });
+        Ok(())
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn expect_ident(&mut self) -> Result<String, String> {
+        match self.next() {
+            Some(Token::Ident(ident)) => Ok(ident),
+            token => Err(format!("Expected an identifier, found {token:?}")),
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<(), String> {
+        match self.next() {
+            Some(Token::Symbol(ch)) if ch == symbol => Ok(()),
+            token => Err(format!("Expected '{symbol}', found {token:?}")),
+        }
+    }
+
+    /// Skips tokens until the given `symbol` (inclusive).
+    fn skip_until(&mut self, symbol: char) -> Result<(), String> {
+        loop {
+            match self.next() {
+                Some(Token::Symbol(ch)) if ch == symbol => return Ok(()),
+                Some(_) => (),
+                None => return Err(format!("Expected '{symbol}', found end of file")),
+            }
+        }
+    }
+
+    /// Skips a statement like `option java_package = "wallet.core.jni.proto";`.
+    fn skip_statement(&mut self) -> Result<(), String> {
+        loop {
+            match self.next() {
+                Some(Token::Symbol(';')) => return Ok(()),
+                Some(Token::Symbol('{')) => {
+                    self.pos -= 1;
+                    return self.skip_block();
+                },
+                Some(_) => (),
+                None => return Err("Expected ';', found end of file".to_string()),
+            }
+        }
+    }
+
+    /// Skips a `{ ... }` block including nested blocks.
+    fn skip_block(&mut self) -> Result<(), String> {
+        self.expect_symbol('{')?;
+        let mut depth = 1;
+        while depth > 0 {
+            match self.next() {
+                Some(Token::Symbol('{')) => depth += 1,
+                Some(Token::Symbol('}')) => depth -= 1,
+                Some(_) => (),
+                None => return Err("Expected '}', found end of file".to_string()),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn field_type(ty: String) -> FieldType {
+    match scalar_kind(&ty) {
+        Some(kind) => FieldType::Scalar(kind),
+        None => FieldType::Named(ty),
+    }
+}
@@ -0,0 +1,282 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::json::descriptor::{
+    find_enum, find_message, FieldDescriptor, FieldKind, FieldLabel, MessageDescriptor,
+};
+use crate::json::encoder::{is_packable, WIRE_FIXED32, WIRE_FIXED64, WIRE_LEN, WIRE_VARINT};
+use crate::json::{JsonError, JsonResult};
+use serde_json::{Map as JsonMap, Value as Json};
+use tw_encoding::base64;
+
+pub(crate) fn decode_message(message_name: &str, data: &[u8]) -> JsonResult<Json> {
+    let descriptor = find_message(message_name)
+        .ok_or_else(|| JsonError::UnknownType(message_name.to_string()))?;
+    decode_object(descriptor, data).map(Json::Object)
+}
+
+fn decode_object(descriptor: &MessageDescriptor, data: &[u8]) -> JsonResult<JsonMap<String, Json>> {
+    let mut object = JsonMap::new();
+    let mut reader = WireReader { data, pos: 0 };
+
+    while !reader.is_eof() {
+        let tag = reader.read_varint()?;
+        let number = (tag >> 3) as u32;
+        let wire_type = (tag & 0x7) as u32;
+
+        let Some(field) = descriptor.field_by_number(number) else {
+            // Skip unknown fields as Protobuf parsers do.
+            reader.skip(wire_type)?;
+            continue;
+        };
+
+        decode_field(field, wire_type, &mut reader, &mut object)?;
+    }
+
+    Ok(object)
+}
+
+fn decode_field(
+    field: &FieldDescriptor,
+    wire_type: u32,
+    reader: &mut WireReader,
+    object: &mut JsonMap<String, Json>,
+) -> JsonResult<()> {
+    if let FieldKind::Map(key_kind, value_kind) = field.kind {
+        let entry = reader.read_len_delimited()?;
+        let (key, value) = decode_map_entry(key_kind, value_kind, entry)?;
+        let map = object
+            .entry(field.json_name)
+            .or_insert_with(|| Json::Object(JsonMap::new()));
+        if let Json::Object(map) = map {
+            map.insert(key, value);
+        }
+        return Ok(());
+    }
+
+    match field.label {
+        FieldLabel::Singular => {
+            let value = decode_value(&field.kind, wire_type, reader)?;
+            object.insert(field.json_name.to_string(), value);
+        },
+        FieldLabel::Repeated => {
+            let array = object
+                .entry(field.json_name)
+                .or_insert_with(|| Json::Array(Vec::new()));
+            let Json::Array(array) = array else {
+                return Ok(());
+            };
+
+            // Packed and unpacked encodings must both be accepted.
+            if wire_type == WIRE_LEN && is_packable(&field.kind) {
+                let mut packed = WireReader {
+                    data: reader.read_len_delimited()?,
+                    pos: 0,
+                };
+                while !packed.is_eof() {
+                    array.push(decode_scalar(&field.kind, &mut packed)?);
+                }
+            } else {
+                array.push(decode_value(&field.kind, wire_type, reader)?);
+            }
+        },
+    }
+    Ok(())
+}
+
+fn decode_map_entry(
+    key_kind: &FieldKind,
+    value_kind: &FieldKind,
+    data: &[u8],
+) -> JsonResult<(String, Json)> {
+    let mut reader = WireReader { data, pos: 0 };
+    let mut key = String::new();
+    let mut value = None;
+
+    while !reader.is_eof() {
+        let tag = reader.read_varint()?;
+        let wire_type = (tag & 0x7) as u32;
+        match tag >> 3 {
+            1 => {
+                key = match decode_value(key_kind, wire_type, &mut reader)? {
+                    Json::String(s) => s,
+                    other => other.to_string(),
+                }
+            },
+            2 => value = Some(decode_value(value_kind, wire_type, &mut reader)?),
+            _ => reader.skip(wire_type)?,
+        }
+    }
+
+    let value = match value {
+        Some(value) => value,
+        None => default_value(value_kind)?,
+    };
+    Ok((key, value))
+}
+
+fn decode_value(kind: &FieldKind, wire_type: u32, reader: &mut WireReader) -> JsonResult<Json> {
+    let expected_wire_type = crate::json::encoder::wire_type(kind);
+    if wire_type != expected_wire_type {
+        return Err(JsonError::InvalidProtobuf(format!(
+            "Unexpected wire type {wire_type}, expected {expected_wire_type}"
+        )));
+    }
+
+    match kind {
+        FieldKind::String => {
+            let data = reader.read_len_delimited()?;
+            let s = std::str::from_utf8(data)
+                .map_err(|_| JsonError::InvalidProtobuf("Invalid UTF-8 string".to_string()))?;
+            Ok(Json::String(s.to_string()))
+        },
+        FieldKind::Bytes => {
+            let data = reader.read_len_delimited()?;
+            Ok(Json::String(base64::encode(data, false)))
+        },
+        FieldKind::Message(message_name) => {
+            let descriptor = find_message(message_name)
+                .ok_or_else(|| JsonError::UnknownType(message_name.to_string()))?;
+            let data = reader.read_len_delimited()?;
+            decode_object(descriptor, data).map(Json::Object)
+        },
+        FieldKind::Map(..) => Err(JsonError::InvalidProtobuf(
+            "Map cannot be a value of another field".to_string(),
+        )),
+        _ => decode_scalar(kind, reader),
+    }
+}
+
+/// Decodes a scalar value without a tag.
+fn decode_scalar(kind: &FieldKind, reader: &mut WireReader) -> JsonResult<Json> {
+    let value = match kind {
+        FieldKind::Int32 => Json::from(reader.read_varint()? as i32),
+        FieldKind::Int64 => Json::String((reader.read_varint()? as i64).to_string()),
+        FieldKind::Uint32 => Json::from(reader.read_varint()? as u32),
+        FieldKind::Uint64 => Json::String(reader.read_varint()?.to_string()),
+        FieldKind::Sint32 => Json::from(unzigzag(reader.read_varint()?) as i32),
+        FieldKind::Sint64 => Json::String(unzigzag(reader.read_varint()?).to_string()),
+        FieldKind::Fixed32 => Json::from(u32::from_le_bytes(reader.read_array()?)),
+        FieldKind::Fixed64 => Json::String(u64::from_le_bytes(reader.read_array()?).to_string()),
+        FieldKind::Sfixed32 => Json::from(i32::from_le_bytes(reader.read_array()?)),
+        FieldKind::Sfixed64 => Json::String(i64::from_le_bytes(reader.read_array()?).to_string()),
+        FieldKind::Float => float_to_json(f32::from_le_bytes(reader.read_array()?) as f64),
+        FieldKind::Double => float_to_json(f64::from_le_bytes(reader.read_array()?)),
+        FieldKind::Bool => Json::Bool(reader.read_varint()? != 0),
+        FieldKind::Enum(enum_name) => {
+            let value = reader.read_varint()? as i32 as i64;
+            enum_to_json(enum_name, value)?
+        },
+        FieldKind::String | FieldKind::Bytes | FieldKind::Message(_) | FieldKind::Map(..) => {
+            return Err(JsonError::InvalidProtobuf(
+                "Length-delimited value cannot be packed".to_string(),
+            ))
+        },
+    };
+    Ok(value)
+}
+
+fn default_value(kind: &FieldKind) -> JsonResult<Json> {
+    match kind {
+        FieldKind::String | FieldKind::Bytes => Ok(Json::String(String::new())),
+        FieldKind::Message(_) => Ok(Json::Object(JsonMap::new())),
+        FieldKind::Bool => Ok(Json::Bool(false)),
+        FieldKind::Int64
+        | FieldKind::Uint64
+        | FieldKind::Sint64
+        | FieldKind::Fixed64
+        | FieldKind::Sfixed64 => Ok(Json::String("0".to_string())),
+        FieldKind::Enum(enum_name) => enum_to_json(enum_name, 0),
+        _ => Ok(Json::from(0)),
+    }
+}
+
+fn enum_to_json(enum_name: &str, value: i64) -> JsonResult<Json> {
+    let descriptor =
+        find_enum(enum_name).ok_or_else(|| JsonError::UnknownType(enum_name.to_string()))?;
+    // Unknown enum values are represented as numbers.
+    Ok(descriptor
+        .name_by_value(value)
+        .map(Json::from)
+        .unwrap_or_else(|| Json::from(value)))
+}
+
+fn float_to_json(value: f64) -> Json {
+    if value.is_nan() {
+        Json::String("NaN".to_string())
+    } else if value == f64::INFINITY {
+        Json::String("Infinity".to_string())
+    } else if value == f64::NEG_INFINITY {
+        Json::String("-Infinity".to_string())
+    } else {
+        Json::from(value)
+    }
+}
+
+fn unzigzag(num: u64) -> i64 {
+    ((num >> 1) as i64) ^ -((num & 1) as i64)
+}
+
+struct WireReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> WireReader<'a> {
+    fn is_eof(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn read_varint(&mut self) -> JsonResult<u64> {
+        let mut result = 0_u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self
+                .data
+                .get(self.pos)
+                .ok_or_else(|| JsonError::InvalidProtobuf("Unexpected end of data".to_string()))?;
+            self.pos += 1;
+
+            result |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+        Err(JsonError::InvalidProtobuf("Varint is too long".to_string()))
+    }
+
+    fn read_bytes(&mut self, len: usize) -> JsonResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| JsonError::InvalidProtobuf("Unexpected end of data".to_string()))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> JsonResult<[u8; N]> {
+        let bytes = self.read_bytes(N)?;
+        let mut array = [0; N];
+        array.copy_from_slice(bytes);
+        Ok(array)
+    }
+
+    fn read_len_delimited(&mut self) -> JsonResult<&'a [u8]> {
+        let len = self.read_varint()? as usize;
+        self.read_bytes(len)
+    }
+
+    fn skip(&mut self, wire_type: u32) -> JsonResult<()> {
+        match wire_type {
+            WIRE_VARINT => self.read_varint().map(|_| ()),
+            WIRE_FIXED64 => self.read_bytes(8).map(|_| ()),
+            WIRE_LEN => self.read_len_delimited().map(|_| ()),
+            WIRE_FIXED32 => self.read_bytes(4).map(|_| ()),
+            _ => Err(JsonError::InvalidProtobuf(format!(
+                "Unsupported wire type {wire_type}"
+            ))),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+/// Describes a Protobuf message declared in one of the `src/proto/*.proto` files.
+#[derive(Debug)]
+pub struct MessageDescriptor {
+    /// Fully qualified message name, e.g `TW.Ethereum.Proto.SigningInput`.
+    pub full_name: &'static str,
+    pub fields: &'static [FieldDescriptor],
+}
+
+impl MessageDescriptor {
+    /// Finds a field by its original (snake_case) or JSON (lowerCamelCase) name.
+    pub fn field_by_name(&self, name: &str) -> Option<&'static FieldDescriptor> {
+        self.fields
+            .iter()
+            .find(|field| field.json_name == name || field.name == name)
+    }
+
+    pub fn field_by_number(&self, number: u32) -> Option<&'static FieldDescriptor> {
+        self.fields.iter().find(|field| field.number == number)
+    }
+}
+
+#[derive(Debug)]
+pub struct FieldDescriptor {
+    /// Original field name as it is declared in the `.proto` file.
+    pub name: &'static str,
+    /// lowerCamelCase field name used in the proto3 JSON mapping.
+    pub json_name: &'static str,
+    pub number: u32,
+    pub label: FieldLabel,
+    pub kind: FieldKind,
+    /// Whether the repeated scalar values are encoded as a packed sequence.
+    pub packed: bool,
+    /// Name of the `oneof` group the field belongs to.
+    pub oneof: Option<&'static str>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FieldLabel {
+    Singular,
+    Repeated,
+}
+
+#[derive(Debug)]
+pub enum FieldKind {
+    Double,
+    Float,
+    Int32,
+    Int64,
+    Uint32,
+    Uint64,
+    Sint32,
+    Sint64,
+    Fixed32,
+    Fixed64,
+    Sfixed32,
+    Sfixed64,
+    Bool,
+    String,
+    Bytes,
+    /// Fully qualified enum name.
+    Enum(&'static str),
+    /// Fully qualified message name.
+    Message(&'static str),
+    /// `map<Key, Value>` is encoded as a repeated message with `key = 1` and `value = 2` fields.
+    Map(&'static FieldKind, &'static FieldKind),
+}
+
+#[derive(Debug)]
+pub struct EnumDescriptor {
+    /// Fully qualified enum name, e.g `TW.Common.Proto.SigningError`.
+    pub full_name: &'static str,
+    pub values: &'static [(&'static str, i64)],
+}
+
+impl EnumDescriptor {
+    pub fn value_by_name(&self, name: &str) -> Option<i64> {
+        self.values
+            .iter()
+            .find(|(value_name, _)| *value_name == name)
+            .map(|(_, value)| *value)
+    }
+
+    pub fn name_by_value(&self, value: i64) -> Option<&'static str> {
+        self.values
+            .iter()
+            .find(|(_, value_number)| *value_number == value)
+            .map(|(name, _)| *name)
+    }
+}
+
+/// Finds a message descriptor by its fully qualified name, e.g `TW.Ethereum.Proto.SigningInput`.
+pub fn find_message(full_name: &str) -> Option<&'static MessageDescriptor> {
+    MESSAGES
+        .binary_search_by(|msg| msg.full_name.cmp(full_name))
+        .ok()
+        .map(|idx| &MESSAGES[idx])
+}
+
+/// Finds an enum descriptor by its fully qualified name, e.g `TW.Common.Proto.SigningError`.
+pub fn find_enum(full_name: &str) -> Option<&'static EnumDescriptor> {
+    ENUMS
+        .binary_search_by(|en| en.full_name.cmp(full_name))
+        .ok()
+        .map(|idx| &ENUMS[idx])
+}
+
+include!(concat!(env!("OUT_DIR"), "/descriptors.rs"));
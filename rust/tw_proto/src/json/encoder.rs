@@ -0,0 +1,305 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::json::descriptor::{
+    find_enum, find_message, FieldDescriptor, FieldKind, FieldLabel, MessageDescriptor,
+};
+use crate::json::{JsonError, JsonResult};
+use serde_json::{Map as JsonMap, Value as Json};
+use tw_encoding::base64;
+
+pub(crate) const WIRE_VARINT: u32 = 0;
+pub(crate) const WIRE_FIXED64: u32 = 1;
+pub(crate) const WIRE_LEN: u32 = 2;
+pub(crate) const WIRE_FIXED32: u32 = 5;
+
+pub(crate) fn encode_message(message_name: &str, json: &Json) -> JsonResult<Vec<u8>> {
+    let descriptor = find_message(message_name)
+        .ok_or_else(|| JsonError::UnknownType(message_name.to_string()))?;
+    let object = json.as_object().ok_or_else(|| {
+        JsonError::InvalidValue(format!("Expected an object for '{message_name}' message"))
+    })?;
+
+    let mut out = Vec::new();
+    encode_object(descriptor, object, &mut out)?;
+    Ok(out)
+}
+
+fn encode_object(
+    descriptor: &MessageDescriptor,
+    object: &JsonMap<String, Json>,
+    out: &mut Vec<u8>,
+) -> JsonResult<()> {
+    let mut oneofs_set: Vec<&str> = Vec::new();
+
+    for (key, value) in object {
+        let field = descriptor
+            .field_by_name(key)
+            .ok_or_else(|| JsonError::UnknownField(format!("{}.{key}", descriptor.full_name)))?;
+        // `null` is accepted for all field types and treated as the default value of the field.
+        if value.is_null() {
+            continue;
+        }
+
+        if let Some(oneof) = field.oneof {
+            if oneofs_set.contains(&oneof) {
+                return Err(JsonError::InvalidValue(format!(
+                    "Multiple values of '{}.{oneof}' oneof are set",
+                    descriptor.full_name
+                )));
+            }
+            oneofs_set.push(oneof);
+        }
+
+        encode_field(field, value, out).map_err(|e| with_field_context(e, descriptor, field))?;
+    }
+
+    Ok(())
+}
+
+fn encode_field(field: &FieldDescriptor, value: &Json, out: &mut Vec<u8>) -> JsonResult<()> {
+    if let FieldKind::Map(key_kind, value_kind) = field.kind {
+        let entries = value
+            .as_object()
+            .ok_or_else(|| JsonError::InvalidValue("Expected a map object".to_string()))?;
+        for (map_key, map_value) in entries {
+            let mut entry = Vec::new();
+            encode_value(1, key_kind, &Json::String(map_key.clone()), &mut entry)?;
+            encode_value(2, value_kind, map_value, &mut entry)?;
+            write_len_delimited(field.number, &entry, out);
+        }
+        return Ok(());
+    }
+
+    match field.label {
+        FieldLabel::Singular => encode_value(field.number, &field.kind, value, out),
+        FieldLabel::Repeated => {
+            let items = value
+                .as_array()
+                .ok_or_else(|| JsonError::InvalidValue("Expected an array".to_string()))?;
+
+            if field.packed && is_packable(&field.kind) {
+                let mut packed = Vec::new();
+                for item in items {
+                    encode_packable_value(&field.kind, item, &mut packed)?;
+                }
+                write_len_delimited(field.number, &packed, out);
+                return Ok(());
+            }
+
+            for item in items {
+                encode_value(field.number, &field.kind, item, out)?;
+            }
+            Ok(())
+        },
+    }
+}
+
+fn encode_value(number: u32, kind: &FieldKind, value: &Json, out: &mut Vec<u8>) -> JsonResult<()> {
+    match kind {
+        FieldKind::String => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| JsonError::InvalidValue("Expected a string".to_string()))?;
+            write_len_delimited(number, s.as_bytes(), out);
+        },
+        FieldKind::Bytes => {
+            let bytes = parse_bytes(value)?;
+            write_len_delimited(number, &bytes, out);
+        },
+        FieldKind::Message(message_name) => {
+            let descriptor = find_message(message_name)
+                .ok_or_else(|| JsonError::UnknownType(message_name.to_string()))?;
+            let object = value.as_object().ok_or_else(|| {
+                JsonError::InvalidValue(format!("Expected an object for '{message_name}' message"))
+            })?;
+            let mut nested = Vec::new();
+            encode_object(descriptor, object, &mut nested)?;
+            write_len_delimited(number, &nested, out);
+        },
+        FieldKind::Map(..) => {
+            return Err(JsonError::InvalidValue(
+                "Map cannot be a value of another field".to_string(),
+            ))
+        },
+        _ => {
+            write_tag(number, wire_type(kind), out);
+            encode_packable_value(kind, value, out)?;
+        },
+    }
+    Ok(())
+}
+
+/// Encodes a scalar value without a tag.
+fn encode_packable_value(kind: &FieldKind, value: &Json, out: &mut Vec<u8>) -> JsonResult<()> {
+    match kind {
+        FieldKind::Int32 => write_varint(
+            parse_int(value, i32::MIN as i64, i32::MAX as i64)? as u64,
+            out,
+        ),
+        FieldKind::Int64 => write_varint(parse_int(value, i64::MIN, i64::MAX)? as u64, out),
+        FieldKind::Uint32 => write_varint(parse_uint(value, u32::MAX as u64)?, out),
+        FieldKind::Uint64 => write_varint(parse_uint(value, u64::MAX)?, out),
+        FieldKind::Sint32 => {
+            let num = parse_int(value, i32::MIN as i64, i32::MAX as i64)?;
+            write_varint(zigzag(num), out)
+        },
+        FieldKind::Sint64 => write_varint(zigzag(parse_int(value, i64::MIN, i64::MAX)?), out),
+        FieldKind::Fixed32 => {
+            out.extend_from_slice(&(parse_uint(value, u32::MAX as u64)? as u32).to_le_bytes())
+        },
+        FieldKind::Fixed64 => out.extend_from_slice(&parse_uint(value, u64::MAX)?.to_le_bytes()),
+        FieldKind::Sfixed32 => {
+            let num = parse_int(value, i32::MIN as i64, i32::MAX as i64)? as i32;
+            out.extend_from_slice(&num.to_le_bytes())
+        },
+        FieldKind::Sfixed64 => {
+            out.extend_from_slice(&parse_int(value, i64::MIN, i64::MAX)?.to_le_bytes())
+        },
+        FieldKind::Float => out.extend_from_slice(&(parse_float(value)? as f32).to_le_bytes()),
+        FieldKind::Double => out.extend_from_slice(&parse_float(value)?.to_le_bytes()),
+        FieldKind::Bool => {
+            let b = value
+                .as_bool()
+                .ok_or_else(|| JsonError::InvalidValue("Expected a boolean".to_string()))?;
+            write_varint(b as u64, out)
+        },
+        FieldKind::Enum(enum_name) => write_varint(parse_enum(enum_name, value)? as u64, out),
+        FieldKind::String | FieldKind::Bytes | FieldKind::Message(_) | FieldKind::Map(..) => {
+            return Err(JsonError::InvalidValue(
+                "Length-delimited value cannot be packed".to_string(),
+            ))
+        },
+    }
+    Ok(())
+}
+
+pub(crate) fn wire_type(kind: &FieldKind) -> u32 {
+    match kind {
+        FieldKind::Int32
+        | FieldKind::Int64
+        | FieldKind::Uint32
+        | FieldKind::Uint64
+        | FieldKind::Sint32
+        | FieldKind::Sint64
+        | FieldKind::Bool
+        | FieldKind::Enum(_) => WIRE_VARINT,
+        FieldKind::Fixed64 | FieldKind::Sfixed64 | FieldKind::Double => WIRE_FIXED64,
+        FieldKind::Fixed32 | FieldKind::Sfixed32 | FieldKind::Float => WIRE_FIXED32,
+        FieldKind::String | FieldKind::Bytes | FieldKind::Message(_) | FieldKind::Map(..) => {
+            WIRE_LEN
+        },
+    }
+}
+
+pub(crate) fn is_packable(kind: &FieldKind) -> bool {
+    wire_type(kind) != WIRE_LEN
+}
+
+fn parse_int(value: &Json, min: i64, max: i64) -> JsonResult<i64> {
+    let num = match value {
+        Json::Number(num) => num.as_i64().or_else(|| {
+            // Accept integral floats like `1.0` or `1e3`.
+            num.as_f64()
+                .filter(|f| f.fract() == 0.0 && *f >= i64::MIN as f64 && *f <= i64::MAX as f64)
+                .map(|f| f as i64)
+        }),
+        // 64-bit integers are encoded as strings in the proto3 JSON mapping.
+        Json::String(s) => s.parse().ok(),
+        _ => None,
+    };
+    num.filter(|num| (min..=max).contains(num))
+        .ok_or_else(|| JsonError::InvalidValue(format!("Expected an integer, found {value}")))
+}
+
+fn parse_uint(value: &Json, max: u64) -> JsonResult<u64> {
+    let num = match value {
+        Json::Number(num) => num.as_u64().or_else(|| {
+            num.as_f64()
+                .filter(|f| f.fract() == 0.0 && *f >= 0.0 && *f <= u64::MAX as f64)
+                .map(|f| f as u64)
+        }),
+        Json::String(s) => s.parse().ok(),
+        _ => None,
+    };
+    num.filter(|num| *num <= max).ok_or_else(|| {
+        JsonError::InvalidValue(format!("Expected an unsigned integer, found {value}"))
+    })
+}
+
+fn parse_float(value: &Json) -> JsonResult<f64> {
+    let num = match value {
+        Json::Number(num) => num.as_f64(),
+        Json::String(s) => match s.as_str() {
+            "NaN" => Some(f64::NAN),
+            "Infinity" => Some(f64::INFINITY),
+            "-Infinity" => Some(f64::NEG_INFINITY),
+            s => s.parse().ok(),
+        },
+        _ => None,
+    };
+    num.ok_or_else(|| JsonError::InvalidValue(format!("Expected a number, found {value}")))
+}
+
+fn parse_enum(enum_name: &str, value: &Json) -> JsonResult<i64> {
+    let descriptor =
+        find_enum(enum_name).ok_or_else(|| JsonError::UnknownType(enum_name.to_string()))?;
+    match value {
+        Json::String(name) => descriptor.value_by_name(name).ok_or_else(|| {
+            JsonError::InvalidValue(format!("Unknown '{enum_name}' enum value: {name}"))
+        }),
+        _ => parse_int(value, i32::MIN as i64, i32::MAX as i64),
+    }
+}
+
+/// Parses a base64 string. Both standard and URL-safe alphabets are accepted, with or without padding.
+fn parse_bytes(value: &Json) -> JsonResult<Vec<u8>> {
+    let s = value
+        .as_str()
+        .ok_or_else(|| JsonError::InvalidValue("Expected a base64 string".to_string()))?;
+
+    let is_url = s.contains(['-', '_']);
+    let mut padded = s.trim_end_matches('=').to_string();
+    while padded.len() % 4 != 0 {
+        padded.push('=');
+    }
+
+    base64::decode(&padded, is_url)
+        .map_err(|_| JsonError::InvalidValue(format!("Expected a base64 string, found {s}")))
+}
+
+fn zigzag(num: i64) -> u64 {
+    ((num << 1) ^ (num >> 63)) as u64
+}
+
+fn write_tag(number: u32, wire_type: u32, out: &mut Vec<u8>) {
+    write_varint(((number << 3) | wire_type) as u64, out);
+}
+
+fn write_len_delimited(number: u32, data: &[u8], out: &mut Vec<u8>) {
+    write_tag(number, WIRE_LEN, out);
+    write_varint(data.len() as u64, out);
+    out.extend_from_slice(data);
+}
+
+fn write_varint(mut num: u64, out: &mut Vec<u8>) {
+    while num >= 0x80 {
+        out.push((num as u8 & 0x7F) | 0x80);
+        num >>= 7;
+    }
+    out.push(num as u8);
+}
+
+fn with_field_context(
+    err: JsonError,
+    descriptor: &MessageDescriptor,
+    field: &FieldDescriptor,
+) -> JsonError {
+    match err {
+        JsonError::InvalidValue(desc) => {
+            JsonError::InvalidValue(format!("{}.{}: {desc}", descriptor.full_name, field.name))
+        },
+        other => other,
+    }
+}
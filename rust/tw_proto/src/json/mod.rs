@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Canonical proto3 JSON mapping of the `TW.*` Protobuf messages.
+//! https://protobuf.dev/programming-guides/proto3/#json
+//!
+//! `pb-rs` does not generate any reflection information, so the conversion is driven by
+//! the static [`descriptor`]s generated by `build.rs` from the same `.proto` files.
+//! A JSON value is encoded into the Protobuf binary format, and then can be deserialized into
+//! a generated message via [`crate::deserialize`] as usual, and vice versa.
+
+use crate::{serialize, MessageInfo, MessageWrite};
+use serde_json::Value as Json;
+use std::fmt;
+
+mod decoder;
+pub mod descriptor;
+mod encoder;
+
+pub type JsonResult<T> = Result<T, JsonError>;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum JsonError {
+    /// There is no descriptor of the given message or enum type.
+    UnknownType(String),
+    /// JSON object contains a field not declared in the message.
+    UnknownField(String),
+    /// JSON value cannot be converted into the field type.
+    InvalidValue(String),
+    /// Protobuf binary data is malformed.
+    InvalidProtobuf(String),
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonError::UnknownType(name) => write!(f, "Unknown Protobuf type: {name}"),
+            JsonError::UnknownField(name) => write!(f, "Unknown field: {name}"),
+            JsonError::InvalidValue(desc) => write!(f, "Invalid value: {desc}"),
+            JsonError::InvalidProtobuf(desc) => write!(f, "Invalid Protobuf data: {desc}"),
+        }
+    }
+}
+
+/// Encodes a proto3 JSON representation of the `message_name` message into the Protobuf binary format.
+/// Both lowerCamelCase and original field names are accepted.
+pub fn json_to_proto(message_name: &str, json: &Json) -> JsonResult<Vec<u8>> {
+    encoder::encode_message(message_name, json)
+}
+
+/// Decodes a serialized `message_name` message into the proto3 JSON representation.
+pub fn proto_to_json(message_name: &str, data: &[u8]) -> JsonResult<Json> {
+    decoder::decode_message(message_name, data)
+}
+
+/// Converts the given message into the proto3 JSON representation.
+pub fn message_to_json<T>(message: &T) -> JsonResult<Json>
+where
+    T: MessageInfo + MessageWrite,
+{
+    let data = serialize(message).map_err(|e| JsonError::InvalidProtobuf(e.to_string()))?;
+    proto_to_json(T::PATH, &data)
+}
+
+/// Encodes a proto3 JSON representation of the `T` message into the Protobuf binary format.
+/// The result can be deserialized via [`crate::deserialize`].
+pub fn json_to_message_data<T: MessageInfo>(json: &Json) -> JsonResult<Vec<u8>> {
+    json_to_proto(T::PATH, json)
+}
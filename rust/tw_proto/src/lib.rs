@@ -2,12 +2,13 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use quick_protobuf::{BytesReader, MessageInfo, Writer};
+use quick_protobuf::{BytesReader, Writer};
 
 #[allow(non_snake_case)]
 #[rustfmt::skip]
 mod common;
 mod impls;
+pub mod json;
 
 #[allow(non_snake_case)]
 #[rustfmt::skip]
@@ -19,7 +20,7 @@ pub use common::google;
 pub use generated::TW::*;
 pub use quick_protobuf::{
     deserialize_from_slice as deserialize_prefixed, serialize_into_vec as serialize_prefixed,
-    Error as ProtoError, MessageRead, MessageWrite, Result as ProtoResult,
+    Error as ProtoError, MessageInfo, MessageRead, MessageWrite, Result as ProtoResult,
 };
 
 /// Serializes a Protobuf message without the length prefix.
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde_json::json;
+use std::borrow::Cow;
+use tw_proto::json::{
+    json_to_message_data, json_to_proto, message_to_json, proto_to_json, JsonError,
+};
+use tw_proto::{deserialize, serialize, Bitcoin, Ethereum, Solana};
+
+fn test_msg() -> Ethereum::Proto::SigningInput<'static> {
+    Ethereum::Proto::SigningInput {
+        chain_id: Cow::Owned(vec![1]),
+        nonce: Cow::Owned(vec![11]),
+        ..Ethereum::Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_json_to_message() {
+    let input = json!({"chainId": "AQ==", "nonce": "Cw"});
+    let data = json_to_message_data::<Ethereum::Proto::SigningInput>(&input).unwrap();
+    assert_eq!(data, serialize(&test_msg()).unwrap());
+
+    // Original field names must be accepted as well.
+    let input = json!({"chain_id": "AQ==", "nonce": "Cw==", "to_address": null});
+    let data = json_to_message_data::<Ethereum::Proto::SigningInput>(&input).unwrap();
+    let actual: Ethereum::Proto::SigningInput = deserialize(&data).unwrap();
+    assert_eq!(actual, test_msg());
+}
+
+#[test]
+fn test_message_to_json() {
+    let actual = message_to_json(&test_msg()).unwrap();
+    assert_eq!(actual, json!({"chainId": "AQ==", "nonce": "Cw=="}));
+}
+
+#[test]
+fn test_json_nested_oneof_enum() {
+    let input = json!({
+        "chainId": "AQ==",
+        "txMode": "Enveloped",
+        "transaction": {
+            "erc20Transfer": {
+                "to": "0x5322b34c88ed0691971bf52a7047448f0f4efc84",
+                "amount": "G8FtZ07IAAA="
+            }
+        }
+    });
+    let data = json_to_message_data::<Ethereum::Proto::SigningInput>(&input).unwrap();
+    let msg: Ethereum::Proto::SigningInput = deserialize(&data).unwrap();
+    assert_eq!(msg.tx_mode, Ethereum::Proto::TransactionMode::Enveloped);
+
+    let actual = message_to_json(&msg).unwrap();
+    assert_eq!(actual, input);
+}
+
+#[test]
+fn test_json_map_and_int64() {
+    let input = json!({
+        "coinType": 0,
+        "byteFee": 5,
+        "amount": "12345678901234",
+        "scripts": {"abc": "AQI="}
+    });
+    let data = json_to_message_data::<Bitcoin::Proto::SigningInput>(&input).unwrap();
+    let msg: Bitcoin::Proto::SigningInput = deserialize(&data).unwrap();
+    assert_eq!(msg.amount, 12_345_678_901_234);
+    assert_eq!(msg.byte_fee, 5);
+    assert_eq!(msg.scripts.get("abc").unwrap().as_ref(), &[1, 2]);
+
+    let expected = json!({
+        "byteFee": "5",
+        "amount": "12345678901234",
+        "scripts": {"abc": "AQI="}
+    });
+    assert_eq!(message_to_json(&msg).unwrap(), expected);
+}
+
+#[test]
+fn test_json_packed_repeated() {
+    let input = json!({"programId": 2, "accounts": [0, 1, 300]});
+    let data = json_to_proto("TW.Solana.Proto.RawMessage.Instruction", &input).unwrap();
+
+    let expected = Solana::Proto::mod_RawMessage::Instruction {
+        program_id: 2,
+        accounts: vec![0, 1, 300],
+        ..Solana::Proto::mod_RawMessage::Instruction::default()
+    };
+    assert_eq!(data, serialize(&expected).unwrap());
+
+    let actual = proto_to_json("TW.Solana.Proto.RawMessage.Instruction", &data).unwrap();
+    assert_eq!(actual, input);
+}
+
+#[test]
+fn test_json_errors() {
+    let err =
+        json_to_message_data::<Ethereum::Proto::SigningInput>(&json!({"unknown": 1})).unwrap_err();
+    assert_eq!(
+        err,
+        JsonError::UnknownField("TW.Ethereum.Proto.SigningInput.unknown".to_string())
+    );
+
+    let err =
+        json_to_message_data::<Ethereum::Proto::SigningInput>(&json!({"chainId": 1})).unwrap_err();
+    assert!(matches!(err, JsonError::InvalidValue(_)));
+
+    let input = json!({"transaction": {"transfer": {}, "erc20Transfer": {}}});
+    let err = json_to_message_data::<Ethereum::Proto::SigningInput>(&input).unwrap_err();
+    assert!(matches!(err, JsonError::InvalidValue(_)));
+
+    let err = json_to_proto("TW.Unknown.Proto.SigningInput", &json!({})).unwrap_err();
+    assert!(matches!(err, JsonError::UnknownType(_)));
+}
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::proto_types::ProtoTypes;
+use crate::Error;
+use serde_json::Value as Json;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::custom_registry::{custom_coin_items, get_custom_coin_item};
+use tw_coin_registry::registry::{get_coin_item, registry_iter};
+use tw_encoding::hex::DecodeHex;
+use tw_memory::Data;
+use tw_proto::json::{json_to_proto, proto_to_json};
+
+/// A built-in or custom coin supported in Rust.
+pub struct Coin {
+    pub coin_id: u32,
+    pub proto_types: ProtoTypes,
+}
+
+impl Coin {
+    /// Parses either a numeric coin type (e.g `60`) or a coin ID from the registry (e.g `ethereum`).
+    pub fn parse(coin: &str) -> Result<Coin, Error> {
+        if let Ok(coin_id) = coin.parse::<u32>() {
+            return Coin::with_coin_id(coin_id);
+        }
+
+        let built_in = registry_iter()
+            .find(|item| item.id == coin)
+            .map(|item| item.coin_id.into());
+        let coin_id = match built_in {
+            Some(coin_id) => coin_id,
+            None => custom_coin_items()
+                .into_iter()
+                .find(|item| item.id == coin)
+                .map(|item| item.coin_id)
+                .ok_or(Error::UnknownCoin)?,
+        };
+        Coin::with_coin_id(coin_id)
+    }
+
+    pub fn with_coin_id(coin_id: u32) -> Result<Coin, Error> {
+        let blockchain = match CoinType::try_from(coin_id) {
+            Ok(coin_type) => get_coin_item(coin_type)?.blockchain,
+            Err(_) => get_custom_coin_item(coin_id)?.blockchain,
+        };
+        let proto_types = ProtoTypes::for_blockchain(blockchain).ok_or(Error::Unsupported)?;
+        Ok(Coin {
+            coin_id,
+            proto_types,
+        })
+    }
+}
+
+/// Encodes a `message` given as either a proto3 JSON object or a hex-encoded binary string.
+pub fn encode_message(message: &str, input: &Json) -> Result<Data, Error> {
+    match input {
+        Json::String(hex) => decode_hex(hex, "input"),
+        Json::Object(_) => Ok(json_to_proto(message, input)?),
+        _ => Err(Error::InvalidArgument { param: "input" }),
+    }
+}
+
+/// Decodes a serialized `message` into the proto3 JSON representation.
+pub fn decode_message(message: &str, data: &[u8]) -> Result<Json, Error> {
+    Ok(proto_to_json(message, data)?)
+}
+
+pub fn decode_hex(hex: &str, param: &'static str) -> Result<Data, Error> {
+    hex.trim()
+        .decode_hex()
+        .map_err(|_| Error::InvalidArgument { param })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_coin_registry::custom_registry::register_custom_coin_json;
+
+    const CUSTOM_EVM_COIN: u32 = 90_000_101;
+
+    #[test]
+    fn test_parse_numeric_coin() {
+        let coin = Coin::parse("60").unwrap();
+        assert_eq!(coin.coin_id, 60);
+        assert_eq!(
+            coin.proto_types.signing_input(),
+            "TW.Ethereum.Proto.SigningInput"
+        );
+    }
+
+    #[test]
+    fn test_parse_registry_coin() {
+        let coin = Coin::parse("cosmos").unwrap();
+        assert_eq!(coin.coin_id, 118);
+        assert_eq!(
+            coin.proto_types.signing_input(),
+            "TW.Cosmos.Proto.SigningInput"
+        );
+    }
+
+    #[test]
+    fn test_parse_custom_coin() {
+        let item_json = format!(
+            r#"{{
+                "id": "wallet-core-bin-evm",
+                "name": "Custom EVM",
+                "coinId": {CUSTOM_EVM_COIN},
+                "symbol": "CEVM",
+                "decimals": 18,
+                "blockchain": "Ethereum",
+                "derivation": [{{ "path": "m/44'/60'/0'/0/0" }}],
+                "publicKeyType": "secp256k1Extended",
+                "addressHasher": "keccak256"
+            }}"#
+        );
+        register_custom_coin_json(&item_json).unwrap();
+
+        let coin = Coin::parse("wallet-core-bin-evm").unwrap();
+        assert_eq!(coin.coin_id, CUSTOM_EVM_COIN);
+        assert_eq!(
+            coin.proto_types.signing_input(),
+            "TW.Ethereum.Proto.SigningInput"
+        );
+
+        let coin = Coin::parse(&CUSTOM_EVM_COIN.to_string()).unwrap();
+        assert_eq!(coin.coin_id, CUSTOM_EVM_COIN);
+    }
+
+    #[test]
+    fn test_parse_invalid_coin() {
+        assert!(matches!(Coin::parse("unknown"), Err(Error::UnknownCoin)));
+        // Tron is not supported in Rust yet.
+        assert!(matches!(Coin::parse("tron"), Err(Error::Unsupported)));
+        assert!(matches!(Coin::parse("90000199"), Err(Error::Registry(_))));
+    }
+}
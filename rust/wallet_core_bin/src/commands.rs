@@ -0,0 +1,241 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Offline operations over a coin that go through the same `tw_any_coin` API as the apps do.
+//! Inputs are accepted either as a proto3 JSON object or as a hex-encoded binary string,
+//! outputs are returned as JSON.
+
+use crate::coin::{decode_message, encode_message, Coin};
+use crate::Error;
+use serde_json::{json, Value as Json};
+use tw_any_coin::any_address::AnyAddress;
+use tw_any_coin::any_signer::AnySigner;
+use tw_any_coin::message_signer::MessageSigner;
+use tw_any_coin::transaction_compiler::TransactionCompiler;
+use tw_any_coin::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::derivation::Derivation;
+use tw_coin_registry::dispatcher::{coin_dispatcher, evm_dispatcher};
+use tw_evm::evm_entry::EvmEntryExt;
+use tw_keypair::tw::PublicKey;
+use tw_memory::Data;
+
+const ABI_CONTRACT_CALL_DECODING_INPUT: &str = "TW.EthereumAbi.Proto.ContractCallDecodingInput";
+const ABI_CONTRACT_CALL_DECODING_OUTPUT: &str = "TW.EthereumAbi.Proto.ContractCallDecodingOutput";
+const ABI_PARAMS_DECODING_INPUT: &str = "TW.EthereumAbi.Proto.ParamsDecodingInput";
+const ABI_PARAMS_DECODING_OUTPUT: &str = "TW.EthereumAbi.Proto.ParamsDecodingOutput";
+const ABI_VALUE_DECODING_INPUT: &str = "TW.EthereumAbi.Proto.ValueDecodingInput";
+const ABI_VALUE_DECODING_OUTPUT: &str = "TW.EthereumAbi.Proto.ValueDecodingOutput";
+const ABI_FUNCTION_ENCODING_INPUT: &str = "TW.EthereumAbi.Proto.FunctionEncodingInput";
+const ABI_FUNCTION_ENCODING_OUTPUT: &str = "TW.EthereumAbi.Proto.FunctionEncodingOutput";
+const ABI_FUNCTION_GET_TYPE_INPUT: &str = "TW.EthereumAbi.Proto.FunctionGetTypeInput";
+
+pub fn sign(coin: &Coin, input: &Json) -> Result<Json, Error> {
+    let input = encode_message(&coin.proto_types.signing_input(), input)?;
+    let output = AnySigner::sign(&input, coin.coin_id)?;
+    decode_message(&coin.proto_types.signing_output(), &output)
+}
+
+pub fn preimage_hashes(coin: &Coin, input: &Json) -> Result<Json, Error> {
+    let input = encode_message(&coin.proto_types.signing_input(), input)?;
+    let output = TransactionCompiler::preimage_hashes(coin.coin_id, &input)?;
+    decode_message(&coin.proto_types.pre_signing_output(), &output)
+}
+
+pub fn compile(
+    coin: &Coin,
+    input: &Json,
+    signatures: Vec<Data>,
+    public_keys: Vec<Data>,
+) -> Result<Json, Error> {
+    let input = encode_message(&coin.proto_types.signing_input(), input)?;
+    let output = TransactionCompiler::compile(coin.coin_id, &input, signatures, public_keys)?;
+    decode_message(&coin.proto_types.signing_output(), &output)
+}
+
+pub fn plan(coin: &Coin, input: &Json) -> Result<Json, Error> {
+    let plan_message = coin
+        .proto_types
+        .transaction_plan()
+        .ok_or(Error::Unsupported)?;
+
+    let input = encode_message(&coin.proto_types.signing_input(), input)?;
+    let output = AnySigner::plan(&input, coin.coin_id)?;
+    decode_message(&plan_message, &output)
+}
+
+pub fn decode_transaction(coin: &Coin, tx: &[u8]) -> Result<Json, Error> {
+    let output = TransactionDecoder::decode_transaction(coin.coin_id, tx)?;
+    decode_message(&coin.proto_types.decoding_output(), &output)
+}
+
+pub fn derive_address(
+    coin: &Coin,
+    public_key: Data,
+    derivation: Derivation,
+) -> Result<Json, Error> {
+    let (ctx, _entry) = coin_dispatcher(coin.coin_id)?;
+    let public_key =
+        PublicKey::new(public_key, ctx.public_key_type()).map_err(|_| Error::InvalidArgument {
+            param: "public key",
+        })?;
+
+    let address = AnyAddress::with_public_key(coin.coin_id, public_key, derivation, None)?;
+    Ok(json!({ "address": address.description() }))
+}
+
+pub fn validate_address(coin: &Coin, address: &str) -> Json {
+    let valid = AnyAddress::is_valid(coin.coin_id, address, None);
+    json!({ "valid": valid })
+}
+
+pub fn normalize_address(coin: &Coin, address: &str) -> Result<Json, Error> {
+    let address = AnyAddress::with_string(coin.coin_id, address, None)?;
+    Ok(json!({ "address": address.description() }))
+}
+
+pub fn sign_message(coin: &Coin, input: &Json) -> Result<Json, Error> {
+    let input = encode_message(&coin.proto_types.message_signing_input(), input)?;
+    let output = MessageSigner::sign_message(&input, coin.coin_id)?;
+    decode_message(&coin.proto_types.message_signing_output(), &output)
+}
+
+pub fn verify_message(coin: &Coin, input: &Json) -> Result<Json, Error> {
+    let input = encode_message(&coin.proto_types.message_verifying_input(), input)?;
+    let valid = MessageSigner::verify_message(&input, coin.coin_id)?;
+    Ok(json!({ "valid": valid }))
+}
+
+pub fn abi_encode_function(coin: &Coin, input: &Json) -> Result<Json, Error> {
+    let input = encode_message(ABI_FUNCTION_ENCODING_INPUT, input)?;
+    let output = evm_dispatcher(coin.coin_id)?.encode_abi_function(&input)?;
+    decode_message(ABI_FUNCTION_ENCODING_OUTPUT, &output)
+}
+
+pub fn abi_decode_contract_call(coin: &Coin, input: &Json) -> Result<Json, Error> {
+    let input = encode_message(ABI_CONTRACT_CALL_DECODING_INPUT, input)?;
+    let output = evm_dispatcher(coin.coin_id)?.decode_abi_contract_call(&input)?;
+    decode_message(ABI_CONTRACT_CALL_DECODING_OUTPUT, &output)
+}
+
+pub fn abi_decode_params(coin: &Coin, input: &Json) -> Result<Json, Error> {
+    let input = encode_message(ABI_PARAMS_DECODING_INPUT, input)?;
+    let output = evm_dispatcher(coin.coin_id)?.decode_abi_params(&input)?;
+    decode_message(ABI_PARAMS_DECODING_OUTPUT, &output)
+}
+
+pub fn abi_decode_value(coin: &Coin, input: &Json) -> Result<Json, Error> {
+    let input = encode_message(ABI_VALUE_DECODING_INPUT, input)?;
+    let output = evm_dispatcher(coin.coin_id)?.decode_abi_value(&input)?;
+    decode_message(ABI_VALUE_DECODING_OUTPUT, &output)
+}
+
+pub fn abi_function_signature(coin: &Coin, input: &Json) -> Result<Json, Error> {
+    let input = encode_message(ABI_FUNCTION_GET_TYPE_INPUT, input)?;
+    let signature = evm_dispatcher(coin.coin_id)?.get_abi_function_signature(&input)?;
+    Ok(json!({ "signature": signature }))
+}
+
+/// Parses a derivation name as it is declared in `registry.json`.
+pub fn parse_derivation(derivation: &str) -> Result<Derivation, Error> {
+    match derivation {
+        "default" => Ok(Derivation::Default),
+        "segwit" => Ok(Derivation::Segwit),
+        "legacy" => Ok(Derivation::Legacy),
+        "testnet" => Ok(Derivation::Testnet),
+        _ => Err(Error::InvalidArgument {
+            param: "derivation",
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coin::decode_hex;
+    use tw_proto::json::JsonError;
+
+    /// https://eips.ethereum.org/EIPS/eip-155#example
+    const SIGNED_LEGACY_TX: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+
+    fn ethereum() -> Coin {
+        Coin::parse("ethereum").unwrap()
+    }
+
+    #[test]
+    fn test_sign() {
+        let input = json!({
+            "chainId": "AQ==",
+            "nonce": "CQ==",
+            "gasPrice": "BKgXyAA=",
+            "gasLimit": "Ugg=",
+            "toAddress": "0x3535353535353535353535353535353535353535",
+            "transaction": { "transfer": { "amount": "DeC2s6dkAAA=" } },
+            "privateKey": "RkZGRkZGRkZGRkZGRkZGRkZGRkZGRkZGRkZGRkZGRkY=",
+        });
+
+        let output = sign(&ethereum(), &input).unwrap();
+        assert_eq!(
+            output["encoded"],
+            "+GwJhQSoF8gAglIIlDU1NTU1NTU1NTU1NTU1NTU1NTU1iA3gtrOnZAAAgCWgKO9hNAvZObwhlf5TdWeGYAPhoV08cf9j4VkGIKpjYnagZ8vp2Jl/dhrstwMwSzgAzPVVyfPcZCFLKX+xlmo7bYM="
+        );
+        assert_eq!(output.get("error"), None);
+    }
+
+    #[test]
+    fn test_sign_invalid_input() {
+        let input = json!({ "unknownField": "" });
+        assert!(matches!(
+            sign(&ethereum(), &input),
+            Err(Error::Json(JsonError::UnknownField(_)))
+        ));
+    }
+
+    #[test]
+    fn test_decode_transaction() {
+        let tx = decode_hex(SIGNED_LEGACY_TX, "tx").unwrap();
+
+        let output = decode_transaction(&ethereum(), &tx).unwrap();
+        assert_eq!(
+            output["sender"],
+            "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"
+        );
+        assert_eq!(output["v"], "JQ==");
+        assert_eq!(
+            output["transaction"]["toAddress"],
+            "0x3535353535353535353535353535353535353535"
+        );
+        assert_eq!(
+            output["transaction"]["transaction"]["transfer"]["amount"],
+            "DeC2s6dkAAA="
+        );
+    }
+
+    #[test]
+    fn test_validate_address() {
+        let coin = ethereum();
+        assert_eq!(
+            validate_address(&coin, "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"),
+            json!({ "valid": true })
+        );
+        assert_eq!(
+            validate_address(&coin, "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4"),
+            json!({ "valid": false })
+        );
+        assert_eq!(
+            validate_address(&coin, "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02"),
+            json!({ "valid": false })
+        );
+    }
+
+    #[test]
+    fn test_normalize_address() {
+        let output =
+            normalize_address(&ethereum(), "0x9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f").unwrap();
+        assert_eq!(
+            output,
+            json!({ "address": "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F" })
+        );
+    }
+}
@@ -2,38 +2,317 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+mod coin;
+mod commands;
+mod proto_types;
 mod registry_stats;
+mod serve;
+
+use crate::coin::{decode_hex, Coin};
+use serde_json::{json, Value as Json};
+use std::fmt;
+use std::io::Read;
+use std::process::ExitCode;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::error::RegistryError;
+use tw_proto::json::JsonError;
+use tw_proto::ProtoError;
 
 #[derive(Debug)]
 enum Error {
     MissingArguments,
     UnknownCommand,
     InvalidRegistry,
-    InvalidRegistryParam {
-        #[allow(dead_code)]
-        param: &'static str,
-    },
+    InvalidRegistryParam { param: &'static str },
+    InvalidArgument { param: &'static str },
+    UnknownCoin,
+    Unsupported,
+    Registry(RegistryError),
+    Json(JsonError),
+    Signing(SigningError),
+    Address(AddressError),
+    Proto(ProtoError),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingArguments => write!(f, "Missing arguments"),
+            Error::UnknownCommand => write!(f, "Unknown command"),
+            Error::InvalidRegistry => write!(f, "Invalid registry"),
+            Error::InvalidRegistryParam { param } => write!(f, "Invalid registry param: {param}"),
+            Error::InvalidArgument { param } => write!(f, "Invalid argument: {param}"),
+            Error::UnknownCoin => write!(f, "Unknown coin"),
+            Error::Unsupported => write!(f, "Operation not supported for the coin"),
+            Error::Registry(e) => write!(f, "{e}"),
+            Error::Json(e) => write!(f, "{e}"),
+            Error::Signing(e) => write!(f, "{e}"),
+            Error::Address(e) => write!(f, "{e}"),
+            Error::Proto(e) => write!(f, "Invalid Protobuf message: {e}"),
+            Error::Io(e) => write!(f, "{e}"),
+        }
+    }
 }
 
+impl From<RegistryError> for Error {
+    fn from(e: RegistryError) -> Self {
+        Error::Registry(e)
+    }
+}
+
+impl From<JsonError> for Error {
+    fn from(e: JsonError) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl From<SigningError> for Error {
+    fn from(e: SigningError) -> Self {
+        Error::Signing(e)
+    }
+}
+
+impl From<AddressError> for Error {
+    fn from(e: AddressError) -> Self {
+        Error::Address(e)
+    }
+}
+
+impl From<ProtoError> for Error {
+    fn from(e: ProtoError) -> Self {
+        Error::Proto(e)
+    }
+}
+
+const COMMANDS: &[(&str, &str)] = &[
+    (
+        "registry-stats",
+        "Print registry statistic (e.g Rust transition progress)",
+    ),
+    ("sign <coin> <input>", "Sign a transaction"),
+    (
+        "preimage-hashes <coin> <input>",
+        "Compute preimage hashes of a transaction for external signing",
+    ),
+    (
+        "compile <coin> <input> <sigs> <pubkeys>",
+        "Compile a transaction with comma-separated hex signatures and public keys",
+    ),
+    (
+        "plan <coin> <input>",
+        "Plan a transaction (UTXO chains only)",
+    ),
+    ("decode-tx <coin> <tx>", "Decode a hex-encoded transaction"),
+    (
+        "address derive <coin> <pubkey> [derivation]",
+        "Derive an address from a hex-encoded public key",
+    ),
+    (
+        "address validate <coin> <address>",
+        "Check whether an address is valid",
+    ),
+    (
+        "address normalize <coin> <address>",
+        "Validate and normalize an address",
+    ),
+    ("message sign <coin> <input>", "Sign a message"),
+    (
+        "message verify <coin> <input>",
+        "Verify a message signature",
+    ),
+    (
+        "serve [--socket <path>]",
+        "Serve JSON-RPC requests over stdin/stdout or a Unix socket",
+    ),
+];
+
 fn help() {
     println!("WalletCore binary tools:");
     println!();
-    println!("\tregistry-stats  Print registry statistic (e.g Rust transition progress)");
+    for (command, description) in COMMANDS {
+        println!("\t{command:<45}{description}");
+    }
+    println!();
+    println!("<coin> is either a numeric coin type (e.g 60) or a registry coin ID (e.g ethereum).");
+    println!("<input> is either a proto3 JSON object or a hex-encoded binary message.");
+    println!("Pass '-' as <input> to read it from stdin. The output is printed as JSON.");
+    println!("Errors are printed as {{\"error\": ...}} with a non-zero exit code.");
 }
 
-fn main() -> Result<(), Error> {
-    let args: Vec<String> = std::env::args().collect();
+/// Iterates over command line arguments.
+struct Args(std::vec::IntoIter<String>);
+
+impl Args {
+    fn next(&mut self) -> Result<String, Error> {
+        self.0.next().ok_or(Error::MissingArguments)
+    }
+
+    fn next_coin(&mut self) -> Result<Coin, Error> {
+        Coin::parse(&self.next()?)
+    }
+
+    /// Reads the input from the next argument or from stdin if the argument is `-`.
+    fn next_input(&mut self) -> Result<Json, Error> {
+        let mut input = self.next()?;
+        if input == "-" {
+            input.clear();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(Error::Io)?;
+        }
+
+        let input = input.trim();
+        if input.starts_with('{') {
+            serde_json::from_str(input).map_err(|_| Error::InvalidArgument { param: "input" })
+        } else {
+            Ok(Json::String(input.to_string()))
+        }
+    }
+
+    /// Parses the next argument as a comma-separated list of hex-encoded values.
+    fn next_hex_list(&mut self, param: &'static str) -> Result<Vec<Vec<u8>>, Error> {
+        self.next()?
+            .split(',')
+            .filter(|item| !item.trim().is_empty())
+            .map(|item| decode_hex(item, param))
+            .collect()
+    }
+}
 
+fn run_command(command: &str, args: &mut Args) -> Result<Json, Error> {
+    match command {
+        "sign" => {
+            let coin = args.next_coin()?;
+            commands::sign(&coin, &args.next_input()?)
+        },
+        "preimage-hashes" => {
+            let coin = args.next_coin()?;
+            commands::preimage_hashes(&coin, &args.next_input()?)
+        },
+        "compile" => {
+            let coin = args.next_coin()?;
+            let input = args.next_input()?;
+            let signatures = args.next_hex_list("signatures")?;
+            let public_keys = args.next_hex_list("public keys")?;
+            commands::compile(&coin, &input, signatures, public_keys)
+        },
+        "plan" => {
+            let coin = args.next_coin()?;
+            commands::plan(&coin, &args.next_input()?)
+        },
+        "decode-tx" => {
+            let coin = args.next_coin()?;
+            let tx = decode_hex(&args.next()?, "tx")?;
+            commands::decode_transaction(&coin, &tx)
+        },
+        "address" => match args.next()?.as_str() {
+            "derive" => {
+                let coin = args.next_coin()?;
+                let public_key = decode_hex(&args.next()?, "public key")?;
+                let derivation = match args.next() {
+                    Ok(derivation) => commands::parse_derivation(&derivation)?,
+                    Err(_) => Default::default(),
+                };
+                commands::derive_address(&coin, public_key, derivation)
+            },
+            "validate" => {
+                let coin = args.next_coin()?;
+                Ok(commands::validate_address(&coin, &args.next()?))
+            },
+            "normalize" => {
+                let coin = args.next_coin()?;
+                commands::normalize_address(&coin, &args.next()?)
+            },
+            _ => Err(Error::UnknownCommand),
+        },
+        "message" => match args.next()?.as_str() {
+            "sign" => {
+                let coin = args.next_coin()?;
+                commands::sign_message(&coin, &args.next_input()?)
+            },
+            "verify" => {
+                let coin = args.next_coin()?;
+                commands::verify_message(&coin, &args.next_input()?)
+            },
+            _ => Err(Error::UnknownCommand),
+        },
+        _ => Err(Error::UnknownCommand),
+    }
+}
+
+/// Checks whether the output is a `SigningOutput` or a similar message that reports an error.
+/// Default values are omitted in the proto3 JSON, so the `error` field is present only if it's not `OK`.
+fn output_has_error(output: &Json) -> bool {
+    match output.get("error") {
+        None | Some(Json::Null) => false,
+        Some(Json::String(error)) => !error.is_empty() && error != "OK",
+        Some(_) => true,
+    }
+}
+
+fn run(args: &[String]) -> Result<ExitCode, Error> {
     if args.len() < 2 {
         help();
         return Err(Error::MissingArguments);
     }
 
     match args[1].as_str() {
-        "registry-stats" => registry_stats::registry_stats(),
-        _ => {
-            help();
-            Err(Error::UnknownCommand)
+        "registry-stats" => return registry_stats::registry_stats().map(|_| ExitCode::SUCCESS),
+        "serve" => {
+            let result = match args.get(2).map(String::as_str) {
+                None => serve::serve_stdio(),
+                Some("--socket") => {
+                    let path = args.get(3).ok_or(Error::MissingArguments)?;
+                    serve::serve_unix_socket(path)
+                },
+                Some(_) => {
+                    help();
+                    Err(Error::UnknownCommand)
+                },
+            };
+            return result.map(|_| ExitCode::SUCCESS);
         },
+        _ => (),
+    }
+
+    let mut command_args = Args(args[2..].to_vec().into_iter());
+    let output = run_command(&args[1], &mut command_args).map_err(|e| {
+        if matches!(e, Error::MissingArguments | Error::UnknownCommand) {
+            help();
+        }
+        e
+    })?;
+
+    println!("{output:#}");
+    if output_has_error(&output) {
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Prints the output or the error as JSON.
+/// Exits with a non-zero code if the command fails or the output reports an error.
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+    run(&args).unwrap_or_else(|e| {
+        println!("{:#}", json!({ "error": e.to_string() }));
+        ExitCode::FAILURE
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_has_error() {
+        assert!(!output_has_error(&json!({ "encoded": "0x00" })));
+        assert!(!output_has_error(&json!({ "error": "OK" })));
+        assert!(output_has_error(
+            &json!({ "error": "Error_invalid_params", "errorMessage": "Invalid params" })
+        ));
+        // Unknown enum values are represented as numbers.
+        assert!(output_has_error(&json!({ "error": 100 })));
     }
 }
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_registry::blockchain_type::BlockchainType;
+
+const TX_COMPILER_PACKAGE: &str = "TxCompiler";
+
+/// Fully qualified names of the Protobuf messages used by a blockchain implementation.
+/// Required to convert the messages to/from the proto3 JSON representation.
+pub struct ProtoTypes {
+    /// Package of the `SigningInput`, `SigningOutput` and message signing types, e.g `Ethereum`.
+    package: &'static str,
+    /// Package of the `PreSigningOutput` message.
+    pre_signing_package: &'static str,
+    /// Whether the blockchain supports transaction planning.
+    supports_plan: bool,
+}
+
+impl ProtoTypes {
+    /// Returns `None` if the blockchain is not supported in Rust.
+    pub fn for_blockchain(blockchain: BlockchainType) -> Option<ProtoTypes> {
+        let types = |package| ProtoTypes {
+            package,
+            pre_signing_package: TX_COMPILER_PACKAGE,
+            supports_plan: false,
+        };
+
+        let proto_types = match blockchain {
+            BlockchainType::Aptos => types("Aptos"),
+            BlockchainType::Binance => types("Binance"),
+            BlockchainType::Bitcoin => ProtoTypes {
+                pre_signing_package: "BitcoinV2",
+                supports_plan: true,
+                ..types("BitcoinV2")
+            },
+            BlockchainType::Cosmos
+            | BlockchainType::NativeEvmos
+            | BlockchainType::NativeInjective
+            | BlockchainType::Thorchain => types("Cosmos"),
+            BlockchainType::Ethereum | BlockchainType::Ronin => types("Ethereum"),
+            BlockchainType::Greenfield => types("Greenfield"),
+            BlockchainType::InternetComputer => types("InternetComputer"),
+            BlockchainType::Solana => ProtoTypes {
+                pre_signing_package: "Solana",
+                ..types("Solana")
+            },
+            BlockchainType::Sui => types("Sui"),
+            BlockchainType::Unsupported => return None,
+        };
+        Some(proto_types)
+    }
+
+    pub fn signing_input(&self) -> String {
+        message_path(self.package, "SigningInput")
+    }
+
+    pub fn signing_output(&self) -> String {
+        message_path(self.package, "SigningOutput")
+    }
+
+    pub fn pre_signing_output(&self) -> String {
+        message_path(self.pre_signing_package, "PreSigningOutput")
+    }
+
+    /// Returns `None` if the blockchain does not support transaction planning.
+    pub fn transaction_plan(&self) -> Option<String> {
+        self.supports_plan
+            .then(|| message_path(self.package, "TransactionPlan"))
+    }
+
+    pub fn decoding_output(&self) -> String {
+        message_path(self.package, "DecodingTransactionOutput")
+    }
+
+    pub fn message_signing_input(&self) -> String {
+        message_path(self.package, "MessageSigningInput")
+    }
+
+    pub fn message_signing_output(&self) -> String {
+        message_path(self.package, "MessageSigningOutput")
+    }
+
+    pub fn message_verifying_input(&self) -> String {
+        message_path(self.package, "MessageVerifyingInput")
+    }
+}
+
+fn message_path(package: &str, message: &str) -> String {
+    format!("TW.{package}.Proto.{message}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_any_coin::any_signer::AnySigner;
+    use tw_any_coin::transaction_decoder::TransactionDecoder;
+    use tw_coin_registry::registry::supported_coin_items;
+    use tw_proto::json::descriptor::find_message;
+    use tw_proto::json::proto_to_json;
+
+    /// Checks that the [`ProtoTypes`] table matches the actual blockchain implementations.
+    #[test]
+    fn test_proto_types_match_blockchains() {
+        for item in supported_coin_items() {
+            let coin_id = &item.id;
+            let proto_types = ProtoTypes::for_blockchain(item.blockchain)
+                .unwrap_or_else(|| panic!("No proto types for '{coin_id}'"));
+
+            for message in [
+                proto_types.signing_input(),
+                proto_types.signing_output(),
+                proto_types.pre_signing_output(),
+            ] {
+                assert!(
+                    find_message(&message).is_some(),
+                    "'{message}' not found for '{coin_id}'"
+                );
+            }
+
+            // Plan an empty input to check if the blockchain supports planning.
+            let supports_plan = AnySigner::plan(&[], item.coin_id).is_ok();
+            assert_eq!(
+                proto_types.supports_plan, supports_plan,
+                "Unexpected plan support for '{coin_id}'"
+            );
+            if let Some(plan) = proto_types.transaction_plan() {
+                assert!(find_message(&plan).is_some(), "'{plan}' not found");
+            }
+
+            // Decode an invalid transaction, so the decoder returns an output with an error.
+            // The `error` field is decoded as expected only if the output type is correct.
+            if let Ok(output) = TransactionDecoder::decode_transaction(item.coin_id, &[0xFF]) {
+                let decoding_output = proto_types.decoding_output();
+                let output = proto_to_json(&decoding_output, &output).unwrap_or_else(|e| {
+                    panic!("Error decoding '{decoding_output}' for '{coin_id}': {e}")
+                });
+                assert!(
+                    output.get("error").is_some_and(|error| error.is_string()),
+                    "Unexpected '{decoding_output}' for '{coin_id}': {output}"
+                );
+            }
+        }
+    }
+}
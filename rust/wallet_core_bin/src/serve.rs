@@ -0,0 +1,453 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! JSON-RPC 2.0 server over stdin/stdout or a local Unix socket.
+//! https://www.jsonrpc.org/specification
+//!
+//! Requests and responses are newline-delimited JSON, so every request or batch must be written as a single line.
+//! Method params are passed by name, e.g:
+//! `{"jsonrpc":"2.0","id":1,"method":"validateAddress","params":{"coin":"ethereum","address":"0x..."}}`
+
+use crate::coin::{decode_hex, Coin};
+use crate::commands;
+use crate::Error;
+use serde_json::{json, Map as JsonMap, Value as Json};
+use std::io::{BufRead, BufReader, Write};
+use tw_memory::Data;
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// Implementation-defined server error, e.g a signing error.
+const SERVER_ERROR: i64 = -32000;
+
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> RpcError {
+        RpcError {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<Error> for RpcError {
+    fn from(e: Error) -> Self {
+        let code = match &e {
+            Error::MissingArguments
+            | Error::InvalidArgument { .. }
+            | Error::UnknownCoin
+            | Error::Registry(_)
+            | Error::Json(_) => INVALID_PARAMS,
+            _ => SERVER_ERROR,
+        };
+        RpcError::new(code, e.to_string())
+    }
+}
+
+/// Serves requests read from stdin until EOF.
+pub fn serve_stdio() -> Result<(), Error> {
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    serve_lines(stdin.lock(), stdout.lock())
+}
+
+/// Serves connections of the Unix socket bound to the given `path`.
+/// Every connection is served in a separate thread.
+#[cfg(unix)]
+pub fn serve_unix_socket(path: &str) -> Result<(), Error> {
+    use std::os::unix::net::UnixListener;
+
+    remove_stale_socket(path)?;
+    let listener = UnixListener::bind(path).map_err(Error::Io)?;
+    for stream in listener.incoming() {
+        let stream = stream.map_err(Error::Io)?;
+        std::thread::spawn(move || {
+            let reader = match stream.try_clone() {
+                Ok(reader) => BufReader::new(reader),
+                Err(_) => return,
+            };
+            // The connection is closed on an IO error, other connections are still served.
+            let _ = serve_lines(reader, stream);
+        });
+    }
+    Ok(())
+}
+
+/// Removes a socket file left by a server that is no longer running, so the `path` can be bound again.
+/// Fails if the `path` is not a socket, or if another server still listens on it.
+#[cfg(unix)]
+fn remove_stale_socket(path: &str) -> Result<(), Error> {
+    use std::io::ErrorKind;
+    use std::os::unix::fs::FileTypeExt;
+    use std::os::unix::net::UnixStream;
+
+    let metadata = match std::fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(Error::Io(e)),
+    };
+    if !metadata.file_type().is_socket() {
+        return Err(Error::InvalidArgument { param: "socket" });
+    }
+    if UnixStream::connect(path).is_ok() {
+        return Err(Error::Io(ErrorKind::AddrInUse.into()));
+    }
+    std::fs::remove_file(path).map_err(Error::Io)
+}
+
+#[cfg(not(unix))]
+pub fn serve_unix_socket(_path: &str) -> Result<(), Error> {
+    Err(Error::Unsupported)
+}
+
+fn serve_lines<R: BufRead, W: Write>(reader: R, mut writer: W) -> Result<(), Error> {
+    for line in reader.lines() {
+        let line = line.map_err(Error::Io)?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(response) = handle_message(&line) {
+            writeln!(writer, "{response}").map_err(Error::Io)?;
+            writer.flush().map_err(Error::Io)?;
+        }
+    }
+    Ok(())
+}
+
+/// Handles a single request or a batch of requests.
+/// Returns `None` if there is nothing to respond, i.e. the message contains notifications only.
+fn handle_message(message: &str) -> Option<Json> {
+    let message: Json = match serde_json::from_str(message) {
+        Ok(message) => message,
+        Err(e) => {
+            let error = RpcError::new(PARSE_ERROR, e.to_string());
+            return Some(error_response(Json::Null, error));
+        },
+    };
+
+    match message {
+        Json::Array(requests) if requests.is_empty() => Some(error_response(
+            Json::Null,
+            RpcError::new(INVALID_REQUEST, "Empty batch"),
+        )),
+        Json::Array(requests) => {
+            let responses: Vec<Json> = requests.iter().filter_map(handle_request).collect();
+            (!responses.is_empty()).then_some(Json::Array(responses))
+        },
+        request => handle_request(&request),
+    }
+}
+
+/// Returns `None` if the request is a valid notification, i.e. has no `id`.
+fn handle_request(request: &Json) -> Option<Json> {
+    let Some(request) = request.as_object() else {
+        let error = RpcError::new(INVALID_REQUEST, "Expected a request object");
+        return Some(error_response(Json::Null, error));
+    };
+
+    let id = request.get("id").cloned();
+    let (method, params) = match parse_request(request) {
+        Ok(parsed) => parsed,
+        // Invalid requests are always responded, with a null `id` if it is missing.
+        Err(error) => return Some(error_response(id.unwrap_or(Json::Null), error)),
+    };
+    let result = call(method, params);
+
+    // Notifications are not responded, even if they fail.
+    let id = id?;
+    Some(match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => error_response(id, error),
+    })
+}
+
+fn parse_request(request: &JsonMap<String, Json>) -> Result<(&str, Params<'_>), RpcError> {
+    if request.get("jsonrpc").and_then(Json::as_str) != Some("2.0") {
+        return Err(RpcError::new(INVALID_REQUEST, "Expected 'jsonrpc: 2.0'"));
+    }
+    let method = request
+        .get("method")
+        .and_then(Json::as_str)
+        .ok_or_else(|| RpcError::new(INVALID_REQUEST, "Expected a 'method' string"))?;
+
+    let params = match request.get("params") {
+        Some(Json::Object(params)) => Params(Some(params)),
+        None => Params(None),
+        Some(_) => {
+            return Err(RpcError::new(
+                INVALID_PARAMS,
+                "Expected params to be passed by name",
+            ))
+        },
+    };
+    Ok((method, params))
+}
+
+fn call(method: &str, params: Params<'_>) -> Result<Json, RpcError> {
+    let result = match method {
+        "sign" => commands::sign(&params.coin()?, params.get("input")?),
+        "compile" => commands::compile(
+            &params.coin()?,
+            params.get("input")?,
+            params.hex_list("signatures")?,
+            params.hex_list("publicKeys")?,
+        ),
+        "preimageHashes" => commands::preimage_hashes(&params.coin()?, params.get("input")?),
+        "plan" => commands::plan(&params.coin()?, params.get("input")?),
+        "decodeTransaction" => commands::decode_transaction(&params.coin()?, &params.hex("tx")?),
+        "deriveAddress" => {
+            let derivation = match params.0.and_then(|params| params.get("derivation")) {
+                Some(Json::String(derivation)) => commands::parse_derivation(derivation)?,
+                None | Some(Json::Null) => Default::default(),
+                Some(_) => {
+                    return Err(Error::InvalidArgument {
+                        param: "derivation",
+                    }
+                    .into())
+                },
+            };
+            commands::derive_address(&params.coin()?, params.hex("publicKey")?, derivation)
+        },
+        "validateAddress" => Ok(commands::validate_address(
+            &params.coin()?,
+            params.str("address")?,
+        )),
+        "abiEncodeFunction" => commands::abi_encode_function(&params.coin()?, params.get("input")?),
+        "abiDecodeContractCall" => {
+            commands::abi_decode_contract_call(&params.coin()?, params.get("input")?)
+        },
+        "abiDecodeParams" => commands::abi_decode_params(&params.coin()?, params.get("input")?),
+        "abiDecodeValue" => commands::abi_decode_value(&params.coin()?, params.get("input")?),
+        "abiFunctionSignature" => {
+            commands::abi_function_signature(&params.coin()?, params.get("input")?)
+        },
+        _ => {
+            return Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Unknown method: {method}"),
+            ))
+        },
+    };
+    result.map_err(RpcError::from)
+}
+
+fn error_response(id: Json, error: RpcError) -> Json {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": error.code, "message": error.message },
+    })
+}
+
+/// Named params of a request.
+struct Params<'a>(Option<&'a JsonMap<String, Json>>);
+
+impl<'a> Params<'a> {
+    fn get(&self, param: &'static str) -> Result<&'a Json, Error> {
+        self.0
+            .and_then(|params| params.get(param))
+            .ok_or(Error::InvalidArgument { param })
+    }
+
+    fn str(&self, param: &'static str) -> Result<&'a str, Error> {
+        self.get(param)?
+            .as_str()
+            .ok_or(Error::InvalidArgument { param })
+    }
+
+    fn hex(&self, param: &'static str) -> Result<Data, Error> {
+        decode_hex(self.str(param)?, param)
+    }
+
+    fn hex_list(&self, param: &'static str) -> Result<Vec<Data>, Error> {
+        self.get(param)?
+            .as_array()
+            .ok_or(Error::InvalidArgument { param })?
+            .iter()
+            .map(|item| {
+                let item = item.as_str().ok_or(Error::InvalidArgument { param })?;
+                decode_hex(item, param)
+            })
+            .collect()
+    }
+
+    /// The `coin` param is either a numeric coin type or a coin ID from the registry.
+    fn coin(&self) -> Result<Coin, Error> {
+        match self.get("coin")? {
+            Json::Number(coin) => {
+                let coin_id = coin
+                    .as_u64()
+                    .and_then(|coin| u32::try_from(coin).ok())
+                    .ok_or(Error::InvalidArgument { param: "coin" })?;
+                Coin::with_coin_id(coin_id)
+            },
+            Json::String(coin) => Coin::parse(coin),
+            _ => Err(Error::InvalidArgument { param: "coin" }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handle(message: Json) -> Option<Json> {
+        handle_message(&message.to_string())
+    }
+
+    #[test]
+    fn test_handle_parse_error() {
+        let response = handle_message(r#"{"jsonrpc": "2.0", "method""#).unwrap();
+        assert_eq!(response["id"], Json::Null);
+        assert_eq!(response["error"]["code"], PARSE_ERROR);
+    }
+
+    #[test]
+    fn test_handle_empty_batch() {
+        let response = handle(json!([])).unwrap();
+        assert_eq!(response["id"], Json::Null);
+        assert_eq!(response["error"]["code"], INVALID_REQUEST);
+    }
+
+    #[test]
+    fn test_handle_invalid_request() {
+        let response = handle(json!({ "method": "validateAddress" })).unwrap();
+        assert_eq!(response["id"], Json::Null);
+        assert_eq!(response["error"]["code"], INVALID_REQUEST);
+
+        let response = handle(json!({ "jsonrpc": "2.0", "id": 1 })).unwrap();
+        assert_eq!(response["id"], 1);
+        assert_eq!(response["error"]["code"], INVALID_REQUEST);
+
+        let response = handle(json!([1])).unwrap();
+        assert_eq!(response[0]["id"], Json::Null);
+        assert_eq!(response[0]["error"]["code"], INVALID_REQUEST);
+    }
+
+    #[test]
+    fn test_handle_notification() {
+        let notification = json!({
+            "jsonrpc": "2.0",
+            "method": "validateAddress",
+            "params": { "coin": "ethereum", "address": "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F" },
+        });
+        assert_eq!(handle(notification.clone()), None);
+        assert_eq!(handle(json!([notification])), None);
+
+        // Failed notifications are not responded too.
+        let unknown_method = json!({ "jsonrpc": "2.0", "method": "unknownMethod" });
+        assert_eq!(handle(unknown_method), None);
+    }
+
+    #[test]
+    fn test_handle_unknown_method() {
+        let response = handle(json!({ "jsonrpc": "2.0", "id": 1, "method": "unknownMethod" }));
+        assert_eq!(
+            response,
+            Some(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "error": { "code": METHOD_NOT_FOUND, "message": "Unknown method: unknownMethod" },
+            }))
+        );
+    }
+
+    #[test]
+    fn test_handle_invalid_params() {
+        let response = handle(json!({
+            "jsonrpc": "2.0",
+            "id": "validate",
+            "method": "validateAddress",
+            "params": { "coin": "ethereum" },
+        }))
+        .unwrap();
+        assert_eq!(response["id"], "validate");
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+        assert_eq!(response["error"]["message"], "Invalid argument: address");
+    }
+
+    #[test]
+    fn test_handle_validate_address() {
+        let response = handle(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "validateAddress",
+            "params": { "coin": "ethereum", "address": "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F" },
+        }));
+        assert_eq!(
+            response,
+            Some(json!({ "jsonrpc": "2.0", "id": 1, "result": { "valid": true } }))
+        );
+    }
+
+    #[test]
+    fn test_handle_batch() {
+        let response = handle(json!([
+            {
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "validateAddress",
+                "params": { "coin": 60, "address": "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F" },
+            },
+            { "jsonrpc": "2.0", "method": "unknownMethod" },
+            {
+                "jsonrpc": "2.0",
+                "id": 2,
+                "method": "validateAddress",
+                "params": { "coin": 60, "address": "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4" },
+            },
+        ]));
+        assert_eq!(
+            response,
+            Some(json!([
+                { "jsonrpc": "2.0", "id": 1, "result": { "valid": true } },
+                { "jsonrpc": "2.0", "id": 2, "result": { "valid": false } },
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_signing_error_message() {
+        let response = handle(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "plan",
+            "params": { "coin": "bitcoin", "input": "ff" },
+        }))
+        .unwrap();
+        assert_eq!(response["error"]["code"], SERVER_ERROR);
+        let message = response["error"]["message"].as_str().unwrap();
+        assert!(
+            message.starts_with("Some input field cannot be parsed"),
+            "{message}"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_remove_stale_socket() {
+        use std::os::unix::net::UnixListener;
+
+        let path = std::env::temp_dir().join(format!("wallet-core-{}.sock", std::process::id()));
+        let path_str = path.to_str().unwrap();
+        // Not existing socket files are ignored.
+        remove_stale_socket(path_str).unwrap();
+
+        let listener = UnixListener::bind(&path).unwrap();
+        remove_stale_socket(path_str).unwrap_err();
+
+        // The socket file is left after the listener is closed.
+        drop(listener);
+        assert!(path.exists());
+        remove_stale_socket(path_str).unwrap();
+        assert!(!path.exists());
+    }
+}
@@ -4,7 +4,6 @@
 
 #![allow(clippy::missing_safety_doc)]
 
-use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::dispatcher::evm_dispatcher;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::tw_string::TWString;
@@ -21,7 +20,6 @@ pub unsafe extern "C" fn tw_ethereum_abi_decode_contract_call(
     coin: u32,
     input: *const TWData,
 ) -> *mut TWData {
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
     let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
     let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
 
@@ -41,7 +39,6 @@ pub unsafe extern "C" fn tw_ethereum_abi_decode_params(
     coin: u32,
     input: *const TWData,
 ) -> *mut TWData {
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
     let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
     let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
 
@@ -61,7 +58,6 @@ pub unsafe extern "C" fn tw_ethereum_abi_function_get_signature(
     coin: u32,
     input: *const TWData,
 ) -> *mut TWString {
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
     let input_data = try_or_else!(TWData::from_ptr_as_ref(input), || TWString::new()
         .into_ptr());
     let evm_dispatcher = try_or_else!(evm_dispatcher(coin), || TWString::new().into_ptr());
@@ -82,7 +78,6 @@ pub unsafe extern "C" fn tw_ethereum_abi_encode_function(
     coin: u32,
     input: *const TWData,
 ) -> *mut TWData {
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
     let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
     let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
 
@@ -102,7 +97,6 @@ pub unsafe extern "C" fn tw_ethereum_abi_decode_value(
     coin: u32,
     input: *const TWData,
 ) -> *mut TWData {
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
     let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
     let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
 
@@ -4,7 +4,6 @@
 
 #![allow(clippy::missing_safety_doc)]
 
-use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::dispatcher::evm_dispatcher;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::RawPtrTrait;
@@ -17,7 +16,6 @@ use tw_misc::try_or_else;
 /// \return serialized `EthereumRlp::Proto::EncodingOutput`.
 #[no_mangle]
 pub unsafe extern "C" fn tw_ethereum_rlp_encode(coin: u32, input: *const TWData) -> *mut TWData {
-    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
     let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
     let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
     evm_dispatcher
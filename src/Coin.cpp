@@ -5,6 +5,8 @@
 #include "Coin.h"
 
 #include "CoinEntry.h"
+#include "rust/RustCoinEntry.h"
+#include "rust/Wrapper.h"
 #include <TrustWalletCore/TWCoinTypeConfiguration.h>
 #include <TrustWalletCore/TWHRP.h>
 
@@ -127,7 +129,14 @@ NativeEvmos::Entry NativeEvmosDP;
 NativeInjective::Entry NativeInjectiveDP;
 // end_of_coin_dipatcher_declarations_marker_do_not_modify
 
+// Custom coins registered at runtime via `TWCustomCoinRegistryRegister` are implemented in Rust.
+Rust::RustCoinEntry customCoinDP;
+
 CoinEntry* coinDispatcher(TWCoinType coinType) {
+    if (Rust::tw_coin_registry_is_custom_coin(static_cast<uint32_t>(coinType))) {
+        return &customCoinDP;
+    }
+
     // switch is preferred instead of a data structure, due to initialization issues
     CoinEntry* entry = nullptr;
     const auto blockchain = TW::blockchain(coinType);
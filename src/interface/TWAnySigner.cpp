@@ -5,6 +5,8 @@
 #include <TrustWalletCore/TWAnySigner.h>
 
 #include "Coin.h"
+#include "DataVector.h"
+#include "rust/Wrapper.h"
 
 using namespace TW;
 
@@ -15,6 +17,14 @@ TWData* _Nonnull TWAnySignerSign(TWData* _Nonnull data, enum TWCoinType coin) {
     return TWDataCreateWithBytes(dataOut.data(), dataOut.size());
 }
 
+TWData* _Nonnull TWAnySignerSignBatch(const struct TWDataVector* _Nonnull inputs, enum TWCoinType coin) {
+    const Rust::TWDataVectorWrapper inputsVec = createFromTWDataVector(inputs);
+    const Rust::TWDataWrapper output = Rust::tw_any_signer_sign_batch(inputsVec.get(), static_cast<uint32_t>(coin));
+
+    const auto outputData = output.toDataOrDefault();
+    return TWDataCreateWithBytes(outputData.data(), outputData.size());
+}
+
 TWString *_Nonnull TWAnySignerSignJSON(TWString *_Nonnull json, TWData *_Nonnull key, enum TWCoinType coin) {
     const Data& keyData = *(reinterpret_cast<const Data*>(key));
     const std::string& jsonString = *(reinterpret_cast<const std::string*>(json));
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include "TrustWalletCore/TWCustomCoinRegistry.h"
+#include "rust/Wrapper.h"
+
+using namespace TW;
+
+bool TWCustomCoinRegistryRegister(TWString *_Nonnull itemJson) {
+    const auto& itemJsonRef = *reinterpret_cast<const std::string*>(itemJson);
+    const Rust::TWStringWrapper itemJsonPtr = itemJsonRef;
+    return Rust::tw_coin_registry_register_custom_coin(itemJsonPtr.get());
+}
+
+bool TWCustomCoinRegistryUnregister(uint32_t coinType) {
+    return Rust::tw_coin_registry_unregister_custom_coin(coinType);
+}
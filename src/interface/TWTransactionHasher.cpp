@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include "TrustWalletCore/TWTransactionHasher.h"
+#include "rust/Wrapper.h"
+
+using namespace TW;
+
+TWString *_Nullable TWTransactionHasherTxId(enum TWCoinType coinType, TWData *_Nonnull signedTx) {
+    const Data& signedTxData = *(reinterpret_cast<const Data*>(signedTx));
+
+    const Rust::TWDataWrapper signedTxPtr(signedTxData);
+    const Rust::TWStringWrapper txIdPtr = Rust::tw_transaction_hasher_tx_id(static_cast<uint32_t>(coinType), signedTxPtr.get());
+
+    if (!txIdPtr) {
+        return nullptr;
+    }
+    return TWStringCreateWithUTF8Bytes(txIdPtr.c_str());
+}
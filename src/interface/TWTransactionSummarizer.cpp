@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include "TrustWalletCore/TWTransactionSummarizer.h"
+#include "rust/Wrapper.h"
+
+using namespace TW;
+
+TWData *_Nonnull TWTransactionSummarizerSummarize(enum TWCoinType coinType, TWData *_Nonnull input, TWData *_Nonnull tokens) {
+    const Data& inputData = *(reinterpret_cast<const Data*>(input));
+    const Data& tokensData = *(reinterpret_cast<const Data*>(tokens));
+
+    const Rust::TWDataWrapper inputDataPtr(inputData);
+    const Rust::TWDataWrapper tokensDataPtr(tokensData);
+    const Rust::TWDataWrapper outputDataPtr = Rust::tw_transaction_summarizer_summarize(static_cast<uint32_t>(coinType), inputDataPtr.get(), tokensDataPtr.get());
+
+    const auto outputData = outputDataPtr.toDataOrDefault();
+    return TWDataCreateWithBytes(outputData.data(), outputData.size());
+}
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include "TrustWalletCore/TWTransactionVerifier.h"
+#include "rust/Wrapper.h"
+
+using namespace TW;
+
+TWData *_Nonnull TWTransactionVerifierVerify(enum TWCoinType coinType, TWData *_Nonnull input) {
+    const Data& inputData = *(reinterpret_cast<const Data*>(input));
+
+    const Rust::TWDataWrapper inputDataPtr(inputData);
+    const Rust::TWDataWrapper outputDataPtr = Rust::tw_transaction_verifier_verify(static_cast<uint32_t>(coinType), inputDataPtr.get());
+
+    const auto outputData = outputDataPtr.toDataOrDefault();
+    return TWDataCreateWithBytes(outputData.data(), outputData.size());
+}
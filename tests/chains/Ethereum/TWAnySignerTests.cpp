@@ -4,8 +4,10 @@
 
 #include "TestUtilities.h"
 #include <TrustWalletCore/TWAnySigner.h>
+#include <TrustWalletCore/TWDataVector.h>
 #include "HexCoding.h"
 #include "uint256.h"
+#include "proto/BatchSigner.pb.h"
 #include "proto/Ethereum.pb.h"
 #include "Ethereum/ABI/Function.h"
 #include "PrivateKey.h"
@@ -301,6 +303,47 @@ TEST(TWAnySignerEthereum, SignJSON) {
     assertStringsEqual(result, "f86a8084d693a400825208947d8bf18c7ce84b3e175b339c4ca93aed1dd166f1870348bca5a160008025a0fe5802b49e04c6b1705088310e133605ed8b549811a18968ad409ea02ad79f21a05bf845646fb1e1b9365f63a7fd5eb5e984094e3ed35c3bed7361aebbcbf41f10");
 }
 
+TEST(TWAnySignerEthereum, SignBatch) {
+    // https://eips.ethereum.org/EIPS/eip-155#example
+    Proto::SigningInput input;
+    auto chainId = store(uint256_t(1));
+    auto nonce = store(uint256_t(9));
+    auto gasPrice = store(uint256_t(20000000000));
+    auto gasLimit = store(uint256_t(21000));
+    auto amount = store(uint256_t(1000000000000000000));
+    auto key = parse_hex("0x4646464646464646464646464646464646464646464646464646464646464646");
+
+    input.set_chain_id(chainId.data(), chainId.size());
+    input.set_nonce(nonce.data(), nonce.size());
+    input.set_gas_price(gasPrice.data(), gasPrice.size());
+    input.set_gas_limit(gasLimit.data(), gasLimit.size());
+    input.set_to_address("0x3535353535353535353535353535353535353535");
+    input.set_private_key(key.data(), key.size());
+    auto& transfer = *input.mutable_transaction()->mutable_transfer();
+    transfer.set_amount(amount.data(), amount.size());
+
+    const auto inputData = input.SerializeAsString();
+    const Data invalidInput = {0xFF};
+
+    auto inputs = WRAP(TWDataVector, TWDataVectorCreate());
+    TWDataVectorAdd(inputs.get(), WRAPD(TWDataCreateWithBytes((const uint8_t*)inputData.data(), inputData.size())).get());
+    TWDataVectorAdd(inputs.get(), WRAPD(TWDataCreateWithBytes(invalidInput.data(), invalidInput.size())).get());
+
+    auto outputData = WRAPD(TWAnySignerSignBatch(inputs.get(), TWCoinTypeEthereum));
+    BatchSigner::Proto::BatchSigningOutput output;
+    ASSERT_TRUE(output.ParseFromArray(TWDataBytes(outputData.get()), static_cast<int>(TWDataSize(outputData.get()))));
+
+    EXPECT_EQ(output.error(), Common::Proto::OK);
+    ASSERT_EQ(output.results_size(), 2);
+
+    Proto::SigningOutput signingOutput;
+    ASSERT_TRUE(signingOutput.ParseFromString(output.results(0).output()));
+    EXPECT_EQ(hex(signingOutput.encoded()), "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83");
+
+    EXPECT_NE(output.results(1).error(), Common::Proto::OK);
+    EXPECT_TRUE(output.results(1).output().empty());
+}
+
 TEST(TWAnySignerEthereum, PlanNotSupported) {
     // Ethereum does not use plan(), call it nonetheless
     Proto::SigningInput input;
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include "TestUtilities.h"
+
+#include "HexCoding.h"
+#include "uint256.h"
+#include "proto/Ethereum.pb.h"
+#include <TrustWalletCore/TWAnyAddress.h>
+#include <TrustWalletCore/TWAnySigner.h>
+#include <TrustWalletCore/TWCustomCoinRegistry.h>
+
+#include <gtest/gtest.h>
+
+using namespace TW;
+
+namespace {
+
+const auto customEvmCoin = static_cast<TWCoinType>(90000101);
+
+const auto customEvmCoinJson = R"({
+    "id": "custom-evm",
+    "name": "Custom EVM",
+    "coinId": 90000101,
+    "symbol": "CEVM",
+    "decimals": 18,
+    "blockchain": "Ethereum",
+    "chainId": "1",
+    "derivation": [{ "path": "m/44'/60'/0'/0/0" }],
+    "publicKeyType": "secp256k1Extended",
+    "addressHasher": "keccak256"
+})";
+
+} // namespace
+
+TEST(TWCustomCoinRegistry, EvmAddressAndSign) {
+    ASSERT_TRUE(TWCustomCoinRegistryRegister(STRING(customEvmCoinJson).get()));
+
+    const auto address = STRING("0x3535353535353535353535353535353535353535");
+    EXPECT_TRUE(TWAnyAddressIsValid(address.get(), customEvmCoin));
+    EXPECT_FALSE(TWAnyAddressIsValid(STRING("cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx").get(), customEvmCoin));
+
+    // The chain ID is taken from the coin item.
+    Ethereum::Proto::SigningInput input;
+    auto nonce = store(uint256_t(9));
+    auto gasPrice = store(uint256_t(20000000000));
+    auto gasLimit = store(uint256_t(21000));
+    auto amount = store(uint256_t(1000000000000000000));
+    auto key = parse_hex("4646464646464646464646464646464646464646464646464646464646464646");
+    input.set_nonce(nonce.data(), nonce.size());
+    input.set_gas_price(gasPrice.data(), gasPrice.size());
+    input.set_gas_limit(gasLimit.data(), gasLimit.size());
+    input.set_to_address("0x3535353535353535353535353535353535353535");
+    input.set_private_key(key.data(), key.size());
+    input.mutable_transaction()->mutable_transfer()->set_amount(amount.data(), amount.size());
+
+    Ethereum::Proto::SigningOutput output;
+    ANY_SIGN(input, customEvmCoin);
+    EXPECT_EQ(output.error(), Common::Proto::OK);
+    // The same transaction as signed with `TWCoinTypeEthereum`.
+    EXPECT_EQ(hex(output.encoded()), "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83");
+
+    ASSERT_TRUE(TWCustomCoinRegistryUnregister(customEvmCoin));
+    EXPECT_FALSE(TWCustomCoinRegistryUnregister(customEvmCoin));
+}
+
+TEST(TWCustomCoinRegistry, Invalid) {
+    // Conflicts with a built-in coin type.
+    auto ethereumJson = std::string(customEvmCoinJson);
+    ethereumJson.replace(ethereumJson.find("90000101"), 8, "60");
+    EXPECT_FALSE(TWCustomCoinRegistryRegister(STRING(ethereumJson.c_str()).get()));
+
+    EXPECT_FALSE(TWCustomCoinRegistryRegister(STRING("{}").get()));
+    EXPECT_FALSE(TWCustomCoinRegistryRegister(STRING("not a json").get()));
+}